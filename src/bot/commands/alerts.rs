@@ -0,0 +1,194 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! Commands for notifications about replies and reactions to your messages.
+
+use std::fmt::Write as _;
+
+use anyhow::{bail, Context as _, Result};
+use serenity::{
+	client::Context,
+	model::{
+		application::interaction::application_command::ApplicationCommandInteraction as Command,
+		channel::ReactionType,
+	},
+};
+
+use crate::{
+	bot::{highlighting::emoji_key, i18n::locale, util::respond_eph},
+	db::{ReactionAlert, ReplyAlert},
+};
+
+/// Maximum number of emoji one user can add reaction alerts for.
+const MAX_REACTION_ALERTS: usize = 10;
+
+/// Enable or disable notifications about replies that don't mention you.
+///
+/// Usage: `/reply-alerts <enabled>`
+#[tracing::instrument(
+	skip_all,
+	fields(
+		user_id = %command.user.id,
+		channel_id = %command.channel_id,
+		command = %command.data.name,
+	)
+)]
+pub(crate) async fn reply_alerts(ctx: Context, command: Command) -> Result<()> {
+	check_opt_out!(ctx, command);
+	let locale = locale(&command.locale);
+
+	let enabled = command
+		.data
+		.options
+		.iter()
+		.find(|o| o.name == "enabled")
+		.and_then(|o| o.value.as_ref())
+		.context("No enabled option provided")?
+		.as_bool()
+		.context("Enabled option was not a boolean")?;
+
+	let alert = ReplyAlert {
+		user_id: command.user.id,
+	};
+
+	let key = match (enabled, alert.clone().exists().await?) {
+		(true, true) => "reply-alerts-already-enabled",
+		(false, false) => "reply-alerts-already-disabled",
+		(true, false) => {
+			alert.insert().await?;
+			"reply-alerts-enabled"
+		}
+		(false, true) => {
+			alert.delete().await?;
+			"reply-alerts-disabled"
+		}
+	};
+
+	respond_eph(&ctx, &command, locale.get(key)).await
+}
+
+/// Add, remove, or list emoji to be notified of when they're added as
+/// reactions to your messages.
+///
+/// Usage:
+/// - `/reaction-alerts add <emoji>`
+/// - `/reaction-alerts remove <emoji>`
+/// - `/reaction-alerts list`
+#[tracing::instrument(
+	skip_all,
+	fields(
+		user_id = %command.user.id,
+		channel_id = %command.channel_id,
+		command = %command.data.name,
+	)
+)]
+pub(crate) async fn reaction_alerts(
+	ctx: Context,
+	command: Command,
+) -> Result<()> {
+	check_opt_out!(ctx, command);
+	let user_id = command.user.id;
+	let locale = locale(&command.locale);
+
+	let subcommand = command
+		.data
+		.options
+		.first()
+		.context("No reaction alerts subcommand provided")?;
+
+	let alerts = ReactionAlert::user_alerts(user_id).await?;
+
+	if subcommand.name == "list" {
+		if alerts.is_empty() {
+			return respond_eph(
+				&ctx,
+				&command,
+				locale.get("reaction-alerts-empty"),
+			)
+			.await;
+		}
+
+		let mut response = format!("{}\n", locale.get("reaction-alerts-title"));
+
+		for alert in alerts {
+			write!(response, "{} ", display_emoji(&alert.emoji)).unwrap();
+		}
+
+		return respond_eph(&ctx, &command, response.trim_end()).await;
+	}
+
+	let input = subcommand
+		.options
+		.iter()
+		.find(|o| o.name == "emoji")
+		.and_then(|o| o.value.as_ref())
+		.context("No emoji provided")?
+		.as_str()
+		.context("Emoji provided was not a string")?
+		.trim();
+
+	let emoji = match parse_emoji(input) {
+		Some(emoji) => emoji,
+		None => {
+			return respond_eph(
+				&ctx,
+				&command,
+				locale.get("reaction-alert-invalid"),
+			)
+			.await
+		}
+	};
+
+	let display = display_emoji(&emoji);
+	let alert = ReactionAlert { user_id, emoji };
+	let exists = alerts.iter().any(|a| a.emoji == alert.emoji);
+
+	let key = match subcommand.name.as_str() {
+		"add" if exists => "reaction-alert-exists",
+		"add" if alerts.len() >= MAX_REACTION_ALERTS => "reaction-alerts-limit",
+		"add" => {
+			alert.insert().await?;
+			"reaction-alert-added"
+		}
+		"remove" if !exists => "reaction-alert-missing",
+		"remove" => {
+			alert.delete().await?;
+			"reaction-alert-removed"
+		}
+		other => bail!("Unknown reaction alerts subcommand {}", other),
+	};
+
+	let response = locale
+		.format(key, &[("emoji", &display), ("max", &MAX_REACTION_ALERTS)]);
+
+	respond_eph(&ctx, &command, response).await
+}
+
+/// Parses an emoji entered by a user into the key its reaction alerts are
+/// stored with.
+///
+/// Returns `None` if the input isn't a single unicode or custom emoji.
+fn parse_emoji(input: &str) -> Option<String> {
+	let emoji = ReactionType::try_from(input).ok()?;
+
+	if let ReactionType::Unicode(emoji) = &emoji {
+		if emoji.is_ascii()
+			|| emoji.chars().count() > 8
+			|| emoji
+				.chars()
+				.any(|c| c.is_ascii_alphabetic() || c.is_whitespace())
+		{
+			return None;
+		}
+	}
+
+	emoji_key(&emoji)
+}
+
+/// Formats the key of a reaction alert as an emoji for a message.
+fn display_emoji(emoji: &str) -> String {
+	match emoji.parse::<u64>() {
+		Ok(id) => format!("<:emoji:{id}>"),
+		Err(_) => emoji.to_owned(),
+	}
+}
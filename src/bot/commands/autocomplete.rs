@@ -0,0 +1,227 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! Suggestions for command options as the user types them.
+
+use std::collections::BTreeSet;
+
+use anyhow::{Context as _, Result};
+use serenity::{
+	client::Context,
+	model::{
+		application::interaction::autocomplete::AutocompleteInteraction,
+		id::{ChannelId, GuildId},
+	},
+};
+
+use super::COMMAND_INFO;
+use crate::{
+	bot::i18n::locale,
+	db::{Ignore, Keyword, KeywordKind, KeywordPack, PackSubscription},
+};
+
+/// Maximum number of suggestions Discord shows.
+const MAX_CHOICES: usize = 25;
+
+/// Maximum length of the name and value of a suggestion.
+const MAX_CHOICE_LENGTH: usize = 100;
+
+/// Suggest values for the option the user is typing.
+#[tracing::instrument(
+	skip_all,
+	fields(
+		user_id = %interaction.user.id,
+		channel_id = %interaction.channel_id,
+		command = %interaction.data.name,
+	)
+)]
+pub(crate) async fn autocomplete(
+	ctx: Context,
+	interaction: AutocompleteInteraction,
+) -> Result<()> {
+	let focused = match interaction.data.options.iter().find(|o| o.focused) {
+		Some(option) => option,
+		None => return Ok(()),
+	};
+
+	let typed = focused
+		.value
+		.as_ref()
+		.and_then(|v| v.as_str())
+		.unwrap_or_default()
+		.trim()
+		.to_lowercase();
+
+	let choices = match (interaction.data.name.as_str(), interaction.guild_id) {
+		("remove" | "edit-keyword", Some(guild_id)) => {
+			keyword_choices(&interaction, guild_id).await?
+		}
+		("unignore", Some(guild_id)) => {
+			ignore_choices(&interaction, guild_id).await?
+		}
+		("subscribe", Some(guild_id)) => KeywordPack::guild_packs(guild_id)
+			.await?
+			.into_iter()
+			.map(|pack| (pack.name.clone(), pack.name))
+			.collect(),
+		("unsubscribe", Some(guild_id)) => {
+			PackSubscription::user_subscriptions(interaction.user.id)
+				.await?
+				.into_iter()
+				.filter(|subscription| subscription.guild_id == guild_id)
+				.map(|subscription| {
+					(subscription.pack.clone(), subscription.pack)
+				})
+				.collect()
+		}
+		("remove-server", _) => guild_choices(&ctx, &interaction, None).await?,
+		("copy-server", current) => {
+			guild_choices(&ctx, &interaction, current).await?
+		}
+		("keywords" | "ignores" | "mutes", _) if focused.name == "server" => {
+			guild_choices(&ctx, &interaction, None).await?
+		}
+		("help", _) => COMMAND_INFO
+			.iter()
+			.map(|info| (info.name.to_owned(), info.name.to_owned()))
+			.collect(),
+		_ => Vec::new(),
+	};
+
+	let mut choices = choices
+		.into_iter()
+		.filter(|(name, value)| {
+			value.len() <= MAX_CHOICE_LENGTH
+				&& (name.to_lowercase().contains(&typed)
+					|| value.contains(&typed))
+		})
+		.collect::<Vec<_>>();
+
+	// suggestions starting with what the user typed come first
+	choices.sort_by_key(|(name, _)| !name.to_lowercase().starts_with(&typed));
+	choices.truncate(MAX_CHOICES);
+
+	interaction
+		.create_autocomplete_response(&ctx, |r| {
+			for (name, value) in choices {
+				r.add_string_choice(
+					name.chars().take(MAX_CHOICE_LENGTH).collect::<String>(),
+					value,
+				);
+			}
+			r
+		})
+		.await
+		.context("Failed to send autocomplete suggestions")
+}
+
+/// Suggests the user's keywords in the channel given to `/remove` or
+/// `/edit-keyword`, or their server-wide keywords if no channel was given.
+async fn keyword_choices(
+	interaction: &AutocompleteInteraction,
+	guild_id: GuildId,
+) -> Result<Vec<(String, String)>> {
+	let user_id = interaction.user.id;
+
+	let channel_id = interaction
+		.data
+		.options
+		.iter()
+		.find(|o| o.name == "channel")
+		.and_then(|o| o.value.as_ref())
+		.and_then(|v| v.as_str())
+		.and_then(|id| id.parse().ok())
+		.map(ChannelId);
+
+	let keywords = match channel_id {
+		Some(channel_id) => Keyword::user_channel_keywords(user_id)
+			.await?
+			.into_iter()
+			.filter(|keyword| {
+				matches!(
+					keyword.kind,
+					KeywordKind::Channel(id) if id == channel_id
+				)
+			})
+			.collect(),
+		None => Keyword::user_guild_keywords(user_id, guild_id).await?,
+	};
+
+	Ok(keywords
+		.into_iter()
+		.map(|keyword| (keyword.keyword.clone(), keyword.keyword))
+		.collect())
+}
+
+/// Suggests the user's ignored phrases in the channel and for the keyword
+/// given to `/unignore`, or those ignored throughout the server for all
+/// keywords if neither was given.
+async fn ignore_choices(
+	interaction: &AutocompleteInteraction,
+	guild_id: GuildId,
+) -> Result<Vec<(String, String)>> {
+	let option = |name: &str| {
+		interaction
+			.data
+			.options
+			.iter()
+			.find(|o| o.name == name)
+			.and_then(|o| o.value.as_ref())
+			.and_then(|v| v.as_str())
+	};
+
+	let channel_id = option("channel")
+		.and_then(|id| id.parse().ok())
+		.map(ChannelId);
+
+	let keyword = option("keyword")
+		.map(|keyword| keyword.trim().to_lowercase())
+		.filter(|keyword| !keyword.is_empty());
+
+	Ok(Ignore::user_guild_ignores(interaction.user.id, guild_id)
+		.await?
+		.into_iter()
+		.filter(|ignore| {
+			ignore.channel_id == channel_id && ignore.keyword == keyword
+		})
+		.map(|ignore| (ignore.phrase.clone(), ignore.phrase))
+		.collect())
+}
+
+/// Suggests the servers the user has server-wide keywords or ignored phrases
+/// in, by name and ID, excluding the given server.
+async fn guild_choices(
+	ctx: &Context,
+	interaction: &AutocompleteInteraction,
+	exclude: Option<GuildId>,
+) -> Result<Vec<(String, String)>> {
+	let user_id = interaction.user.id;
+	let locale = locale(&interaction.locale);
+
+	let mut guild_ids = BTreeSet::new();
+
+	for keyword in Keyword::user_keywords(user_id).await? {
+		if let KeywordKind::Guild(guild_id) = keyword.kind {
+			guild_ids.insert(guild_id);
+		}
+	}
+
+	for ignore in Ignore::user_ignores(user_id).await? {
+		guild_ids.insert(ignore.guild_id);
+	}
+
+	Ok(guild_ids
+		.into_iter()
+		.filter(|&guild_id| Some(guild_id) != exclude)
+		.map(|guild_id| {
+			let name =
+				match ctx.cache.guild_field(guild_id, |g| g.name.clone()) {
+					Some(name) => format!("{name} ({guild_id})"),
+					None => locale
+						.format("list-unknown-server", &[("id", &guild_id)]),
+				};
+
+			(name, guild_id.to_string())
+		})
+		.collect())
+}
@@ -6,14 +6,21 @@
 use anyhow::{Context as _, Result};
 use serenity::{
 	client::Context,
-	model::application::interaction::application_command::ApplicationCommandInteraction as Command,
+	model::{
+		application::interaction::application_command::ApplicationCommandInteraction as Command,
+		id::UserId, mention::Mentionable,
+	},
 };
 
-use crate::{bot::util::respond_eph, db::Block};
+use super::pages::{send_list, ListEntry, ListOptions};
+use crate::{
+	bot::{i18n::locale, util::respond_eph},
+	db::Block,
+};
 
 /// Block a user.
 ///
-/// Usage: `/block <user>`
+/// Usage: `/block <user> [hide-messages]`
 #[tracing::instrument(
 	skip_all,
 	fields(
@@ -34,27 +41,92 @@ pub(crate) async fn block(ctx: Context, mut command: Command) -> Result<()> {
 		.map(|(_, user)| user)
 		.context("User to block not provided")?;
 
-	if user.id == command.user.id {
-		return respond_eph(&ctx, &command, "❌ You can't block yourself!")
-			.await;
+	let hide_messages = command
+		.data
+		.options
+		.iter()
+		.find(|o| o.name == "hide-messages")
+		.and_then(|o| o.value.as_ref())
+		.and_then(|v| v.as_bool())
+		.unwrap_or(false);
+
+	block_user(&ctx, &command, user.id, hide_messages).await
+}
+
+/// Block the author of a message.
+///
+/// Usage: message context menu > Apps > Block author
+#[tracing::instrument(
+	skip_all,
+	fields(
+		user_id = %command.user.id,
+		channel_id = %command.channel_id,
+		command = %command.data.name,
+	)
+)]
+pub(crate) async fn block_author(ctx: Context, command: Command) -> Result<()> {
+	check_opt_out!(ctx, command);
+
+	let author_id = command
+		.data
+		.resolved
+		.messages
+		.values()
+		.next()
+		.map(|message| message.author.id)
+		.context("Message to block author of not provided")?;
+
+	block_user(&ctx, &command, author_id, false).await
+}
+
+/// Blocks the given user for the author of the command, if they aren't the
+/// author or already blocked, optionally hiding the author's messages from the
+/// blocked user's highlights.
+async fn block_user(
+	ctx: &Context,
+	command: &Command,
+	blocked_id: UserId,
+	hide_messages: bool,
+) -> Result<()> {
+	let locale = locale(&command.locale);
+
+	if blocked_id == command.user.id {
+		return respond_eph(ctx, command, locale.get("block-self")).await;
 	}
 
 	let block = Block {
 		user_id: command.user.id,
-		blocked_id: user.id,
+		blocked_id,
+		hide_messages,
 	};
 
-	if block.clone().exists().await? {
-		respond_eph(
-			&ctx,
-			&command,
-			format!("❌ You already blocked <@{}>!", user.id),
-		)
-		.await
-	} else {
-		block.insert().await?;
-		respond_eph(&ctx, &command, format!("✅ Blocked <@{}>", user.id)).await
-	}
+	let existing = block.clone().get().await?;
+
+	let key = match existing {
+		Some(existing) if existing.hide_messages == hide_messages => {
+			"block-exists"
+		}
+		Some(_) if hide_messages => {
+			block.set().await?;
+			"block-hidden"
+		}
+		Some(_) => {
+			block.set().await?;
+			"block-unhidden"
+		}
+		None if hide_messages => {
+			block.set().await?;
+			"block-added-hidden"
+		}
+		None => {
+			block.set().await?;
+			"block-added"
+		}
+	};
+
+	let response = locale.format(key, &[("user", &blocked_id.mention())]);
+
+	respond_eph(ctx, command, response).await
 }
 
 /// Unblock a user.
@@ -70,6 +142,7 @@ pub(crate) async fn block(ctx: Context, mut command: Command) -> Result<()> {
 )]
 pub(crate) async fn unblock(ctx: Context, mut command: Command) -> Result<()> {
 	check_opt_out!(ctx, command);
+	let locale = locale(&command.locale);
 
 	let user = command
 		.data
@@ -81,26 +154,24 @@ pub(crate) async fn unblock(ctx: Context, mut command: Command) -> Result<()> {
 		.context("User to unblock not provided")?;
 
 	if user.id == command.user.id {
-		return respond_eph(&ctx, &command, "❌ You can't unblock yourself!")
-			.await;
+		return respond_eph(&ctx, &command, locale.get("unblock-self")).await;
 	}
 
 	let block = Block {
 		user_id: command.user.id,
 		blocked_id: user.id,
+		hide_messages: false,
 	};
 
+	let mention = user.id.mention();
+
 	if !block.clone().exists().await? {
-		respond_eph(
-			&ctx,
-			&command,
-			format!("❌ You haven't blocked <@{}>!", user.id),
-		)
-		.await
+		let response = locale.format("block-missing", &[("user", &mention)]);
+		respond_eph(&ctx, &command, response).await
 	} else {
 		block.delete().await?;
-		respond_eph(&ctx, &command, format!("✅ Unblocked <@{}>", user.id))
-			.await
+		let response = locale.format("block-removed", &[("user", &mention)]);
+		respond_eph(&ctx, &command, response).await
 	}
 }
 
@@ -117,22 +188,50 @@ pub(crate) async fn unblock(ctx: Context, mut command: Command) -> Result<()> {
 )]
 pub(crate) async fn blocks(ctx: Context, command: Command) -> Result<()> {
 	check_opt_out!(ctx, command);
+	let locale = locale(&command.locale);
+
+	let options = match ListOptions::from_command(&command) {
+		// blocks apply everywhere, so they aren't filtered
+		Ok(options) => ListOptions {
+			guild_id: None,
+			channel_id: None,
+			..options
+		},
+		Err(message) => return respond_eph(&ctx, &command, message).await,
+	};
 
-	let blocks = Block::user_blocks(command.user.id)
+	let entries = Block::user_blocks(command.user.id)
 		.await?
 		.into_iter()
-		.map(|block| format!("<@{}>", block.blocked_id))
-		.collect::<Vec<_>>();
-
-	if blocks.is_empty() {
-		respond_eph(&ctx, &command, "You haven't blocked any users!").await
-	} else {
-		let msg = format!(
-			"{}'s blocked users:\n  - {}",
-			command.user.name,
-			blocks.join("\n  - ")
-		);
-
-		respond_eph(&ctx, &command, msg).await
-	}
+		.map(|block| {
+			let mut text = match ctx.cache.user(block.blocked_id) {
+				Some(user) => {
+					format!("{} (<@{}>)", user.name, block.blocked_id)
+				}
+				None => format!("<@{}>", block.blocked_id),
+			};
+
+			if block.hide_messages {
+				text.push(' ');
+				text.push_str(locale.get("blocks-hidden"));
+			}
+
+			ListEntry {
+				text,
+				group: None,
+				guild_id: None,
+				channel_id: None,
+			}
+		})
+		.collect();
+
+	send_list(
+		&ctx,
+		&command,
+		locale.get("blocks-title"),
+		entries,
+		&options,
+		locale.get("blocks-empty"),
+	)
+	.await
 }
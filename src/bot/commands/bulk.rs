@@ -0,0 +1,717 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! Commands for adding keywords and ignored phrases through forms, and for
+//! removing many keywords at once.
+
+use std::{collections::HashSet, fmt::Write as _, sync::Arc, time::Duration};
+
+use anyhow::{bail, Context as _, Result};
+use futures_util::StreamExt;
+use rand::{distributions::Standard, Rng};
+use serenity::{
+	builder::CreateComponents,
+	client::Context,
+	collector::{
+		ComponentInteractionCollectorBuilder, ModalInteractionCollectorBuilder,
+	},
+	model::{
+		application::{
+			component::{ActionRowComponent, ButtonStyle, InputTextStyle},
+			interaction::{
+				application_command::ApplicationCommandInteraction as Command,
+				modal::ModalSubmitInteraction, InteractionResponseType,
+				MessageFlags,
+			},
+		},
+		id::{ChannelId, GuildId},
+	},
+};
+
+use super::{
+	keywords::{keyword_shared, KeywordLimits},
+	util::short_list,
+};
+use crate::{
+	bot::{
+		i18n::{locale, Locale},
+		stats,
+		util::respond_eph,
+	},
+	db::{Ignore, Keyword, KeywordKind},
+};
+
+/// How long the user has to fill out a bulk add form.
+const MODAL_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+
+/// How long the manage menu keeps working after it's shown.
+const MANAGE_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
+/// Number of keywords shown on each page of the manage menu; the most options
+/// Discord allows in a select menu.
+const PAGE_SIZE: usize = 25;
+
+/// Maximum length of the text in a form's text area.
+const MAX_INPUT_LENGTH: usize = 4000;
+
+/// Maximum length of a select menu option's label.
+const MAX_LABEL_LENGTH: usize = 100;
+
+/// Results of adding a list of keywords or ignored phrases.
+#[derive(Debug, Default)]
+struct BulkReport {
+	/// Entries that were added.
+	added: Vec<String>,
+	/// Entries that were already added.
+	duplicate: Vec<String>,
+	/// Entries that can't be added.
+	invalid: Vec<String>,
+	/// Keywords that weren't added because of the keyword limit.
+	over_limit: Vec<String>,
+}
+
+impl BulkReport {
+	/// Describes what was added and what was skipped.
+	fn message(&self, locale: &Locale) -> String {
+		let mut message = if self.added.is_empty() {
+			locale.get("bulk-nothing-added").to_owned()
+		} else {
+			locale
+				.format("bulk-added", &[("entries", &short_list(&self.added))])
+		};
+
+		for (entries, key) in [
+			(&self.duplicate, "bulk-duplicate"),
+			(&self.invalid, "bulk-invalid"),
+			(&self.over_limit, "bulk-over-limit"),
+		] {
+			if !entries.is_empty() {
+				message.push('\n');
+				message +=
+					&locale.format(key, &[("entries", &short_list(entries))]);
+			}
+		}
+
+		if !self.invalid.is_empty() {
+			message.push('\n');
+			message.push_str(locale.get("bulk-invalid-hint"));
+		}
+
+		message
+	}
+}
+
+/// Shows a form to add several keywords at once, then adds the submitted
+/// keywords in the given server or channel.
+///
+/// Used by `/add` when no keyword is given.
+pub(super) async fn add_keywords(
+	ctx: &Context,
+	command: &Command,
+	kind: KeywordKind,
+	mut limits: KeywordLimits,
+) -> Result<()> {
+	let user_id = command.user.id;
+	let locale = locale(&command.locale);
+
+	let (submission, keywords) = match request_text(
+		ctx,
+		command,
+		locale.get("bulk-keywords-title"),
+		locale.get("bulk-keywords-label"),
+		None,
+	)
+	.await?
+	{
+		Some((submission, text)) => (submission, parse_list(&text)),
+		None => return Ok(()),
+	};
+
+	let mut report = BulkReport::default();
+
+	for keyword in keywords {
+		if limits.check_keyword(&keyword, locale).is_some() {
+			report.invalid.push(keyword);
+			continue;
+		}
+
+		let keyword = Keyword {
+			keyword,
+			user_id,
+			kind,
+		};
+
+		if keyword.clone().exists().await? {
+			report.duplicate.push(keyword.keyword);
+		} else if limits.check_count(locale).is_some() {
+			report.over_limit.push(keyword.keyword);
+		} else {
+			report.added.push(keyword.keyword.clone());
+			keyword.insert().await?;
+			limits.add();
+		}
+	}
+
+	respond_to_submission(ctx, &submission, report.message(locale)).await
+}
+
+/// Shows a form to ignore several phrases at once, then adds the submitted
+/// phrases in the given server, limited to the given channel and keyword if
+/// any.
+///
+/// Used by `/ignore` when no phrase is given.
+pub(super) async fn add_ignores(
+	ctx: &Context,
+	command: &Command,
+	guild_id: GuildId,
+	channel_id: Option<ChannelId>,
+	keyword: Option<String>,
+) -> Result<()> {
+	let locale = locale(&command.locale);
+
+	let (submission, phrases) = match request_text(
+		ctx,
+		command,
+		locale.get("bulk-ignores-title"),
+		locale.get("bulk-ignores-label"),
+		None,
+	)
+	.await?
+	{
+		Some((submission, text)) => (submission, parse_list(&text)),
+		None => return Ok(()),
+	};
+
+	let mut report = BulkReport::default();
+
+	for phrase in phrases {
+		if phrase.len() < 3 {
+			report.invalid.push(phrase);
+			continue;
+		}
+
+		let ignore = Ignore {
+			user_id: command.user.id,
+			guild_id,
+			phrase,
+			channel_id,
+			keyword: keyword.clone(),
+		};
+
+		if ignore.clone().exists().await? {
+			report.duplicate.push(ignore.phrase);
+		} else {
+			report.added.push(ignore.phrase.clone());
+			ignore.insert().await?;
+		}
+	}
+
+	respond_to_submission(ctx, &submission, report.message(locale)).await
+}
+
+/// Add a keyword from the text of a message, after letting the user edit it
+/// down to the part they want.
+///
+/// Usage: message context menu > Apps > Add keyword from message…
+#[tracing::instrument(
+	skip_all,
+	fields(
+		user_id = %command.user.id,
+		channel_id = %command.channel_id,
+		command = %command.data.name,
+	)
+)]
+pub(crate) async fn add_from_message(
+	ctx: Context,
+	command: Command,
+) -> Result<()> {
+	check_opt_out!(ctx, command);
+	let guild_id = require_guild!(&ctx, &command);
+	let user_id = command.user.id;
+	let locale = locale(&command.locale);
+	let limits = KeywordLimits::new(&ctx, user_id, guild_id).await?;
+
+	if let Some(message) = limits.check_count(locale) {
+		return respond_eph(&ctx, &command, message).await;
+	}
+
+	let content = &command
+		.data
+		.resolved
+		.messages
+		.values()
+		.next()
+		.context("No message to add keyword from provided")?
+		.content;
+
+	if content.trim().is_empty() {
+		return respond_eph(&ctx, &command, locale.get("from-message-empty"))
+			.await;
+	}
+
+	let content = content.chars().take(MAX_INPUT_LENGTH).collect::<String>();
+
+	let (submission, keyword) = match request_text(
+		&ctx,
+		&command,
+		locale.get("from-message-title"),
+		locale.get("from-message-label"),
+		Some(&content),
+	)
+	.await?
+	{
+		Some((submission, text)) => (submission, text.trim().to_lowercase()),
+		None => return Ok(()),
+	};
+
+	let response = if let Some(message) = limits.check_keyword(&keyword, locale)
+	{
+		message
+	} else {
+		let keyword = Keyword {
+			keyword,
+			user_id,
+			kind: KeywordKind::Guild(guild_id),
+		};
+
+		if keyword.clone().exists().await? {
+			locale.get("keyword-exists").to_owned()
+		} else {
+			let response = locale
+				.format("from-message-added", &[("keyword", &keyword.keyword)]);
+			keyword.insert().await?;
+			response
+		}
+	};
+
+	respond_to_submission(&ctx, &submission, response).await
+}
+
+/// Shows the user a form with a text area, optionally filled in with the given
+/// text, and waits for them to submit it.
+///
+/// Returns the submission and the text in the text area, or `None` if the
+/// user didn't submit the form in time.
+async fn request_text(
+	ctx: &Context,
+	command: &Command,
+	title: &str,
+	label: &str,
+	value: Option<&str>,
+) -> Result<Option<(Arc<ModalSubmitInteraction>, String)>> {
+	let nonce = rand::thread_rng()
+		.sample_iter::<char, _>(Standard)
+		.take(90)
+		.collect::<String>();
+
+	command
+		.create_interaction_response(ctx, |r| {
+			r.kind(InteractionResponseType::Modal)
+				.interaction_response_data(|d| {
+					d.custom_id(&nonce).title(title).components(|c| {
+						c.create_action_row(|row| {
+							row.create_input_text(|t| {
+								t.custom_id("text")
+									.style(InputTextStyle::Paragraph)
+									.label(label)
+									.required(true);
+
+								match value {
+									Some(value) => t.value(value),
+									None => t.placeholder(
+										"rust, cargo\nborrow checker",
+									),
+								}
+							})
+						})
+					})
+				})
+		})
+		.await
+		.context("Failed to send text form")?;
+
+	let submission = ModalInteractionCollectorBuilder::new(ctx)
+		.collect_limit(1)
+		.author_id(command.user.id)
+		.filter(move |interaction| interaction.data.custom_id == nonce)
+		.timeout(MODAL_TIMEOUT)
+		.build()
+		.next()
+		.await;
+
+	let submission = match submission {
+		Some(submission) => submission,
+		None => return Ok(None),
+	};
+
+	let text = submission
+		.data
+		.components
+		.iter()
+		.flat_map(|row| &row.components)
+		.find_map(|component| match component {
+			ActionRowComponent::InputText(input) => Some(input.value.clone()),
+			_ => None,
+		})
+		.context("No text submitted in text form")?;
+
+	Ok(Some((submission, text)))
+}
+
+/// Splits a list of comma- or newline-separated entries, lowercasing them and
+/// removing empty entries and repeats.
+pub(super) fn parse_list(text: &str) -> Vec<String> {
+	let mut entries = Vec::new();
+
+	for entry in text.split([',', '\n']) {
+		let entry = entry.trim().to_lowercase();
+
+		if !entry.is_empty() && !entries.contains(&entry) {
+			entries.push(entry);
+		}
+	}
+
+	entries
+}
+
+/// Responds ephemerally to a form submission.
+pub(super) async fn respond_to_submission(
+	ctx: &Context,
+	submission: &ModalSubmitInteraction,
+	response: String,
+) -> Result<()> {
+	submission
+		.create_interaction_response(ctx, |r| {
+			r.interaction_response_data(|m| {
+				m.flags(MessageFlags::EPHEMERAL).content(response)
+			})
+		})
+		.await
+		.context("Failed to respond to form")
+}
+
+/// Show a menu to select and remove several keywords at once.
+///
+/// Usage: `/manage`
+#[tracing::instrument(
+	skip_all,
+	fields(
+		user_id = %command.user.id,
+		channel_id = %command.channel_id,
+		command = %command.data.name,
+	)
+)]
+pub(crate) async fn manage(ctx: Context, command: Command) -> Result<()> {
+	check_opt_out!(ctx, command);
+	let guild_id = require_guild!(&ctx, &command);
+	let user_id = command.user.id;
+	let locale = locale(&command.locale);
+
+	let mut keywords = Keyword::user_guild_keywords(user_id, guild_id).await?;
+
+	let mut channel_keywords = Keyword::user_channel_keywords(user_id)
+		.await?
+		.into_iter()
+		.filter(|keyword| match keyword.kind {
+			KeywordKind::Channel(channel_id) => {
+				ctx.cache.guild_channel_field(channel_id, |c| c.guild_id)
+					== Some(guild_id)
+			}
+			KeywordKind::Guild(_) => false,
+		})
+		.collect::<Vec<_>>();
+
+	keywords.sort_by(|a, b| a.keyword.cmp(&b.keyword));
+	channel_keywords.sort_by(|a, b| a.keyword.cmp(&b.keyword));
+	keywords.append(&mut channel_keywords);
+
+	if keywords.is_empty() {
+		return respond_eph(&ctx, &command, locale.get("manage-empty")).await;
+	}
+
+	let nonce = rand::thread_rng()
+		.sample_iter::<char, _>(Standard)
+		.take(90)
+		.collect::<String>();
+
+	let ids = ManageIds {
+		select: format!("select{}", nonce),
+		prev: format!("prev{}", nonce),
+		next: format!("next{}", nonce),
+		remove: format!("remove{}", nonce),
+	};
+
+	let mut page = 0;
+	let mut selected = HashSet::new();
+
+	let components =
+		manage_components(&ctx, &ids, &keywords, &selected, page, locale);
+
+	command
+		.create_interaction_response(&ctx, |r| {
+			r.interaction_response_data(|m| {
+				m.flags(MessageFlags::EPHEMERAL)
+					.content(manage_content(None, keywords.len(), page, locale))
+					.set_components(components)
+			})
+		})
+		.await
+		.context("Failed to send manage menu")?;
+
+	let mut interactions = ComponentInteractionCollectorBuilder::new(&ctx)
+		.author_id(user_id)
+		.filter({
+			let ids = ids.clone();
+			move |interaction| ids.contains(&interaction.data.custom_id)
+		})
+		.timeout(MANAGE_TIMEOUT)
+		.build();
+
+	while let Some(interaction) = interactions.next().await {
+		let mut removed = None;
+
+		match interaction.data.custom_id.as_str() {
+			id if id == ids.select => {
+				let start = page * PAGE_SIZE;
+				let end = usize::min(start + PAGE_SIZE, keywords.len());
+
+				selected.retain(|i| !(start..end).contains(i));
+				selected.extend(
+					interaction
+						.data
+						.values
+						.iter()
+						.filter_map(|value| value.parse::<usize>().ok())
+						.filter(|i| (start..end).contains(i)),
+				);
+			}
+			id if id == ids.prev => page = page.saturating_sub(1),
+			id if id == ids.next => page += 1,
+			id if id == ids.remove => {
+				let mut names = Vec::new();
+				let mut kept = Vec::new();
+
+				for (i, keyword) in keywords.into_iter().enumerate() {
+					if selected.contains(&i) {
+						names.push(keyword.keyword.clone());
+						let authors_in =
+							(!keyword_shared(&ctx, &keyword, guild_id).await?)
+								.then_some(guild_id);
+						keyword.clone().delete(authors_in).await?;
+						stats::keyword_removed(&ctx, &keyword).await?;
+					} else {
+						kept.push(keyword);
+					}
+				}
+
+				keywords = kept;
+				selected.clear();
+				removed = Some(names);
+			}
+			other => bail!("Unknown manage message component ID {}", other),
+		}
+
+		let pages = keywords.len().div_ceil(PAGE_SIZE);
+		page = page.min(pages.saturating_sub(1));
+
+		if keywords.is_empty() {
+			let content = format!(
+				"{}\n{}",
+				manage_content(removed.as_deref(), 0, 0, locale),
+				locale.get("manage-none-left"),
+			);
+
+			interaction
+				.create_interaction_response(&ctx, |r| {
+					r.kind(InteractionResponseType::UpdateMessage)
+						.interaction_response_data(|m| {
+							m.content(content).components(|c| c)
+						})
+				})
+				.await
+				.context("Failed to update manage menu")?;
+
+			return Ok(());
+		}
+
+		let content =
+			manage_content(removed.as_deref(), keywords.len(), page, locale);
+		let components =
+			manage_components(&ctx, &ids, &keywords, &selected, page, locale);
+
+		interaction
+			.create_interaction_response(&ctx, |r| {
+				r.kind(InteractionResponseType::UpdateMessage)
+					.interaction_response_data(|m| {
+						m.content(content).set_components(components)
+					})
+			})
+			.await
+			.context("Failed to update manage menu")?;
+	}
+
+	command
+		.edit_original_interaction_response(&ctx, |r| r.components(|c| c))
+		.await
+		.context("Failed to remove manage menu components")?;
+
+	Ok(())
+}
+
+/// Custom IDs of the components of a manage menu.
+#[derive(Debug, Clone)]
+struct ManageIds {
+	select: String,
+	prev: String,
+	next: String,
+	remove: String,
+}
+
+impl ManageIds {
+	fn contains(&self, id: &str) -> bool {
+		[&self.select, &self.prev, &self.next, &self.remove]
+			.into_iter()
+			.any(|own| *own == id)
+	}
+}
+
+/// Builds the text of the manage menu, noting which keywords were just
+/// removed, if any.
+fn manage_content(
+	removed: Option<&[String]>,
+	total: usize,
+	page: usize,
+	locale: &Locale,
+) -> String {
+	let mut content = String::new();
+
+	if let Some(removed) = removed {
+		if removed.is_empty() {
+			writeln!(content, "{}", locale.get("manage-none-selected"))
+				.unwrap();
+		} else {
+			let removed = locale.format(
+				"manage-removed",
+				&[("keywords", &short_list(removed))],
+			);
+			writeln!(content, "{removed}").unwrap();
+		}
+	}
+
+	if total > 0 {
+		let pages = total.div_ceil(PAGE_SIZE);
+
+		content.push_str(&locale.format(
+			"manage-instructions",
+			&[("page", &(page + 1)), ("pages", &pages)],
+		));
+	}
+
+	content
+}
+
+/// Builds the select menu and buttons of the manage menu for the given page.
+fn manage_components(
+	ctx: &Context,
+	ids: &ManageIds,
+	keywords: &[Keyword],
+	selected: &HashSet<usize>,
+	page: usize,
+	locale: &Locale,
+) -> CreateComponents {
+	let start = page * PAGE_SIZE;
+	let end = usize::min(start + PAGE_SIZE, keywords.len());
+	let pages = keywords.len().div_ceil(PAGE_SIZE);
+
+	let mut components = CreateComponents::default();
+
+	components.create_action_row(|row| {
+		row.create_select_menu(|menu| {
+			menu.custom_id(&ids.select)
+				.placeholder(locale.get("manage-placeholder"))
+				.min_values(0)
+				.max_values((end - start) as u64)
+				.options(|options| {
+					for (i, keyword) in
+						keywords.iter().enumerate().take(end).skip(start)
+					{
+						let description = match keyword.kind {
+							KeywordKind::Guild(_) => {
+								locale.get("list-server-wide").to_owned()
+							}
+							KeywordKind::Channel(channel_id) => {
+								let name = ctx
+									.cache
+									.guild_channel_field(channel_id, |c| {
+										c.name.clone()
+									})
+									.unwrap_or_else(|| channel_id.to_string());
+								locale.format(
+									"manage-channel",
+									&[("channel", &name)],
+								)
+							}
+						};
+
+						options.create_option(|o| {
+							o.label(
+								keyword
+									.keyword
+									.chars()
+									.take(MAX_LABEL_LENGTH)
+									.collect::<String>(),
+							)
+							.value(i)
+							.description(description)
+							.default_selection(selected.contains(&i))
+						});
+					}
+
+					options
+				})
+		})
+	});
+
+	components.create_action_row(|row| {
+		if pages > 1 {
+			row.create_button(|b| {
+				b.style(ButtonStyle::Secondary)
+					.label(locale.get("list-previous"))
+					.custom_id(&ids.prev)
+					.disabled(page == 0)
+			})
+			.create_button(|b| {
+				b.style(ButtonStyle::Secondary)
+					.label(locale.get("list-next"))
+					.custom_id(&ids.next)
+					.disabled(page + 1 >= pages)
+			});
+		}
+
+		row.create_button(|b| {
+			b.style(ButtonStyle::Danger)
+				.label(
+					locale
+						.format("manage-remove", &[("count", &selected.len())]),
+				)
+				.custom_id(&ids.remove)
+				.disabled(selected.is_empty())
+		})
+	});
+
+	components
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn list_parsing() {
+		assert_eq!(
+			parse_list("Rust, cargo\n\nborrow checker,rust ,"),
+			vec!["rust", "cargo", "borrow checker"]
+		);
+		assert!(parse_list(" ,\n").is_empty());
+	}
+}
@@ -0,0 +1,309 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! Commands for copying keywords and ignored phrases between servers and
+//! channels.
+
+use std::fmt::Write as _;
+
+use anyhow::{Context as _, Result};
+use serenity::{
+	client::Context,
+	model::{
+		application::interaction::application_command::ApplicationCommandInteraction as Command,
+		channel::ChannelType,
+		id::{ChannelId, GuildId},
+		mention::Mentionable,
+	},
+};
+
+use super::{keywords::KeywordLimits, util::short_list};
+use crate::{
+	bot::{
+		i18n::{locale, Locale},
+		util::{respond_eph, user_can_read_channel},
+	},
+	db::{Ignore, Keyword, KeywordKind},
+};
+
+/// Keywords and ignored phrases copied by a command, and those that were
+/// skipped.
+#[derive(Debug, Default)]
+struct CopyReport {
+	/// Keywords that were copied.
+	keywords: Vec<String>,
+	/// Ignored phrases that were copied.
+	ignores: Vec<String>,
+	/// Keywords and ignored phrases that were already added at the
+	/// destination.
+	duplicates: Vec<String>,
+	/// Keywords that weren't copied because the destination server doesn't
+	/// allow them.
+	invalid: Vec<String>,
+	/// Keywords that weren't copied because of the keyword limits.
+	over_limit: Vec<String>,
+}
+
+impl CopyReport {
+	/// Copies the given keywords to the given destination, skipping
+	/// duplicates, keywords the destination server doesn't allow, and keywords
+	/// past the keyword limits.
+	async fn copy_keywords(
+		&mut self,
+		keywords: Vec<Keyword>,
+		kind: KeywordKind,
+		mut limits: KeywordLimits,
+		locale: &Locale,
+	) -> Result<()> {
+		for keyword in keywords {
+			let keyword = Keyword { kind, ..keyword };
+
+			if keyword.clone().exists().await? {
+				self.duplicates.push(keyword.keyword);
+			} else if limits.check_keyword(&keyword.keyword, locale).is_some() {
+				self.invalid.push(keyword.keyword);
+			} else if limits.check_count(locale).is_some() {
+				self.over_limit.push(keyword.keyword);
+			} else {
+				self.keywords.push(keyword.keyword.clone());
+				keyword.insert().await?;
+				limits.add();
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Copies the given ignored phrases to the given guild, skipping
+	/// duplicates.
+	async fn copy_ignores(
+		&mut self,
+		ignores: Vec<Ignore>,
+		guild_id: GuildId,
+	) -> Result<()> {
+		for ignore in ignores {
+			let ignore = Ignore { guild_id, ..ignore };
+
+			if ignore.clone().exists().await? {
+				self.duplicates.push(ignore.phrase);
+			} else {
+				self.ignores.push(ignore.phrase.clone());
+				ignore.insert().await?;
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Describes what was copied from the given source and what was skipped.
+	fn message(&self, source: &str, locale: &Locale) -> String {
+		let count = |items: &[String], one, many| match items.len() {
+			0 => None,
+			1 => Some(locale.get(one).to_owned()),
+			n => Some(locale.format(many, &[("count", &n)])),
+		};
+
+		let keywords =
+			count(&self.keywords, "copy-keywords-one", "copy-keywords");
+		let ignores = count(&self.ignores, "copy-ignores-one", "copy-ignores");
+
+		let copied = match (keywords, ignores) {
+			(Some(keywords), Some(ignores)) => Some(locale.format(
+				"copy-both",
+				&[("keywords", &keywords), ("ignores", &ignores)],
+			)),
+			(Some(copied), None) | (None, Some(copied)) => Some(copied),
+			(None, None) => None,
+		};
+
+		let mut message = match copied {
+			Some(copied) => locale.format(
+				"copy-done",
+				&[("items", &copied), ("source", &source)],
+			),
+			None => locale.format("copy-nothing", &[("source", &source)]),
+		};
+
+		for (skipped, key) in [
+			(&self.duplicates, "copy-skipped-duplicates"),
+			(&self.invalid, "copy-skipped-invalid"),
+			(&self.over_limit, "copy-skipped-limit"),
+		] {
+			if !skipped.is_empty() {
+				let list = short_list(skipped);
+				write!(message, "\n{}", locale.format(key, &[("list", &list)]))
+					.unwrap();
+			}
+		}
+
+		message
+	}
+}
+
+/// Copy the user's server-wide keywords and ignored phrases from another
+/// server to the current one.
+///
+/// Usage: `/copy-server <server ID>`
+#[tracing::instrument(
+	skip_all,
+	fields(
+		user_id = %command.user.id,
+		channel_id = %command.channel_id,
+		command = %command.data.name,
+	)
+)]
+pub(crate) async fn copy_server(ctx: Context, command: Command) -> Result<()> {
+	check_opt_out!(ctx, command);
+	let guild_id = require_guild!(&ctx, &command);
+	let locale = locale(&command.locale);
+	let user_id = command.user.id;
+
+	let arg = command
+		.data
+		.options
+		.first()
+		.and_then(|o| o.value.as_ref())
+		.context("No guild ID to copy from provided")?
+		.as_str()
+		.context("Guild ID to copy from was not a string")?;
+
+	let from = match arg.trim().parse() {
+		Ok(id) => GuildId(id),
+		Err(_) => {
+			return respond_eph(
+				&ctx,
+				&command,
+				locale.get("error-invalid-server"),
+			)
+			.await
+		}
+	};
+
+	if from == guild_id {
+		return respond_eph(&ctx, &command, locale.get("copy-same-server"))
+			.await;
+	}
+
+	let keywords = Keyword::user_guild_keywords(user_id, from).await?;
+	// phrases ignored in one channel don't apply in other servers
+	let ignores = Ignore::user_guild_ignores(user_id, from)
+		.await?
+		.into_iter()
+		.filter(|ignore| ignore.channel_id.is_none())
+		.collect::<Vec<_>>();
+
+	if keywords.is_empty() && ignores.is_empty() {
+		return respond_eph(&ctx, &command, locale.get("copy-server-empty"))
+			.await;
+	}
+
+	let limits = KeywordLimits::new(&ctx, user_id, guild_id).await?;
+	let mut report = CopyReport::default();
+	report
+		.copy_keywords(keywords, KeywordKind::Guild(guild_id), limits, locale)
+		.await?;
+	report.copy_ignores(ignores, guild_id).await?;
+
+	let source = match ctx.cache.guild_field(from, |g| g.name.clone()) {
+		Some(name) => format!("**{name}**"),
+		None => locale.format("copy-unknown-server", &[("id", &from)]),
+	};
+
+	respond_eph(&ctx, &command, report.message(&source, locale)).await
+}
+
+/// Copy the user's channel-specific keywords from one channel to another.
+///
+/// Usage: `/copy-channel <channel> [to channel]`
+#[tracing::instrument(
+	skip_all,
+	fields(
+		user_id = %command.user.id,
+		channel_id = %command.channel_id,
+		command = %command.data.name,
+	)
+)]
+pub(crate) async fn copy_channel(ctx: Context, command: Command) -> Result<()> {
+	check_opt_out!(ctx, command);
+	let guild_id = require_guild!(&ctx, &command);
+	let locale = locale(&command.locale);
+	let user_id = command.user.id;
+
+	let channel_option = |name: &str| {
+		command
+			.data
+			.options
+			.iter()
+			.find(|o| o.name == name)
+			.and_then(|o| o.value.as_ref())
+			.and_then(|v| v.as_str())
+			.and_then(|id| id.parse().ok())
+			.map(ChannelId)
+	};
+
+	let from = channel_option("from").context("No channel to copy from")?;
+	let to = channel_option("to").unwrap_or(command.channel_id);
+
+	if from == to {
+		return respond_eph(&ctx, &command, locale.get("copy-same-channel"))
+			.await;
+	}
+
+	let channel = match ctx.cache.guild_channel(to) {
+		Some(c) if c.kind == ChannelType::Text => c,
+		_ => {
+			return respond_eph(
+				&ctx,
+				&command,
+				locale.get("error-text-channel"),
+			)
+			.await
+		}
+	};
+
+	let self_id = ctx.cache.current_user_id();
+	match user_can_read_channel(&ctx, &channel, self_id).await {
+		Ok(Some(true)) => {}
+		Ok(Some(false)) => {
+			let response = locale
+				.format("error-cannot-read", &[("channel", &to.mention())]);
+			return respond_eph(&ctx, &command, response).await;
+		}
+		Ok(None) => {
+			return Err(anyhow::anyhow!(
+				"Self permissions not found in channel {} in guild {}",
+				to,
+				guild_id
+			))
+		}
+		Err(e) => {
+			return Err(e.context(
+				"Failed to check for self permissions to read copy destination",
+			))
+		}
+	}
+
+	let keywords = Keyword::user_channel_keywords(user_id)
+		.await?
+		.into_iter()
+		.filter(
+			|keyword| matches!(keyword.kind, KeywordKind::Channel(id) if id == from),
+		)
+		.collect::<Vec<_>>();
+
+	if keywords.is_empty() {
+		let response = locale
+			.format("copy-channel-empty", &[("channel", &from.mention())]);
+		return respond_eph(&ctx, &command, response).await;
+	}
+
+	let limits = KeywordLimits::new(&ctx, user_id, channel.guild_id).await?;
+	let mut report = CopyReport::default();
+	report
+		.copy_keywords(keywords, KeywordKind::Channel(to), limits, locale)
+		.await?;
+
+	let source = from.mention().to_string();
+
+	respond_eph(&ctx, &command, report.message(&source, locale)).await
+}
@@ -0,0 +1,162 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! Command for choosing how notifications are delivered.
+
+use anyhow::{Context as _, Result};
+use serenity::{
+	client::Context,
+	model::application::interaction::application_command::ApplicationCommandInteraction as Command,
+	utils::parse_webhook,
+};
+use url::Url;
+
+use crate::{
+	bot::{email, i18n::locale, util::respond_eph},
+	db::{Delivery, DeliveryMethod, EmailAddress},
+	settings::settings,
+};
+
+/// Set or display how notifications are delivered.
+///
+/// Usage: `/delivery [method] [url]`
+#[tracing::instrument(
+	skip_all,
+	fields(
+		user_id = %command.user.id,
+		channel_id = %command.channel_id,
+		command = %command.data.name,
+	)
+)]
+pub(crate) async fn delivery(ctx: Context, command: Command) -> Result<()> {
+	check_opt_out!(ctx, command);
+	let locale = locale(&command.locale);
+	let user_id = command.user.id;
+
+	let option = |name: &str| {
+		command
+			.data
+			.options
+			.iter()
+			.find(|o| o.name == name)
+			.and_then(|o| o.value.as_ref())
+			.map(|v| v.as_str().context("Option provided was not a string"))
+			.transpose()
+	};
+
+	let method = match option("method")? {
+		Some(method) => method,
+		None => {
+			let current = Delivery::user_delivery(user_id).await?;
+
+			let key = match current.method {
+				DeliveryMethod::DirectMessage => "delivery-current-dm",
+				DeliveryMethod::Webhook(_) => "delivery-current-webhook",
+				DeliveryMethod::Http(_) => "delivery-current-http",
+				DeliveryMethod::Email { digest: false } => {
+					"delivery-current-email"
+				}
+				DeliveryMethod::Email { digest: true } => {
+					"delivery-current-digest"
+				}
+			};
+
+			return respond_eph(&ctx, &command, locale.get(key)).await;
+		}
+	};
+
+	let url = option("url")?
+		.map(|url| url.trim().parse::<Url>())
+		.transpose();
+
+	let method = match (method, url) {
+		("dm", _) => DeliveryMethod::DirectMessage,
+		("email" | "email-digest", _) => {
+			if !email::enabled() {
+				return respond_eph(
+					&ctx,
+					&command,
+					locale.get("email-disabled"),
+				)
+				.await;
+			}
+
+			if EmailAddress::verified_email(user_id).await?.is_none() {
+				return respond_eph(
+					&ctx,
+					&command,
+					locale.get("delivery-email-unverified"),
+				)
+				.await;
+			}
+
+			DeliveryMethod::Email {
+				digest: method == "email-digest",
+			}
+		}
+		(_, Err(_)) => {
+			return respond_eph(
+				&ctx,
+				&command,
+				locale.get("delivery-url-invalid"),
+			)
+			.await;
+		}
+		(_, Ok(None)) => {
+			return respond_eph(
+				&ctx,
+				&command,
+				locale.get("delivery-url-missing"),
+			)
+			.await;
+		}
+		("webhook", Ok(Some(url))) => {
+			if parse_webhook(&url).is_none() {
+				return respond_eph(
+					&ctx,
+					&command,
+					locale.get("delivery-webhook-invalid"),
+				)
+				.await;
+			}
+
+			DeliveryMethod::Webhook(url)
+		}
+		("http", Ok(Some(url))) => {
+			if !settings().behavior.http_delivery {
+				return respond_eph(
+					&ctx,
+					&command,
+					locale.get("delivery-http-disabled"),
+				)
+				.await;
+			}
+
+			if !matches!(url.scheme(), "http" | "https") {
+				return respond_eph(
+					&ctx,
+					&command,
+					locale.get("delivery-http-scheme"),
+				)
+				.await;
+			}
+
+			DeliveryMethod::Http(url)
+		}
+		(other, _) => {
+			return Err(anyhow::anyhow!("Unknown delivery method: {other}"));
+		}
+	};
+
+	let key = match &method {
+		DeliveryMethod::DirectMessage => "delivery-set-dm",
+		DeliveryMethod::Webhook(_) => "delivery-set-webhook",
+		DeliveryMethod::Http(_) => "delivery-set-http",
+		DeliveryMethod::Email { digest: false } => "delivery-set-email",
+		DeliveryMethod::Email { digest: true } => "delivery-set-digest",
+	};
+
+	Delivery { user_id, method }.set().await?;
+
+	respond_eph(&ctx, &command, locale.get(key)).await
+}
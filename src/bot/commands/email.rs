@@ -0,0 +1,170 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! Command for adding and verifying an email address to receive notifications.
+
+use std::time::Duration;
+
+use anyhow::{Context as _, Result};
+use lettre::Address;
+use rand::Rng;
+use serenity::{
+	client::Context,
+	model::application::interaction::application_command::ApplicationCommandInteraction as Command,
+};
+
+use crate::{
+	bot::{email, i18n::locale, notifier::NotifyError, util::respond_eph},
+	db::{CodeRequest, Delivery, DeliveryMethod, EmailAddress, Verification},
+};
+
+/// How long verification codes can be used for.
+const CODE_LIFETIME: Duration = Duration::from_secs(15 * 60);
+
+/// Set, verify, remove, or display the email address notifications can be sent
+/// to.
+///
+/// Usage: `/email [address] [code] [remove]`
+#[tracing::instrument(
+	skip_all,
+	fields(
+		user_id = %command.user.id,
+		channel_id = %command.channel_id,
+		command = %command.data.name,
+	)
+)]
+pub(crate) async fn email(ctx: Context, command: Command) -> Result<()> {
+	check_opt_out!(ctx, command);
+	let locale = locale(&command.locale);
+	let user_id = command.user.id;
+
+	if !email::enabled() {
+		return respond_eph(&ctx, &command, locale.get("email-disabled")).await;
+	}
+
+	let option = |name: &str| {
+		command
+			.data
+			.options
+			.iter()
+			.find(|o| o.name == name)
+			.and_then(|o| o.value.as_ref())
+	};
+
+	let remove = option("remove")
+		.map(|v| v.as_bool().context("Option provided was not a boolean"))
+		.transpose()?
+		.unwrap_or(false);
+
+	if remove {
+		if !EmailAddress::delete(user_id).await? {
+			return respond_eph(&ctx, &command, locale.get("email-missing"))
+				.await;
+		}
+
+		let delivery = Delivery::user_delivery(user_id).await?;
+		if let DeliveryMethod::Email { .. } = delivery.method {
+			Delivery {
+				user_id,
+				method: DeliveryMethod::DirectMessage,
+			}
+			.set()
+			.await?;
+
+			return respond_eph(
+				&ctx,
+				&command,
+				locale.get("email-removed-delivery"),
+			)
+			.await;
+		}
+
+		return respond_eph(&ctx, &command, locale.get("email-removed")).await;
+	}
+
+	let string_option = |name: &str| {
+		option(name)
+			.map(|v| v.as_str().context("Option provided was not a string"))
+			.transpose()
+	};
+
+	if let Some(code) = string_option("code")? {
+		let key = match EmailAddress::verify(user_id, code).await? {
+			Verification::Verified => "email-verified",
+			Verification::Incorrect => "email-code-incorrect",
+			Verification::Expired => "email-code-expired",
+			Verification::TooManyAttempts => "email-too-many-attempts",
+		};
+
+		return respond_eph(&ctx, &command, locale.get(key)).await;
+	}
+
+	let address = match string_option("address")? {
+		Some(address) => address.trim(),
+		None => {
+			let response = match EmailAddress::user_email(user_id).await? {
+				Some(email) if email.verified => locale
+					.format("email-current", &[("address", &email.address)]),
+				Some(email) => locale.format(
+					"email-current-unverified",
+					&[("address", &email.address)],
+				),
+				None => locale.get("email-none").to_owned(),
+			};
+
+			return respond_eph(&ctx, &command, response).await;
+		}
+	};
+
+	if address.parse::<Address>().is_err() {
+		return respond_eph(&ctx, &command, locale.get("email-invalid")).await;
+	}
+
+	let code = format!("{:06}", rand::thread_rng().gen_range(0..1_000_000));
+
+	match EmailAddress::set_pending(
+		user_id,
+		address.to_owned(),
+		&code,
+		CODE_LIFETIME,
+	)
+	.await?
+	{
+		CodeRequest::Accepted => {}
+		CodeRequest::TooSoon(wait) => {
+			let response = locale.format(
+				"email-cooldown",
+				&[("seconds", &wait.as_secs().max(1))],
+			);
+			return respond_eph(&ctx, &command, response).await;
+		}
+		CodeRequest::TooManyAttempts => {
+			return respond_eph(
+				&ctx,
+				&command,
+				locale.get("email-too-many-attempts"),
+			)
+			.await;
+		}
+	}
+
+	respond_eph(&ctx, &command, locale.get("email-sending")).await?;
+
+	let response = match email::send_verification_code(address, &code).await {
+		Ok(()) => locale.format("email-sent", &[("address", &address)]),
+		Err(NotifyError::Undeliverable) => {
+			locale.get("email-undeliverable").to_owned()
+		}
+		Err(NotifyError::Transient(_)) => {
+			locale.get("email-send-failed").to_owned()
+		}
+		Err(NotifyError::Other(e)) => return Err(e),
+	};
+
+	command
+		.edit_original_interaction_response(&ctx, |r| r.content(response))
+		.await
+		.context("Failed to edit verification response")?;
+
+	Ok(())
+}
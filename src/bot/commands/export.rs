@@ -0,0 +1,1423 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! Commands for exporting and importing a user's keywords and other
+//! preferences as JSON.
+
+use std::{
+	borrow::Cow,
+	collections::{hash_map::Entry, HashMap},
+	fmt::Write as _,
+	time::Duration,
+};
+
+use anyhow::{bail, Context as _, Result};
+use futures_util::StreamExt;
+use rand::{distributions::Standard, Rng};
+use serde::{Deserialize, Serialize};
+use serenity::{
+	client::Context,
+	collector::ComponentInteractionCollectorBuilder,
+	model::{
+		application::{
+			component::ButtonStyle,
+			interaction::{
+				application_command::ApplicationCommandInteraction as Command,
+				InteractionResponseType, MessageFlags,
+			},
+		},
+		channel::{AttachmentType, ChannelType},
+		id::{ChannelId, GuildId, RoleId, UserId},
+	},
+};
+
+use super::{
+	keyword_authors::MAX_AUTHORS,
+	keywords::{check_channel, KeywordLimits, MAX_KEYWORD_CHANNELS},
+	util::short_list,
+};
+use crate::{
+	bot::{
+		i18n::{locale, Locale},
+		stats,
+		util::{optional_result, respond_eph, user_can_read_channel},
+	},
+	db::{
+		AuthorFilter, Block, Ignore, Keyword, KeywordAuthor, KeywordChannel,
+		KeywordKind, Mute,
+	},
+};
+
+/// Version of the export format written by [`export`].
+const EXPORT_VERSION: u32 = 2;
+
+/// Largest file accepted by [`import`], in bytes.
+const MAX_IMPORT_SIZE: u64 = 1024 * 1024;
+
+/// How long the import buttons keep working after the preview is shown.
+const CONFIRM_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Maximum length of the import preview message.
+const MAX_PREVIEW_LENGTH: usize = 2000;
+
+/// A user's keywords and other preferences, as exported to JSON.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Export {
+	version: u32,
+	#[serde(default)]
+	keywords: Vec<ServerKeyword>,
+	#[serde(default)]
+	channel_keywords: Vec<ChannelKeyword>,
+	#[serde(default)]
+	keyword_authors: Vec<ServerKeywordAuthor>,
+	#[serde(default)]
+	ignores: Vec<ServerIgnore>,
+	#[serde(default)]
+	mutes: Vec<ChannelId>,
+	#[serde(default)]
+	blocks: Vec<UserId>,
+	/// Blocked users the user's messages are hidden from too.
+	#[serde(default)]
+	hidden_from: Vec<UserId>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ServerKeyword {
+	keyword: String,
+	server: GuildId,
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	channels: Vec<ChannelId>,
+	#[serde(default, skip_serializing_if = "Vec::is_empty")]
+	excluded_channels: Vec<ChannelId>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ChannelKeyword {
+	keyword: String,
+	channel: ChannelId,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ServerKeywordAuthor {
+	keyword: String,
+	server: GuildId,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	user: Option<UserId>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	role: Option<RoleId>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ServerIgnore {
+	phrase: String,
+	server: GuildId,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	channel: Option<ChannelId>,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	keyword: Option<String>,
+}
+
+/// A user's current keywords and other preferences in the DB.
+struct Current {
+	keywords: Vec<Keyword>,
+	keyword_channels: Vec<KeywordChannel>,
+	keyword_authors: Vec<KeywordAuthor>,
+	ignores: Vec<Ignore>,
+	mutes: Vec<Mute>,
+	blocks: Vec<Block>,
+}
+
+impl Current {
+	/// Fetches the given user's keywords and other preferences from the DB.
+	async fn of_user(user_id: UserId) -> Result<Self> {
+		Ok(Self {
+			keywords: Keyword::user_keywords(user_id).await?,
+			keyword_channels: KeywordChannel::user_channels(user_id).await?,
+			keyword_authors: KeywordAuthor::user_filters(user_id).await?,
+			ignores: Ignore::user_ignores(user_id).await?,
+			mutes: Mute::user_mutes(user_id).await?,
+			blocks: Block::user_blocks(user_id).await?,
+		})
+	}
+
+	fn is_empty(&self) -> bool {
+		self.keywords.is_empty()
+			&& self.keyword_channels.is_empty()
+			&& self.keyword_authors.is_empty()
+			&& self.ignores.is_empty()
+			&& self.mutes.is_empty()
+			&& self.blocks.is_empty()
+	}
+}
+
+impl From<Current> for Export {
+	fn from(current: Current) -> Self {
+		let mut export = Export {
+			version: EXPORT_VERSION,
+			..Default::default()
+		};
+
+		for keyword in current.keywords {
+			match keyword.kind {
+				KeywordKind::Guild(server) => {
+					let channels = |excluded: bool| {
+						current
+							.keyword_channels
+							.iter()
+							.filter(|channel| {
+								channel.guild_id == server
+									&& channel.keyword == keyword.keyword
+									&& channel.excluded == excluded
+							})
+							.map(|channel| channel.channel_id)
+							.collect()
+					};
+
+					export.keywords.push(ServerKeyword {
+						channels: channels(false),
+						excluded_channels: channels(true),
+						keyword: keyword.keyword,
+						server,
+					})
+				}
+				KeywordKind::Channel(channel) => {
+					export.channel_keywords.push(ChannelKeyword {
+						keyword: keyword.keyword,
+						channel,
+					})
+				}
+			}
+		}
+
+		export.keyword_authors = current
+			.keyword_authors
+			.into_iter()
+			.map(|filter| {
+				let (user, role) = match filter.author {
+					AuthorFilter::User(user_id) => (Some(user_id), None),
+					AuthorFilter::Role(role_id) => (None, Some(role_id)),
+				};
+
+				ServerKeywordAuthor {
+					keyword: filter.keyword,
+					server: filter.guild_id,
+					user,
+					role,
+				}
+			})
+			.collect();
+
+		export.ignores = current
+			.ignores
+			.into_iter()
+			.map(|ignore| ServerIgnore {
+				phrase: ignore.phrase,
+				server: ignore.guild_id,
+				channel: ignore.channel_id,
+				keyword: ignore.keyword,
+			})
+			.collect();
+
+		export.mutes =
+			current.mutes.into_iter().map(|m| m.channel_id).collect();
+
+		export.hidden_from = current
+			.blocks
+			.iter()
+			.filter(|b| b.hide_messages)
+			.map(|b| b.blocked_id)
+			.collect();
+
+		export.blocks =
+			current.blocks.into_iter().map(|b| b.blocked_id).collect();
+
+		export
+	}
+}
+
+/// Looks up which servers and channels a user can use, remembering the
+/// results.
+struct Access<'a> {
+	ctx: &'a Context,
+	user_id: UserId,
+	guilds: HashMap<GuildId, bool>,
+	channels: HashMap<ChannelId, Option<GuildId>>,
+}
+
+impl<'a> Access<'a> {
+	fn new(ctx: &'a Context, user_id: UserId) -> Self {
+		Self {
+			ctx,
+			user_id,
+			guilds: HashMap::new(),
+			channels: HashMap::new(),
+		}
+	}
+
+	/// Checks if the user is a member of the given guild, and the bot is too.
+	async fn guild(&mut self, guild_id: GuildId) -> Result<bool> {
+		if let Some(&member) = self.guilds.get(&guild_id) {
+			return Ok(member);
+		}
+
+		let member = self.ctx.cache.guild_field(guild_id, |_| ()).is_some()
+			&& optional_result(guild_id.member(self.ctx, self.user_id).await)?
+				.is_some();
+
+		self.guilds.insert(guild_id, member);
+
+		Ok(member)
+	}
+
+	/// Gets the guild of the given text channel, if the user can read it.
+	async fn channel(
+		&mut self,
+		channel_id: ChannelId,
+	) -> Result<Option<GuildId>> {
+		if let Some(&guild_id) = self.channels.get(&channel_id) {
+			return Ok(guild_id);
+		}
+
+		let guild_id = match self.ctx.cache.guild_channel(channel_id) {
+			Some(channel) if channel.kind == ChannelType::Text => {
+				match user_can_read_channel(self.ctx, &channel, self.user_id)
+					.await?
+				{
+					Some(true) => Some(channel.guild_id),
+					_ => None,
+				}
+			}
+			_ => None,
+		};
+
+		self.channels.insert(channel_id, guild_id);
+
+		Ok(guild_id)
+	}
+}
+
+/// Changes to make to a user's preferences to import an [`Export`].
+#[derive(Default)]
+struct ImportPlan {
+	add_keywords: Vec<Keyword>,
+	remove_keywords: Vec<Keyword>,
+	add_keyword_channels: Vec<KeywordChannel>,
+	remove_keyword_channels: Vec<KeywordChannel>,
+	add_keyword_authors: Vec<KeywordAuthor>,
+	remove_keyword_authors: Vec<KeywordAuthor>,
+	add_ignores: Vec<Ignore>,
+	remove_ignores: Vec<Ignore>,
+	add_mutes: Vec<Mute>,
+	remove_mutes: Vec<Mute>,
+	/// Blocks to add, or to change whether messages are hidden from the
+	/// blocked user.
+	add_blocks: Vec<Block>,
+	remove_blocks: Vec<Block>,
+	/// Descriptions of entries that were skipped because they're invalid.
+	invalid: Vec<String>,
+	/// Descriptions of entries that were skipped because they're in servers
+	/// or channels the user isn't in or can't read.
+	inaccessible: Vec<String>,
+	/// Keywords that were skipped because they would exceed the keyword
+	/// limits.
+	over_limit: Vec<String>,
+}
+
+impl ImportPlan {
+	/// Determines the changes to make to the user's current preferences to
+	/// import the given export.
+	///
+	/// Entries of the export are added unless they already exist. Keywords
+	/// are checked against the limits of the server they'd be added in, like
+	/// `/add`. If `replace` is true, current entries not in the export are
+	/// removed.
+	async fn new(
+		ctx: &Context,
+		user_id: UserId,
+		export: Export,
+		current: Current,
+		replace: bool,
+		locale: &Locale,
+	) -> Result<Self> {
+		let mut access = Access::new(ctx, user_id);
+		let mut plan = ImportPlan::default();
+
+		let mut keywords = Vec::new();
+		let mut keyword_channels = Vec::<KeywordChannel>::new();
+
+		let mut server_keywords = Vec::new();
+
+		for keyword in export.keywords {
+			let normalized = keyword.keyword.trim().to_lowercase();
+			let channels = keyword
+				.channels
+				.into_iter()
+				.map(|channel_id| (channel_id, false))
+				.chain(
+					keyword
+						.excluded_channels
+						.into_iter()
+						.map(|channel_id| (channel_id, true)),
+				);
+
+			for (channel_id, excluded) in channels {
+				let channel = KeywordChannel {
+					user_id,
+					guild_id: keyword.server,
+					keyword: normalized.clone(),
+					channel_id,
+					excluded,
+				};
+
+				match keyword_channels
+					.iter()
+					.find(|c| same_keyword_channel(c, &channel))
+				{
+					Some(c) if c.excluded != channel.excluded => {
+						plan.invalid.push(locale.format(
+							"import-entry-keyword-channel",
+							&[
+								("channel", &channel_id),
+								("keyword", &normalized),
+							],
+						));
+					}
+					Some(_) => {}
+					None => keyword_channels.push(channel),
+				}
+			}
+
+			server_keywords
+				.push((keyword.keyword, KeywordKind::Guild(keyword.server)));
+		}
+
+		let channel_keywords = export
+			.channel_keywords
+			.into_iter()
+			.map(|k| (k.keyword, KeywordKind::Channel(k.channel)));
+
+		for (keyword, kind) in
+			server_keywords.into_iter().chain(channel_keywords)
+		{
+			let normalized = keyword.trim().to_lowercase();
+
+			if !Keyword::is_allowed(&normalized) {
+				plan.invalid.push(
+					locale.format(
+						"import-entry-keyword",
+						&[("keyword", &keyword)],
+					),
+				);
+				continue;
+			}
+
+			let keyword = Keyword {
+				keyword: normalized,
+				user_id,
+				kind,
+			};
+
+			if !keywords.iter().any(|k| same_keyword(k, &keyword)) {
+				keywords.push(keyword);
+			}
+		}
+
+		if replace {
+			plan.remove_keywords = current
+				.keywords
+				.iter()
+				.filter(|c| !keywords.iter().any(|k| same_keyword(k, c)))
+				.cloned()
+				.collect();
+		}
+
+		let keyword_guild = |kind: KeywordKind| match kind {
+			KeywordKind::Guild(guild_id) => Some(guild_id),
+			KeywordKind::Channel(channel_id) => {
+				ctx.cache.guild_channel_field(channel_id, |c| c.guild_id)
+			}
+		};
+
+		let removed_guilds = plan
+			.remove_keywords
+			.iter()
+			.map(|keyword| keyword_guild(keyword.kind))
+			.collect::<Vec<_>>();
+		let mut added_guilds = Vec::new();
+		let mut limits = HashMap::<GuildId, KeywordLimits>::new();
+
+		for keyword in keywords {
+			if current.keywords.iter().any(|c| same_keyword(c, &keyword)) {
+				continue;
+			}
+
+			let guild_id = match keyword.kind {
+				KeywordKind::Guild(guild_id) => {
+					access.guild(guild_id).await?.then_some(guild_id)
+				}
+				KeywordKind::Channel(channel_id) => {
+					match access.channel(channel_id).await? {
+						Some(guild_id)
+							if check_channel(
+								ctx, guild_id, channel_id, locale,
+							)
+							.await?
+							.is_none() =>
+						{
+							Some(guild_id)
+						}
+						_ => None,
+					}
+				}
+			};
+
+			let guild_id = match guild_id {
+				Some(guild_id) => guild_id,
+				None => {
+					plan.inaccessible.push(locale.format(
+						"import-entry-keyword",
+						&[("keyword", &keyword.keyword)],
+					));
+					continue;
+				}
+			};
+
+			let guild_limits = match limits.entry(guild_id) {
+				Entry::Occupied(entry) => entry.into_mut(),
+				Entry::Vacant(entry) => {
+					let mut guild_limits =
+						KeywordLimits::new(ctx, user_id, guild_id).await?;
+
+					for &removed in &removed_guilds {
+						guild_limits.remove(removed == Some(guild_id));
+					}
+
+					for &added in &added_guilds {
+						if added == guild_id {
+							guild_limits.add();
+						} else {
+							guild_limits.add_elsewhere();
+						}
+					}
+
+					entry.insert(guild_limits)
+				}
+			};
+
+			if guild_limits
+				.check_keyword(&keyword.keyword, locale)
+				.is_some()
+			{
+				plan.invalid.push(locale.format(
+					"import-entry-keyword",
+					&[("keyword", &keyword.keyword)],
+				));
+				continue;
+			}
+
+			if guild_limits.check_count(locale).is_some() {
+				plan.over_limit.push(keyword.keyword);
+				continue;
+			}
+
+			for (&id, guild_limits) in &mut limits {
+				if id == guild_id {
+					guild_limits.add();
+				} else {
+					guild_limits.add_elsewhere();
+				}
+			}
+
+			added_guilds.push(guild_id);
+			plan.add_keywords.push(keyword);
+		}
+
+		let kept_keywords = current
+			.keywords
+			.iter()
+			.filter(|c| {
+				!plan.remove_keywords.iter().any(|r| same_keyword(r, c))
+			})
+			.chain(&plan.add_keywords)
+			.collect::<Vec<_>>();
+
+		let mut valid_channels = Vec::new();
+
+		for channel in keyword_channels {
+			let kept = kept_keywords.iter().any(|k| {
+				k.keyword == channel.keyword
+					&& k.kind == KeywordKind::Guild(channel.guild_id)
+			});
+
+			// the keyword itself was skipped
+			if !kept {
+				continue;
+			}
+
+			let accessible = access.channel(channel.channel_id).await?
+				== Some(channel.guild_id)
+				&& (channel.excluded
+					|| check_channel(
+						ctx,
+						channel.guild_id,
+						channel.channel_id,
+						locale,
+					)
+					.await?
+					.is_none());
+
+			if !accessible {
+				plan.inaccessible.push(locale.format(
+					"import-entry-keyword-channel",
+					&[
+						("channel", &channel.channel_id),
+						("keyword", &channel.keyword),
+					],
+				));
+				continue;
+			}
+
+			valid_channels.push(channel);
+		}
+
+		if replace {
+			plan.remove_keyword_channels = current
+				.keyword_channels
+				.iter()
+				.filter(|c| {
+					kept_keywords.iter().any(|k| {
+						k.keyword == c.keyword
+							&& k.kind == KeywordKind::Guild(c.guild_id)
+					}) && !valid_channels.iter().any(|v| {
+						same_keyword_channel(v, c) && v.excluded == c.excluded
+					})
+				})
+				.cloned()
+				.collect();
+		}
+
+		for channel in valid_channels {
+			match current
+				.keyword_channels
+				.iter()
+				.find(|c| same_keyword_channel(c, &channel))
+			{
+				Some(c) if c.excluded == channel.excluded => continue,
+				// the channel switches between being limited and excluded
+				Some(c)
+					if !plan
+						.remove_keyword_channels
+						.iter()
+						.any(|r| same_keyword_channel(r, c)) =>
+				{
+					plan.remove_keyword_channels.push(c.clone());
+				}
+				_ => {}
+			}
+
+			let count = current
+				.keyword_channels
+				.iter()
+				.filter(|c| {
+					!plan
+						.remove_keyword_channels
+						.iter()
+						.any(|r| same_keyword_channel(r, c))
+				})
+				.chain(&plan.add_keyword_channels)
+				.filter(|c| {
+					c.guild_id == channel.guild_id
+						&& c.keyword == channel.keyword
+						&& c.excluded == channel.excluded
+				})
+				.count();
+
+			if count >= MAX_KEYWORD_CHANNELS {
+				plan.invalid.push(locale.format(
+					"import-entry-keyword-channel",
+					&[
+						("channel", &channel.channel_id),
+						("keyword", &channel.keyword),
+					],
+				));
+				continue;
+			}
+
+			plan.add_keyword_channels.push(channel);
+		}
+
+		let mut keyword_authors = Vec::<KeywordAuthor>::new();
+
+		for filter in export.keyword_authors {
+			let keyword = filter.keyword.trim().to_lowercase();
+
+			let author = match (filter.user, filter.role) {
+				(Some(user_id), None) => AuthorFilter::User(user_id),
+				(None, Some(role_id)) => AuthorFilter::Role(role_id),
+				_ => {
+					plan.invalid.push(locale.format(
+						"import-entry-keyword-author",
+						&[("keyword", &keyword)],
+					));
+					continue;
+				}
+			};
+
+			let filter = KeywordAuthor {
+				user_id,
+				guild_id: filter.server,
+				keyword,
+				author,
+			};
+
+			let kept = kept_keywords.iter().any(|k| {
+				k.keyword == filter.keyword
+					&& keyword_guild(k.kind) == Some(filter.guild_id)
+			});
+
+			// the keyword itself was skipped
+			if !kept {
+				continue;
+			}
+
+			if !access.guild(filter.guild_id).await? {
+				plan.inaccessible.push(locale.format(
+					"import-entry-keyword-author",
+					&[("keyword", &filter.keyword)],
+				));
+				continue;
+			}
+
+			if !keyword_authors
+				.iter()
+				.any(|f| same_keyword_author(f, &filter))
+			{
+				keyword_authors.push(filter);
+			}
+		}
+
+		if replace {
+			plan.remove_keyword_authors = current
+				.keyword_authors
+				.iter()
+				.filter(|c| {
+					!keyword_authors.iter().any(|f| same_keyword_author(f, c))
+				})
+				.cloned()
+				.collect();
+		}
+
+		for filter in keyword_authors {
+			if current
+				.keyword_authors
+				.iter()
+				.any(|c| same_keyword_author(c, &filter))
+			{
+				continue;
+			}
+
+			let count = current
+				.keyword_authors
+				.iter()
+				.filter(|c| {
+					!plan
+						.remove_keyword_authors
+						.iter()
+						.any(|r| same_keyword_author(r, c))
+				})
+				.chain(&plan.add_keyword_authors)
+				.filter(|c| {
+					c.guild_id == filter.guild_id && c.keyword == filter.keyword
+				})
+				.count();
+
+			if count >= MAX_AUTHORS {
+				plan.invalid.push(locale.format(
+					"import-entry-keyword-author",
+					&[("keyword", &filter.keyword)],
+				));
+				continue;
+			}
+
+			plan.add_keyword_authors.push(filter);
+		}
+
+		let mut ignores = Vec::<Ignore>::new();
+
+		for ignore in export.ignores {
+			if ignore.phrase.len() < 3 {
+				plan.invalid.push(locale.format(
+					"import-entry-ignore",
+					&[("phrase", &ignore.phrase)],
+				));
+				continue;
+			}
+
+			let ignore = Ignore {
+				phrase: ignore.phrase.to_lowercase(),
+				user_id,
+				guild_id: ignore.server,
+				channel_id: ignore.channel,
+				keyword: ignore
+					.keyword
+					.map(|keyword| keyword.trim().to_lowercase())
+					.filter(|keyword| !keyword.is_empty()),
+			};
+
+			if !ignores.iter().any(|i| same_ignore(i, &ignore)) {
+				ignores.push(ignore);
+			}
+		}
+
+		if replace {
+			plan.remove_ignores = current
+				.ignores
+				.iter()
+				.filter(|c| !ignores.iter().any(|i| same_ignore(i, c)))
+				.cloned()
+				.collect();
+		}
+
+		for ignore in ignores {
+			if current.ignores.iter().any(|c| same_ignore(c, &ignore)) {
+				continue;
+			}
+
+			let accessible = match ignore.channel_id {
+				Some(channel_id) => {
+					access.channel(channel_id).await? == Some(ignore.guild_id)
+				}
+				None => access.guild(ignore.guild_id).await?,
+			};
+
+			if !accessible {
+				plan.inaccessible.push(locale.format(
+					"import-entry-ignore",
+					&[("phrase", &ignore.phrase)],
+				));
+				continue;
+			}
+
+			plan.add_ignores.push(ignore);
+		}
+
+		let mut mutes = export.mutes;
+		mutes.sort_unstable();
+		mutes.dedup();
+
+		if replace {
+			plan.remove_mutes = current
+				.mutes
+				.iter()
+				.filter(|c| !mutes.contains(&c.channel_id))
+				.cloned()
+				.collect();
+		}
+
+		for channel_id in mutes {
+			if current.mutes.iter().any(|c| c.channel_id == channel_id) {
+				continue;
+			}
+
+			if access.channel(channel_id).await?.is_none() {
+				plan.inaccessible.push(
+					locale.format(
+						"import-entry-mute",
+						&[("channel", &channel_id)],
+					),
+				);
+				continue;
+			}
+
+			plan.add_mutes.push(Mute {
+				user_id,
+				channel_id,
+			});
+		}
+
+		let mut blocks = export.blocks;
+		blocks.sort_unstable();
+		blocks.dedup();
+
+		if blocks.contains(&user_id) {
+			plan.invalid
+				.push(locale.get("import-entry-self-block").to_owned());
+			blocks.retain(|&id| id != user_id);
+		}
+
+		if replace {
+			plan.remove_blocks = current
+				.blocks
+				.iter()
+				.filter(|c| !blocks.contains(&c.blocked_id))
+				.cloned()
+				.collect();
+		}
+
+		plan.add_blocks = blocks
+			.into_iter()
+			.map(|blocked_id| Block {
+				user_id,
+				blocked_id,
+				hide_messages: export.hidden_from.contains(&blocked_id),
+			})
+			.filter(|block| {
+				!current.blocks.iter().any(|c| {
+					c.blocked_id == block.blocked_id
+						&& c.hide_messages == block.hide_messages
+				})
+			})
+			.collect();
+
+		Ok(plan)
+	}
+
+	fn is_empty(&self) -> bool {
+		self.add_keywords.is_empty()
+			&& self.remove_keywords.is_empty()
+			&& self.add_keyword_channels.is_empty()
+			&& self.remove_keyword_channels.is_empty()
+			&& self.add_keyword_authors.is_empty()
+			&& self.remove_keyword_authors.is_empty()
+			&& self.add_ignores.is_empty()
+			&& self.remove_ignores.is_empty()
+			&& self.add_mutes.is_empty()
+			&& self.remove_mutes.is_empty()
+			&& self.add_blocks.is_empty()
+			&& self.remove_blocks.is_empty()
+	}
+
+	/// Describes each change in this plan as a line of a diff.
+	fn diff(&self, ctx: &Context, locale: &Locale) -> Vec<String> {
+		let guild_name = |guild_id: GuildId| {
+			ctx.cache
+				.guild_field(guild_id, |g| g.name.clone())
+				.unwrap_or_else(|| {
+					locale.format("import-unknown-server", &[("id", &guild_id)])
+				})
+		};
+
+		let channel_name = |channel_id: ChannelId| {
+			ctx.cache
+				.guild_channel_field(channel_id, |c| format!("#{}", c.name))
+				.unwrap_or_else(|| {
+					locale.format(
+						"import-unknown-channel",
+						&[("id", &channel_id)],
+					)
+				})
+		};
+
+		let user_name = |user_id: UserId| {
+			ctx.cache.user(user_id).map(|u| u.tag()).unwrap_or_else(|| {
+				locale.format("import-unknown-user", &[("id", &user_id)])
+			})
+		};
+
+		let role_name = |guild_id: GuildId, role_id: RoleId| {
+			ctx.cache
+				.guild_field(guild_id, |g| {
+					g.roles.get(&role_id).map(|r| format!("@{}", r.name))
+				})
+				.flatten()
+				.unwrap_or_else(|| {
+					locale.format("import-unknown-role", &[("id", &role_id)])
+				})
+		};
+
+		let keyword_line = |sign: char, keyword: &Keyword| {
+			let location = match keyword.kind {
+				KeywordKind::Guild(guild_id) => guild_name(guild_id),
+				KeywordKind::Channel(channel_id) => channel_name(channel_id),
+			};
+			let line = locale.format(
+				"import-diff-keyword",
+				&[("keyword", &keyword.keyword), ("location", &location)],
+			);
+			format!("{sign} {line}")
+		};
+
+		let keyword_channel_line = |sign: char, channel: &KeywordChannel| {
+			let key = if channel.excluded {
+				"import-diff-keyword-not-in"
+			} else {
+				"import-diff-keyword-only-in"
+			};
+			let line = locale.format(
+				key,
+				&[
+					("keyword", &channel.keyword),
+					("server", &guild_name(channel.guild_id)),
+					("channel", &channel_name(channel.channel_id)),
+				],
+			);
+			format!("{sign} {line}")
+		};
+
+		let keyword_author_line = |sign: char, filter: &KeywordAuthor| {
+			let author = match filter.author {
+				AuthorFilter::User(user_id) => user_name(user_id),
+				AuthorFilter::Role(role_id) => {
+					role_name(filter.guild_id, role_id)
+				}
+			};
+			let line = locale.format(
+				"import-diff-keyword-author",
+				&[
+					("keyword", &filter.keyword),
+					("server", &guild_name(filter.guild_id)),
+					("author", &author),
+				],
+			);
+			format!("{sign} {line}")
+		};
+
+		let ignore_line = |sign: char, ignore: &Ignore| {
+			let location = match ignore.channel_id {
+				Some(channel_id) => channel_name(channel_id),
+				None => guild_name(ignore.guild_id),
+			};
+			let line = match &ignore.keyword {
+				Some(keyword) => locale.format(
+					"import-diff-ignore-keyword",
+					&[
+						("phrase", &ignore.phrase),
+						("location", &location),
+						("keyword", keyword),
+					],
+				),
+				None => locale.format(
+					"import-diff-ignore",
+					&[("phrase", &ignore.phrase), ("location", &location)],
+				),
+			};
+			format!("{sign} {line}")
+		};
+
+		let mute_line = |sign: char, mute: &Mute| {
+			let line = locale.format(
+				"import-diff-mute",
+				&[("channel", &channel_name(mute.channel_id))],
+			);
+			format!("{sign} {line}")
+		};
+
+		let block_line = |sign: char, block: &Block| {
+			let key = if sign == '+' && block.hide_messages {
+				"import-diff-block-hidden"
+			} else {
+				"import-diff-block"
+			};
+			let line =
+				locale.format(key, &[("user", &user_name(block.blocked_id))]);
+			format!("{sign} {line}")
+		};
+
+		let mut lines = Vec::new();
+
+		lines.extend(self.remove_keywords.iter().map(|k| keyword_line('-', k)));
+		lines.extend(self.add_keywords.iter().map(|k| keyword_line('+', k)));
+		lines.extend(
+			self.remove_keyword_channels
+				.iter()
+				.map(|c| keyword_channel_line('-', c)),
+		);
+		lines.extend(
+			self.add_keyword_channels
+				.iter()
+				.map(|c| keyword_channel_line('+', c)),
+		);
+		lines.extend(
+			self.remove_keyword_authors
+				.iter()
+				.map(|f| keyword_author_line('-', f)),
+		);
+		lines.extend(
+			self.add_keyword_authors
+				.iter()
+				.map(|f| keyword_author_line('+', f)),
+		);
+		lines.extend(self.remove_ignores.iter().map(|i| ignore_line('-', i)));
+		lines.extend(self.add_ignores.iter().map(|i| ignore_line('+', i)));
+		lines.extend(self.remove_mutes.iter().map(|m| mute_line('-', m)));
+		lines.extend(self.add_mutes.iter().map(|m| mute_line('+', m)));
+		lines.extend(self.remove_blocks.iter().map(|b| block_line('-', b)));
+		lines.extend(self.add_blocks.iter().map(|b| block_line('+', b)));
+
+		lines
+	}
+
+	/// Summarizes the changes in this plan, and what was skipped.
+	fn summary(&self, locale: &Locale) -> String {
+		let mut summary = String::new();
+
+		for (added, removed, kind) in [
+			(
+				self.add_keywords.len(),
+				self.remove_keywords.len(),
+				"import-kind-keywords",
+			),
+			(
+				self.add_keyword_channels.len(),
+				self.remove_keyword_channels.len(),
+				"import-kind-keyword-channels",
+			),
+			(
+				self.add_keyword_authors.len(),
+				self.remove_keyword_authors.len(),
+				"import-kind-keyword-authors",
+			),
+			(
+				self.add_ignores.len(),
+				self.remove_ignores.len(),
+				"import-kind-ignores",
+			),
+			(
+				self.add_mutes.len(),
+				self.remove_mutes.len(),
+				"import-kind-mutes",
+			),
+			(
+				self.add_blocks.len(),
+				self.remove_blocks.len(),
+				"import-kind-blocks",
+			),
+		] {
+			if added == 0 && removed == 0 {
+				continue;
+			}
+
+			let line = locale.format(
+				"import-added",
+				&[("kind", &locale.get(kind)), ("count", &added)],
+			);
+			write!(summary, "\n- {line}").unwrap();
+
+			if removed > 0 {
+				let removed =
+					locale.format("import-removed", &[("count", &removed)]);
+				write!(summary, ", {removed}").unwrap();
+			}
+		}
+
+		for (skipped, key) in [
+			(&self.invalid, "import-skipped-invalid"),
+			(&self.inaccessible, "import-skipped-inaccessible"),
+			(&self.over_limit, "import-skipped-limit"),
+		] {
+			if !skipped.is_empty() {
+				let list = short_list(skipped);
+				write!(summary, "\n{}", locale.format(key, &[("list", &list)]))
+					.unwrap();
+			}
+		}
+
+		summary
+	}
+
+	/// Makes the changes in this plan in the DB.
+	async fn apply(self, ctx: &Context) -> Result<()> {
+		for channel in self.remove_keyword_channels {
+			channel.delete().await?;
+		}
+
+		for filter in self.remove_keyword_authors {
+			filter.delete().await?;
+		}
+
+		// author filters are removed above as planned
+		for keyword in self.remove_keywords {
+			keyword.clone().delete(None).await?;
+			stats::keyword_removed(ctx, &keyword).await?;
+		}
+
+		for keyword in self.add_keywords {
+			keyword.insert().await?;
+		}
+
+		for channel in self.add_keyword_channels {
+			channel.insert().await?;
+		}
+
+		for filter in self.add_keyword_authors {
+			filter.insert().await?;
+		}
+
+		for ignore in self.remove_ignores {
+			ignore.delete().await?;
+		}
+
+		for ignore in self.add_ignores {
+			ignore.insert().await?;
+		}
+
+		for mute in self.remove_mutes {
+			mute.delete().await?;
+		}
+
+		for mute in self.add_mutes {
+			mute.insert().await?;
+		}
+
+		for block in self.remove_blocks {
+			block.delete().await?;
+		}
+
+		for block in self.add_blocks {
+			block.set().await?;
+		}
+
+		Ok(())
+	}
+}
+
+fn same_keyword(a: &Keyword, b: &Keyword) -> bool {
+	a.keyword == b.keyword
+		&& match (a.kind, b.kind) {
+			(KeywordKind::Guild(a), KeywordKind::Guild(b)) => a == b,
+			(KeywordKind::Channel(a), KeywordKind::Channel(b)) => a == b,
+			_ => false,
+		}
+}
+
+/// Checks if two keyword channels are for the same channel, whether they're
+/// limited or excluded.
+fn same_keyword_channel(a: &KeywordChannel, b: &KeywordChannel) -> bool {
+	a.guild_id == b.guild_id
+		&& a.keyword == b.keyword
+		&& a.channel_id == b.channel_id
+}
+
+fn same_keyword_author(a: &KeywordAuthor, b: &KeywordAuthor) -> bool {
+	a.guild_id == b.guild_id && a.keyword == b.keyword && a.author == b.author
+}
+
+fn same_ignore(a: &Ignore, b: &Ignore) -> bool {
+	a.phrase == b.phrase
+		&& a.guild_id == b.guild_id
+		&& a.channel_id == b.channel_id
+		&& a.keyword == b.keyword
+}
+
+/// Export keywords, ignores, mutes, and blocks as a JSON file.
+///
+/// Usage: `/export`
+#[tracing::instrument(
+	skip_all,
+	fields(
+		user_id = %command.user.id,
+		channel_id = %command.channel_id,
+		command = %command.data.name,
+	)
+)]
+pub(crate) async fn export(ctx: Context, command: Command) -> Result<()> {
+	check_opt_out!(ctx, command);
+	let locale = locale(&command.locale);
+
+	let current = Current::of_user(command.user.id).await?;
+
+	if current.is_empty() {
+		return respond_eph(&ctx, &command, locale.get("export-empty")).await;
+	}
+
+	let data = serde_json::to_vec_pretty(&Export::from(current))
+		.context("Failed to serialize export")?;
+
+	command
+		.create_interaction_response(&ctx, |r| {
+			r.interaction_response_data(|m| {
+				m.flags(MessageFlags::EPHEMERAL)
+					.content(locale.get("export-done"))
+					.add_file(AttachmentType::Bytes {
+						data: Cow::Owned(data),
+						filename: "highlights.json".to_owned(),
+					})
+			})
+		})
+		.await
+		.context("Failed to send export")?;
+
+	Ok(())
+}
+
+/// Import keywords, ignores, mutes, and blocks from a file created by
+/// `/export`, after previewing the changes.
+///
+/// Usage: `/import <file> [replace]`
+#[tracing::instrument(
+	skip_all,
+	fields(
+		user_id = %command.user.id,
+		channel_id = %command.channel_id,
+		command = %command.data.name,
+	)
+)]
+pub(crate) async fn import(ctx: Context, command: Command) -> Result<()> {
+	check_opt_out!(ctx, command);
+	let locale = locale(&command.locale);
+	let user_id = command.user.id;
+
+	let attachment = command
+		.data
+		.resolved
+		.attachments
+		.values()
+		.next()
+		.context("No file to import provided")?;
+
+	let replace = command
+		.data
+		.options
+		.iter()
+		.find(|o| o.name == "replace")
+		.and_then(|o| o.value.as_ref())
+		.and_then(|v| v.as_bool())
+		.unwrap_or(false);
+
+	if attachment.size > MAX_IMPORT_SIZE {
+		return respond_eph(&ctx, &command, locale.get("import-too-large"))
+			.await;
+	}
+
+	let data = attachment
+		.download()
+		.await
+		.context("Failed to download import file")?;
+
+	let export = match serde_json::from_slice::<Export>(&data) {
+		Ok(export) if export.version <= EXPORT_VERSION => export,
+		Ok(_) => {
+			return respond_eph(&ctx, &command, locale.get("import-newer"))
+				.await
+		}
+		Err(_) => {
+			return respond_eph(&ctx, &command, locale.get("import-invalid"))
+				.await
+		}
+	};
+
+	let current = Current::of_user(user_id).await?;
+
+	let plan = ImportPlan::new(&ctx, user_id, export, current, replace, locale)
+		.await?;
+
+	if plan.is_empty() {
+		let mut response = locale.get("import-nothing").to_owned();
+		response.push_str(&plan.summary(locale));
+		return respond_eph(&ctx, &command, response).await;
+	}
+
+	let preview = preview(&plan, &ctx, locale);
+
+	let nonce = rand::thread_rng()
+		.sample_iter::<char, _>(Standard)
+		.take(90)
+		.collect::<String>();
+
+	let confirm_id = format!("confirm{}", nonce);
+	let cancel_id = format!("cancel{}", nonce);
+
+	command
+		.create_interaction_response(&ctx, |r| {
+			r.interaction_response_data(|m| {
+				m.flags(MessageFlags::EPHEMERAL)
+					.content(preview)
+					.components(|c| {
+						c.create_action_row(|row| {
+							row.create_button(|b| {
+								b.style(ButtonStyle::Primary)
+									.label(locale.get("import-confirm"))
+									.custom_id(&confirm_id)
+							})
+							.create_button(|b| {
+								b.style(ButtonStyle::Secondary)
+									.label(locale.get("import-cancel"))
+									.custom_id(&cancel_id)
+							})
+						})
+					})
+			})
+		})
+		.await
+		.context("Failed to send import preview")?;
+
+	let button_press = ComponentInteractionCollectorBuilder::new(&ctx)
+		.collect_limit(1)
+		.author_id(user_id)
+		.filter({
+			let confirm_id = confirm_id.clone();
+			let cancel_id = cancel_id.clone();
+			move |interaction| {
+				let id = interaction.data.custom_id.as_str();
+				id == confirm_id || id == cancel_id
+			}
+		})
+		.timeout(CONFIRM_TIMEOUT)
+		.build()
+		.next()
+		.await;
+
+	let press = match button_press {
+		Some(press) => press,
+		None => {
+			command
+				.edit_original_interaction_response(&ctx, |r| {
+					r.content(locale.get("import-timeout")).components(|c| c)
+				})
+				.await
+				.context("Failed to edit import preview")?;
+
+			return Ok(());
+		}
+	};
+
+	let response = match press.data.custom_id.as_str() {
+		id if id == confirm_id => {
+			plan.apply(&ctx).await?;
+			locale.get("import-done")
+		}
+		id if id == cancel_id => locale.get("import-cancelled"),
+		other => bail!("Unknown import message component ID {}", other),
+	};
+
+	press
+		.create_interaction_response(&ctx, |r| {
+			r.kind(InteractionResponseType::UpdateMessage)
+				.interaction_response_data(|m| {
+					m.content(response).components(|c| c)
+				})
+		})
+		.await
+		.context("Failed to update import preview")?;
+
+	Ok(())
+}
+
+/// Builds a message previewing the changes an import would make.
+fn preview(plan: &ImportPlan, ctx: &Context, locale: &Locale) -> String {
+	let mut preview = locale.get("import-preview").to_owned();
+	preview.push_str(&plan.summary(locale));
+	preview.push_str("\n```diff\n");
+
+	let lines = plan.diff(ctx, locale);
+	let total = lines.len();
+
+	// leave room for the end of the code block and a count of omitted lines
+	let max_length = MAX_PREVIEW_LENGTH - 40;
+
+	for (i, line) in lines.into_iter().enumerate() {
+		if preview.len() + line.len() + 1 > max_length {
+			preview.push_str("```");
+			preview.push_str(
+				&locale.format("import-more", &[("count", &(total - i))]),
+			);
+			return preview;
+		}
+
+		preview.push_str(&line.replace('`', "'"));
+		preview.push('\n');
+	}
+
+	preview.push_str("```");
+	preview
+}
@@ -0,0 +1,117 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! Command for configuring where notifications are sent to users that can't
+//! receive DMs.
+
+use anyhow::{Context as _, Result};
+use serenity::{
+	client::Context,
+	model::{
+		application::interaction::application_command::ApplicationCommandInteraction as Command,
+		channel::ChannelType, mention::Mentionable,
+	},
+};
+
+use crate::{
+	bot::{
+		i18n::locale,
+		util::{respond_eph, user_can_read_channel},
+	},
+	db::{FallbackChannel, FallbackThread},
+};
+
+/// Set, remove, or display the fallback notification channel of a server.
+///
+/// Usage: `/fallback [channel] [disable]`
+#[tracing::instrument(
+	skip_all,
+	fields(
+		user_id = %command.user.id,
+		channel_id = %command.channel_id,
+		command = %command.data.name,
+	)
+)]
+pub(crate) async fn fallback(ctx: Context, command: Command) -> Result<()> {
+	let guild_id = require_guild!(&ctx, &command);
+	require_manage_guild!(&ctx, &command);
+	let locale = locale(&command.locale);
+
+	let disable = command
+		.data
+		.options
+		.iter()
+		.find(|o| o.name == "disable")
+		.and_then(|o| o.value.as_ref())
+		.map(|v| v.as_bool().context("Option provided was not a boolean"))
+		.transpose()?
+		.unwrap_or(false);
+
+	if disable {
+		let key = if FallbackChannel::delete(guild_id).await? {
+			FallbackThread::delete_in_guild(guild_id).await?;
+			"fallback-disabled"
+		} else {
+			"fallback-missing"
+		};
+
+		return respond_eph(&ctx, &command, locale.get(key)).await;
+	}
+
+	let channel_id = match command.data.resolved.channels.keys().next() {
+		Some(&channel_id) => channel_id,
+		None => {
+			let response =
+				match FallbackChannel::guild_fallback(guild_id).await? {
+					Some(fallback) => locale.format(
+						"fallback-current",
+						&[("channel", &fallback.channel_id.mention())],
+					),
+					None => locale.get("fallback-none").to_owned(),
+				};
+
+			return respond_eph(&ctx, &command, response).await;
+		}
+	};
+
+	let channel = ctx
+		.cache
+		.guild_channel(channel_id)
+		.context("Failed to get fallback channel")?;
+
+	if channel.kind != ChannelType::Text {
+		return respond_eph(&ctx, &command, locale.get("error-text-channel"))
+			.await;
+	}
+
+	let self_id = ctx.cache.current_user_id();
+
+	let can_create_threads = channel
+		.permissions_for_user(&ctx, self_id)
+		.context("Failed to get permissions for self")?
+		.create_private_threads();
+
+	match user_can_read_channel(&ctx, &channel, self_id).await? {
+		Some(true) if can_create_threads => {}
+		_ => {
+			let response = locale.format(
+				"fallback-permissions",
+				&[("channel", &channel_id.mention())],
+			);
+			return respond_eph(&ctx, &command, response).await;
+		}
+	}
+
+	FallbackThread::delete_in_guild(guild_id).await?;
+
+	FallbackChannel {
+		guild_id,
+		channel_id,
+	}
+	.set()
+	.await?;
+
+	let response =
+		locale.format("fallback-set", &[("channel", &channel_id.mention())]);
+	respond_eph(&ctx, &command, response).await
+}
@@ -0,0 +1,243 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! Commands for following users' messages in channels.
+
+use anyhow::{Context as _, Result};
+use serenity::{
+	client::Context,
+	model::{
+		application::interaction::application_command::ApplicationCommandInteraction as Command,
+		id::{ChannelId, UserId},
+		mention::Mentionable,
+	},
+};
+
+use super::pages::{location, send_list, ListEntry, ListOptions};
+use crate::{
+	bot::{
+		i18n::locale,
+		util::{respond_eph, user_can_read_channel},
+	},
+	db::{Follow, OptOut},
+	settings::settings,
+};
+
+/// Gets the user option of a follow command.
+fn user_option(command: &Command) -> Result<(UserId, bool)> {
+	command
+		.data
+		.resolved
+		.users
+		.values()
+		.next()
+		.map(|user| (user.id, user.bot))
+		.context("No user provided")
+}
+
+/// Gets the channel option of a follow command, if provided.
+fn channel_option(command: &Command) -> Option<ChannelId> {
+	command.data.resolved.channels.keys().next().copied()
+}
+
+/// Follow a user's messages in a channel.
+///
+/// Usage: `/follow <user> [channel]`
+#[tracing::instrument(
+	skip_all,
+	fields(
+		user_id = %command.user.id,
+		channel_id = %command.channel_id,
+		command = %command.data.name,
+	)
+)]
+pub(crate) async fn follow(ctx: Context, command: Command) -> Result<()> {
+	check_opt_out!(ctx, command);
+	let guild_id = require_guild!(&ctx, &command);
+	let user_id = command.user.id;
+	let locale = locale(&command.locale);
+	let (followed_id, bot) = user_option(&command)?;
+	let channel_id = channel_option(&command).unwrap_or(command.channel_id);
+	let args = [
+		("user", &followed_id.mention() as _),
+		("channel", &channel_id.mention() as _),
+	];
+
+	if followed_id == user_id {
+		return respond_eph(&ctx, &command, locale.get("follow-self")).await;
+	}
+
+	if bot
+		|| (OptOut {
+			user_id: followed_id,
+		})
+		.exists()
+		.await?
+	{
+		return respond_eph(
+			&ctx,
+			&command,
+			locale.format("follow-never-highlighted", &args),
+		)
+		.await;
+	}
+
+	let channel = match ctx.cache.guild_channel(channel_id) {
+		Some(channel) if channel.guild_id == guild_id => channel,
+		_ => {
+			return respond_eph(
+				&ctx,
+				&command,
+				locale.get("follow-other-server"),
+			)
+			.await
+		}
+	};
+
+	match user_can_read_channel(&ctx, &channel, user_id).await {
+		Ok(Some(true)) => {}
+		Ok(Some(false)) | Ok(None) => {
+			return respond_eph(
+				&ctx,
+				&command,
+				locale.format("follow-cannot-read", &args),
+			)
+			.await
+		}
+		Err(e) => return Err(e).context("Failed to check permissions"),
+	}
+
+	let follow = Follow {
+		user_id,
+		followed_id,
+		guild_id,
+		channel_id,
+	};
+
+	if follow.clone().exists().await? {
+		return respond_eph(
+			&ctx,
+			&command,
+			locale.format("follow-exists", &args),
+		)
+		.await;
+	}
+
+	let max_follows = settings().behavior.max_follows;
+
+	if Follow::user_follow_count(user_id).await? >= max_follows as u64 {
+		return respond_eph(
+			&ctx,
+			&command,
+			locale.format("follow-limit", &[("max", &max_follows)]),
+		)
+		.await;
+	}
+
+	follow.insert().await?;
+
+	respond_eph(&ctx, &command, locale.format("follow-added", &args)).await
+}
+
+/// Stop following a user's messages in a channel, or in all channels.
+///
+/// Usage: `/unfollow <user> [channel]`
+#[tracing::instrument(
+	skip_all,
+	fields(
+		user_id = %command.user.id,
+		channel_id = %command.channel_id,
+		command = %command.data.name,
+	)
+)]
+pub(crate) async fn unfollow(ctx: Context, command: Command) -> Result<()> {
+	check_opt_out!(ctx, command);
+	let user_id = command.user.id;
+	let locale = locale(&command.locale);
+	let (followed_id, _) = user_option(&command)?;
+
+	let response = match channel_option(&command) {
+		Some(channel_id) => {
+			let follow = Follow::user_follows(user_id).await?.into_iter().find(
+				|follow| {
+					follow.followed_id == followed_id
+						&& follow.channel_id == channel_id
+				},
+			);
+
+			let args = [
+				("user", &followed_id.mention() as _),
+				("channel", &channel_id.mention() as _),
+			];
+
+			match follow {
+				Some(follow) => {
+					follow.delete().await?;
+					locale.format("follow-removed", &args)
+				}
+				None => locale.format("follow-missing", &args),
+			}
+		}
+		None => {
+			let key = match Follow::delete_all(user_id, followed_id).await? {
+				0 => "follow-missing-all",
+				_ => "follow-removed-all",
+			};
+
+			locale.format(key, &[("user", &followed_id.mention())])
+		}
+	};
+
+	respond_eph(&ctx, &command, response).await
+}
+
+/// List the users you follow.
+///
+/// Usage: `/follows`
+#[tracing::instrument(
+	skip_all,
+	fields(
+		user_id = %command.user.id,
+		channel_id = %command.channel_id,
+		command = %command.data.name,
+	)
+)]
+pub(crate) async fn follows(ctx: Context, command: Command) -> Result<()> {
+	check_opt_out!(ctx, command);
+	let locale = locale(&command.locale);
+
+	let options = match ListOptions::from_command(&command) {
+		Ok(options) => options,
+		Err(message) => return respond_eph(&ctx, &command, message).await,
+	};
+
+	let entries = Follow::user_follows(command.user.id)
+		.await?
+		.into_iter()
+		.map(|follow| ListEntry {
+			text: locale.format(
+				"follows-entry",
+				&[
+					("user", &follow.followed_id.mention()),
+					("channel", &follow.channel_id.mention()),
+				],
+			),
+			group: options
+				.guild_id
+				.is_none()
+				.then(|| location(&ctx, &options, Some(follow.guild_id), None)),
+			guild_id: Some(follow.guild_id),
+			channel_id: Some(follow.channel_id),
+		})
+		.collect();
+
+	send_list(
+		&ctx,
+		&command,
+		locale.get("follows-title"),
+		entries,
+		&options,
+		locale.get("follows-empty"),
+	)
+	.await
+}
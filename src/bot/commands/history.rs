@@ -0,0 +1,345 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! Command for browsing and searching notifications previously sent.
+
+use std::{
+	fmt::Write as _,
+	time::{Duration, UNIX_EPOCH},
+};
+
+use anyhow::{bail, Context as _, Result};
+use futures_util::StreamExt;
+use rand::{distributions::Standard, Rng};
+use serenity::{
+	builder::CreateEmbed,
+	client::Context,
+	collector::ComponentInteractionCollectorBuilder,
+	model::{
+		application::interaction::{
+			application_command::{
+				ApplicationCommandInteraction as Command, CommandDataOption,
+			},
+			InteractionResponseType, MessageFlags,
+		},
+		id::GuildId,
+		Timestamp,
+	},
+};
+
+use super::pages::page_buttons;
+use crate::{
+	bot::{
+		i18n::{locale, Locale},
+		util::respond_eph,
+	},
+	db::{
+		Delivery, DeliveryMethod, HistoryFilter, Notification,
+		NotificationTrigger,
+	},
+	global::{DISCORD_EPOCH, EMBED_COLOR},
+};
+
+/// Number of notifications shown on each page of history.
+const PAGE_SIZE: u64 = 10;
+
+/// Maximum number of characters of message content shown for each
+/// notification.
+const SNIPPET_LENGTH: usize = 80;
+
+/// How long the page buttons keep working after history is shown.
+const BUTTON_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// List or search notifications previously sent to the user.
+///
+/// Usage:
+/// - `/history list [keyword] [server] [after] [before]`
+/// - `/history search <text> [keyword] [server] [after] [before]`
+#[tracing::instrument(
+	skip_all,
+	fields(
+		user_id = %command.user.id,
+		channel_id = %command.channel_id,
+		command = %command.data.name,
+	)
+)]
+pub(crate) async fn history(ctx: Context, command: Command) -> Result<()> {
+	check_opt_out!(ctx, command);
+	let user_id = command.user.id;
+	let locale = locale(&command.locale);
+
+	let subcommand = command
+		.data
+		.options
+		.first()
+		.context("No history subcommand provided")?;
+
+	let filter = match parse_filter(subcommand, locale) {
+		Ok(filter) => filter,
+		Err(message) => return respond_eph(&ctx, &command, message).await,
+	};
+
+	let (notifications, pages) =
+		Notification::user_history(user_id, &filter, 0, PAGE_SIZE).await?;
+
+	// these backends don't return a message that can be recorded
+	let unrecorded = matches!(
+		Delivery::user_delivery(user_id).await?.method,
+		DeliveryMethod::Http(_) | DeliveryMethod::Email { .. }
+	);
+
+	if notifications.is_empty() {
+		let key = if unrecorded {
+			"history-empty-unrecorded"
+		} else {
+			"history-empty"
+		};
+
+		return respond_eph(&ctx, &command, locale.get(key)).await;
+	}
+
+	let nonce = rand::thread_rng()
+		.sample_iter::<char, _>(Standard)
+		.take(90)
+		.collect::<String>();
+
+	let prev_id = format!("prev{}", nonce);
+	let next_id = format!("next{}", nonce);
+
+	let mut page = 0;
+
+	let embed =
+		history_embed(&ctx, &notifications, page, pages, unrecorded, locale);
+	let components = page_buttons(&prev_id, &next_id, page, pages, locale);
+
+	command
+		.create_interaction_response(&ctx, |r| {
+			r.interaction_response_data(|m| {
+				m.flags(MessageFlags::EPHEMERAL)
+					.set_embed(embed)
+					.set_components(components)
+			})
+		})
+		.await
+		.context("Failed to send history")?;
+
+	if pages <= 1 {
+		return Ok(());
+	}
+
+	let mut presses = ComponentInteractionCollectorBuilder::new(&ctx)
+		.author_id(user_id)
+		.filter({
+			let prev_id = prev_id.clone();
+			let next_id = next_id.clone();
+			move |interaction| {
+				let id = interaction.data.custom_id.as_str();
+				id == prev_id || id == next_id
+			}
+		})
+		.timeout(BUTTON_TIMEOUT)
+		.build();
+
+	while let Some(press) = presses.next().await {
+		page = match press.data.custom_id.as_str() {
+			id if id == prev_id => page.saturating_sub(1),
+			id if id == next_id => page + 1,
+			other => bail!("Unknown history message component ID {}", other),
+		};
+
+		let (notifications, pages) =
+			Notification::user_history(user_id, &filter, page, PAGE_SIZE)
+				.await?;
+
+		page = page.min(pages.saturating_sub(1));
+
+		let embed = history_embed(
+			&ctx,
+			&notifications,
+			page,
+			pages,
+			unrecorded,
+			locale,
+		);
+		let components = page_buttons(&prev_id, &next_id, page, pages, locale);
+
+		press
+			.create_interaction_response(&ctx, |r| {
+				r.kind(InteractionResponseType::UpdateMessage)
+					.interaction_response_data(|m| {
+						m.set_embed(embed).set_components(components)
+					})
+			})
+			.await
+			.context("Failed to update history page")?;
+	}
+
+	command
+		.edit_original_interaction_response(&ctx, |r| r.components(|c| c))
+		.await
+		.context("Failed to remove history page buttons")?;
+
+	Ok(())
+}
+
+/// Builds a [`HistoryFilter`] from the options of a history subcommand.
+///
+/// Returns an error message to show the user if any option is invalid.
+fn parse_filter(
+	subcommand: &CommandDataOption,
+	locale: &Locale,
+) -> Result<HistoryFilter, String> {
+	let option = |name: &str| {
+		subcommand
+			.options
+			.iter()
+			.find(|o| o.name == name)
+			.and_then(|o| o.value.as_ref())
+			.and_then(|v| v.as_str())
+			.map(str::trim)
+	};
+
+	let date = |name: &str| {
+		option(name)
+			.map(|date| {
+				Timestamp::parse(&format!("{date}T00:00:00Z"))
+					.ok()
+					.and_then(|t| u64::try_from(t.unix_timestamp()).ok())
+					.map(|secs| UNIX_EPOCH + Duration::from_secs(secs))
+					.ok_or_else(|| {
+						locale
+							.format("history-invalid-date", &[("name", &name)])
+					})
+			})
+			.transpose()
+	};
+
+	let guild_id = option("server")
+		.map(|id| {
+			id.parse()
+				.map(GuildId)
+				.map_err(|_| locale.get("error-invalid-server").to_owned())
+		})
+		.transpose()?;
+
+	let text = match subcommand.name.as_str() {
+		"search" => Some(
+			option("text")
+				.filter(|text| !text.is_empty())
+				.ok_or_else(|| locale.get("history-search-empty").to_owned())?
+				.to_owned(),
+		),
+		_ => None,
+	};
+
+	Ok(HistoryFilter {
+		keyword: option("keyword").map(str::to_lowercase),
+		guild_id,
+		after: date("after")?,
+		before: date("before")?,
+		text,
+	})
+}
+
+/// Builds an embed listing a page of notifications.
+///
+/// If `unrecorded` is set, the embed notes that notifications sent with the
+/// user's current delivery method aren't included.
+fn history_embed(
+	ctx: &Context,
+	notifications: &[Notification],
+	page: u64,
+	pages: u64,
+	unrecorded: bool,
+	locale: &Locale,
+) -> CreateEmbed {
+	let mut description = String::new();
+
+	if unrecorded {
+		writeln!(description, "*{}*\n", locale.get("history-unrecorded"))
+			.unwrap();
+	}
+
+	for notification in notifications {
+		let sent = (notification.original_message.0 >> 22) + DISCORD_EPOCH;
+		let sent = Duration::from_millis(sent).as_secs();
+
+		let location = match (notification.guild_id, notification.channel_id) {
+			(Some(guild_id), Some(channel_id)) => {
+				let guild_name = ctx
+					.cache
+					.guild_field(guild_id, |g| g.name.clone())
+					.unwrap_or_else(|| guild_id.to_string());
+
+				format!(
+					"<#{channel_id}> ({guild_name}) · \
+					[{}](https://discord.com/channels/{guild_id}/{channel_id}/{})",
+					locale.get("history-jump"),
+					notification.original_message
+				)
+			}
+			_ => locale.get("history-unknown-channel").to_owned(),
+		};
+
+		let entry = locale.format(
+			"history-entry",
+			&[
+				("keyword", &notification_reason(notification, locale)),
+				("location", &location),
+				("time", &format!("<t:{sent}:f>")),
+			],
+		);
+
+		writeln!(description, "{entry}").unwrap();
+
+		if let Some(content) = &notification.content {
+			let mut snippet = content
+				.chars()
+				.take(SNIPPET_LENGTH)
+				.filter(|&c| c != '\n')
+				.collect::<String>();
+			if content.chars().count() > SNIPPET_LENGTH {
+				snippet.push('…');
+			}
+
+			writeln!(description, "> {snippet}").unwrap();
+		}
+	}
+
+	let mut embed = CreateEmbed::default();
+	embed
+		.title(locale.get("history-title"))
+		.description(description)
+		.footer(|f| {
+			f.text(locale.format(
+				"history-footer",
+				&[("page", &(page + 1)), ("pages", &pages.max(1))],
+			))
+		})
+		.color(EMBED_COLOR);
+
+	embed
+}
+
+/// Describes what caused a notification to be sent: its keyword, or the kind
+/// of alert for notifications that weren't caused by a keyword.
+pub(super) fn notification_reason(
+	notification: &Notification,
+	locale: &Locale,
+) -> String {
+	match notification.trigger {
+		NotificationTrigger::Keyword => notification.keyword.clone(),
+		NotificationTrigger::Follow => {
+			locale.get("history-reason-follow").to_owned()
+		}
+		NotificationTrigger::Watch => {
+			locale.get("history-reason-watch").to_owned()
+		}
+		NotificationTrigger::Reply => {
+			locale.get("history-reason-reply").to_owned()
+		}
+		NotificationTrigger::Reaction => {
+			locale.get("history-reason-reaction").to_owned()
+		}
+	}
+}
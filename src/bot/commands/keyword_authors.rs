@@ -0,0 +1,277 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! Command for restricting keywords to messages from particular users or
+//! roles.
+
+use std::{collections::BTreeMap, fmt::Write as _};
+
+use anyhow::{bail, Context as _, Result};
+use serenity::{
+	client::Context,
+	model::{
+		application::interaction::application_command::{
+			ApplicationCommandInteraction as Command, CommandDataOption,
+		},
+		id::{GuildId, RoleId, UserId},
+	},
+};
+
+use crate::{
+	bot::{
+		i18n::{locale, Locale},
+		util::respond_eph,
+	},
+	db::{AuthorFilter, Keyword, KeywordAuthor, KeywordKind},
+};
+
+/// Maximum number of authors one keyword can be restricted to.
+pub(super) const MAX_AUTHORS: usize = 10;
+
+/// Restrict keywords to messages from particular users or roles, or lift or
+/// list those restrictions.
+///
+/// Usage:
+/// - `/keyword-authors add <keyword> [user] [role]`
+/// - `/keyword-authors remove <keyword> [user] [role]`
+/// - `/keyword-authors list`
+#[tracing::instrument(
+	skip_all,
+	fields(
+		user_id = %command.user.id,
+		channel_id = %command.channel_id,
+		command = %command.data.name,
+	)
+)]
+pub(crate) async fn keyword_authors(
+	ctx: Context,
+	command: Command,
+) -> Result<()> {
+	check_opt_out!(ctx, command);
+	let guild_id = require_guild!(&ctx, &command);
+	let user_id = command.user.id;
+	let locale = locale(&command.locale);
+
+	let subcommand = command
+		.data
+		.options
+		.first()
+		.context("No keyword authors subcommand provided")?;
+
+	let response = match subcommand.name.as_str() {
+		"list" => list(guild_id, user_id, locale).await?,
+		"add" | "remove" => {
+			let keyword = subcommand
+				.options
+				.iter()
+				.find(|o| o.name == "keyword")
+				.and_then(|o| o.value.as_ref())
+				.context("No keyword provided")?
+				.as_str()
+				.context("Keyword provided was not a string")?
+				.trim()
+				.to_lowercase();
+
+			let mut authors = Vec::new();
+
+			if let Some(id) = id_option(subcommand, "user")? {
+				authors.push(AuthorFilter::User(UserId(id)));
+			}
+
+			if let Some(id) = id_option(subcommand, "role")? {
+				authors.push(AuthorFilter::Role(RoleId(id)));
+			}
+
+			if subcommand.name == "add" {
+				add(&ctx, guild_id, user_id, keyword, authors, locale).await?
+			} else {
+				remove(guild_id, user_id, keyword, authors, locale).await?
+			}
+		}
+		other => bail!("Unknown keyword authors subcommand {}", other),
+	};
+
+	respond_eph(&ctx, &command, response).await
+}
+
+/// Gets an optional user or role option of a subcommand.
+fn id_option(
+	subcommand: &CommandDataOption,
+	name: &str,
+) -> Result<Option<u64>> {
+	subcommand
+		.options
+		.iter()
+		.find(|o| o.name == name)
+		.and_then(|o| o.value.as_ref())
+		.map(|v| {
+			v.as_str()
+				.context("ID provided was not a string")?
+				.parse()
+				.context("ID provided was not an ID")
+		})
+		.transpose()
+}
+
+/// Restricts a keyword of the user to the given authors.
+async fn add(
+	ctx: &Context,
+	guild_id: GuildId,
+	user_id: UserId,
+	keyword: String,
+	authors: Vec<AuthorFilter>,
+	locale: &Locale,
+) -> Result<String> {
+	if authors.is_empty() {
+		return Ok(locale.get("keyword-authors-missing").to_owned());
+	}
+
+	if !has_keyword(ctx, guild_id, user_id, &keyword).await? {
+		return Ok(locale
+			.format("keyword-authors-no-keyword", &[("keyword", &keyword)]));
+	}
+
+	let existing = KeywordAuthor::user_filters(user_id)
+		.await?
+		.into_iter()
+		.filter(|filter| {
+			filter.guild_id == guild_id && filter.keyword == keyword
+		})
+		.count();
+
+	let mut added = Vec::new();
+
+	for author in authors {
+		let filter = KeywordAuthor {
+			user_id,
+			guild_id,
+			keyword: keyword.clone(),
+			author,
+		};
+
+		if filter.clone().exists().await? {
+			continue;
+		}
+
+		if existing + added.len() >= MAX_AUTHORS {
+			return Ok(locale
+				.format("keyword-authors-limit", &[("max", &MAX_AUTHORS)]));
+		}
+
+		filter.insert().await?;
+		added.push(author.mention());
+	}
+
+	if added.is_empty() {
+		return Ok(
+			locale.format("keyword-authors-exist", &[("keyword", &keyword)])
+		);
+	}
+
+	Ok(locale.format(
+		"keyword-authors-added",
+		&[("keyword", &keyword), ("authors", &added.join(", "))],
+	))
+}
+
+/// Lifts restrictions of a keyword of the user to the given authors, or all
+/// of its restrictions if no authors are given.
+async fn remove(
+	guild_id: GuildId,
+	user_id: UserId,
+	keyword: String,
+	authors: Vec<AuthorFilter>,
+	locale: &Locale,
+) -> Result<String> {
+	let filters = KeywordAuthor::user_filters(user_id)
+		.await?
+		.into_iter()
+		.filter(|filter| {
+			filter.guild_id == guild_id
+				&& filter.keyword == keyword
+				&& (authors.is_empty() || authors.contains(&filter.author))
+		})
+		.collect::<Vec<_>>();
+
+	if filters.is_empty() {
+		return Ok(locale
+			.format("keyword-authors-not-found", &[("keyword", &keyword)]));
+	}
+
+	let removed = filters
+		.iter()
+		.map(|filter| filter.author.mention())
+		.collect::<Vec<_>>();
+
+	for filter in filters {
+		filter.delete().await?;
+	}
+
+	Ok(locale.format(
+		"keyword-authors-removed",
+		&[("keyword", &keyword), ("authors", &removed.join(", "))],
+	))
+}
+
+/// Lists the user's keywords restricted to particular authors in the guild.
+async fn list(
+	guild_id: GuildId,
+	user_id: UserId,
+	locale: &Locale,
+) -> Result<String> {
+	let mut keywords = BTreeMap::new();
+
+	for filter in KeywordAuthor::user_filters(user_id).await? {
+		if filter.guild_id == guild_id {
+			keywords
+				.entry(filter.keyword)
+				.or_insert_with(Vec::new)
+				.push(filter.author.mention());
+		}
+	}
+
+	if keywords.is_empty() {
+		return Ok(locale.get("keyword-authors-empty").to_owned());
+	}
+
+	let mut response = locale.get("keyword-authors-title").to_owned();
+
+	for (keyword, authors) in keywords {
+		write!(response, "\n\"{keyword}\": {}", authors.join(", ")).unwrap();
+	}
+
+	Ok(response)
+}
+
+/// Checks if the user added the given keyword in the guild, either
+/// server-wide or in one of its channels.
+async fn has_keyword(
+	ctx: &Context,
+	guild_id: GuildId,
+	user_id: UserId,
+	keyword: &str,
+) -> Result<bool> {
+	let in_guild = Keyword::user_guild_keywords(user_id, guild_id)
+		.await?
+		.into_iter()
+		.any(|k| k.keyword == keyword);
+
+	if in_guild {
+		return Ok(true);
+	}
+
+	Ok(Keyword::user_channel_keywords(user_id)
+		.await?
+		.into_iter()
+		.any(|k| {
+			k.keyword == keyword
+				&& match k.kind {
+					KeywordKind::Channel(channel_id) => {
+						ctx.cache
+							.guild_channel_field(channel_id, |c| c.guild_id)
+							== Some(guild_id)
+					}
+					KeywordKind::Guild(id) => id == guild_id,
+				}
+		}))
+}
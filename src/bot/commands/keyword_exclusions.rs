@@ -0,0 +1,233 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! Command for excluding server-wide keywords from particular channels.
+
+use std::{collections::BTreeMap, fmt::Write as _};
+
+use anyhow::{bail, Context as _, Result};
+use serenity::{
+	client::Context,
+	model::{
+		application::interaction::application_command::ApplicationCommandInteraction as Command,
+		id::{ChannelId, GuildId, UserId},
+		mention::Mentionable,
+	},
+};
+
+use super::keywords::MAX_KEYWORD_CHANNELS;
+use crate::{
+	bot::{
+		i18n::{locale, Locale},
+		util::respond_eph,
+	},
+	db::{Keyword, KeywordChannel, KeywordKind},
+};
+
+/// Exclude server-wide keywords from channels, or lift or list those
+/// exclusions.
+///
+/// Usage:
+/// - `/keyword-exclusions add <keyword> <channel>`
+/// - `/keyword-exclusions remove <keyword> [channel]`
+/// - `/keyword-exclusions list`
+#[tracing::instrument(
+	skip_all,
+	fields(
+		user_id = %command.user.id,
+		channel_id = %command.channel_id,
+		command = %command.data.name,
+	)
+)]
+pub(crate) async fn keyword_exclusions(
+	ctx: Context,
+	command: Command,
+) -> Result<()> {
+	check_opt_out!(ctx, command);
+	let guild_id = require_guild!(&ctx, &command);
+	let user_id = command.user.id;
+	let locale = locale(&command.locale);
+
+	let subcommand = command
+		.data
+		.options
+		.first()
+		.context("No keyword exclusions subcommand provided")?;
+
+	let channel_id = command.data.resolved.channels.keys().next().copied();
+
+	let response = match subcommand.name.as_str() {
+		"list" => list(guild_id, user_id, locale).await?,
+		"add" | "remove" => {
+			let keyword = subcommand
+				.options
+				.iter()
+				.find(|o| o.name == "keyword")
+				.and_then(|o| o.value.as_ref())
+				.context("No keyword provided")?
+				.as_str()
+				.context("Keyword provided was not a string")?
+				.trim()
+				.to_lowercase();
+
+			if subcommand.name == "add" {
+				let channel_id = channel_id.context("No channel provided")?;
+				add(&ctx, guild_id, user_id, keyword, channel_id, locale)
+					.await?
+			} else {
+				remove(guild_id, user_id, keyword, channel_id, locale).await?
+			}
+		}
+		other => bail!("Unknown keyword exclusions subcommand {}", other),
+	};
+
+	respond_eph(&ctx, &command, response).await
+}
+
+/// Excludes a server-wide keyword of the user from the given channel.
+async fn add(
+	ctx: &Context,
+	guild_id: GuildId,
+	user_id: UserId,
+	keyword: String,
+	channel_id: ChannelId,
+	locale: &Locale,
+) -> Result<String> {
+	let args = [
+		("keyword", &keyword as _),
+		("channel", &channel_id.mention() as _),
+	];
+
+	if ctx.cache.guild_channel_field(channel_id, |c| c.guild_id)
+		!= Some(guild_id)
+	{
+		return Ok(locale.get("keyword-exclusion-other-server").to_owned());
+	}
+
+	let guild_keyword = Keyword {
+		keyword: keyword.clone(),
+		user_id,
+		kind: KeywordKind::Guild(guild_id),
+	};
+
+	if !guild_keyword.exists().await? {
+		return Ok(locale.format("keyword-exclusion-no-keyword", &args));
+	}
+
+	let channels = KeywordChannel::user_channels(user_id)
+		.await?
+		.into_iter()
+		.filter(|channel| {
+			channel.guild_id == guild_id && channel.keyword == keyword
+		})
+		.collect::<Vec<_>>();
+
+	if let Some(channel) = channels
+		.iter()
+		.find(|channel| channel.channel_id == channel_id)
+	{
+		let key = if channel.excluded {
+			"keyword-exclusion-exists"
+		} else {
+			"keyword-exclusion-added-in"
+		};
+		return Ok(locale.format(key, &args));
+	}
+
+	if channels.iter().filter(|channel| channel.excluded).count()
+		>= MAX_KEYWORD_CHANNELS
+	{
+		return Ok(locale.format(
+			"keyword-exclusions-limit",
+			&[("max", &MAX_KEYWORD_CHANNELS)],
+		));
+	}
+
+	KeywordChannel {
+		user_id,
+		guild_id,
+		keyword: keyword.clone(),
+		channel_id,
+		excluded: true,
+	}
+	.insert()
+	.await?;
+
+	Ok(locale.format("keyword-exclusion-added", &args))
+}
+
+/// Lifts the exclusion of a keyword of the user from the given channel, or
+/// all of its exclusions if no channel is given.
+async fn remove(
+	guild_id: GuildId,
+	user_id: UserId,
+	keyword: String,
+	channel_id: Option<ChannelId>,
+	locale: &Locale,
+) -> Result<String> {
+	let exclusions = KeywordChannel::user_channels(user_id)
+		.await?
+		.into_iter()
+		.filter(|channel| {
+			channel.excluded
+				&& channel.guild_id == guild_id
+				&& channel.keyword == keyword
+				&& channel_id.is_none_or(|id| id == channel.channel_id)
+		})
+		.collect::<Vec<_>>();
+
+	if exclusions.is_empty() {
+		return Ok(match channel_id {
+			Some(channel_id) => locale.format(
+				"keyword-exclusion-missing",
+				&[("keyword", &keyword), ("channel", &channel_id.mention())],
+			),
+			None => locale
+				.format("keyword-exclusions-missing", &[("keyword", &keyword)]),
+		});
+	}
+
+	let removed = exclusions
+		.iter()
+		.map(|channel| format!("<#{}>", channel.channel_id))
+		.collect::<Vec<_>>();
+
+	for exclusion in exclusions {
+		exclusion.delete().await?;
+	}
+
+	Ok(locale.format(
+		"keyword-exclusions-removed",
+		&[("keyword", &keyword), ("channels", &removed.join(", "))],
+	))
+}
+
+/// Lists the user's keywords excluded from channels in the guild.
+async fn list(
+	guild_id: GuildId,
+	user_id: UserId,
+	locale: &Locale,
+) -> Result<String> {
+	let mut keywords = BTreeMap::new();
+
+	for channel in KeywordChannel::user_channels(user_id).await? {
+		if channel.excluded && channel.guild_id == guild_id {
+			keywords
+				.entry(channel.keyword)
+				.or_insert_with(Vec::new)
+				.push(format!("<#{}>", channel.channel_id));
+		}
+	}
+
+	if keywords.is_empty() {
+		return Ok(locale.get("keyword-exclusions-empty").to_owned());
+	}
+
+	let mut response = locale.get("keyword-exclusions-title").to_owned();
+
+	for (keyword, channels) in keywords {
+		write!(response, "\n\"{keyword}\": {}", channels.join(", ")).unwrap();
+	}
+
+	Ok(response)
+}
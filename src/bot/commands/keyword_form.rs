@@ -0,0 +1,844 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! Commands for adding and editing a keyword with a form showing all of its
+//! options.
+
+use std::{sync::Arc, time::Duration};
+
+use anyhow::{Context as _, Result};
+use futures_util::StreamExt;
+use rand::{distributions::Standard, Rng};
+use serenity::{
+	client::Context,
+	collector::ModalInteractionCollectorBuilder,
+	model::{
+		application::{
+			component::{ActionRowComponent, InputTextStyle},
+			interaction::{
+				application_command::ApplicationCommandInteraction as Command,
+				modal::ModalSubmitInteraction, InteractionResponseType,
+			},
+		},
+		channel::{Channel, ChannelType},
+		id::{ChannelId, GuildId, RoleId, UserId},
+		mention::Mentionable,
+	},
+};
+
+use super::{
+	bulk::respond_to_submission,
+	keyword_authors::MAX_AUTHORS,
+	keywords::{
+		check_channel, keyword_shared, KeywordLimits, MAX_KEYWORD_CHANNELS,
+	},
+};
+use crate::{
+	bot::{
+		i18n::{locale, Locale},
+		stats,
+		util::{respond_eph, user_can_read_channel},
+	},
+	db::{
+		AuthorFilter, Keyword, KeywordAuthor, KeywordChannel, KeywordKind,
+		KeywordOptions,
+	},
+};
+
+/// How long the user has to fill out a keyword form.
+const FORM_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+
+/// Maximum length of a channel name or ID entered in a keyword form.
+const MAX_CHANNEL_LENGTH: u64 = 100;
+
+/// Maximum length of a list of channels or authors entered in a keyword form.
+const MAX_LIST_LENGTH: u64 = 1000;
+
+/// Text entered in a keyword form.
+#[derive(Debug, Clone, Default)]
+struct KeywordForm {
+	/// The keyword.
+	keyword: String,
+	/// Name, mention, or ID of the channel the keyword is specific to; empty
+	/// for server-wide keywords.
+	channel: String,
+	/// Channels a server-wide keyword is limited to, separated by commas or
+	/// spaces.
+	channels: String,
+	/// Channels a server-wide keyword is excluded from, separated by commas or
+	/// spaces.
+	excluded_channels: String,
+	/// Users and roles the keyword is restricted to, separated by commas.
+	authors: String,
+}
+
+impl KeywordForm {
+	/// Fills out a form with the options of an existing keyword.
+	fn from_keyword(
+		ctx: &Context,
+		keyword: &Keyword,
+		channels: &[KeywordChannel],
+		authors: &[KeywordAuthor],
+	) -> Self {
+		let channel = match keyword.kind {
+			KeywordKind::Guild(_) => String::new(),
+			KeywordKind::Channel(channel_id) => channel_name(ctx, channel_id),
+		};
+
+		let channel_list = |excluded: bool| {
+			channels
+				.iter()
+				.filter(|channel| channel.excluded == excluded)
+				.map(|channel| channel_name(ctx, channel.channel_id))
+				.collect::<Vec<_>>()
+				.join(", ")
+		};
+
+		Self {
+			keyword: keyword.keyword.clone(),
+			channel,
+			channels: channel_list(false),
+			excluded_channels: channel_list(true),
+			authors: authors
+				.iter()
+				.map(|filter| filter.author.mention())
+				.collect::<Vec<_>>()
+				.join(", "),
+		}
+	}
+
+	/// Reads the text entered in a submitted keyword form.
+	fn from_submission(submission: &ModalSubmitInteraction) -> Self {
+		let mut form = Self::default();
+
+		for component in submission
+			.data
+			.components
+			.iter()
+			.flat_map(|row| &row.components)
+		{
+			if let ActionRowComponent::InputText(input) = component {
+				match input.custom_id.as_str() {
+					"keyword" => form.keyword = input.value.clone(),
+					"channel" => form.channel = input.value.clone(),
+					"channels" => form.channels = input.value.clone(),
+					"excluded_channels" => {
+						form.excluded_channels = input.value.clone()
+					}
+					"authors" => form.authors = input.value.clone(),
+					_ => {}
+				}
+			}
+		}
+
+		form
+	}
+
+	/// Checks the entered options, and creates the keyword and the options
+	/// they describe.
+	///
+	/// Returns an error message to show the user if any option is invalid.
+	async fn into_keyword(
+		self,
+		ctx: &Context,
+		guild_id: GuildId,
+		user_id: UserId,
+		limits: &KeywordLimits,
+		locale: &Locale,
+	) -> Result<Result<(Keyword, KeywordOptions), String>> {
+		let keyword = self.keyword.trim().to_lowercase();
+
+		if let Some(message) = limits.check_keyword(&keyword, locale) {
+			return Ok(Err(message));
+		}
+
+		let channels =
+			match find_channels(ctx, guild_id, &self.channels, locale) {
+				Ok(channels) => channels,
+				Err(message) => return Ok(Err(message)),
+			};
+
+		if channels.len() > MAX_KEYWORD_CHANNELS {
+			return Ok(Err(locale.format(
+				"keyword-channels-limit",
+				&[("max", &MAX_KEYWORD_CHANNELS)],
+			)));
+		}
+
+		for &channel_id in &channels {
+			if let Some(message) =
+				check_channel(ctx, guild_id, channel_id, locale).await?
+			{
+				return Ok(Err(message));
+			}
+		}
+
+		let excluded_channels =
+			match find_channels(ctx, guild_id, &self.excluded_channels, locale)
+			{
+				Ok(channels) => channels,
+				Err(message) => return Ok(Err(message)),
+			};
+
+		if excluded_channels.len() > MAX_KEYWORD_CHANNELS {
+			return Ok(Err(locale.format(
+				"keyword-form-excluded-limit",
+				&[("max", &MAX_KEYWORD_CHANNELS)],
+			)));
+		}
+
+		if let Some(channel_id) = channels
+			.iter()
+			.find(|channel_id| excluded_channels.contains(channel_id))
+		{
+			return Ok(Err(locale.format(
+				"keyword-form-channel-conflict",
+				&[("channel", &channel_id.mention())],
+			)));
+		}
+
+		let authors = match find_authors(ctx, guild_id, &self.authors, locale) {
+			Ok(authors) => authors,
+			Err(message) => return Ok(Err(message)),
+		};
+
+		let options = KeywordOptions {
+			channels,
+			excluded_channels,
+			authors,
+		};
+
+		let channel = self.channel.trim();
+
+		if channel.is_empty() {
+			let keyword = Keyword {
+				keyword,
+				user_id,
+				kind: KeywordKind::Guild(guild_id),
+			};
+
+			return Ok(Ok((keyword, options)));
+		}
+
+		if !options.channels.is_empty() || !options.excluded_channels.is_empty()
+		{
+			return Ok(Err(locale
+				.get("keyword-form-channel-options")
+				.to_owned()));
+		}
+
+		let channel = match find_channel(ctx, guild_id, channel)
+			.and_then(|id| ctx.cache.guild_channel(id))
+		{
+			Some(c)
+				if c.kind == ChannelType::Text && c.guild_id == guild_id =>
+			{
+				c
+			}
+			_ => {
+				return Ok(Err(locale.format(
+					"keyword-form-unknown-text-channel",
+					&[("channel", &channel)],
+				)))
+			}
+		};
+
+		let self_id = ctx.cache.current_user_id();
+		match user_can_read_channel(ctx, &channel, self_id).await {
+			Ok(Some(true)) => {
+				let keyword = Keyword {
+					keyword,
+					user_id,
+					kind: KeywordKind::Channel(channel.id),
+				};
+
+				Ok(Ok((keyword, options)))
+			}
+			Ok(Some(false)) => Ok(Err(locale.format(
+				"error-cannot-read",
+				&[("channel", &format!("<#{}>", channel.id))],
+			))),
+			Ok(None) => Err(anyhow::anyhow!(
+				"Self permissions not found in channel {} in guild {}",
+				channel.id,
+				guild_id
+			)),
+			Err(e) => Err(e.context(
+				"Failed to check for self permissions to read keyword channel",
+			)),
+		}
+	}
+}
+
+/// Formats a channel for a keyword form, by name if it's known.
+fn channel_name(ctx: &Context, channel_id: ChannelId) -> String {
+	ctx.cache
+		.guild_channel_field(channel_id, |c| format!("#{}", c.name))
+		.unwrap_or_else(|| channel_id.to_string())
+}
+
+/// Finds the channels of the given guild listed in the given text, separated
+/// by commas or spaces.
+///
+/// Returns an error message to show the user if any of them isn't a channel
+/// of the guild.
+fn find_channels(
+	ctx: &Context,
+	guild_id: GuildId,
+	text: &str,
+	locale: &Locale,
+) -> Result<Vec<ChannelId>, String> {
+	let mut channels = Vec::new();
+
+	for entry in text
+		.split(|c: char| c == ',' || c.is_whitespace())
+		.filter(|entry| !entry.is_empty())
+	{
+		let channel_id = find_channel(ctx, guild_id, entry).filter(|&id| {
+			ctx.cache.guild_channel_field(id, |c| c.guild_id) == Some(guild_id)
+		});
+
+		match channel_id {
+			Some(channel_id) if !channels.contains(&channel_id) => {
+				channels.push(channel_id)
+			}
+			Some(_) => {}
+			None => {
+				return Err(locale.format(
+					"keyword-form-unknown-channel",
+					&[("channel", &entry)],
+				))
+			}
+		}
+	}
+
+	Ok(channels)
+}
+
+/// Finds the users and roles listed in the given text, separated by commas.
+///
+/// Users can be entered by mention or ID, and roles by mention, ID, or name.
+/// Returns an error message to show the user if any of them can't be found,
+/// or if there are too many.
+fn find_authors(
+	ctx: &Context,
+	guild_id: GuildId,
+	text: &str,
+	locale: &Locale,
+) -> Result<Vec<AuthorFilter>, String> {
+	let mut authors = Vec::new();
+
+	for entry in text.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+		let mention = |prefix: &str| {
+			entry
+				.strip_prefix(prefix)
+				.and_then(|id| id.strip_suffix('>'))
+				.and_then(|id| id.parse().ok())
+		};
+
+		let author = if let Some(id) = mention("<@&") {
+			AuthorFilter::Role(RoleId(id))
+		} else if let Some(id) = mention("<@!").or_else(|| mention("<@")) {
+			AuthorFilter::User(UserId(id))
+		} else if let Ok(id) = entry.parse() {
+			let is_role = ctx
+				.cache
+				.guild_field(guild_id, |g| g.roles.contains_key(&RoleId(id)))
+				.unwrap_or(false);
+
+			if is_role {
+				AuthorFilter::Role(RoleId(id))
+			} else {
+				AuthorFilter::User(UserId(id))
+			}
+		} else {
+			let name = entry.trim_start_matches('@').to_lowercase();
+
+			let role_id = ctx
+				.cache
+				.guild_field(guild_id, |g| {
+					g.roles
+						.values()
+						.find(|role| role.name.to_lowercase() == name)
+						.map(|role| role.id)
+				})
+				.flatten();
+
+			match role_id {
+				Some(role_id) => AuthorFilter::Role(role_id),
+				None => {
+					return Err(locale.format(
+						"keyword-form-unknown-role",
+						&[("role", &entry)],
+					))
+				}
+			}
+		};
+
+		if !authors.contains(&author) {
+			authors.push(author);
+		}
+	}
+
+	if authors.len() > MAX_AUTHORS {
+		return Err(
+			locale.format("keyword-authors-limit", &[("max", &MAX_AUTHORS)])
+		);
+	}
+
+	Ok(authors)
+}
+
+/// Adds the author filters the user already has for the given keyword in the
+/// given guild to the given options, so storing them doesn't remove any.
+///
+/// Returns an error message to show the user if there are too many authors
+/// together.
+async fn merge_authors(
+	keyword: &Keyword,
+	guild_id: GuildId,
+	options: &mut KeywordOptions,
+	locale: &Locale,
+) -> Result<Option<String>> {
+	let existing = KeywordAuthor::user_filters(keyword.user_id)
+		.await?
+		.into_iter()
+		.filter(|filter| {
+			filter.guild_id == guild_id && filter.keyword == keyword.keyword
+		});
+
+	for filter in existing {
+		if !options.authors.contains(&filter.author) {
+			options.authors.push(filter.author);
+		}
+	}
+
+	if options.authors.len() > MAX_AUTHORS {
+		return Ok(Some(
+			locale.format("keyword-authors-limit", &[("max", &MAX_AUTHORS)]),
+		));
+	}
+
+	Ok(None)
+}
+
+/// Checks if two lists without duplicates have the same items, in any order.
+fn same_items<T: PartialEq>(a: &[T], b: &[T]) -> bool {
+	a.len() == b.len() && a.iter().all(|item| b.contains(item))
+}
+
+/// Finds a channel in the given guild by mention, ID, or name.
+fn find_channel(
+	ctx: &Context,
+	guild_id: GuildId,
+	text: &str,
+) -> Option<ChannelId> {
+	let id = text.trim_start_matches("<#").trim_end_matches('>');
+	if let Ok(id) = id.parse() {
+		return Some(ChannelId(id));
+	}
+
+	let name = text.trim_start_matches('#').to_lowercase();
+
+	ctx.cache
+		.guild_field(guild_id, |g| {
+			g.channels.iter().find_map(|(&id, channel)| match channel {
+				Channel::Guild(c) if c.name.to_lowercase() == name => Some(id),
+				_ => None,
+			})
+		})
+		.flatten()
+}
+
+/// Describes where a keyword applies, for responses.
+fn location(kind: KeywordKind, locale: &Locale) -> String {
+	match kind {
+		KeywordKind::Guild(_) => {
+			locale.get("keyword-form-in-server").to_owned()
+		}
+		KeywordKind::Channel(channel_id) => locale.format(
+			"keyword-form-in-channel",
+			&[("channel", &channel_id.mention())],
+		),
+	}
+}
+
+/// Shows the user a keyword form, filled in with the given options, and
+/// waits for them to submit it.
+///
+/// Returns the submission and the submitted options, or `None` if the user
+/// didn't submit the form in time.
+async fn request_form(
+	ctx: &Context,
+	command: &Command,
+	title: &str,
+	form: KeywordForm,
+	locale: &Locale,
+) -> Result<Option<(Arc<ModalSubmitInteraction>, KeywordForm)>> {
+	let nonce = rand::thread_rng()
+		.sample_iter::<char, _>(Standard)
+		.take(90)
+		.collect::<String>();
+
+	command
+		.create_interaction_response(ctx, |r| {
+			r.kind(InteractionResponseType::Modal)
+				.interaction_response_data(|d| {
+					d.custom_id(&nonce).title(title).components(|c| {
+						c.create_action_row(|row| {
+							row.create_input_text(|t| {
+								t.custom_id("keyword")
+									.style(InputTextStyle::Short)
+									.label(
+										locale
+											.get("keyword-form-label-keyword"),
+									)
+									.placeholder("rust")
+									.required(true);
+
+								if !form.keyword.is_empty() {
+									t.value(&form.keyword);
+								}
+
+								t
+							})
+						})
+						.create_action_row(|row| {
+							row.create_input_text(|t| {
+								t.custom_id("channel")
+									.style(InputTextStyle::Short)
+									.label(
+										locale
+											.get("keyword-form-label-channel"),
+									)
+									.placeholder("#general")
+									.max_length(MAX_CHANNEL_LENGTH)
+									.required(false);
+
+								if !form.channel.is_empty() {
+									t.value(&form.channel);
+								}
+
+								t
+							})
+						})
+						.create_action_row(|row| {
+							row.create_input_text(|t| {
+								t.custom_id("channels")
+									.style(InputTextStyle::Short)
+									.label(
+										locale
+											.get("keyword-form-label-channels"),
+									)
+									.placeholder("#ops, #releases")
+									.max_length(MAX_LIST_LENGTH)
+									.required(false);
+
+								if !form.channels.is_empty() {
+									t.value(&form.channels);
+								}
+
+								t
+							})
+						})
+						.create_action_row(|row| {
+							row.create_input_text(|t| {
+								t.custom_id("excluded_channels")
+									.style(InputTextStyle::Short)
+									.label(locale.get(
+										"keyword-form-label-excluded-channels",
+									))
+									.placeholder("#off-topic")
+									.max_length(MAX_LIST_LENGTH)
+									.required(false);
+
+								if !form.excluded_channels.is_empty() {
+									t.value(&form.excluded_channels);
+								}
+
+								t
+							})
+						})
+						.create_action_row(|row| {
+							row.create_input_text(|t| {
+								t.custom_id("authors")
+									.style(InputTextStyle::Short)
+									.label(
+										locale
+											.get("keyword-form-label-authors"),
+									)
+									.placeholder(
+										"@Moderators, 123456789012345678",
+									)
+									.max_length(MAX_LIST_LENGTH)
+									.required(false);
+
+								if !form.authors.is_empty() {
+									t.value(&form.authors);
+								}
+
+								t
+							})
+						})
+					})
+				})
+		})
+		.await
+		.context("Failed to send keyword form")?;
+
+	let submission = ModalInteractionCollectorBuilder::new(ctx)
+		.collect_limit(1)
+		.author_id(command.user.id)
+		.filter(move |interaction| interaction.data.custom_id == nonce)
+		.timeout(FORM_TIMEOUT)
+		.build()
+		.next()
+		.await;
+
+	Ok(submission.map(|submission| {
+		let form = KeywordForm::from_submission(&submission);
+		(submission, form)
+	}))
+}
+
+/// Add a keyword, choosing all of its options in a form.
+///
+/// Usage: `/add-advanced`
+#[tracing::instrument(
+	skip_all,
+	fields(
+		user_id = %command.user.id,
+		channel_id = %command.channel_id,
+		command = %command.data.name,
+	)
+)]
+pub(crate) async fn add_advanced(ctx: Context, command: Command) -> Result<()> {
+	check_opt_out!(ctx, command);
+	let guild_id = require_guild!(&ctx, &command);
+	let user_id = command.user.id;
+	let locale = locale(&command.locale);
+
+	let limits = KeywordLimits::new(&ctx, user_id, guild_id).await?;
+
+	if let Some(message) = limits.check_count(locale) {
+		return respond_eph(&ctx, &command, message).await;
+	}
+
+	let (submission, form) = match request_form(
+		&ctx,
+		&command,
+		locale.get("keyword-form-add-title"),
+		KeywordForm::default(),
+		locale,
+	)
+	.await?
+	{
+		Some(submitted) => submitted,
+		None => return Ok(()),
+	};
+
+	let (keyword, mut options) = match form
+		.into_keyword(&ctx, guild_id, user_id, &limits, locale)
+		.await?
+	{
+		Ok(keyword) => keyword,
+		Err(message) => {
+			return respond_to_submission(&ctx, &submission, message).await
+		}
+	};
+
+	let response = if keyword.clone().exists().await? {
+		locale.get("keyword-exists").to_owned()
+	} else if let Some(message) =
+		merge_authors(&keyword, guild_id, &mut options, locale).await?
+	{
+		message
+	} else {
+		let response = locale.format(
+			"keyword-form-added",
+			&[
+				("keyword", &keyword.keyword),
+				("location", &location(keyword.kind, locale)),
+			],
+		);
+		keyword.insert_with_options(guild_id, options).await?;
+		response
+	};
+
+	respond_to_submission(&ctx, &submission, response).await
+}
+
+/// Change the options of an existing keyword in a form.
+///
+/// Usage: `/edit-keyword <keyword> [channel]`
+#[tracing::instrument(
+	skip_all,
+	fields(
+		user_id = %command.user.id,
+		channel_id = %command.channel_id,
+		command = %command.data.name,
+	)
+)]
+pub(crate) async fn edit_keyword(ctx: Context, command: Command) -> Result<()> {
+	check_opt_out!(ctx, command);
+	let guild_id = require_guild!(&ctx, &command);
+	let user_id = command.user.id;
+	let locale = locale(&command.locale);
+
+	let keyword = command
+		.data
+		.options
+		.iter()
+		.find(|o| o.name == "keyword")
+		.and_then(|o| o.value.as_ref())
+		.context("No keyword to edit provided")?
+		.as_str()
+		.context("Keyword provided was not a string")?
+		.trim()
+		.to_lowercase();
+
+	let old = Keyword {
+		keyword,
+		user_id,
+		kind: match command.data.resolved.channels.values().next() {
+			Some(channel) => KeywordKind::Channel(channel.id),
+			None => KeywordKind::Guild(guild_id),
+		},
+	};
+
+	if !old.clone().exists().await? {
+		return respond_eph(&ctx, &command, locale.get("keyword-missing"))
+			.await;
+	}
+
+	let channels = match old.kind {
+		KeywordKind::Guild(_) => KeywordChannel::user_channels(user_id)
+			.await?
+			.into_iter()
+			.filter(|channel| {
+				channel.guild_id == guild_id && channel.keyword == old.keyword
+			})
+			.collect(),
+		KeywordKind::Channel(_) => Vec::new(),
+	};
+
+	let authors = KeywordAuthor::user_filters(user_id)
+		.await?
+		.into_iter()
+		.filter(|filter| {
+			filter.guild_id == guild_id && filter.keyword == old.keyword
+		})
+		.collect::<Vec<_>>();
+
+	let limits = KeywordLimits::new(&ctx, user_id, guild_id).await?;
+
+	let (submission, form) = match request_form(
+		&ctx,
+		&command,
+		locale.get("keyword-form-edit-title"),
+		KeywordForm::from_keyword(&ctx, &old, &channels, &authors),
+		locale,
+	)
+	.await?
+	{
+		Some(submitted) => submitted,
+		None => return Ok(()),
+	};
+
+	let (new, mut options) = match form
+		.into_keyword(&ctx, guild_id, user_id, &limits, locale)
+		.await?
+	{
+		Ok(keyword) => keyword,
+		Err(message) => {
+			return respond_to_submission(&ctx, &submission, message).await
+		}
+	};
+
+	let renamed = new.keyword != old.keyword;
+	let moved = renamed || new.kind != old.kind;
+
+	let channel_ids = |excluded: bool| {
+		channels
+			.iter()
+			.filter(|channel| channel.excluded == excluded)
+			.map(|channel| channel.channel_id)
+			.collect::<Vec<_>>()
+	};
+	let author_filters = authors
+		.iter()
+		.map(|filter| filter.author)
+		.collect::<Vec<_>>();
+
+	let unchanged = !moved
+		&& same_items(&options.channels, &channel_ids(false))
+		&& same_items(&options.excluded_channels, &channel_ids(true))
+		&& same_items(&options.authors, &author_filters);
+
+	if unchanged {
+		return respond_to_submission(
+			&ctx,
+			&submission,
+			locale.get("keyword-form-unchanged").to_owned(),
+		)
+		.await;
+	}
+
+	if moved && new.clone().exists().await? {
+		return respond_to_submission(
+			&ctx,
+			&submission,
+			locale.get("keyword-exists").to_owned(),
+		)
+		.await;
+	}
+
+	if renamed {
+		if let Some(message) =
+			merge_authors(&new, guild_id, &mut options, locale).await?
+		{
+			return respond_to_submission(&ctx, &submission, message).await;
+		}
+	}
+
+	let old_shared = keyword_shared(&ctx, &old, guild_id).await?;
+
+	let response = if moved {
+		locale.format(
+			"keyword-form-moved",
+			&[
+				("old_keyword", &old.keyword),
+				("old_location", &location(old.kind, locale)),
+				("keyword", &new.keyword),
+				("location", &location(new.kind, locale)),
+			],
+		)
+	} else {
+		locale.format(
+			"keyword-form-changed",
+			&[
+				("keyword", &new.keyword),
+				("location", &location(new.kind, locale)),
+			],
+		)
+	};
+
+	let old_keyword = old.keyword.clone();
+	let new_keyword = new.keyword.clone();
+	let replace = new.replace(old, guild_id, options, old_shared);
+
+	if renamed && !old_shared {
+		stats::keyword_renamed(
+			user_id,
+			guild_id,
+			&old_keyword,
+			&new_keyword,
+			replace,
+		)
+		.await?;
+	} else {
+		replace.await?;
+	}
+
+	respond_to_submission(&ctx, &submission, response).await
+}
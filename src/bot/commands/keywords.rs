@@ -3,38 +3,238 @@
 
 //! Commands for adding, removing, and listing keywords.
 
-use std::{collections::HashMap, fmt::Write};
+use std::collections::{hash_map::Entry, HashMap};
 
 use anyhow::{Context as _, Result};
 use futures_util::{stream::FuturesUnordered, TryStreamExt};
-use indoc::indoc;
 use lazy_regex::regex;
-use once_cell::sync::Lazy;
 use serenity::{
 	client::Context,
 	http::error::ErrorResponse,
 	model::{
 		application::interaction::application_command::ApplicationCommandInteraction as Command,
 		channel::{Channel, ChannelType, GuildChannel},
-		id::{ChannelId, GuildId},
+		id::{ChannelId, GuildId, UserId},
 	},
 	prelude::HttpError,
 	Error as SerenityError,
 };
 
-use super::util::get_text_channels_in_guild;
+use super::{
+	bulk::{add_ignores, add_keywords},
+	packs::user_packs,
+	pages::{location, send_list, ListEntry, ListOptions},
+	team_keywords::user_team_keywords,
+};
 use crate::{
 	bot::{
 		highlighting::warn_for_failed_dm,
+		i18n::{locale, Locale},
+		stats,
 		util::{respond_eph, success, user_can_read_channel},
 	},
-	db::{Ignore, Keyword, KeywordKind},
+	db::{
+		BannedKeyword, GuildSettings, Ignore, Keyword, KeywordAuthor,
+		KeywordChannel, KeywordKind, PackSubscription, RevokedMember,
+	},
 	settings::settings,
 };
 
+/// Maximum number of channels one keyword can be limited to or excluded from.
+pub(super) const MAX_KEYWORD_CHANNELS: usize = 10;
+
+/// Rules every keyword added in a server must follow, from the bot's settings
+/// and the server's.
+#[derive(Debug, Clone)]
+pub(super) struct KeywordRules {
+	/// Shortest keywords allowed in the server.
+	min_length: usize,
+	/// Words the server doesn't allow keywords to contain.
+	banned: Vec<String>,
+}
+
+impl KeywordRules {
+	/// Looks up the rules for keywords in the given guild.
+	pub(super) async fn new(guild_id: GuildId) -> Result<Self> {
+		let guild_settings = GuildSettings::guild_settings(guild_id).await?;
+
+		Self::with_settings(guild_id, &guild_settings).await
+	}
+
+	/// Looks up the rules for keywords in the given guild, which has the
+	/// given settings.
+	async fn with_settings(
+		guild_id: GuildId,
+		guild_settings: &GuildSettings,
+	) -> Result<Self> {
+		let banned = BannedKeyword::guild_bans(guild_id)
+			.await?
+			.into_iter()
+			.map(|ban| ban.word)
+			.collect();
+
+		Ok(Self {
+			min_length: guild_min_length(guild_settings),
+			banned,
+		})
+	}
+
+	/// Describes why the given keyword can't be added, if it can't.
+	pub(super) fn check(
+		&self,
+		keyword: &str,
+		locale: &Locale,
+	) -> Option<String> {
+		let max_length = settings().behavior.max_keyword_length;
+
+		if keyword.len() < self.min_length {
+			Some(
+				locale
+					.format("keyword-too-short", &[("min", &self.min_length)]),
+			)
+		} else if let Some(max) =
+			max_length.filter(|&max| keyword.chars().count() > max)
+		{
+			Some(locale.format("keyword-too-long", &[("max", &max)]))
+		} else if let Some(word) = banned_word(keyword, &self.banned) {
+			Some(locale.format("keyword-banned", &[("word", &word)]))
+		} else if !Keyword::is_allowed(keyword) {
+			Some(locale.get("keyword-invalid").to_owned())
+		} else {
+			None
+		}
+	}
+}
+
+/// Limits on the keywords a user can add in a server, from the bot's settings
+/// and the server's.
+#[derive(Debug, Clone)]
+pub(super) struct KeywordLimits {
+	/// Number of keywords the user has added everywhere, including keywords of
+	/// packs they subscribed to if the bot's host counts those.
+	pub(super) total: u64,
+	/// Number of keywords the user has added in the server, and the most the
+	/// server allows, if it has a limit.
+	guild: Option<(u64, u64)>,
+	/// Rules for the keywords themselves.
+	rules: KeywordRules,
+	/// Whether the server's admins revoked the user's highlighting.
+	revoked: bool,
+}
+
+impl KeywordLimits {
+	/// Looks up the limits on the given user's keywords in the given guild.
+	pub(super) async fn new(
+		ctx: &Context,
+		user_id: UserId,
+		guild_id: GuildId,
+	) -> Result<Self> {
+		let guild_settings = GuildSettings::guild_settings(guild_id).await?;
+
+		let guild = match guild_settings.max_keywords {
+			Some(max) => Some((
+				guild_keyword_count(ctx, user_id, guild_id).await?,
+				max as u64,
+			)),
+			None => None,
+		};
+
+		let rules =
+			KeywordRules::with_settings(guild_id, &guild_settings).await?;
+
+		let revoked = RevokedMember { guild_id, user_id }.exists().await?;
+
+		let mut total = Keyword::user_keyword_count(user_id).await?;
+
+		if settings().behavior.pack_keywords_limited {
+			total += PackSubscription::user_pack_keyword_count(user_id).await?;
+		}
+
+		Ok(Self {
+			total,
+			guild,
+			rules,
+			revoked,
+		})
+	}
+
+	/// Describes why the given keyword can't be added, if it can't.
+	pub(super) fn check_keyword(
+		&self,
+		keyword: &str,
+		locale: &Locale,
+	) -> Option<String> {
+		self.rules.check(keyword, locale)
+	}
+
+	/// Describes why the user can't add another keyword, if they can't.
+	pub(super) fn check_count(&self, locale: &Locale) -> Option<String> {
+		let max_keywords = settings().behavior.max_keywords;
+
+		match self.guild {
+			_ if self.revoked => Some(locale.get("keyword-revoked").to_owned()),
+			_ if self.total >= max_keywords as u64 => {
+				Some(locale.format("keyword-limit", &[("max", &max_keywords)]))
+			}
+			Some((count, max)) if count >= max => {
+				Some(locale.format("keyword-guild-limit", &[("max", &max)]))
+			}
+			_ => None,
+		}
+	}
+
+	/// Records that the user added a keyword.
+	pub(super) fn add(&mut self) {
+		self.total += 1;
+
+		if let Some((count, _)) = &mut self.guild {
+			*count += 1;
+		}
+	}
+
+	/// Records that the user added a keyword in another server.
+	pub(super) fn add_elsewhere(&mut self) {
+		self.total += 1;
+	}
+
+	/// Records that the user removed a keyword, in this server if `here`.
+	pub(super) fn remove(&mut self, here: bool) {
+		self.total = self.total.saturating_sub(1);
+
+		if let Some((count, _)) = self.guild.as_mut().filter(|_| here) {
+			*count = count.saturating_sub(1);
+		}
+	}
+}
+
+/// Counts the keywords the user has added in the given guild, including
+/// channel-specific keywords.
+async fn guild_keyword_count(
+	ctx: &Context,
+	user_id: UserId,
+	guild_id: GuildId,
+) -> Result<u64> {
+	let guild_keywords =
+		Keyword::user_guild_keywords(user_id, guild_id).await?.len();
+
+	let channel_keywords = Keyword::user_channel_keywords(user_id)
+		.await?
+		.into_iter()
+		.filter(|keyword| match keyword.kind {
+			KeywordKind::Channel(channel_id) => {
+				ctx.cache.guild_channel_field(channel_id, |c| c.guild_id)
+					== Some(guild_id)
+			}
+			KeywordKind::Guild(_) => false,
+		})
+		.count();
+
+	Ok((guild_keywords + channel_keywords) as u64)
+}
+
 /// Add a keyword.
 ///
-/// Usage: `/add <keyword> [channel]`
+/// Usage: `/add <keyword> [channel] [channels]`
 #[tracing::instrument(
 	skip_all,
 	fields(
@@ -47,118 +247,115 @@ pub(crate) async fn add(ctx: Context, command: Command) -> Result<()> {
 	check_opt_out!(ctx, command);
 	let guild_id = require_guild!(&ctx, &command);
 	let user_id = command.user.id;
+	let locale = locale(&command.locale);
 
-	let keyword_count = Keyword::user_keyword_count(user_id).await?;
-
-	if keyword_count >= settings().behavior.max_keywords as u64 {
-		static MSG: Lazy<String, fn() -> String> = Lazy::new(|| {
-			format!(
-				"You can't create more than {} keywords!",
-				settings().behavior.max_keywords
-			)
-		});
+	let limits = KeywordLimits::new(&ctx, user_id, guild_id).await?;
 
-		return respond_eph(&ctx, &command, MSG.as_str()).await;
+	if let Some(message) = limits.check_count(locale) {
+		return respond_eph(&ctx, &command, message).await;
 	}
 
 	let keyword = command
 		.data
 		.options
-		.get(0)
+		.iter()
+		.find(|o| o.name == "keyword")
 		.and_then(|o| o.value.as_ref())
-		.context("No keyword to add provided")?
-		.as_str()
-		.context("Keyword provided was not a string")?
-		.trim()
-		.to_lowercase();
+		.map(|v| v.as_str().context("Keyword provided was not a string"))
+		.transpose()?
+		.map(|keyword| keyword.trim().to_lowercase());
 
-	if keyword.len() < 3 {
-		return respond_eph(
-			&ctx,
-			&command,
-			"❌ You can't highlight keywords shorter than 3 characters!",
-		)
-		.await;
+	if let Some(message) = keyword
+		.as_ref()
+		.and_then(|keyword| limits.check_keyword(keyword, locale))
+	{
+		return respond_eph(&ctx, &command, message).await;
 	}
 
-	if !is_valid_keyword(&keyword) {
-		return respond_eph(&ctx, &command, "❌ You can't add that keyword!")
-			.await;
-	}
+	let channels = match command
+		.data
+		.options
+		.iter()
+		.find(|o| o.name == "channels")
+		.and_then(|o| o.value.as_ref())
+	{
+		Some(value) => {
+			let text = value.as_str().context("Channels were not a string")?;
 
-	let keyword = match command.data.resolved.channels.values().next() {
-		Some(channel) => {
-			let channel = match ctx.cache.guild_channel(channel.id) {
-				Some(c) if c.kind == ChannelType::Text => c,
-				_ => {
-					return respond_eph(
-						&ctx,
-						&command,
-						"❌ Please provide a text channel!",
-					)
-					.await
+			match parse_channels(&ctx, guild_id, text, locale).await? {
+				Ok(channels) => channels,
+				Err(message) => {
+					return respond_eph(&ctx, &command, message).await
 				}
-			};
+			}
+		}
+		None => Vec::new(),
+	};
 
-			let self_id = ctx.cache.current_user_id();
-			match user_can_read_channel(&ctx, &channel, self_id).await {
-				Ok(Some(true)) => Keyword {
-					keyword,
-					user_id,
-					kind: KeywordKind::Channel(channel.id),
-				},
-				Ok(Some(false)) => {
-					return respond_eph(
-						&ctx,
-						&command,
-						format!("❌ I can't read <#{}>!", channel.id),
-					)
-					.await
-				}
-				Ok(None) => return Err(anyhow::anyhow!(
-					"Self permissions not found in channel {} in guild {}",
-					channel.id,
-					guild_id
-				)),
-				Err(e) => return Err(e.context(
-					"Failed to check for self permissions to read muted channel",
-				)),
+	let kind = match command.data.resolved.channels.values().next() {
+		Some(_) if !channels.is_empty() => {
+			return respond_eph(
+				&ctx,
+				&command,
+				locale.get("keyword-channels-conflict"),
+			)
+			.await
+		}
+		Some(channel) => {
+			if let Some(message) =
+				check_channel(&ctx, guild_id, channel.id, locale).await?
+			{
+				return respond_eph(&ctx, &command, message).await;
 			}
+
+			KeywordKind::Channel(channel.id)
 		}
-		None => Keyword {
+		None => KeywordKind::Guild(guild_id),
+	};
+
+	let keyword = match keyword {
+		Some(keyword) => Keyword {
 			keyword,
 			user_id,
-			kind: KeywordKind::Guild(guild_id),
+			kind,
 		},
+		None if !channels.is_empty() => {
+			return respond_eph(
+				&ctx,
+				&command,
+				locale.get("keyword-channels-missing"),
+			)
+			.await
+		}
+		None => return add_keywords(&ctx, &command, kind, limits).await,
 	};
 
 	if keyword.clone().exists().await? {
-		return respond_eph(
-			&ctx,
-			&command,
-			"❌ You already added that keyword!",
-		)
-		.await;
+		return respond_eph(&ctx, &command, locale.get("keyword-exists")).await;
 	}
 
+	let text = keyword.keyword.clone();
+
 	keyword.insert().await?;
 
+	for channel_id in channels {
+		KeywordChannel {
+			user_id,
+			guild_id,
+			keyword: text.clone(),
+			channel_id,
+			excluded: false,
+		}
+		.insert()
+		.await?;
+	}
+
 	success(&ctx, &command).await?;
 
-	if keyword_count == 0 {
+	if limits.total == 0 {
 		let dm_channel = command.user.create_dm_channel(&ctx).await?;
 
-		match dm_channel
-			.say(
-				&ctx,
-				indoc!(
-					"
-					Test message; if you can read this, \
-					I can send you notifications successfully!"
-				),
-			)
-			.await
-		{
+		match dm_channel.say(&ctx, locale.get("keyword-test-dm")).await {
 			Err(SerenityError::Http(err)) => match &*err {
 				HttpError::UnsuccessfulRequest(ErrorResponse {
 					error, ..
@@ -176,8 +373,121 @@ pub(crate) async fn add(ctx: Context, command: Command) -> Result<()> {
 	Ok(())
 }
 
-fn is_valid_keyword(keyword: &str) -> bool {
-	!regex!(r"<([@#&]|a?:)").is_match(keyword)
+/// Checks that a keyword can be added in the given channel: that it's a text
+/// channel in the guild the bot can read.
+///
+/// Returns a message describing why not if it can't.
+pub(super) async fn check_channel(
+	ctx: &Context,
+	guild_id: GuildId,
+	channel_id: ChannelId,
+	locale: &Locale,
+) -> Result<Option<String>> {
+	let channel = match ctx.cache.guild_channel(channel_id) {
+		Some(c) if c.kind == ChannelType::Text && c.guild_id == guild_id => c,
+		_ => return Ok(Some(locale.get("error-text-channel").to_owned())),
+	};
+
+	let self_id = ctx.cache.current_user_id();
+	match user_can_read_channel(ctx, &channel, self_id).await {
+		Ok(Some(true)) => Ok(None),
+		Ok(Some(false)) => Ok(Some(locale.format(
+			"error-cannot-read",
+			&[("channel", &format!("<#{}>", channel.id))],
+		))),
+		Ok(None) => Err(anyhow::anyhow!(
+			"Self permissions not found in channel {} in guild {}",
+			channel.id,
+			guild_id
+		)),
+		Err(e) => Err(e.context(
+			"Failed to check for self permissions to read keyword channel",
+		)),
+	}
+}
+
+/// Parses the channels mentioned in the given text for a multi-channel
+/// keyword, checking each with [`check_channel`].
+///
+/// Returns a message describing why the channels can't be used if they
+/// can't.
+async fn parse_channels(
+	ctx: &Context,
+	guild_id: GuildId,
+	text: &str,
+	locale: &Locale,
+) -> Result<Result<Vec<ChannelId>, String>> {
+	let mut channels = Vec::new();
+
+	for captures in regex!(r"<#([0-9]+)>").captures_iter(text) {
+		let channel_id = ChannelId(captures[1].parse()?);
+
+		if !channels.contains(&channel_id) {
+			channels.push(channel_id);
+		}
+	}
+
+	if channels.is_empty() {
+		return Ok(Err(locale.get("keyword-channels-invalid").to_owned()));
+	}
+
+	if channels.len() > MAX_KEYWORD_CHANNELS {
+		return Ok(Err(locale.format(
+			"keyword-channels-limit",
+			&[("max", &MAX_KEYWORD_CHANNELS)],
+		)));
+	}
+
+	for &channel_id in &channels {
+		if let Some(message) =
+			check_channel(ctx, guild_id, channel_id, locale).await?
+		{
+			return Ok(Err(message));
+		}
+	}
+
+	Ok(Ok(channels))
+}
+
+/// Finds a word banned in a guild that the given keyword contains, if any.
+fn banned_word<'a>(keyword: &str, banned: &'a [String]) -> Option<&'a str> {
+	banned
+		.iter()
+		.find(|word| keyword.contains(word.as_str()))
+		.map(String::as_str)
+}
+
+/// Gets the shortest keywords allowed in a guild with the given settings.
+fn guild_min_length(guild_settings: &GuildSettings) -> usize {
+	let min = settings().behavior.min_keyword_length;
+
+	guild_settings
+		.min_keyword_length
+		.map_or(min, |guild_min| (guild_min as usize).max(min))
+}
+
+/// Checks if the user has another keyword with the same text as the given one
+/// in the given guild, so that they share author filters and statistics.
+pub(super) async fn keyword_shared(
+	ctx: &Context,
+	keyword: &Keyword,
+	guild_id: GuildId,
+) -> Result<bool> {
+	Ok(Keyword::user_keywords(keyword.user_id)
+		.await?
+		.into_iter()
+		.any(|other| {
+			let other_guild = match other.kind {
+				KeywordKind::Guild(guild_id) => Some(guild_id),
+				KeywordKind::Channel(channel_id) => {
+					ctx.cache.guild_channel_field(channel_id, |c| c.guild_id)
+				}
+			};
+
+			other.keyword == keyword.keyword
+				&& other.kind != keyword.kind
+				&& other_guild == Some(guild_id)
+		}))
 }
 
 /// Remove a keyword.
@@ -195,11 +505,12 @@ pub(crate) async fn remove(ctx: Context, command: Command) -> Result<()> {
 	check_opt_out!(ctx, command);
 	let guild_id = require_guild!(&ctx, &command);
 	let user_id = command.user.id;
+	let locale = locale(&command.locale);
 
 	let keyword = command
 		.data
 		.options
-		.get(0)
+		.first()
 		.and_then(|o| o.value.as_ref())
 		.context("No keyword to add provided")?
 		.as_str()
@@ -221,22 +532,22 @@ pub(crate) async fn remove(ctx: Context, command: Command) -> Result<()> {
 	};
 
 	if !keyword.clone().exists().await? {
-		return respond_eph(
-			&ctx,
-			&command,
-			"❌ You haven't added that keyword!",
-		)
-		.await;
+		return respond_eph(&ctx, &command, locale.get("keyword-missing"))
+			.await;
 	}
 
-	keyword.delete().await?;
+	let authors_in =
+		(!keyword_shared(&ctx, &keyword, guild_id).await?).then_some(guild_id);
+
+	keyword.clone().delete(authors_in).await?;
+	stats::keyword_removed(&ctx, &keyword).await?;
 
 	success(&ctx, &command).await
 }
 
-/// Add an ignored phrase.
+/// Add an ignored phrase, optionally only in one channel or for one keyword.
 ///
-/// Usage: `/ignore <phrase>`
+/// Usage: `/ignore [phrase] [channel] [keyword]`
 #[tracing::instrument(
 	skip_all,
 	fields(
@@ -248,38 +559,47 @@ pub(crate) async fn remove(ctx: Context, command: Command) -> Result<()> {
 pub(crate) async fn ignore(ctx: Context, command: Command) -> Result<()> {
 	check_opt_out!(&ctx, command);
 	let guild_id = require_guild!(&ctx, &command);
+	let locale = locale(&command.locale);
 
-	let phrase = command
-		.data
-		.options
-		.get(0)
-		.and_then(|o| o.value.as_ref())
-		.context("No phrase to ignore provided")?
-		.as_str()
-		.context("Phrase provided not string")?;
+	let (channel_id, keyword) = ignore_scope(&command);
+
+	if let Some(channel_id) = channel_id {
+		if let Some(message) =
+			check_channel(&ctx, guild_id, channel_id, locale).await?
+		{
+			return respond_eph(&ctx, &command, message).await;
+		}
+	}
+
+	let phrase = match command.data.options.iter().find(|o| o.name == "phrase")
+	{
+		Some(option) => option
+			.value
+			.as_ref()
+			.context("No phrase to ignore provided")?
+			.as_str()
+			.context("Phrase provided not string")?,
+		None => {
+			return add_ignores(&ctx, &command, guild_id, channel_id, keyword)
+				.await
+		}
+	};
 
 	if phrase.len() < 3 {
-		return respond_eph(
-			&ctx,
-			&command,
-			"❌ You can't ignore phrases shorter than 3 characters!",
-		)
-		.await;
+		return respond_eph(&ctx, &command, locale.get("ignore-too-short"))
+			.await;
 	}
 
 	let ignore = Ignore {
 		user_id: command.user.id,
 		guild_id,
 		phrase: phrase.to_lowercase(),
+		channel_id,
+		keyword,
 	};
 
 	if ignore.clone().exists().await? {
-		return respond_eph(
-			&ctx,
-			&command,
-			"❌ You already ignored that phrase!",
-		)
-		.await;
+		return respond_eph(&ctx, &command, locale.get("ignore-exists")).await;
 	}
 
 	ignore.insert().await?;
@@ -287,9 +607,10 @@ pub(crate) async fn ignore(ctx: Context, command: Command) -> Result<()> {
 	success(&ctx, &command).await
 }
 
-/// Remove an ignored phrase.
+/// Remove an ignored phrase, in the channel and for the keyword it was ignored
+/// in and for, if any.
 ///
-/// Usage: `/unignore <phrase>`
+/// Usage: `/unignore <phrase> [channel] [keyword]`
 #[tracing::instrument(
 	skip_all,
 	fields(
@@ -301,29 +622,30 @@ pub(crate) async fn ignore(ctx: Context, command: Command) -> Result<()> {
 pub(crate) async fn unignore(ctx: Context, command: Command) -> Result<()> {
 	check_opt_out!(&ctx, command);
 	let guild_id = require_guild!(&ctx, &command);
+	let locale = locale(&command.locale);
 
 	let phrase = command
 		.data
 		.options
-		.get(0)
+		.iter()
+		.find(|o| o.name == "phrase")
 		.and_then(|o| o.value.as_ref())
 		.context("No phrase to ignore provided")?
 		.as_str()
 		.context("Phrase provided not string")?;
 
+	let (channel_id, keyword) = ignore_scope(&command);
+
 	let ignore = Ignore {
 		user_id: command.user.id,
 		guild_id,
 		phrase: phrase.to_lowercase(),
+		channel_id,
+		keyword,
 	};
 
 	if !ignore.clone().exists().await? {
-		return respond_eph(
-			&ctx,
-			&command,
-			"❌ You haven't ignored that phrase!",
-		)
-		.await;
+		return respond_eph(&ctx, &command, locale.get("ignore-missing")).await;
 	}
 
 	ignore.delete().await?;
@@ -331,9 +653,30 @@ pub(crate) async fn unignore(ctx: Context, command: Command) -> Result<()> {
 	success(&ctx, &command).await
 }
 
+/// Reads the channel and keyword an ignored phrase is limited to from the
+/// `channel` and `keyword` options of `/ignore` or `/unignore`.
+fn ignore_scope(command: &Command) -> (Option<ChannelId>, Option<String>) {
+	let channel_id = command.data.resolved.channels.keys().next().copied();
+
+	let keyword = command
+		.data
+		.options
+		.iter()
+		.find(|o| o.name == "keyword")
+		.and_then(|o| o.value.as_ref())
+		.and_then(|v| v.as_str())
+		.map(|keyword| keyword.trim().to_lowercase())
+		.filter(|keyword| !keyword.is_empty());
+
+	(channel_id, keyword)
+}
+
 /// List ignored phrases in the current guild, or in all guilds when used in
 /// DMs.
 ///
+/// Phrases ignored in one channel are grouped under that channel, and phrases
+/// ignored for one keyword are marked with it.
+///
 /// Usage: `/ignores`
 #[tracing::instrument(
 	skip_all,
@@ -345,83 +688,55 @@ pub(crate) async fn unignore(ctx: Context, command: Command) -> Result<()> {
 )]
 pub(crate) async fn ignores(ctx: Context, command: Command) -> Result<()> {
 	check_opt_out!(&ctx, command);
+	let locale = locale(&command.locale);
 
-	match command.guild_id {
-		Some(guild_id) => {
-			let ignores = Ignore::user_guild_ignores(command.user.id, guild_id)
-				.await?
-				.into_iter()
-				.map(|ignore| ignore.phrase)
-				.collect::<Vec<_>>();
+	let options = match ListOptions::from_command(&command) {
+		Ok(options) => options,
+		Err(message) => return respond_eph(&ctx, &command, message).await,
+	};
 
-			if ignores.is_empty() {
-				return respond_eph(
-					&ctx,
-					&command,
-					"❌ You haven't ignored any phrases!",
-				)
-				.await;
+	let entries = Ignore::user_ignores(command.user.id)
+		.await?
+		.into_iter()
+		.map(|ignore| {
+			let mut text = ignore.phrase;
+
+			if let Some(keyword) = &ignore.keyword {
+				text.push(' ');
+				text.push_str(
+					&locale.format("ignore-keyword", &[("keyword", keyword)]),
+				);
 			}
 
-			let guild_name = ctx
-				.cache
-				.guild_field(guild_id, |g| g.name.clone())
-				.context("Couldn't get guild to list ignores")?;
-
-			let response = format!(
-				"{}'s ignored phrases in {}:\n  - {}",
-				command.user.name,
-				guild_name,
-				ignores.join("\n  - ")
-			);
-
-			respond_eph(&ctx, &command, response).await
-		}
-		None => {
-			let ignores = Ignore::user_ignores(command.user.id).await?;
-
-			if ignores.is_empty() {
-				return respond_eph(
+			let group = (options.guild_id.is_none()
+				|| ignore.channel_id.is_some())
+			.then(|| {
+				location(
 					&ctx,
-					&command,
-					"❌ You haven't ignored any phrases!",
+					&options,
+					Some(ignore.guild_id),
+					ignore.channel_id,
 				)
-				.await;
-			}
+			});
 
-			let mut ignores_by_guild = HashMap::new();
-
-			for ignore in ignores {
-				ignores_by_guild
-					.entry(ignore.guild_id)
-					.or_insert_with(Vec::new)
-					.push(ignore.phrase);
-			}
-
-			let mut response = String::new();
-
-			for (guild_id, phrases) in ignores_by_guild {
-				if !response.is_empty() {
-					response.push_str("\n\n");
-				}
-
-				let guild_name = ctx
-					.cache
-					.guild_field(guild_id, |g| g.name.clone())
-					.context("Couldn't get guild to list ignores")?;
-
-				write!(
-					&mut response,
-					"Your ignored phrases in {}:\n  – {}",
-					guild_name,
-					phrases.join("\n  – ")
-				)
-				.unwrap();
+			ListEntry {
+				text,
+				group,
+				guild_id: Some(ignore.guild_id),
+				channel_id: ignore.channel_id,
 			}
-
-			respond_eph(&ctx, &command, response).await
-		}
-	}
+		})
+		.collect();
+
+	send_list(
+		&ctx,
+		&command,
+		locale.get("ignores-title"),
+		entries,
+		&options,
+		locale.get("ignores-empty"),
+	)
+	.await
 }
 
 /// Remove keywords and ignores in a guild by ID.
@@ -444,7 +759,7 @@ pub(crate) async fn remove_server(
 	let arg = command
 		.data
 		.options
-		.get(0)
+		.first()
 		.and_then(|o| o.value.as_ref())
 		.context("No guild ID to remove provided")?
 		.as_str()
@@ -453,7 +768,12 @@ pub(crate) async fn remove_server(
 	let guild_id = match arg.parse() {
 		Ok(id) => GuildId(id),
 		Err(_) => {
-			return respond_eph(&ctx, &command, "❌ Invalid server ID!").await
+			return respond_eph(
+				&ctx,
+				&command,
+				locale(&command.locale).get("error-invalid-server"),
+			)
+			.await
 		}
 	};
 
@@ -474,6 +794,17 @@ pub(crate) async fn remove_server(
 				.collect()
 		});
 
+	let removed = Keyword::user_keywords(command.user.id)
+		.await?
+		.into_iter()
+		.filter(|keyword| match keyword.kind {
+			KeywordKind::Guild(id) => id == guild_id,
+			KeywordKind::Channel(id) => {
+				channels.as_ref().is_some_and(|c| c.contains(&id))
+			}
+		})
+		.collect::<Vec<_>>();
+
 	let guild_keywords_deleted =
 		Keyword::delete_in_guild(command.user.id, guild_id).await?;
 
@@ -496,12 +827,16 @@ pub(crate) async fn remove_server(
 		None => 0,
 	};
 
+	for keyword in &removed {
+		stats::keyword_removed(&ctx, keyword).await?;
+	}
+
 	if guild_keywords_deleted + ignores_deleted + channel_keywords_deleted == 0
 	{
 		respond_eph(
 			&ctx,
 			&command,
-			"❌ You didn't have any keywords or ignores in that server!",
+			locale(&command.locale).get("remove-server-empty"),
 		)
 		.await
 	} else {
@@ -511,6 +846,9 @@ pub(crate) async fn remove_server(
 
 /// List keywords in the current guild, or in all guilds when used in DMs.
 ///
+/// Team keywords of the user's roles and keywords of packs they subscribed to
+/// are listed separately.
+///
 /// Usage: `/keywords`
 #[tracing::instrument(
 	skip_all,
@@ -522,180 +860,158 @@ pub(crate) async fn remove_server(
 )]
 pub(crate) async fn keywords(ctx: Context, command: Command) -> Result<()> {
 	check_opt_out!(&ctx, command);
+	let locale = locale(&command.locale);
 
-	match command.guild_id {
-		Some(guild_id) => {
-			let guild_keywords =
-				Keyword::user_guild_keywords(command.user.id, guild_id)
+	let options = match ListOptions::from_command(&command) {
+		Ok(options) => options,
+		Err(message) => return respond_eph(&ctx, &command, message).await,
+	};
+
+	let mut entries = Vec::new();
+	let mut guild_bans = HashMap::new();
+	let author_filters = KeywordAuthor::user_filters(command.user.id).await?;
+	let keyword_channels =
+		KeywordChannel::user_channels(command.user.id).await?;
+
+	for keyword in Keyword::user_keywords(command.user.id).await? {
+		let (guild_id, channel_id) = match keyword.kind {
+			KeywordKind::Guild(guild_id) => (Some(guild_id), None),
+			KeywordKind::Channel(channel_id) => (
+				ctx.cache.guild_channel_field(channel_id, |c| c.guild_id),
+				Some(channel_id),
+			),
+		};
+
+		let mut allowed = Keyword::is_allowed(&keyword.keyword);
+
+		if let (true, Some(guild_id)) = (allowed, guild_id) {
+			if let Entry::Vacant(entry) = guild_bans.entry(guild_id) {
+				let bans = BannedKeyword::guild_bans(guild_id)
 					.await?
 					.into_iter()
-					.map(|keyword| keyword.keyword)
+					.map(|ban| ban.word)
 					.collect::<Vec<_>>();
 
-			let guild_channels = get_text_channels_in_guild(&ctx, guild_id)?;
-
-			let mut channel_keywords = HashMap::new();
-
-			for keyword in
-				Keyword::user_channel_keywords(command.user.id).await?
-			{
-				let channel_id = match keyword.kind {
-					KeywordKind::Channel(id) => id,
-					_ => {
-						panic!("user_channel_keywords returned a guild keyword")
-					}
-				};
-
-				if !guild_channels.contains_key(&channel_id) {
-					continue;
-				}
-
-				channel_keywords
-					.entry(channel_id)
-					.or_insert_with(Vec::new)
-					.push(keyword.keyword);
-			}
-
-			if guild_keywords.is_empty() && channel_keywords.is_empty() {
-				return respond_eph(
-					&ctx,
-					&command,
-					"❌ You haven't added any keywords yet!",
-				)
-				.await;
+				entry.insert(bans);
 			}
 
-			let guild_name = ctx
-				.cache
-				.guild_field(guild_id, |g| g.name.clone())
-				.context("Couldn't get guild to list keywords")?;
-
-			let mut response = String::with_capacity(45);
-
-			if guild_keywords.is_empty() {
-				write!(&mut response, "Your keywords in {}:", guild_name)
-					.unwrap();
-			} else {
-				write!(
-					&mut response,
-					"Your keywords in {}:\n  – {}",
-					guild_name,
-					guild_keywords.join("\n  – ")
-				)
-				.unwrap();
-			}
-
-			for (channel_id, channel_keywords) in channel_keywords {
-				response.push('\n');
-
-				write!(
-					&mut response,
-					"  In <#{}>:\n    - {1}",
-					channel_id,
-					channel_keywords.join("\n    - "),
-				)
-				.unwrap();
-			}
-
-			respond_eph(&ctx, &command, response).await
+			allowed =
+				banned_word(&keyword.keyword, &guild_bans[&guild_id]).is_none();
 		}
-		None => {
-			let keywords = Keyword::user_keywords(command.user.id).await?;
 
-			if keywords.is_empty() {
-				return respond_eph(
-					&ctx,
-					&command,
-					"❌ You haven't added any keywords yet!",
-				)
-				.await;
-			}
-
-			let mut keywords_by_guild = HashMap::new();
-
-			let mut unknown_channel_keywords = HashMap::new();
-
-			for keyword in keywords {
-				match keyword.kind {
-					KeywordKind::Guild(guild_id) => {
-						let guild_keywords = &mut keywords_by_guild
-							.entry(guild_id)
-							.or_insert_with(|| (Vec::new(), HashMap::new()))
-							.0;
-
-						guild_keywords.push(keyword.keyword);
-					}
-					KeywordKind::Channel(channel_id) => {
-						let guild_id = ctx
-							.cache
-							.guild_channel_field(channel_id, |c| c.guild_id);
-
-						match guild_id {
-							Some(guild_id) => {
-								keywords_by_guild
-									.entry(guild_id)
-									.or_insert_with(|| {
-										(Vec::new(), HashMap::new())
-									})
-									.1
-									.entry(channel_id)
-									.or_insert_with(Vec::new)
-									.push(keyword.keyword);
-							}
-							None => {
-								unknown_channel_keywords
-									.entry(channel_id)
-									.or_insert_with(Vec::new)
-									.push(keyword.keyword);
-							}
-						}
-					}
-				}
+		let authors = author_filters
+			.iter()
+			.filter(|filter| {
+				Some(filter.guild_id) == guild_id
+					&& filter.keyword == keyword.keyword
+			})
+			.map(|filter| filter.author.mention())
+			.collect::<Vec<_>>();
+
+		let (excluded, limited): (Vec<_>, Vec<_>) = keyword_channels
+			.iter()
+			.filter(|channel| {
+				matches!(keyword.kind, KeywordKind::Guild(_))
+					&& Some(channel.guild_id) == guild_id
+					&& channel.keyword == keyword.keyword
+			})
+			.partition(|channel| channel.excluded);
+
+		let mut text = keyword.keyword;
+
+		for (channels, key) in [
+			(limited, "keyword-channels-only"),
+			(excluded, "keyword-channels-except"),
+		] {
+			if !channels.is_empty() {
+				let channels = channels
+					.iter()
+					.map(|channel| format!("<#{}>", channel.channel_id))
+					.collect::<Vec<_>>()
+					.join(", ");
+				text.push(' ');
+				text.push_str(&locale.format(key, &[("channels", &channels)]));
 			}
+		}
 
-			let mut response = String::new();
+		if !authors.is_empty() {
+			let authors = authors.join(", ");
+			text.push(' ');
+			text.push_str(
+				&locale.format("keyword-authors", &[("authors", &authors)]),
+			);
+		}
 
-			for (guild_id, (guild_keywords, channel_keywords)) in
-				keywords_by_guild
-			{
-				if !response.is_empty() {
-					response.push_str("\n\n");
-				}
+		if !allowed {
+			text.push(' ');
+			text.push_str(locale.get("keyword-flagged"));
+		}
 
-				let guild_name = ctx
-					.cache
-					.guild_field(guild_id, |g| g.name.clone())
-					.unwrap_or_else(|| {
-						format!("<Unknown server> ({})", guild_id)
-					});
-
-				if guild_keywords.is_empty() {
-					write!(&mut response, "Your keywords in {}:", guild_name)
-						.unwrap();
-				} else {
-					write!(
-						&mut response,
-						"Your keywords in {}:\n  – {}",
-						guild_name,
-						guild_keywords.join("\n  – ")
-					)
-					.unwrap();
-				}
+		entries.push(ListEntry {
+			text,
+			group: Some(location(&ctx, &options, guild_id, channel_id)),
+			guild_id,
+			channel_id,
+		});
+	}
 
-				for (channel_id, channel_keywords) in channel_keywords {
-					response.push('\n');
+	let team_keywords =
+		user_team_keywords(&ctx, command.user.id, options.guild_id).await?;
 
-					write!(
-						&mut response,
-						"  In <#{0}> ({0}):\n    - {1}",
-						channel_id,
-						channel_keywords.join("\n    - "),
-					)
-					.unwrap();
-				}
-			}
+	entries.extend(team_keywords.into_iter().map(|(team_keyword, left)| {
+		let guild_id = team_keyword.guild_id;
+		let role = format!("<@&{}>", team_keyword.role_id);
 
-			respond_eph(&ctx, &command, response).await
+		let group = if options.guild_id.is_some() {
+			locale.format("keywords-team-group", &[("role", &role)])
+		} else {
+			let guild = location(&ctx, &options, Some(guild_id), None);
+			locale.format(
+				"keywords-team-group-in",
+				&[("server", &guild), ("role", &role)],
+			)
+		};
+
+		let text = if left {
+			format!("{} {}", team_keyword.keyword, locale.get("keywords-left"))
+		} else {
+			team_keyword.keyword
+		};
+
+		ListEntry {
+			text,
+			group: Some(group),
+			guild_id: Some(guild_id),
+			channel_id: None,
 		}
+	}));
+
+	for pack in user_packs(command.user.id, options.guild_id).await? {
+		let group = if options.guild_id.is_some() {
+			locale.format("keywords-pack-group", &[("pack", &pack.name)])
+		} else {
+			let guild = location(&ctx, &options, Some(pack.guild_id), None);
+			locale.format(
+				"keywords-pack-group-in",
+				&[("server", &guild), ("pack", &pack.name)],
+			)
+		};
+
+		entries.extend(pack.keywords.into_iter().map(|keyword| ListEntry {
+			text: keyword,
+			group: Some(group.clone()),
+			guild_id: Some(pack.guild_id),
+			channel_id: None,
+		}));
 	}
+
+	send_list(
+		&ctx,
+		&command,
+		locale.get("keywords-title"),
+		entries,
+		&options,
+		locale.get("keywords-empty"),
+	)
+	.await
 }
@@ -5,12 +5,33 @@
 
 #[macro_use]
 mod util;
+mod alerts;
+mod autocomplete;
 mod blocks;
+mod bulk;
+mod copy;
+mod delivery;
+mod email;
+mod export;
+mod fallback;
+mod follows;
+mod history;
+mod keyword_authors;
+mod keyword_exclusions;
+mod keyword_form;
 mod keywords;
 mod mutes;
 mod opt_out;
+mod packs;
+mod pages;
+mod server_settings;
+mod stats;
+mod team_keywords;
+mod test_keyword;
+mod watches;
+mod why;
 
-use std::fmt::Write;
+use std::fmt::{Display, Write};
 
 use anyhow::{Context as _, Result};
 use indoc::indoc;
@@ -20,7 +41,7 @@ use serenity::{
 	client::Context,
 	model::{
 		application::{
-			command::Command as ApplicationCommand,
+			command::{Command as ApplicationCommand, CommandType},
 			interaction::{
 				application_command::ApplicationCommandInteraction as Command,
 				MessageFlags,
@@ -33,18 +54,41 @@ use serenity::{
 use tracing::debug;
 
 pub(crate) use self::{
-	blocks::{block, blocks, unblock},
+	alerts::{reaction_alerts, reply_alerts},
+	autocomplete::autocomplete,
+	blocks::{block, block_author, blocks, unblock},
+	bulk::{add_from_message, manage},
+	copy::{copy_channel, copy_server},
+	delivery::delivery,
+	email::email,
+	export::{export, import},
+	fallback::fallback,
+	follows::{follow, follows, unfollow},
+	history::history,
+	keyword_authors::keyword_authors,
+	keyword_exclusions::keyword_exclusions,
+	keyword_form::{add_advanced, edit_keyword},
 	keywords::{
 		add, ignore, ignores, keywords, remove, remove_server, unignore,
 	},
 	mutes::{mute, mutes, unmute},
 	opt_out::{opt_in, opt_out},
+	packs::{keyword_pack, subscribe, unsubscribe},
+	server_settings::server_settings,
+	stats::stats,
+	team_keywords::team_keywords,
+	test_keyword::{test_keyword, test_message},
+	watches::watch,
+	why::why,
 };
 use super::Shards;
 use crate::{
-	bot::{util::respond, STARTED},
+	bot::{
+		i18n::{locale, locales, Locale},
+		util::{respond, respond_eph},
+		STARTED,
+	},
 	global::EMBED_COLOR,
-	require_embed_perms,
 	settings::settings,
 };
 
@@ -54,6 +98,7 @@ pub(crate) async fn create_commands(ctx: &Context) -> Result<()> {
 	let commands = COMMAND_INFO
 		.iter()
 		.map(CommandInfo::create)
+		.chain(CONTEXT_MENU_INFO.iter().map(ContextMenuInfo::create))
 		.collect::<Vec<_>>();
 	if let Some(guild) = settings().bot.test_guild {
 		debug!("Registering commands in test guild");
@@ -102,19 +147,21 @@ pub(crate) async fn ping(ctx: Context, command: Command) -> Result<()> {
 		.expect("No shards managed")
 		.latency;
 
-	let mut reply = "🏓 Pong!".to_owned();
+	let locale = locale(&command.locale);
+	let mut reply = locale.get("ping-pong").to_owned();
 
 	if let Some(latency) = latency {
-		reply += "\nGateway latency: ";
-
 		let micros = latency.as_micros();
-		if micros > 10_000_000 {
-			write!(&mut reply, "{:.2} s", micros as f64 / 1_000_000.0).unwrap();
+		let latency = if micros > 10_000_000 {
+			format!("{:.2} s", micros as f64 / 1_000_000.0)
 		} else if micros > 10 {
-			write!(&mut reply, "{:.2} ms", micros as f64 / 1000.0).unwrap();
+			format!("{:.2} ms", micros as f64 / 1000.0)
 		} else {
-			write!(&mut reply, "{} μs", micros).unwrap();
-		}
+			format!("{} μs", micros)
+		};
+
+		reply.push('\n');
+		reply += &locale.format("ping-latency", &[("latency", &latency)]);
 	}
 
 	respond(&ctx, &command, &reply).await?;
@@ -155,6 +202,8 @@ pub(crate) async fn about(ctx: Context, command: Command) -> Result<()> {
 		)
 	};
 
+	let locale = locale(&command.locale);
+
 	let uptime = STARTED.get().expect("Start time not set").elapsed();
 
 	let uptime = {
@@ -165,17 +214,26 @@ pub(crate) async fn about(ctx: Context, command: Command) -> Result<()> {
 		let minutes = seconds / 60 % 60;
 		let seconds = seconds % 60;
 
-		if days >= 20 {
-			format!("{days} days")
+		let args: [(&str, &(dyn Display + Sync)); 4] = [
+			("days", &days),
+			("hours", &hours),
+			("minutes", &minutes),
+			("seconds", &seconds),
+		];
+
+		let key = if days >= 20 {
+			"uptime-days"
 		} else if days > 0 {
-			format!("{days} days, {hours} hours")
+			"uptime-days-hours"
 		} else if hours > 0 {
-			format!("{hours} hours, {minutes} minutes")
+			"uptime-hours-minutes"
 		} else if minutes > 0 {
-			format!("{minutes} minutes, {seconds} seconds")
+			"uptime-minutes-seconds"
 		} else {
-			format!("{seconds} seconds")
-		}
+			"uptime-seconds"
+		};
+
+		locale.format(key, &args)
 	};
 
 	command
@@ -187,18 +245,25 @@ pub(crate) async fn about(ctx: Context, command: Command) -> Result<()> {
 						" ",
 						env!("CARGO_PKG_VERSION")
 					))
-					.field("Source", env!("CARGO_PKG_REPOSITORY"), true)
-					.field("Author", "ThatsNoMoon#0175", true)
+					.field(
+						locale.get("about-source"),
+						env!("CARGO_PKG_REPOSITORY"),
+						true,
+					)
+					.field(locale.get("about-author"), "ThatsNoMoon#0175", true)
 					.color(EMBED_COLOR);
 
 					if let Some(invite_url) = invite_url {
 						e.field(
-							"Invite",
-							format!("[Add me to your server]({})", invite_url),
+							locale.get("about-invite"),
+							locale.format(
+								"about-invite-link",
+								&[("url", &invite_url)],
+							),
 							true,
 						);
 					};
-					e.field("Uptime", uptime, true)
+					e.field(locale.get("about-uptime"), uptime, true)
 				})
 			})
 		})
@@ -226,22 +291,38 @@ pub(crate) async fn help(ctx: Context, command: Command) -> Result<()> {
 	require_embed_perms!(&ctx, &command);
 
 	let username = ctx.cache.current_user_field(|u| u.name.clone());
+	let locale = locale(&command.locale);
 
-	match command.data.options.get(0) {
+	match command.data.options.first() {
 		None => {
 			command
 				.create_interaction_response(&ctx, |r| {
 					r.interaction_response_data(|m| {
 						m.flags(MessageFlags::EPHEMERAL).embed(|e| {
-							e.title(format!("{} – Help", username))
-								.description(
-									"Use `/help [command]` to see more \
-									information about a specified command",
+							// Discord allows at most 25 fields, so commands
+							// are listed in the description instead
+							let mut description =
+								locale.get("help-description").to_owned();
+							description.push('\n');
+
+							for info in COMMAND_INFO.iter() {
+								write!(
+									description,
+									"\n**{}**: {}",
+									info.name,
+									info.description(locale)
 								)
-								.fields(COMMAND_INFO.iter().map(|info| {
-									(info.name, info.short_desc, true)
-								}))
-								.color(EMBED_COLOR)
+								.unwrap();
+							}
+
+							e.title(
+								locale.format(
+									"help-title",
+									&[("name", &username)],
+								),
+							)
+							.description(description)
+							.color(EMBED_COLOR)
 						})
 					})
 				})
@@ -260,10 +341,9 @@ pub(crate) async fn help(ctx: Context, command: Command) -> Result<()> {
 			{
 				Some(info) => info,
 				None => {
-					return Err(anyhow::anyhow!(
-						"Invalid command name passed to help: {}",
-						name
-					));
+					let message =
+						locale.format("help-unknown", &[("name", &name)]);
+					return respond_eph(&ctx, &command, message).await;
 				}
 			};
 
@@ -271,12 +351,19 @@ pub(crate) async fn help(ctx: Context, command: Command) -> Result<()> {
 				.create_interaction_response(&ctx, |r| {
 					r.interaction_response_data(|m| {
 						m.flags(MessageFlags::EPHEMERAL).embed(|e| {
-							e.title(format!("Help – {}", info.name))
-								.description(info.long_desc)
-								.color(EMBED_COLOR);
-
-							match info.examples.as_ref() {
-								Some(ex) => e.field("Example Usage", ex, false),
+							e.title(locale.format(
+								"help-command-title",
+								&[("name", &info.name)],
+							))
+							.description(info.long_description(locale))
+							.color(EMBED_COLOR);
+
+							match info.example_usage(locale) {
+								Some(ex) => e.field(
+									locale.get("help-examples-title"),
+									ex,
+									false,
+								),
 								None => e,
 							}
 						})
@@ -301,22 +388,172 @@ struct CommandInfo {
 impl CommandInfo {
 	/// Create a [`CreateApplicationCommand`] describing this command to create
 	/// a corresponding slash command.
+	///
+	/// The description is registered in every locale it's translated to.
 	fn create(&self) -> CreateApplicationCommand {
 		let mut builder = CreateApplicationCommand::default();
 		builder
 			.name(self.name)
 			.description(self.short_desc)
 			.set_options(self.options.clone());
+
+		for locale in &locales()[1..] {
+			if let Some(description) = locale.try_get(&self.key()) {
+				builder.description_localized(locale.tag, description);
+			}
+		}
+
+		builder
+	}
+
+	/// Key of the translations of this command's description.
+	fn key(&self) -> String {
+		format!("command-{}", self.name)
+	}
+
+	/// This command's description in the given locale.
+	fn description(&self, locale: &Locale) -> &'static str {
+		locale.try_get(&self.key()).unwrap_or(self.short_desc)
+	}
+
+	/// This command's help text in the given locale.
+	fn long_description(&self, locale: &Locale) -> &'static str {
+		locale
+			.try_get(&format!("help-{}", self.name))
+			.unwrap_or(self.long_desc)
+	}
+
+	/// This command's example usage in the given locale, if it has any.
+	fn example_usage(&self, locale: &Locale) -> Option<&'static str> {
+		self.examples.map(|examples| {
+			locale
+				.try_get(&format!("help-examples-{}", self.name))
+				.unwrap_or(examples)
+		})
+	}
+}
+
+/// Description of a context menu command for slash command creation.
+struct ContextMenuInfo {
+	name: &'static str,
+	kind: CommandType,
+}
+
+impl ContextMenuInfo {
+	/// Create a [`CreateApplicationCommand`] describing this command to create
+	/// a corresponding context menu command.
+	///
+	/// The name is registered in every locale it's translated to; commands
+	/// are still received with their default name.
+	fn create(&self) -> CreateApplicationCommand {
+		let mut builder = CreateApplicationCommand::default();
+		builder.name(self.name).kind(self.kind);
+
+		for locale in &locales()[1..] {
+			if let Some(name) =
+				locale.try_get(&format!("command-{}", self.name))
+			{
+				builder.name_localized(locale.tag, name);
+			}
+		}
+
 		builder
 	}
 }
 
-static COMMAND_INFO: Lazy<[CommandInfo; 18], fn() -> [CommandInfo; 18]> =
+static CONTEXT_MENU_INFO: [ContextMenuInfo; 4] = [
+	ContextMenuInfo {
+		name: "Test keywords",
+		kind: CommandType::Message,
+	},
+	ContextMenuInfo {
+		name: "Add keyword from message…",
+		kind: CommandType::Message,
+	},
+	ContextMenuInfo {
+		name: "Block author",
+		kind: CommandType::Message,
+	},
+	ContextMenuInfo {
+		name: "Block user",
+		kind: CommandType::User,
+	},
+];
+
+static COMMAND_INFO: Lazy<[CommandInfo; 45], fn() -> [CommandInfo; 45]> =
 	Lazy::new(|| {
 		use serenity::{
 			builder::CreateApplicationCommandOption as Option,
-			model::application::command::CommandOptionType,
+			model::{
+				application::command::CommandOptionType, channel::ChannelType,
+			},
 		};
+		fn history_filter_options() -> [Option; 4] {
+			let mut keyword = Option::default();
+			keyword
+				.name("keyword")
+				.description("Only show notifications of this keyword")
+				.kind(CommandOptionType::String);
+
+			let mut server = Option::default();
+			server
+				.name("server")
+				.description(
+					"Only show notifications from the server with this ID",
+				)
+				.kind(CommandOptionType::String);
+
+			let mut after = Option::default();
+			after
+				.name("after")
+				.description(
+					"Only show notifications from this date on (YYYY-MM-DD)",
+				)
+				.kind(CommandOptionType::String);
+
+			let mut before = Option::default();
+			before
+				.name("before")
+				.description("Only show notifications from before this date (YYYY-MM-DD)")
+				.kind(CommandOptionType::String);
+
+			[keyword, server, after, before]
+		}
+
+		fn list_options(server: bool, channel: bool) -> Vec<Option> {
+			let mut options = Vec::new();
+
+			if server {
+				let mut opt = Option::default();
+				opt.name("server")
+					.description(
+						"Only show this server (defaults to the current one)",
+					)
+					.kind(CommandOptionType::String)
+					.set_autocomplete(true);
+				options.push(opt);
+			}
+
+			if channel {
+				let mut opt = Option::default();
+				opt.name("channel")
+					.description("Only show keywords specific to this channel")
+					.kind(CommandOptionType::Channel)
+					.channel_types(&[ChannelType::Text]);
+				options.push(opt);
+			}
+
+			let mut sort = Option::default();
+			sort.name("sort")
+				.description("How to order the list")
+				.kind(CommandOptionType::String)
+				.add_string_choice("By server and channel", "location")
+				.add_string_choice("By name", "name");
+			options.push(sort);
+
+			options
+		}
+
 		let mut commands = [
 			CommandInfo {
 				name: "add",
@@ -333,9 +570,28 @@ static COMMAND_INFO: Lazy<[CommandInfo; 18], fn() -> [CommandInfo; 18]> =
 					`/add [keyword] [channel]`. \
 					You'll only be notified of keywords added this way when they appear in the \
 					specified channel(s) (not when they appear anywhere else).
-					
+
+					To add a keyword in several channels as one entry, mention them all in the \
+					`channels` option, like `/add [keyword] channels: #help #questions`. Removing \
+					the keyword with `/remove [keyword]` removes it from all of them. To add a \
+					keyword everywhere except certain channels instead, see \
+					`/help keyword-exclusions`.
+
+					To add a keyword from a message, right click (or long press) the \
+					message and choose Apps > Add keyword from message…, then edit the \
+					text down to the keyword you want.
+
+					To add several keywords at once, use `/add` without a keyword (and \
+					optionally with a channel) and enter them separated by commas or new \
+					lines.
+
+					To set all of a keyword's options in a form instead, use \
+					`/add-advanced`. You can change a keyword's options later with \
+					`/edit-keyword`.
+
 					You can remove keywords later with `/remove [keyword]`; see \
-					`/help remove` for more information.
+					`/help remove` for more information. To remove several keywords at \
+					once, use `/manage`.
 
 					You can list your current keywords with `/keywords`.",
 				),
@@ -346,17 +602,22 @@ static COMMAND_INFO: Lazy<[CommandInfo; 18], fn() -> [CommandInfo; 18]> =
 					Add the keyword \"optimize\" in only the #javascript channel:
 					/add `keyword:` optimize `channel:` javascript
 
+					Add the keyword \"deploy\" in only the #ops and #releases channels:
+					/add `keyword:` deploy `channels:` #ops #releases
+
 					Add the keyword \"hello world\" in the current server:
-					/add `keyword:` hello world",
+					/add `keyword:` hello world
+
+					Add several keywords in the current server at once:
+					/add",
 				)),
 				options: vec![
 					{
 						let mut opt = Option::default();
 						opt
 							.name("keyword")
-							.description("The keyword to listen to")
-							.kind(CommandOptionType::String)
-							.required(true);
+							.description("The keyword to listen to (leave empty to add several)")
+							.kind(CommandOptionType::String);
 						opt
 					},
 					{
@@ -366,6 +627,14 @@ static COMMAND_INFO: Lazy<[CommandInfo; 18], fn() -> [CommandInfo; 18]> =
 							.description("A specific channel for this keyword")
 							.kind(CommandOptionType::Channel);
 						opt
+					},
+					{
+						let mut opt = Option::default();
+						opt
+							.name("channels")
+							.description("Several channels for this keyword, as mentions (managed as one keyword)")
+							.kind(CommandOptionType::String);
+						opt
 					}
 				],
 			},
@@ -399,7 +668,8 @@ static COMMAND_INFO: Lazy<[CommandInfo; 18], fn() -> [CommandInfo; 18]> =
 							.name("keyword")
 							.description("The keyword to listen to")
 							.kind(CommandOptionType::String)
-							.required(true);
+							.required(true)
+							.set_autocomplete(true);
 						opt
 					},
 					{
@@ -412,6 +682,233 @@ static COMMAND_INFO: Lazy<[CommandInfo; 18], fn() -> [CommandInfo; 18]> =
 					}
 				],
 			},
+			CommandInfo {
+				name: "add-advanced",
+				short_desc: "Add a keyword, choosing all of its options in a form",
+				long_desc: indoc!("
+					Use `/add-advanced` to add a keyword in the current server using a \
+					form with all of the keyword's options, instead of command options.
+
+					Enter the keyword, and optionally a channel by name (like `#general`) \
+					or ID to only highlight the keyword in that channel. Leave the channel \
+					empty to highlight the keyword anywhere in the server.
+
+					A server-wide keyword can also be limited to several channels, or \
+					excluded from some, by listing them separated by commas or spaces. To \
+					only highlight messages from certain users or roles, list their \
+					mentions or IDs, or the names of the roles, separated by commas.
+
+					See `/help add` for more information about keywords. You can change a \
+					keyword's options later with `/edit-keyword`.",
+				),
+				examples: None,
+				options: vec![],
+			},
+			CommandInfo {
+				name: "edit-keyword",
+				short_desc: "Change a keyword or its options",
+				long_desc: indoc!("
+					Use `/edit-keyword [keyword]` to change one of your keywords in the \
+					current server without removing it and adding it again. A form opens \
+					with the keyword's current options, which you can change and submit.
+
+					To edit a keyword you added to a specific channel, use \
+					`/edit-keyword [keyword] [channel]`.
+
+					Clearing the channel in the form makes the keyword server-wide, and \
+					entering a channel makes it specific to that channel. The channels the \
+					keyword is limited to or excluded from and the authors it's restricted \
+					to can be changed in the same form; see `/help add-advanced` for how \
+					to enter them. Its statistics are kept when you change it.",
+				),
+				examples: Some(indoc!("
+					Edit the server-wide keyword \"rust\":
+					/edit-keyword `keyword:` rust
+
+					Edit the keyword \"optimize\" in the #javascript channel:
+					/edit-keyword `keyword:` optimize `channel:` #javascript",
+				)),
+				options: vec![
+					{
+						let mut opt = Option::default();
+						opt
+							.name("keyword")
+							.description("The keyword to edit")
+							.kind(CommandOptionType::String)
+							.required(true)
+							.set_autocomplete(true);
+						opt
+					},
+					{
+						let mut opt = Option::default();
+						opt
+							.name("channel")
+							.description("The specific channel of the keyword to edit")
+							.kind(CommandOptionType::Channel);
+						opt
+					}
+				],
+			},
+			CommandInfo {
+				name: "keyword-authors",
+				short_desc: "Only highlight a keyword in messages from particular users or roles",
+				long_desc: indoc!("
+					Use `/keyword-authors add [keyword] [user] [role]` to only be notified of one \
+					of your keywords in this server when particular users or members of \
+					particular roles say it. A keyword restricted to several authors notifies you \
+					when any of them says it. The restriction applies to the keyword everywhere \
+					in this server, including in channels you added it to.
+
+					Use `/keyword-authors remove [keyword] [user] [role]` to lift a restriction, \
+					or leave out the user and role to lift all of them.
+
+					Use `/keyword-authors list` to see your restricted keywords in this server.",
+				),
+				examples: Some(indoc!("
+					Only be notified of \"release\" when a member of @Maintainers says it:
+					/keyword-authors add `keyword:` release `role:` @Maintainers
+
+					Be notified of \"release\" from anyone again:
+					/keyword-authors remove `keyword:` release",
+				)),
+				options: vec![
+					{
+						let mut opt = Option::default();
+						opt
+							.name("add")
+							.description("Restrict a keyword to messages from a user or role")
+							.kind(CommandOptionType::SubCommand)
+							.create_sub_option(|sub| {
+								sub
+									.name("keyword")
+									.description("The keyword to restrict")
+									.kind(CommandOptionType::String)
+									.required(true)
+							})
+							.create_sub_option(|sub| {
+								sub
+									.name("user")
+									.description("The user whose messages can highlight the keyword")
+									.kind(CommandOptionType::User)
+							})
+							.create_sub_option(|sub| {
+								sub
+									.name("role")
+									.description("The role whose members' messages can highlight the keyword")
+									.kind(CommandOptionType::Role)
+							});
+						opt
+					},
+					{
+						let mut opt = Option::default();
+						opt
+							.name("remove")
+							.description("Lift restrictions of a keyword to certain authors")
+							.kind(CommandOptionType::SubCommand)
+							.create_sub_option(|sub| {
+								sub
+									.name("keyword")
+									.description("The restricted keyword")
+									.kind(CommandOptionType::String)
+									.required(true)
+							})
+							.create_sub_option(|sub| {
+								sub
+									.name("user")
+									.description("The user to lift the restriction to (leave out to lift all)")
+									.kind(CommandOptionType::User)
+							})
+							.create_sub_option(|sub| {
+								sub
+									.name("role")
+									.description("The role to lift the restriction to (leave out to lift all)")
+									.kind(CommandOptionType::Role)
+							});
+						opt
+					},
+					{
+						let mut opt = Option::default();
+						opt
+							.name("list")
+							.description("List your keywords restricted to certain authors")
+							.kind(CommandOptionType::SubCommand);
+						opt
+					},
+				],
+			},
+			CommandInfo {
+				name: "keyword-exclusions",
+				short_desc: "Stop highlighting a server-wide keyword in particular channels",
+				long_desc: indoc!("
+					Use `/keyword-exclusions add [keyword] [channel]` to stop being notified of \
+					one of your server-wide keywords in a channel, while still being notified of \
+					it everywhere else. Unlike muting the channel, this doesn't affect your other \
+					keywords.
+
+					Use `/keyword-exclusions remove [keyword] [channel]` to lift an exclusion, or \
+					leave out the channel to lift all of them.
+
+					Use `/keyword-exclusions list` to see your excluded keywords in this server.",
+				),
+				examples: Some(indoc!("
+					Be notified of \"docker\" everywhere except #docker:
+					/keyword-exclusions add `keyword:` docker `channel:` #docker",
+				)),
+				options: vec![
+					{
+						let mut opt = Option::default();
+						opt
+							.name("add")
+							.description("Stop highlighting a keyword in a channel")
+							.kind(CommandOptionType::SubCommand)
+							.create_sub_option(|sub| {
+								sub
+									.name("keyword")
+									.description("The server-wide keyword to exclude")
+									.kind(CommandOptionType::String)
+									.required(true)
+							})
+							.create_sub_option(|sub| {
+								sub
+									.name("channel")
+									.description("The channel to exclude the keyword from")
+									.kind(CommandOptionType::Channel)
+									.channel_types(&[ChannelType::Text])
+									.required(true)
+							});
+						opt
+					},
+					{
+						let mut opt = Option::default();
+						opt
+							.name("remove")
+							.description("Highlight an excluded keyword in a channel again")
+							.kind(CommandOptionType::SubCommand)
+							.create_sub_option(|sub| {
+								sub
+									.name("keyword")
+									.description("The excluded keyword")
+									.kind(CommandOptionType::String)
+									.required(true)
+							})
+							.create_sub_option(|sub| {
+								sub
+									.name("channel")
+									.description("The channel to lift the exclusion from (leave out for all)")
+									.kind(CommandOptionType::Channel)
+							});
+						opt
+					},
+					{
+						let mut opt = Option::default();
+						opt
+							.name("list")
+							.description("List your keywords excluded from channels")
+							.kind(CommandOptionType::SubCommand);
+						opt
+					},
+				],
+			},
 			CommandInfo {
 				name: "mute",
 				short_desc: "Mute a channel to prevent server keywords from being highlighted there",
@@ -476,13 +973,24 @@ static COMMAND_INFO: Lazy<[CommandInfo; 18], fn() -> [CommandInfo; 18]> =
 					Use `/block [user]` to block the specified users and \
 					prevent notifications about your keywords in their messages.
 
+					You can also right click (or long press) a user and choose \
+					Apps > Block user, or a message and choose Apps > Block author.
+
+					Set `hide-messages` to also keep your messages from highlighting the blocked \
+					user's keywords, so they can't follow what you say through notifications. \
+					Use `/block` again with `hide-messages:False` to stop hiding them while \
+					keeping the block.
+
 					You can unblock users later with `/unblock [user]`.
 
 					You can list your currently blocked users with `/blocks`.",
 				),
 				examples: Some(indoc!("
 					Block AnnoyingUser:
-					/block `user:` @AnnoyingUser",
+					/block `user:` @AnnoyingUser
+
+					Block Stalker and hide your messages from their highlights:
+					/block `user:` @Stalker `hide-messages:` True",
 				)),
 				options: vec![
 					{
@@ -493,7 +1001,15 @@ static COMMAND_INFO: Lazy<[CommandInfo; 18], fn() -> [CommandInfo; 18]> =
 							.kind(CommandOptionType::User)
 							.required(true);
 						opt
-					}
+					},
+					{
+						let mut opt = Option::default();
+						opt
+							.name("hide-messages")
+							.description("Also hide your messages from this user's highlights")
+							.kind(CommandOptionType::Boolean);
+						opt
+					},
 				],
 			},
 			CommandInfo {
@@ -523,161 +1039,1387 @@ static COMMAND_INFO: Lazy<[CommandInfo; 18], fn() -> [CommandInfo; 18]> =
 				],
 			},
 			CommandInfo {
-				name: "ignore",
-				short_desc: "Add a phrase to ignore in the current server",
+				name: "follow",
+				short_desc: "Be notified of every message a user sends in a channel",
 				long_desc: indoc!("
-					Use `/ignore [phrase]` to add a phrase to ignore in the current server.
-
-					You won't be notified of any messages that contain ignored phrases, even if they \
-					contain one of your keywords.
-
-					Phrases are case-insensitive.
-
-					You can remove ignored phrases later with `/unignore [phrase]`; see \
-					`/help unignore` for more information.
-
-					You can list your current keywords with `/ignores`.",
+					Use `/follow [user] [channel]` to be notified of every message the user \
+					sends in the channel, like an announcer or a maintainer. Leave out the \
+					channel to follow them in the current channel; follow them again to add \
+					more channels.
+
+					You're notified about one user at most once every few minutes, so a burst of \
+					messages only sends one notification. You aren't notified if you muted the \
+					channel or blocked the user, and users can stop you from following them by \
+					blocking you with `hide-messages`.
+
+					You can stop following users with `/unfollow [user]`, and list who you follow \
+					with `/follows`.",
 				),
 				examples: Some(indoc!("
-					Ignore messages containing \"meme\" in the current server:
-					/ignore `phrase:` meme
-
-					Ignore messages containing \"hello world\" in the current server:
-					/ignore `phrase:` hello world",
+					Follow Maintainer in #announcements:
+					/follow `user:` @Maintainer `channel:` #announcements",
 				)),
 				options: vec![
 					{
 						let mut opt = Option::default();
 						opt
-							.name("phrase")
-							.description("The phrase to ignore")
-							.kind(CommandOptionType::String)
+							.name("user")
+							.description("The user to follow")
+							.kind(CommandOptionType::User)
 							.required(true);
 						opt
-					}
+					},
+					{
+						let mut opt = Option::default();
+						opt
+							.name("channel")
+							.description("The channel to follow them in (defaults to this channel)")
+							.kind(CommandOptionType::Channel);
+						opt
+					},
 				],
 			},
 			CommandInfo {
-				name: "unignore",
-				short_desc: "Remove an ignored phrase in the current server",
+				name: "unfollow",
+				short_desc: "Stop being notified of a user's messages",
 				long_desc: indoc!("
-					Use `/unignore [phrase]` to remove a phrase you previously ignored in the \
-					current server.
-
-					Phrases are case-insensitive.
+					Use `/unfollow [user]` to stop following a user in every channel, or \
+					`/unfollow [user] [channel]` to stop following them in one channel.
 
-					You can list your current keywords with `/ignores`.",
+					You can list who you follow with `/follows`.",
 				),
 				examples: Some(indoc!("
-					Stop ignoring messages containing \"haskell\" in the current server:
-					/unignore `phrase:` haskell
-
-					Stop ignoring messages containing \"map-reduce\" in the current server:
-					/ignore `phrase:` map-reduce",
+					Stop following Maintainer:
+					/unfollow `user:` @Maintainer",
 				)),
 				options: vec![
 					{
 						let mut opt = Option::default();
 						opt
-							.name("phrase")
-							.description("The phrase to unignore")
-							.kind(CommandOptionType::String)
+							.name("user")
+							.description("The user to stop following")
+							.kind(CommandOptionType::User)
 							.required(true);
 						opt
-					}
+					},
+					{
+						let mut opt = Option::default();
+						opt
+							.name("channel")
+							.description("The channel to stop following them in (leave out for all)")
+							.kind(CommandOptionType::Channel);
+						opt
+					},
 				],
 			},
 			CommandInfo {
-				name: "keywords",
-				short_desc: "List your current highlighted keywords",
+				name: "watch",
+				short_desc: "Be notified of every message in a channel or thread",
 				long_desc: indoc!("
-					Use `/keywords` to list your current highlighted keywords.
+					Use `/watch channel [channel]` to be notified of every new message in a \
+					low-traffic channel, or `/watch thread [thread]` to be notified of every new \
+					message in a thread. Leave out the channel or thread to watch the current one.
 
-					Using `keywords` in a server will show you only the keywords you've highlighted \
-					in that server, including all channel-specific keywords there.
+					Like keywords, you aren't notified if you send a message or reaction in the \
+					channel soon after. You're notified about one channel at most once every \
+					few minutes; messages sent in between are summarized in one notification, so \
+					a busy thread doesn't flood your DMs.
 
-					If the bot can't find information about a server you have keywords in, \
-					its ID will be in parentheses, so you can remove them with `remove-server` \
-					if desired. \
-					See `/help remove-server` for more details.",
+					Use `/watch stop [channel]` to stop watching a channel or thread, and \
+					`/watch list` to list what you watch in this server.",
 				),
-				examples: None,
-				options: vec![],
-			},
-			CommandInfo {
-				name: "mutes",
-				short_desc: "List your currently muted channels",
-				long_desc: indoc!("
-					Use `/mutes` to list your currently muted channels.
-
-					Using `mutes` in a server will only show you the channels you've muted in that \
-					server.
+				examples: Some(indoc!("
+					Watch the #releases channel:
+					/watch channel `channel:` #releases
 
-					Using `mutes` in DMs with the bot will list channels you've muted across \
-					all servers, including deleted channels or channels in servers this bot is \
-					no longer a member of. If the bot can't find information on a channel you \
-					previously muted, its ID will be in parentheses.",
-				),
-				examples: None,
+					Stop watching the current thread:
+					/watch stop",
+				)),
+				options: vec![
+					{
+						let mut opt = Option::default();
+						opt
+							.name("channel")
+							.description("Be notified of every message in a channel")
+							.kind(CommandOptionType::SubCommand)
+							.create_sub_option(|sub| {
+								sub
+									.name("channel")
+									.description("The channel to watch (defaults to this channel)")
+									.kind(CommandOptionType::Channel)
+									.channel_types(&[ChannelType::Text, ChannelType::News])
+							});
+						opt
+					},
+					{
+						let mut opt = Option::default();
+						opt
+							.name("thread")
+							.description("Be notified of every message in a thread")
+							.kind(CommandOptionType::SubCommand)
+							.create_sub_option(|sub| {
+								sub
+									.name("thread")
+									.description("The thread to watch (defaults to this thread)")
+									.kind(CommandOptionType::Channel)
+									.channel_types(&[
+										ChannelType::PublicThread,
+										ChannelType::PrivateThread,
+										ChannelType::NewsThread,
+									])
+							});
+						opt
+					},
+					{
+						let mut opt = Option::default();
+						opt
+							.name("stop")
+							.description("Stop watching a channel or thread")
+							.kind(CommandOptionType::SubCommand)
+							.create_sub_option(|sub| {
+								sub
+									.name("channel")
+									.description("The channel or thread to stop watching (defaults to this one)")
+									.kind(CommandOptionType::Channel)
+							});
+						opt
+					},
+					{
+						let mut opt = Option::default();
+						opt
+							.name("list")
+							.description("List the channels and threads you watch")
+							.kind(CommandOptionType::SubCommand);
+						opt
+					},
+				],
+			},
+			CommandInfo {
+				name: "reply-alerts",
+				short_desc: "Be notified of replies to your messages that don't mention you",
+				long_desc: indoc!("
+					Use `/reply-alerts enabled: True` to be notified when someone replies to one of \
+					your messages with the mention turned off. Replies that mention you already \
+					notify you through Discord.
+
+					Like keywords, you aren't notified if you send a message or reaction in the \
+					channel soon after the reply, or if you blocked the person replying or muted \
+					the channel.
+
+					Use `/reply-alerts enabled: False` to stop these notifications.",
+				),
+				examples: None,
+				options: vec![
+					{
+						let mut opt = Option::default();
+						opt
+							.name("enabled")
+							.description("Whether to be notified of replies that don't mention you")
+							.kind(CommandOptionType::Boolean)
+							.required(true);
+						opt
+					}
+				],
+			},
+			CommandInfo {
+				name: "reaction-alerts",
+				short_desc: "Be notified of particular reactions to your messages",
+				long_desc: indoc!("
+					Use `/reaction-alerts add [emoji]` to be notified when someone reacts to one \
+					of your messages with the emoji. Both unicode and server emoji work.
+
+					Reactions to the same message are collected while waiting to notify you, so \
+					several reactions only send one notification. Like keywords, you aren't \
+					notified if you send a message or reaction in the channel soon after.
+
+					Use `/reaction-alerts remove [emoji]` to stop being notified of an emoji, and \
+					`/reaction-alerts list` to list the emoji you're notified of.",
+				),
+				examples: Some(indoc!("
+					Be notified when someone reacts to your messages with ❓:
+					/reaction-alerts add `emoji:` ❓",
+				)),
+				options: vec![
+					{
+						let mut opt = Option::default();
+						opt
+							.name("add")
+							.description("Be notified of reactions to your messages with an emoji")
+							.kind(CommandOptionType::SubCommand)
+							.create_sub_option(|sub| {
+								sub
+									.name("emoji")
+									.description("The emoji to be notified of")
+									.kind(CommandOptionType::String)
+									.required(true)
+							});
+						opt
+					},
+					{
+						let mut opt = Option::default();
+						opt
+							.name("remove")
+							.description("Stop being notified of reactions with an emoji")
+							.kind(CommandOptionType::SubCommand)
+							.create_sub_option(|sub| {
+								sub
+									.name("emoji")
+									.description("The emoji to stop being notified of")
+									.kind(CommandOptionType::String)
+									.required(true)
+							});
+						opt
+					},
+					{
+						let mut opt = Option::default();
+						opt
+							.name("list")
+							.description("List the emoji you're notified of")
+							.kind(CommandOptionType::SubCommand);
+						opt
+					},
+				],
+			},
+			CommandInfo {
+				name: "ignore",
+				short_desc: "Add a phrase to ignore in the current server",
+				long_desc: indoc!("
+					Use `/ignore [phrase]` to add a phrase to ignore in the current server.
+
+					You won't be notified of any messages that contain ignored phrases, even if they \
+					contain one of your keywords.
+
+					Phrases are case-insensitive.
+
+					To ignore several phrases at once, use `/ignore` without a phrase and \
+					enter them separated by commas or new lines.
+
+					Use the `channel` option to only ignore a phrase in one channel, and the \
+					`keyword` option to only ignore it for one of your keywords. A phrase ignored \
+					for a keyword won't stop your other keywords from notifying you.
+
+					You can remove ignored phrases later with `/unignore [phrase]`; see \
+					`/help unignore` for more information.
+
+					You can list your current keywords with `/ignores`.",
+				),
+				examples: Some(indoc!("
+					Ignore messages containing \"meme\" in the current server:
+					/ignore `phrase:` meme
+
+					Ignore messages containing \"hello world\" in the current server:
+					/ignore `phrase:` hello world
+
+					Ignore messages containing \"meme\" in #general:
+					/ignore `phrase:` meme `channel:` #general
+
+					Don't notify you of \"rust\" in messages containing \"game\":
+					/ignore `phrase:` game `keyword:` rust",
+				)),
+				options: vec![
+					{
+						let mut opt = Option::default();
+						opt
+							.name("phrase")
+							.description("The phrase to ignore (leave empty to add several)")
+							.kind(CommandOptionType::String);
+						opt
+					},
+					{
+						let mut opt = Option::default();
+						opt
+							.name("channel")
+							.description("Only ignore the phrase in this channel")
+							.kind(CommandOptionType::Channel)
+							.channel_types(&[ChannelType::Text]);
+						opt
+					},
+					{
+						let mut opt = Option::default();
+						opt
+							.name("keyword")
+							.description("Only ignore the phrase for this keyword")
+							.kind(CommandOptionType::String);
+						opt
+					}
+				],
+			},
+			CommandInfo {
+				name: "unignore",
+				short_desc: "Remove an ignored phrase in the current server",
+				long_desc: indoc!("
+					Use `/unignore [phrase]` to remove a phrase you previously ignored in the \
+					current server.
+
+					If you only ignored the phrase in one channel or for one keyword, give the \
+					same `channel` or `keyword` to remove it.
+
+					Phrases are case-insensitive.
+
+					You can list your current keywords with `/ignores`.",
+				),
+				examples: Some(indoc!("
+					Stop ignoring messages containing \"haskell\" in the current server:
+					/unignore `phrase:` haskell
+
+					Stop ignoring messages containing \"map-reduce\" in the current server:
+					/ignore `phrase:` map-reduce
+
+					Stop ignoring messages containing \"meme\" in #general:
+					/unignore `phrase:` meme `channel:` #general",
+				)),
+				options: vec![
+					{
+						let mut opt = Option::default();
+						opt
+							.name("phrase")
+							.description("The phrase to unignore")
+							.kind(CommandOptionType::String)
+							.required(true)
+							.set_autocomplete(true);
+						opt
+					},
+					{
+						let mut opt = Option::default();
+						opt
+							.name("channel")
+							.description("The channel the phrase was ignored in")
+							.kind(CommandOptionType::Channel)
+							.channel_types(&[ChannelType::Text]);
+						opt
+					},
+					{
+						let mut opt = Option::default();
+						opt
+							.name("keyword")
+							.description("The keyword the phrase was ignored for")
+							.kind(CommandOptionType::String);
+						opt
+					}
+				],
+			},
+			CommandInfo {
+				name: "keywords",
+				short_desc: "List your current highlighted keywords",
+				long_desc: indoc!("
+					Use `/keywords` to list your current highlighted keywords.
+
+					Using `keywords` in a server will show you only the keywords you've highlighted \
+					in that server, including all channel-specific keywords there.
+
+					Team keywords the server's admins added for your roles are listed under their \
+					own headings. See `/help team-keywords` to leave them.
+
+					Keywords you restricted to certain authors with `/keyword-authors` list those \
+					authors.
+
+					Keywords the bot or the server no longer allows, for example because an admin \
+					banned a word they contain, are marked with ⚠️. They no longer notify you, so \
+					you may want to remove them.
+
+					Long lists are split into pages; use the buttons below the list to move \
+					between them.
+
+					If the bot can't find information about a server you have keywords in, \
+					its ID will be in parentheses, so you can remove them with `remove-server` \
+					if desired. \
+					See `/help remove-server` for more details.
+
+					Use the `server` and `channel` options to only list keywords in another \
+					server or a specific channel, and `sort` to list keywords alphabetically \
+					instead of grouped by where they apply.",
+				),
+				examples: Some(indoc!("
+					List your keywords in the #general channel:
+					/keywords `channel:` #general
+
+					List all of your keywords alphabetically, in DMs with the bot:
+					/keywords `sort:` By name",
+				)),
+				options: list_options(true, true),
+			},
+			CommandInfo {
+				name: "mutes",
+				short_desc: "List your currently muted channels",
+				long_desc: indoc!("
+					Use `/mutes` to list your currently muted channels.
+
+					Using `mutes` in a server will only show you the channels you've muted in that \
+					server.
+
+					Using `mutes` in DMs with the bot will list channels you've muted across \
+					all servers, including deleted channels or channels in servers this bot is \
+					no longer a member of. If the bot can't find information on a channel you \
+					previously muted, its ID will be in parentheses.
+
+					Use the `server` option to list mutes in another server.",
+				),
+				examples: None,
+				options: list_options(true, false),
+			},
+			CommandInfo {
+				name: "blocks",
+				short_desc: "List your currently blocked users",
+				long_desc: indoc!("
+					Use `/blocks` to list your currently blocked users.",
+				),
+				examples: None,
 				options: vec![],
 			},
 			CommandInfo {
-				name: "blocks",
-				short_desc: "List your currently blocked users",
+				name: "follows",
+				short_desc: "List the users you follow",
+				long_desc: indoc!("
+					Use `/follows` to list the users you follow and the channels you follow \
+					them in.
+
+					Using `follows` in a server will only show you who you follow in that server. \
+					Use the `server` option to list follows in another server.",
+				),
+				examples: None,
+				options: list_options(true, false),
+			},
+			CommandInfo {
+				name: "ignores",
+				short_desc: "List your currently ignored phrases",
+				long_desc: indoc!("
+					Use `/ignores` to list your currently ignored phrases.
+
+					Using `ignores` in a server will only show you the phrases you've ignored in that \
+					server. Phrases ignored in one channel are grouped under it, and phrases \
+					ignored for one keyword are marked with it.
+
+					Using `ignores` in DMs with the bot will list phrases you've ignored across \
+					all servers, including servers this bot is no longer a member of.
+
+					If the bot can't find information on a server you ignored phrases in, its ID will \
+					be in parentheses, so you can use `remove-server` to remove the ignores there if \
+					desired.
+
+					Use the `server` option to list ignored phrases in another server.",
+				),
+				examples: None,
+				options: list_options(true, false),
+			},
+			CommandInfo {
+				name: "remove-server",
+				short_desc: "Remove all keywords and ignores on a given server",
+				long_desc: indoc!("
+					Use `/remove-server [server ID]` to remove all keywords **and** ignores on \
+					the server with the given ID.
+
+					This won't remove channel-specific keywords in the given server; you can use the \
+					normal `remove` command for that.
+
+					This is normally not necessary, but if you no longer share a server with the bot \
+					where you added keywords, you can clean up your keywords list by using `keywords` \
+					in DMs to see all keywords, and this command to remove any server IDs the bot \
+					can't find. ",
+				),
+				examples: Some(indoc!("
+					Remove all server-wide keywords and ignores added to the server with an ID of \
+					126029834632:
+					/remove-server `server:` 126029834632",
+				)),
+				options: vec![
+					{
+						let mut opt = Option::default();
+						opt
+							.name("server")
+							.description("The ID of the server to remove")
+							.kind(CommandOptionType::String)
+							.required(true)
+							.set_autocomplete(true);
+						opt
+					}
+				],
+			},
+			CommandInfo {
+				name: "manage",
+				short_desc: "Select several keywords in this server to remove at once",
+				long_desc: indoc!("
+					Use `/manage` to see a menu of your keywords in the current server, \
+					including channel-specific keywords. Select the keywords you want to \
+					remove, across as many pages as you like, then press Remove selected.",
+				),
+				examples: None,
+				options: vec![],
+			},
+			CommandInfo {
+				name: "copy-server",
+				short_desc: "Copy your keywords and ignores from another server",
+				long_desc: indoc!("
+					Use `/copy-server [server ID]` to copy all of your server-wide \
+					keywords and ignored phrases from the server with the given ID to \
+					the current server.
+
+					Keywords and ignored phrases you already added here are skipped, as \
+					are keywords that would go over the keyword limit.
+
+					Use `/keywords` in DMs with the bot to see the IDs of servers you've \
+					added keywords in.",
+				),
+				examples: Some(indoc!("
+					Copy keywords and ignores from the server with an ID of \
+					126029834632:
+					/copy-server `server:` 126029834632",
+				)),
+				options: vec![{
+					let mut opt = Option::default();
+					opt
+						.name("server")
+						.description("The ID of the server to copy from")
+						.kind(CommandOptionType::String)
+						.required(true)
+						.set_autocomplete(true);
+					opt
+				}],
+			},
+			CommandInfo {
+				name: "copy-channel",
+				short_desc: "Copy your channel-specific keywords to another channel",
+				long_desc: indoc!("
+					Use `/copy-channel [from] [to]` to copy all of your keywords \
+					specific to one channel to another channel. If you don't give a \
+					channel to copy to, they're copied to the current channel.
+
+					Keywords you already added in the channel are skipped, as are \
+					keywords that would go over the keyword limit.",
+				),
+				examples: Some(indoc!("
+					Copy keywords from #general to #help:
+					/copy-channel `from:` #general `to:` #help",
+				)),
+				options: vec![
+					{
+						let mut opt = Option::default();
+						opt
+							.name("from")
+							.description("The channel to copy keywords from")
+							.kind(CommandOptionType::Channel)
+							.required(true);
+						opt
+					},
+					{
+						let mut opt = Option::default();
+						opt
+							.name("to")
+							.description("The channel to copy keywords to (defaults to this one)")
+							.kind(CommandOptionType::Channel)
+							.channel_types(&[ChannelType::Text]);
+						opt
+					},
+				],
+			},
+			CommandInfo {
+				name: "history",
+				short_desc: "List or search notifications you've received",
+				long_desc: indoc!("
+					Use `/history list` to list your recent notifications, with the keyword, \
+					channel, and time of each, and a link to the message.
+
+					Use `/history search [text]` to find notifications of messages containing \
+					the given text.
+
+					Both can be filtered to a specific keyword, a server (by ID), or messages sent \
+					`after` or `before` a date, written as YYYY-MM-DD.
+
+					Only notifications sent as Discord messages are recorded, including those sent \
+					through a webhook; those delivered by HTTP, email, or in a digest don't \
+					appear in your history. Notifications are only kept for as long as the bot's \
+					host allows.",
+				),
+				examples: Some(indoc!("
+					List your recent notifications:
+					/history list
+
+					List notifications of the keyword \"rust\" from October 2026:
+					/history list `keyword:` rust `after:` 2026-10-01 `before:` 2026-11-01
+
+					Find notifications of messages mentioning a release:
+					/history search `text:` release",
+				)),
+				options: vec![
+					{
+						let mut opt = Option::default();
+						opt
+							.name("list")
+							.description("List your recent notifications")
+							.kind(CommandOptionType::SubCommand);
+						for filter in history_filter_options() {
+							opt.add_sub_option(filter);
+						}
+						opt
+					},
+					{
+						let mut opt = Option::default();
+						opt
+							.name("search")
+							.description("Search your notifications for messages containing some text")
+							.kind(CommandOptionType::SubCommand)
+							.create_sub_option(|sub| {
+								sub
+									.name("text")
+									.description("The text to search for")
+									.kind(CommandOptionType::String)
+									.required(true)
+							});
+						for filter in history_filter_options() {
+							opt.add_sub_option(filter);
+						}
+						opt
+					}
+				],
+			},
+			CommandInfo {
+				name: "test-keyword",
+				short_desc: "Test whether a keyword would notify you about some text",
+				long_desc: indoc!("
+					Use `/test-keyword [keyword] [text]` to see whether you'd be notified about \
+					the keyword if the text were sent in this channel, without sending any \
+					notifications. Include `author` to also check as if that user sent it.
+
+					You'll see where the keyword matched, and why you wouldn't be notified, \
+					like an ignored phrase appearing, this channel being muted, or the author \
+					being blocked.
+
+					To test your keywords against a message that was already sent, right \
+					click (or long press) the message, and choose Apps > Test keywords.",
+				),
+				examples: Some(indoc!("
+					Test whether \"rust\" would match a message:
+					/test-keyword `keyword:` rust `text:` I'm learning Rust!
+
+					Test as if the message were sent by SomeUser:
+					/test-keyword `keyword:` rust `text:` rust 2.0 when? `author:` @SomeUser",
+				)),
+				options: vec![
+					{
+						let mut opt = Option::default();
+						opt
+							.name("keyword")
+							.description("The keyword to test")
+							.kind(CommandOptionType::String)
+							.required(true);
+						opt
+					},
+					{
+						let mut opt = Option::default();
+						opt
+							.name("text")
+							.description("The text to test the keyword against")
+							.kind(CommandOptionType::String)
+							.required(true);
+						opt
+					},
+					{
+						let mut opt = Option::default();
+						opt
+							.name("author")
+							.description("The user to test as the author of the text")
+							.kind(CommandOptionType::User);
+						opt
+					}
+				],
+			},
+			CommandInfo {
+				name: "why",
+				short_desc: "Find out why you were or weren't notified about a message",
+				long_desc: indoc!("
+					Use `/why [message link]` to check a message against your current keywords \
+					and settings. For each of your keywords, you'll see whether it appears in \
+					the message, and if it does, what would stop you from being notified: \
+					an ignored phrase, a muted channel, a blocked author, the author opting \
+					out, or not being able to read the channel.
+
+					You'll also see whether you were actually sent a notification about the \
+					message.
+
+					To get a message link, right click (or long press) the message and choose \
+					Copy Message Link.",
+				),
+				examples: Some(indoc!("
+					Check a message:
+					/why `message:` https://discord.com/channels/1234/5678/9012",
+				)),
+				options: vec![
+					{
+						let mut opt = Option::default();
+						opt
+							.name("message")
+							.description("A link to the message to check")
+							.kind(CommandOptionType::String)
+							.required(true);
+						opt
+					}
+				],
+			},
+			CommandInfo {
+				name: "stats",
+				short_desc: "See how often your keywords match and notify you",
+				long_desc: indoc!("
+					Use `/stats` to see how many messages each of your keywords in this \
+					server matched, and how many notifications were sent for them. Use it in \
+					DMs to see your keywords in all servers.
+
+					Matches that didn't notify you are broken down by why:
+					- `patience`: you were active in the channel soon after the message
+					- `ignored`: one of your ignored phrases appeared in the message
+					- `muted`: you muted the channel
+					- `blocked`: you blocked the author
+
+					Keywords that never matched are listed too, so you can remove ones you \
+					don't need. Statistics may take a few minutes to update.",
+				),
+				examples: None,
+				options: vec![],
+			},
+			CommandInfo {
+				name: "delivery",
+				short_desc: "Choose how you receive notifications",
+				long_desc: indoc!("
+					Use `/delivery` to see how your notifications are currently delivered.
+
+					Use `/delivery [method] [url]` to change how notifications are delivered:
+					- `dm`: direct messages from the bot (the default)
+					- `webhook`: messages sent through a Discord webhook you created, given \
+					its URL
+					- `http`: JSON POST requests to a URL of your choice, for services like \
+					ntfy or Gotify (if enabled by the bot's host)
+					- `email`: emails sent to the address you verified with `/email` \
+					(if enabled by the bot's host)
+					- `email-digest`: like `email`, but notifications are collected and \
+					sent together periodically
+
+					If a notification can't be delivered, you'll be warned the next time you \
+					use a command.
+
+					Notifications sent over HTTP or email can't be updated or removed when the \
+					original message is edited or deleted.",
+				),
+				examples: Some(indoc!("
+					Receive notifications through a webhook:
+					/delivery `method:` webhook `url:` https://discord.com/api/webhooks/...
+
+					Go back to receiving notifications in DMs:
+					/delivery `method:` dm",
+				)),
+				options: vec![
+					{
+						let mut opt = Option::default();
+						opt
+							.name("method")
+							.description("How notifications should be delivered")
+							.kind(CommandOptionType::String)
+							.add_string_choice("Direct message", "dm")
+							.add_string_choice("Discord webhook", "webhook")
+							.add_string_choice("HTTP POST", "http")
+							.add_string_choice("Email", "email")
+							.add_string_choice("Email digest", "email-digest");
+						opt
+					},
+					{
+						let mut opt = Option::default();
+						opt
+							.name("url")
+							.description("The webhook or HTTP URL to deliver notifications to")
+							.kind(CommandOptionType::String);
+						opt
+					}
+				],
+			},
+			CommandInfo {
+				name: "email",
+				short_desc: "Add an email address to receive notifications at",
 				long_desc: indoc!("
-					Use `/blocks` to list your currently blocked users.",
+					Use `/email [address]` to add an email address to receive notifications at. \
+					I'll send a verification code to that address; use `/email [code]` to \
+					confirm you own it. You can request a new code once a minute.
+
+					Once your address is verified, use `/delivery` to receive notifications or \
+					periodic digests by email.
+
+					Use `/email` to see your current address, or `/email remove:True` to \
+					remove it.
+
+					Email notifications are only available if enabled by the bot's host.",
 				),
-				examples: None,
-				options: vec![],
+				examples: Some(indoc!("
+					Add the address ferris@example.com:
+					/email `address:` ferris@example.com
+
+					Verify your address with the code you received:
+					/email `code:` 123456",
+				)),
+				options: vec![
+					{
+						let mut opt = Option::default();
+						opt
+							.name("address")
+							.description("The email address to send notifications to")
+							.kind(CommandOptionType::String);
+						opt
+					},
+					{
+						let mut opt = Option::default();
+						opt
+							.name("code")
+							.description("The verification code you received by email")
+							.kind(CommandOptionType::String);
+						opt
+					},
+					{
+						let mut opt = Option::default();
+						opt
+							.name("remove")
+							.description("Remove your email address")
+							.kind(CommandOptionType::Boolean);
+						opt
+					}
+				],
 			},
 			CommandInfo {
-				name: "ignores",
-				short_desc: "List your currently ignored phrases",
+				name: "fallback",
+				short_desc: "Choose where notifications are sent in this server when DMs fail",
 				long_desc: indoc!("
-					Use `/ignores` to list your currently ignored phrases.
+					Use `/fallback [channel]` to choose a channel where I'll create a private \
+					thread for each member whose DMs are closed, and send their notifications \
+					from this server there instead.
 
-					Using `ignores` in a server will only show you the phrases you've ignored in that \
-					server.
+					Use `/fallback` to see the current fallback channel, or \
+					`/fallback disable:True` to stop sending notifications in this server.
 
-					Using `ignores` in DMs with the bot will list phrases you've ignored across \
-					all servers, including servers this bot is no longer a member of.
+					You need the Manage Server permission to use this command.",
+				),
+				examples: Some(indoc!("
+					Send notifications in private threads in #highlights:
+					/fallback `channel:` #highlights
 
-					If the bot can't find information on a server you ignored phrases in, its ID will \
-					be in parentheses, so you can use `remove-server` to remove the ignores there if \
-					desired.",
+					Stop sending notifications in this server:
+					/fallback `disable:` True",
+				)),
+				options: vec![
+					{
+						let mut opt = Option::default();
+						opt
+							.name("channel")
+							.description("The channel to create notification threads in")
+							.kind(CommandOptionType::Channel)
+							.channel_types(&[ChannelType::Text]);
+						opt
+					},
+					{
+						let mut opt = Option::default();
+						opt
+							.name("disable")
+							.description("Stop sending notifications in this server")
+							.kind(CommandOptionType::Boolean);
+						opt
+					}
+				],
+			},
+			CommandInfo {
+				name: "server-settings",
+				short_desc: "View or change how highlighting works in this server",
+				long_desc: indoc!("
+					Use `/server-settings view` to see this server's settings.
+
+					Use `/server-settings exclude [channel]` to stop highlighting messages in a \
+					channel for everyone, like staff or HR channels, and \
+					`/server-settings include [channel]` to highlight them again.
+
+					Use `/server-settings keyword-limit [limit]` to limit how many keywords each \
+					member can add in this server, and `/server-settings min-length [length]` to \
+					require longer keywords. Leave out the value to go back to the default.
+
+					Use `/server-settings highlighting enabled:False` to turn off highlighting in \
+					this server entirely.
+
+					Use `/server-settings ban-keyword [word]` to stop members from adding \
+					keywords containing a word, like a member's name, and \
+					`/server-settings unban-keyword [word]` to allow them again. Keywords members \
+					already have that contain the word stop notifying them, and are flagged \
+					in `/keywords`.
+
+					Use `/server-settings revoke [user]` to stop a member from being notified of \
+					keywords in this server or adding keywords here, for example if they use \
+					highlights to harass others, and `/server-settings restore [user]` to undo it.
+
+					Use `/server-settings audit` to see who changed these settings recently.
+
+					You need the Manage Server permission to use this command.",
+				),
+				examples: Some(indoc!("
+					Stop highlighting messages in #staff:
+					/server-settings exclude `channel:` #staff
+
+					Allow each member 20 keywords in this server:
+					/server-settings keyword-limit `limit:` 20
+
+					Turn off highlighting in this server:
+					/server-settings highlighting `enabled:` False
+
+					Stop members from adding keywords containing \"alice\":
+					/server-settings ban-keyword `word:` alice",
+				)),
+				options: vec![
+					{
+						let mut opt = Option::default();
+						opt
+							.name("view")
+							.description("See this server's settings")
+							.kind(CommandOptionType::SubCommand);
+						opt
+					},
+					{
+						let mut opt = Option::default();
+						opt
+							.name("exclude")
+							.description("Stop highlighting messages in a channel")
+							.kind(CommandOptionType::SubCommand)
+							.create_sub_option(|sub| {
+								sub
+									.name("channel")
+									.description("The channel to exclude")
+									.kind(CommandOptionType::Channel)
+									.required(true)
+							});
+						opt
+					},
+					{
+						let mut opt = Option::default();
+						opt
+							.name("include")
+							.description("Highlight messages in an excluded channel again")
+							.kind(CommandOptionType::SubCommand)
+							.create_sub_option(|sub| {
+								sub
+									.name("channel")
+									.description("The channel to include again")
+									.kind(CommandOptionType::Channel)
+									.required(true)
+							});
+						opt
+					},
+					{
+						let mut opt = Option::default();
+						opt
+							.name("keyword-limit")
+							.description("Limit how many keywords each member can add here")
+							.kind(CommandOptionType::SubCommand)
+							.create_sub_option(|sub| {
+								sub
+									.name("limit")
+									.description("Most keywords per member (leave out to remove the limit)")
+									.kind(CommandOptionType::Integer)
+									.min_int_value(1u64)
+							});
+						opt
+					},
+					{
+						let mut opt = Option::default();
+						opt
+							.name("min-length")
+							.description("Require keywords added here to be longer")
+							.kind(CommandOptionType::SubCommand)
+							.create_sub_option(|sub| {
+								sub
+									.name("length")
+									.description("Shortest keywords allowed (leave out to reset)")
+									.kind(CommandOptionType::Integer)
+									.min_int_value(3u64)
+									.max_int_value(100u64)
+							});
+						opt
+					},
+					{
+						let mut opt = Option::default();
+						opt
+							.name("highlighting")
+							.description("Turn highlighting in this server on or off")
+							.kind(CommandOptionType::SubCommand)
+							.create_sub_option(|sub| {
+								sub
+									.name("enabled")
+									.description("Whether messages in this server are highlighted")
+									.kind(CommandOptionType::Boolean)
+									.required(true)
+							});
+						opt
+					},
+					{
+						let mut opt = Option::default();
+						opt
+							.name("ban-keyword")
+							.description("Stop members from adding keywords containing a word")
+							.kind(CommandOptionType::SubCommand)
+							.create_sub_option(|sub| {
+								sub
+									.name("word")
+									.description("The word to ban, like a member's name")
+									.kind(CommandOptionType::String)
+									.required(true)
+							});
+						opt
+					},
+					{
+						let mut opt = Option::default();
+						opt
+							.name("unban-keyword")
+							.description("Allow keywords containing a banned word again")
+							.kind(CommandOptionType::SubCommand)
+							.create_sub_option(|sub| {
+								sub
+									.name("word")
+									.description("The banned word")
+									.kind(CommandOptionType::String)
+									.required(true)
+							});
+						opt
+					},
+					{
+						let mut opt = Option::default();
+						opt
+							.name("revoke")
+							.description("Stop a member from using highlighting in this server")
+							.kind(CommandOptionType::SubCommand)
+							.create_sub_option(|sub| {
+								sub
+									.name("user")
+									.description("The member to revoke highlighting for")
+									.kind(CommandOptionType::User)
+									.required(true)
+							});
+						opt
+					},
+					{
+						let mut opt = Option::default();
+						opt
+							.name("restore")
+							.description("Let a member use highlighting in this server again")
+							.kind(CommandOptionType::SubCommand)
+							.create_sub_option(|sub| {
+								sub
+									.name("user")
+									.description("The member to restore highlighting for")
+									.kind(CommandOptionType::User)
+									.required(true)
+							});
+						opt
+					},
+					{
+						let mut opt = Option::default();
+						opt
+							.name("audit")
+							.description("See recent changes to this server's settings")
+							.kind(CommandOptionType::SubCommand);
+						opt
+					},
+				],
+			},
+			CommandInfo {
+				name: "team-keywords",
+				short_desc: "Add keywords that notify every member of a role",
+				long_desc: indoc!("
+					Use `/team-keywords add [role] [keyword]` to notify every member of a role \
+					about a keyword, without each of them adding it, and \
+					`/team-keywords remove [role] [keyword]` to stop. Members are notified like \
+					with their own keywords, so their mutes, blocks, and ignored phrases still \
+					apply. You need the Manage Server permission to add or remove team keywords.
+
+					Use `/team-keywords list` to see this server's team keywords.
+
+					Use `/team-keywords leave [keyword]` to stop being notified about one of \
+					your roles' team keywords, and `/team-keywords rejoin [keyword]` to be \
+					notified again. Your team keywords are also shown in `/keywords`.",
+				),
+				examples: Some(indoc!("
+					Notify everyone in @oncall about outages:
+					/team-keywords add `role:` @oncall `keyword:` outage
+
+					Stop being notified about a team keyword:
+					/team-keywords leave `keyword:` outage",
+				)),
+				options: vec![
+					{
+						let mut opt = Option::default();
+						opt
+							.name("add")
+							.description("Notify every member of a role about a keyword")
+							.kind(CommandOptionType::SubCommand)
+							.create_sub_option(|sub| {
+								sub
+									.name("role")
+									.description("The role to notify")
+									.kind(CommandOptionType::Role)
+									.required(true)
+							})
+							.create_sub_option(|sub| {
+								sub
+									.name("keyword")
+									.description("The keyword to notify them about")
+									.kind(CommandOptionType::String)
+									.required(true)
+							});
+						opt
+					},
+					{
+						let mut opt = Option::default();
+						opt
+							.name("remove")
+							.description("Remove a role's team keyword")
+							.kind(CommandOptionType::SubCommand)
+							.create_sub_option(|sub| {
+								sub
+									.name("role")
+									.description("The role of the team keyword")
+									.kind(CommandOptionType::Role)
+									.required(true)
+							})
+							.create_sub_option(|sub| {
+								sub
+									.name("keyword")
+									.description("The team keyword to remove")
+									.kind(CommandOptionType::String)
+									.required(true)
+							});
+						opt
+					},
+					{
+						let mut opt = Option::default();
+						opt
+							.name("list")
+							.description("List this server's team keywords")
+							.kind(CommandOptionType::SubCommand);
+						opt
+					},
+					{
+						let mut opt = Option::default();
+						opt
+							.name("leave")
+							.description("Stop being notified about one of your team keywords")
+							.kind(CommandOptionType::SubCommand)
+							.create_sub_option(|sub| {
+								sub
+									.name("keyword")
+									.description("The team keyword to leave")
+									.kind(CommandOptionType::String)
+									.required(true)
+							});
+						opt
+					},
+					{
+						let mut opt = Option::default();
+						opt
+							.name("rejoin")
+							.description("Be notified about a team keyword you left again")
+							.kind(CommandOptionType::SubCommand)
+							.create_sub_option(|sub| {
+								sub
+									.name("keyword")
+									.description("The team keyword to rejoin")
+									.kind(CommandOptionType::String)
+									.required(true)
+							});
+						opt
+					},
+				],
+			},
+			CommandInfo {
+				name: "keyword-pack",
+				short_desc: "Publish keyword packs members can subscribe to",
+				long_desc: indoc!("
+					Use `/keyword-pack add [name] [keywords]` to add keywords, separated by \
+					commas, to a pack, creating it if it doesn't exist yet. Members can \
+					subscribe to the pack with `/subscribe` to be notified of all of its \
+					keywords, including any added later.
+
+					Use `/keyword-pack remove [name] [keywords]` to remove keywords from a \
+					pack, and `/keyword-pack delete [name]` to delete a pack entirely.
+
+					Use `/keyword-pack list` to see this server's packs.
+
+					You need the Manage Server permission to change packs.",
+				),
+				examples: Some(indoc!("
+					Publish a pack of frontend keywords:
+					/keyword-pack add `name:` frontend `keywords:` react, vite, css
+
+					Delete the pack:
+					/keyword-pack delete `name:` frontend",
+				)),
+				options: vec![
+					{
+						let mut opt = Option::default();
+						opt
+							.name("add")
+							.description("Add keywords to a pack, creating it if needed")
+							.kind(CommandOptionType::SubCommand)
+							.create_sub_option(|sub| {
+								sub
+									.name("name")
+									.description("The name of the pack")
+									.kind(CommandOptionType::String)
+									.required(true)
+							})
+							.create_sub_option(|sub| {
+								sub
+									.name("keywords")
+									.description("The keywords to add, separated by commas")
+									.kind(CommandOptionType::String)
+									.required(true)
+							});
+						opt
+					},
+					{
+						let mut opt = Option::default();
+						opt
+							.name("remove")
+							.description("Remove keywords from a pack")
+							.kind(CommandOptionType::SubCommand)
+							.create_sub_option(|sub| {
+								sub
+									.name("name")
+									.description("The name of the pack")
+									.kind(CommandOptionType::String)
+									.required(true)
+							})
+							.create_sub_option(|sub| {
+								sub
+									.name("keywords")
+									.description("The keywords to remove, separated by commas")
+									.kind(CommandOptionType::String)
+									.required(true)
+							});
+						opt
+					},
+					{
+						let mut opt = Option::default();
+						opt
+							.name("delete")
+							.description("Delete a pack and all subscriptions to it")
+							.kind(CommandOptionType::SubCommand)
+							.create_sub_option(|sub| {
+								sub
+									.name("name")
+									.description("The name of the pack")
+									.kind(CommandOptionType::String)
+									.required(true)
+							});
+						opt
+					},
+					{
+						let mut opt = Option::default();
+						opt
+							.name("list")
+							.description("List this server's keyword packs")
+							.kind(CommandOptionType::SubCommand);
+						opt
+					},
+				],
+			},
+			CommandInfo {
+				name: "subscribe",
+				short_desc: "Subscribe to a keyword pack in this server",
+				long_desc: indoc!("
+					Use `/subscribe [pack]` to be notified of all keywords in one of the packs \
+					this server's admins published. When the pack changes, you'll be notified \
+					of its new keywords without doing anything.
+
+					Pack keywords are listed in `/keywords`, and don't count toward your \
+					keyword limit unless the bot's host chose otherwise. Use `/keyword-pack list` \
+					to see the available packs.",
+				),
+				examples: Some(indoc!("
+					Subscribe to the frontend pack:
+					/subscribe `pack:` frontend",
+				)),
+				options: vec![{
+					let mut opt = Option::default();
+					opt
+						.name("pack")
+						.description("The pack to subscribe to")
+						.kind(CommandOptionType::String)
+						.required(true)
+						.set_autocomplete(true);
+					opt
+				}],
+			},
+			CommandInfo {
+				name: "unsubscribe",
+				short_desc: "Unsubscribe from a keyword pack in this server",
+				long_desc: indoc!("
+					Use `/unsubscribe [pack]` to stop being notified of the keywords in a \
+					pack you subscribed to.",
+				),
+				examples: Some(indoc!("
+					Unsubscribe from the frontend pack:
+					/unsubscribe `pack:` frontend",
+				)),
+				options: vec![{
+					let mut opt = Option::default();
+					opt
+						.name("pack")
+						.description("The pack to unsubscribe from")
+						.kind(CommandOptionType::String)
+						.required(true)
+						.set_autocomplete(true);
+					opt
+				}],
+			},
+			CommandInfo {
+				name: "export",
+				short_desc: "Export your keywords, ignores, mutes, and blocks as a file",
+				long_desc: indoc!("
+					Use `/export` to get a JSON file with all of your keywords, channel \
+					keywords, the channels and authors your keywords are limited to, \
+					ignored phrases, muted channels, and blocked users.
+
+					Keep it as a backup, for example before using `/opt-out`, or use \
+					`/import` to load it into another instance of this bot.",
 				),
 				examples: None,
 				options: vec![],
 			},
 			CommandInfo {
-				name: "remove-server",
-				short_desc: "Remove all keywords and ignores on a given server",
+				name: "import",
+				short_desc: "Import keywords, ignores, mutes, and blocks from a file",
 				long_desc: indoc!("
-					Use `/remove-server [server ID]` to remove all keywords **and** ignores on \
-					the server with the given ID.
+					Use `/import [file]` with a file created by `/export` to add its \
+					keywords, ignored phrases, muted channels, and blocked users to yours. \
+					You'll see a preview of the changes before anything is imported.
 
-					This won't remove channel-specific keywords in the given server; you can use the \
-					normal `remove` command for that.
+					Use `/import [file] replace:True` to also remove anything you have that \
+					isn't in the file.
 
-					This is normally not necessary, but if you no longer share a server with the bot \
-					where you added keywords, you can clean up your keywords list by using `keywords` \
-					in DMs to see all keywords, and this command to remove any server IDs the bot \
-					can't find. ",
+					Keywords a server doesn't allow are skipped, as are keywords that would \
+					go over your or the server's keyword limit, and anything in servers \
+					you're not in or channels you can't read.",
 				),
 				examples: Some(indoc!("
-					Remove all server-wide keywords and ignores added to the server with an ID of \
-					126029834632:
-					/remove-server `server:` 126029834632",
+					Import a backup:
+					/import `file:` highlights.json
+
+					Make your settings exactly match a backup:
+					/import `file:` highlights.json `replace:` True",
 				)),
 				options: vec![
 					{
 						let mut opt = Option::default();
 						opt
-							.name("server")
-							.description("The ID of the server to remove")
-							.kind(CommandOptionType::String)
+							.name("file")
+							.description("A file created with /export")
+							.kind(CommandOptionType::Attachment)
 							.required(true);
 						opt
+					},
+					{
+						let mut opt = Option::default();
+						opt
+							.name("replace")
+							.description("Remove anything you have that isn't in the file")
+							.kind(CommandOptionType::Boolean);
+						opt
 					}
 				],
 			},
@@ -751,12 +2493,8 @@ static COMMAND_INFO: Lazy<[CommandInfo; 18], fn() -> [CommandInfo; 18]> =
 			let mut opt = Option::default();
 			opt.name("command")
 				.description("Command to view help for")
-				.kind(CommandOptionType::String);
-
-			for command in &commands {
-				opt.add_string_choice(command.name, command.name);
-			}
-
+				.kind(CommandOptionType::String)
+				.set_autocomplete(true);
 			opt
 		};
 
@@ -764,3 +2502,54 @@ static COMMAND_INFO: Lazy<[CommandInfo; 18], fn() -> [CommandInfo; 18]> =
 
 		commands
 	});
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn commands_translated() {
+		for locale in &locales()[1..] {
+			for info in COMMAND_INFO.iter() {
+				let description = locale.try_get(&info.key());
+				assert!(
+					description.is_some(),
+					"{} has no description in {}",
+					info.name,
+					locale.tag,
+				);
+				assert!(
+					description.unwrap().chars().count() <= 100,
+					"{} description in {} is too long",
+					info.name,
+					locale.tag,
+				);
+				assert!(
+					locale.try_get(&format!("help-{}", info.name)).is_some(),
+					"{} has no help in {}",
+					info.name,
+					locale.tag,
+				);
+				assert_eq!(
+					locale
+						.try_get(&format!("help-examples-{}", info.name))
+						.is_some(),
+					info.examples.is_some(),
+					"{} examples don't match in {}",
+					info.name,
+					locale.tag,
+				);
+			}
+
+			for info in &CONTEXT_MENU_INFO {
+				let name = locale.try_get(&format!("command-{}", info.name));
+				assert!(
+					name.is_some_and(|name| name.chars().count() <= 32),
+					"{} has no name of at most 32 characters in {}",
+					info.name,
+					locale.tag,
+				);
+			}
+		}
+	}
+}
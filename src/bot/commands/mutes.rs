@@ -3,16 +3,21 @@
 
 //! Commands for adding, removing, and listing channel mutes.
 
-use std::{collections::HashMap, fmt::Write};
-
 use anyhow::{Context as _, Result};
 use serenity::{
 	client::Context,
-	model::application::interaction::application_command::ApplicationCommandInteraction as Command,
+	model::{
+		application::interaction::application_command::ApplicationCommandInteraction as Command,
+		mention::Mentionable,
+	},
 };
 
+use super::pages::{location, send_list, ListEntry, ListOptions};
 use crate::{
-	bot::util::{respond_eph, user_can_read_channel},
+	bot::{
+		i18n::locale,
+		util::{respond_eph, user_can_read_channel},
+	},
 	db::Mute,
 };
 
@@ -22,6 +27,7 @@ use crate::{
 pub(crate) async fn mute(ctx: Context, mut command: Command) -> Result<()> {
 	check_opt_out!(ctx, command);
 	let guild_id = require_guild!(&ctx, &command);
+	let locale = locale(&command.locale);
 
 	let channel_id = command
 		.data
@@ -32,13 +38,18 @@ pub(crate) async fn mute(ctx: Context, mut command: Command) -> Result<()> {
 		.map(|(id, _)| id)
 		.context("No channel to mute provided")?;
 
-	let channel = ctx
+	let guild_channel = ctx
 		.cache
 		.guild_channel(channel_id)
 		.context("Failed to get guild channel to mute")?;
-
-	match user_can_read_channel(&ctx, &channel, ctx.cache.current_user_id())
-		.await
+	let channel = guild_channel.mention();
+
+	match user_can_read_channel(
+		&ctx,
+		&guild_channel,
+		ctx.cache.current_user_id(),
+	)
+	.await
 	{
 		Ok(Some(true)) => {
 			let mute = Mute {
@@ -47,29 +58,20 @@ pub(crate) async fn mute(ctx: Context, mut command: Command) -> Result<()> {
 			};
 
 			if mute.clone().exists().await? {
-				respond_eph(
-					&ctx,
-					&command,
-					format!("❌ You've already muted <#{}>!", channel_id),
-				)
-				.await
+				let response =
+					locale.format("mute-exists", &[("channel", &channel)]);
+				respond_eph(&ctx, &command, response).await
 			} else {
 				mute.insert().await?;
-				respond_eph(
-					&ctx,
-					&command,
-					format!("✅ Muted <#{}>", channel_id),
-				)
-				.await
+				let response =
+					locale.format("mute-added", &[("channel", &channel)]);
+				respond_eph(&ctx, &command, response).await
 			}
 		}
 		Ok(Some(false)) => {
-			respond_eph(
-				&ctx,
-				&command,
-				format!("❌ I can't read <#{}>!", channel_id),
-			)
-			.await
+			let response =
+				locale.format("error-cannot-read", &[("channel", &channel)]);
+			respond_eph(&ctx, &command, response).await
 		}
 		Ok(None) => Err(anyhow::anyhow!(
 			"Self permissions not found in channel {} in guild {}",
@@ -87,6 +89,7 @@ pub(crate) async fn mute(ctx: Context, mut command: Command) -> Result<()> {
 /// Usage: `/unmute <channel>`
 pub(crate) async fn unmute(ctx: Context, mut command: Command) -> Result<()> {
 	check_opt_out!(ctx, command);
+	let locale = locale(&command.locale);
 
 	let channel_id = command
 		.data
@@ -102,17 +105,15 @@ pub(crate) async fn unmute(ctx: Context, mut command: Command) -> Result<()> {
 		channel_id,
 	};
 
+	let channel = channel_id.mention();
+
 	if !mute.clone().exists().await? {
-		respond_eph(
-			&ctx,
-			&command,
-			format!("❌ You haven't muted <#{}>!", channel_id),
-		)
-		.await
+		let response = locale.format("mute-missing", &[("channel", &channel)]);
+		respond_eph(&ctx, &command, response).await
 	} else {
 		mute.delete().await?;
-		respond_eph(&ctx, &command, format!("✅ Unmuted <#{}>", channel_id))
-			.await
+		let response = locale.format("mute-removed", &[("channel", &channel)]);
+		respond_eph(&ctx, &command, response).await
 	}
 }
 
@@ -121,104 +122,47 @@ pub(crate) async fn unmute(ctx: Context, mut command: Command) -> Result<()> {
 /// Usage: `/mutes`
 pub(crate) async fn mutes(ctx: Context, command: Command) -> Result<()> {
 	check_opt_out!(ctx, command);
-	match command.guild_id {
-		Some(guild_id) => {
-			let channels = ctx
-				.cache
-				.guild_channels(guild_id)
-				.context("Couldn't get guild channels to list mutes")?;
-
-			let mutes = Mute::user_mutes(command.user.id)
-				.await?
-				.into_iter()
-				.filter(|mute| channels.contains_key(&mute.channel_id))
-				.map(|mute| format!("<#{}>", mute.channel_id))
-				.collect::<Vec<_>>();
-
-			if mutes.is_empty() {
-				return respond_eph(
-					&ctx,
-					&command,
-					"❌ You haven't muted any channels!",
-				)
-				.await;
-			}
-
-			let guild_name = ctx
-				.cache
-				.guild_field(guild_id, |g| g.name.clone())
-				.context("Couldn't get guild to list mutes")?;
-
-			let response = format!(
-				"Your muted channels in {}:\n  - {}",
-				guild_name,
-				mutes.join("\n  - ")
-			);
-
-			respond_eph(&ctx, &command, response).await
-		}
-		None => {
-			let mutes = Mute::user_mutes(command.user.id).await?;
-
-			if mutes.is_empty() {
-				return respond_eph(
-					&ctx,
-					&command,
-					"❌ You haven't muted any channels!",
-				)
-				.await;
-			}
-
-			let mut mutes_by_guild = HashMap::new();
-			let mut not_found = Vec::new();
-
-			for mute in mutes {
-				let channel = match ctx.cache.guild_channel(mute.channel_id) {
-					Some(channel) => channel,
-					None => {
-						not_found
-							.push(format!("<#{0}> ({0})", mute.channel_id));
-						continue;
-					}
-				};
-
-				mutes_by_guild
-					.entry(channel.guild_id)
-					.or_insert_with(Vec::new)
-					.push(format!("<#{}>", mute.channel_id));
-			}
+	let locale = locale(&command.locale);
 
-			let mut response = String::new();
-
-			for (guild_id, channel_ids) in mutes_by_guild {
-				if !response.is_empty() {
-					response.push_str("\n\n");
-				}
-
-				let guild_name = ctx
-					.cache
-					.guild_field(guild_id, |g| g.name.clone())
-					.context("Couldn't get guild to list mutes")?;
-
-				write!(
-					&mut response,
-					"Your muted channels in {}:\n  – {}",
-					guild_name,
-					channel_ids.join("\n  – ")
-				)
-				.unwrap();
-			}
+	let options = match ListOptions::from_command(&command) {
+		Ok(options) => options,
+		Err(message) => return respond_eph(&ctx, &command, message).await,
+	};
 
-			if !not_found.is_empty() {
-				write!(
-					&mut response,
-					"\n\nCouldn't find (deleted?) muted channels:\n  – {}",
-					not_found.join("\n  – ")
-				)
-				.unwrap();
+	let entries = Mute::user_mutes(command.user.id)
+		.await?
+		.into_iter()
+		.map(|mute| {
+			let guild_id = ctx
+				.cache
+				.guild_channel_field(mute.channel_id, |c| c.guild_id);
+
+			match guild_id {
+				Some(guild_id) => ListEntry {
+					text: format!("<#{}>", mute.channel_id),
+					group: options.guild_id.is_none().then(|| {
+						location(&ctx, &options, Some(guild_id), None)
+					}),
+					guild_id: Some(guild_id),
+					channel_id: Some(mute.channel_id),
+				},
+				None => ListEntry {
+					text: format!("<#{0}> ({0})", mute.channel_id),
+					group: Some(locale.get("list-unknown").to_owned()),
+					guild_id: None,
+					channel_id: Some(mute.channel_id),
+				},
 			}
-
-			respond_eph(&ctx, &command, response).await
-		}
-	}
+		})
+		.collect();
+
+	send_list(
+		&ctx,
+		&command,
+		locale.get("mutes-title"),
+		entries,
+		&options,
+		locale.get("mutes-empty"),
+	)
+	.await
 }
@@ -7,7 +7,6 @@ use std::time::Duration;
 
 use anyhow::Result;
 use futures_util::StreamExt;
-use indoc::indoc;
 use rand::{distributions::Standard, Rng};
 use serenity::{
 	client::Context,
@@ -22,7 +21,11 @@ use serenity::{
 };
 
 use crate::{
-	bot::util::{respond_eph, success},
+	bot::{
+		i18n::locale,
+		stats,
+		util::{respond_eph, success},
+	},
 	db::OptOut,
 };
 
@@ -38,26 +41,15 @@ use crate::{
 	)
 )]
 pub(crate) async fn opt_out(ctx: Context, command: Command) -> Result<()> {
+	let locale = locale(&command.locale);
 	let opt_out = OptOut {
 		user_id: command.user.id,
 	};
 
 	if opt_out.exists().await? {
-		return respond_eph(&ctx, &command, "❌ You already opted out!").await;
+		return respond_eph(&ctx, &command, locale.get("opt-out-exists")).await;
 	}
 
-	const OPT_OUT_WARNING: &str = indoc!(
-		"
-		⚠️ Are you sure you want to opt out?
-
-		All of your keywords, muted channels, blocked users, and ignored phrases \
-		will be lost forever.
-
-		You will no longer be able to receive notifications.
-
-		Others will not receive notifications about your messages."
-	);
-
 	let nonce = rand::thread_rng()
 		.sample_iter::<char, _>(Standard)
 		.take(90)
@@ -70,17 +62,17 @@ pub(crate) async fn opt_out(ctx: Context, command: Command) -> Result<()> {
 		.create_interaction_response(&ctx, |r| {
 			r.interaction_response_data(|m| {
 				m.flags(MessageFlags::EPHEMERAL)
-					.content(OPT_OUT_WARNING)
+					.content(locale.get("opt-out-warning"))
 					.components(|c| {
 						c.create_action_row(|row| {
 							row.create_button(|b| {
 								b.style(ButtonStyle::Danger)
-									.label("Opt out")
+									.label(locale.get("opt-out-confirm"))
 									.custom_id(&confirm_id)
 							})
 							.create_button(|b| {
 								b.style(ButtonStyle::Secondary)
-									.label("Cancel")
+									.label(locale.get("opt-out-cancel"))
 									.custom_id(&cancel_id)
 							})
 						})
@@ -109,7 +101,7 @@ pub(crate) async fn opt_out(ctx: Context, command: Command) -> Result<()> {
 		None => {
 			command
 				.edit_original_interaction_response(&ctx, |r| {
-					r.content("Timed out.").components(|c| c)
+					r.content(locale.get("opt-out-timeout")).components(|c| c)
 				})
 				.await?;
 		}
@@ -118,19 +110,22 @@ pub(crate) async fn opt_out(ctx: Context, command: Command) -> Result<()> {
 				let opt_out = OptOut {
 					user_id: press.user.id,
 				};
-				opt_out.clone().delete_user_data().await?;
+				stats::forget_user(
+					opt_out.user_id,
+					opt_out.clone().delete_user_data(),
+				)
+				.await?;
 				opt_out.insert().await?;
 				command
 					.edit_original_interaction_response(&ctx, |r| {
-						r.content("✅ You have been opted out")
-							.components(|c| c)
+						r.content(locale.get("opt-out-done")).components(|c| c)
 					})
 					.await?;
 			}
 			id if id == cancel_id => {
 				command
 					.edit_original_interaction_response(&ctx, |r| {
-						r.content("✅ You have not been opted out")
+						r.content(locale.get("opt-out-cancelled"))
 							.components(|c| c)
 					})
 					.await?;
@@ -159,12 +154,13 @@ pub(crate) async fn opt_out(ctx: Context, command: Command) -> Result<()> {
 	)
 )]
 pub(crate) async fn opt_in(ctx: Context, command: Command) -> Result<()> {
+	let locale = locale(&command.locale);
 	let opt_out = OptOut {
 		user_id: command.user.id,
 	};
 
 	if !opt_out.clone().exists().await? {
-		return respond_eph(&ctx, &command, "❌ You haven't opted out!").await;
+		return respond_eph(&ctx, &command, locale.get("opt-in-missing")).await;
 	}
 
 	opt_out.delete().await?;
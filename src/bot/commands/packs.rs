@@ -0,0 +1,411 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! Commands for keyword packs, which server admins publish for members to
+//! subscribe to.
+
+use std::fmt::Write as _;
+
+use anyhow::{bail, Context as _, Result};
+use serenity::{
+	client::Context,
+	model::{
+		application::interaction::application_command::ApplicationCommandInteraction as Command,
+		id::{GuildId, UserId},
+	},
+};
+
+use super::{
+	bulk::parse_list, keywords::KeywordRules, server_settings::record_change,
+	util::short_list,
+};
+use crate::{
+	bot::{
+		i18n::{locale, Locale},
+		util::respond_eph,
+	},
+	db::{Keyword, KeywordPack, PackSubscription},
+	settings::settings,
+};
+
+/// Maximum length of a pack's name.
+const MAX_NAME_LENGTH: usize = 32;
+
+/// Add keywords to, remove keywords from, delete, or list keyword packs.
+///
+/// Usage:
+/// - `/keyword-pack add <name> <keywords>`
+/// - `/keyword-pack remove <name> <keywords>`
+/// - `/keyword-pack delete <name>`
+/// - `/keyword-pack list`
+#[tracing::instrument(
+	skip_all,
+	fields(
+		user_id = %command.user.id,
+		channel_id = %command.channel_id,
+		command = %command.data.name,
+	)
+)]
+pub(crate) async fn keyword_pack(ctx: Context, command: Command) -> Result<()> {
+	let guild_id = require_guild!(&ctx, &command);
+	let locale = locale(&command.locale);
+
+	let subcommand = command
+		.data
+		.options
+		.first()
+		.context("No keyword pack subcommand provided")?;
+
+	let option = |name: &str| {
+		subcommand
+			.options
+			.iter()
+			.find(|o| o.name == name)
+			.and_then(|o| o.value.as_ref())
+			.and_then(|v| v.as_str())
+	};
+
+	let name = option("name").map(|name| name.trim().to_lowercase());
+	let keywords = option("keywords").map(parse_list).unwrap_or_default();
+
+	let response = match subcommand.name.as_str() {
+		"list" => list(guild_id, command.user.id, locale).await?,
+		"add" | "remove" | "delete" => {
+			require_manage_guild!(&ctx, &command);
+
+			let name = name.context("No pack name provided")?;
+
+			let user_id = command.user.id;
+
+			match subcommand.name.as_str() {
+				"add" => add(guild_id, user_id, name, keywords, locale).await?,
+				"remove" => {
+					remove(guild_id, user_id, name, keywords, locale).await?
+				}
+				_ => delete(guild_id, user_id, name, locale).await?,
+			}
+		}
+		other => bail!("Unknown keyword pack subcommand {}", other),
+	};
+
+	respond_eph(&ctx, &command, response).await
+}
+
+/// Adds keywords to a pack, creating it if necessary.
+///
+/// Keywords that members couldn't add themselves in the guild are skipped.
+async fn add(
+	guild_id: GuildId,
+	user_id: UserId,
+	name: String,
+	keywords: Vec<String>,
+	locale: &Locale,
+) -> Result<String> {
+	if name.is_empty() || name.chars().count() > MAX_NAME_LENGTH {
+		return Ok(
+			locale.format("pack-name-length", &[("max", &MAX_NAME_LENGTH)])
+		);
+	}
+
+	let existing = KeywordPack::pack(guild_id, &name)
+		.await?
+		.map_or_else(Vec::new, |pack| pack.keywords);
+
+	let rules = KeywordRules::new(guild_id).await?;
+	let max_keywords = settings().behavior.max_keywords as usize;
+	let mut count = existing.len();
+	let mut added = Vec::new();
+	let mut skipped = Vec::new();
+
+	for keyword in keywords {
+		if rules.check(&keyword, locale).is_some()
+			|| existing.contains(&keyword)
+			|| count >= max_keywords
+		{
+			skipped.push(keyword);
+			continue;
+		}
+
+		KeywordPack::add_keyword(guild_id, &name, &keyword).await?;
+		added.push(keyword);
+		count += 1;
+	}
+
+	let mut response = if added.is_empty() {
+		locale.format("pack-nothing-added", &[("pack", &name)])
+	} else {
+		let description = format!(
+			"added {} to the keyword pack \"{name}\"",
+			short_list(&added)
+		);
+		record_change(guild_id, user_id, &description).await?;
+
+		locale.format(
+			"pack-added",
+			&[("keywords", &short_list(&added)), ("pack", &name)],
+		)
+	};
+
+	if !skipped.is_empty() {
+		write!(
+			response,
+			"\n{}",
+			locale.format(
+				"pack-skipped",
+				&[("max", &max_keywords), ("keywords", &short_list(&skipped)),],
+			)
+		)
+		.unwrap();
+	}
+
+	Ok(response)
+}
+
+/// Removes keywords from a pack, deleting it if none are left.
+async fn remove(
+	guild_id: GuildId,
+	user_id: UserId,
+	name: String,
+	keywords: Vec<String>,
+	locale: &Locale,
+) -> Result<String> {
+	let pack = match KeywordPack::pack(guild_id, &name).await? {
+		Some(pack) => pack,
+		None => return Ok(locale.format("pack-missing", &[("pack", &name)])),
+	};
+
+	let mut removed = Vec::new();
+
+	for keyword in keywords {
+		if KeywordPack::remove_keyword(guild_id, &name, &keyword).await? {
+			removed.push(keyword);
+		}
+	}
+
+	if removed.is_empty() {
+		return Ok(locale.format("pack-nothing-removed", &[("pack", &name)]));
+	}
+
+	let description = format!(
+		"removed {} from the keyword pack \"{name}\"",
+		short_list(&removed)
+	);
+	record_change(guild_id, user_id, &description).await?;
+
+	if removed.len() == pack.keywords.len() {
+		pack.delete().await?;
+
+		return Ok(locale.format("pack-emptied", &[("pack", &name)]));
+	}
+
+	Ok(locale.format(
+		"pack-removed",
+		&[("keywords", &short_list(&removed)), ("pack", &name)],
+	))
+}
+
+/// Deletes a pack and all subscriptions to it.
+async fn delete(
+	guild_id: GuildId,
+	user_id: UserId,
+	name: String,
+	locale: &Locale,
+) -> Result<String> {
+	let pack = match KeywordPack::pack(guild_id, &name).await? {
+		Some(pack) => pack,
+		None => return Ok(locale.format("pack-missing", &[("pack", &name)])),
+	};
+
+	pack.delete().await?;
+
+	let description = format!("deleted the keyword pack \"{name}\"");
+	record_change(guild_id, user_id, &description).await?;
+
+	Ok(locale.format("pack-deleted", &[("pack", &name)]))
+}
+
+/// Lists the packs of a guild, marking those the user subscribed to.
+async fn list(
+	guild_id: GuildId,
+	user_id: UserId,
+	locale: &Locale,
+) -> Result<String> {
+	let packs = KeywordPack::guild_packs(guild_id).await?;
+
+	if packs.is_empty() {
+		return Ok(locale.get("packs-empty").to_owned());
+	}
+
+	let subscriptions = PackSubscription::user_subscriptions(user_id).await?;
+
+	let mut response = locale.get("packs-title").to_owned();
+
+	for pack in packs {
+		let subscribed = subscriptions.iter().any(|subscription| {
+			subscription.guild_id == guild_id && subscription.pack == pack.name
+		});
+
+		write!(
+			response,
+			"\n**{}**{}: {}",
+			pack.name,
+			if subscribed {
+				locale.get("packs-subscribed")
+			} else {
+				""
+			},
+			short_list(&pack.keywords)
+		)
+		.unwrap();
+	}
+
+	Ok(response)
+}
+
+/// Gets the pack option of `/subscribe` or `/unsubscribe`.
+fn pack_option(command: &Command) -> Result<String> {
+	Ok(command
+		.data
+		.options
+		.iter()
+		.find(|o| o.name == "pack")
+		.and_then(|o| o.value.as_ref())
+		.context("No pack provided")?
+		.as_str()
+		.context("Pack provided was not a string")?
+		.trim()
+		.to_lowercase())
+}
+
+/// Subscribe to a keyword pack in the current guild.
+///
+/// Usage: `/subscribe <pack>`
+#[tracing::instrument(
+	skip_all,
+	fields(
+		user_id = %command.user.id,
+		channel_id = %command.channel_id,
+		command = %command.data.name,
+	)
+)]
+pub(crate) async fn subscribe(ctx: Context, command: Command) -> Result<()> {
+	check_opt_out!(ctx, command);
+	let guild_id = require_guild!(&ctx, &command);
+	let user_id = command.user.id;
+	let locale = locale(&command.locale);
+	let name = pack_option(&command)?;
+
+	let pack = match KeywordPack::pack(guild_id, &name).await? {
+		Some(pack) => pack,
+		None => {
+			return respond_eph(
+				&ctx,
+				&command,
+				locale.format("pack-missing", &[("pack", &name)]),
+			)
+			.await
+		}
+	};
+
+	let subscription = PackSubscription {
+		user_id,
+		guild_id,
+		pack: name,
+	};
+
+	if subscription.clone().exists().await? {
+		return respond_eph(
+			&ctx,
+			&command,
+			locale.get("pack-already-subscribed"),
+		)
+		.await;
+	}
+
+	if settings().behavior.pack_keywords_limited {
+		let max_keywords = settings().behavior.max_keywords as u64;
+		let count = Keyword::user_keyword_count(user_id).await?
+			+ PackSubscription::user_pack_keyword_count(user_id).await?;
+
+		if count + pack.keywords.len() as u64 > max_keywords {
+			return respond_eph(
+				&ctx,
+				&command,
+				locale.format("pack-over-limit", &[("max", &max_keywords)]),
+			)
+			.await;
+		}
+	}
+
+	subscription.insert().await?;
+
+	respond_eph(
+		&ctx,
+		&command,
+		locale.format(
+			"pack-subscribed",
+			&[
+				("pack", &pack.name),
+				("keywords", &short_list(&pack.keywords)),
+			],
+		),
+	)
+	.await
+}
+
+/// Unsubscribe from a keyword pack in the current guild.
+///
+/// Usage: `/unsubscribe <pack>`
+#[tracing::instrument(
+	skip_all,
+	fields(
+		user_id = %command.user.id,
+		channel_id = %command.channel_id,
+		command = %command.data.name,
+	)
+)]
+pub(crate) async fn unsubscribe(ctx: Context, command: Command) -> Result<()> {
+	check_opt_out!(ctx, command);
+	let guild_id = require_guild!(&ctx, &command);
+	let locale = locale(&command.locale);
+
+	let subscription = PackSubscription {
+		user_id: command.user.id,
+		guild_id,
+		pack: pack_option(&command)?,
+	};
+
+	if !subscription.clone().exists().await? {
+		return respond_eph(&ctx, &command, locale.get("pack-not-subscribed"))
+			.await;
+	}
+
+	let response =
+		locale.format("pack-unsubscribed", &[("pack", &subscription.pack)]);
+	subscription.delete().await?;
+
+	respond_eph(&ctx, &command, response).await
+}
+
+/// Fetches the packs the given user subscribed to, in the given guild or in
+/// all guilds.
+pub(super) async fn user_packs(
+	user_id: UserId,
+	guild_id: Option<GuildId>,
+) -> Result<Vec<KeywordPack>> {
+	let mut packs = Vec::new();
+
+	for subscription in PackSubscription::user_subscriptions(user_id).await? {
+		if guild_id.is_some_and(|id| id != subscription.guild_id) {
+			continue;
+		}
+
+		if let Some(pack) =
+			KeywordPack::pack(subscription.guild_id, &subscription.pack).await?
+		{
+			packs.push(pack);
+		}
+	}
+
+	Ok(packs)
+}
@@ -0,0 +1,369 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! Paginated embeds shared by the commands that list keywords, mutes, ignored
+//! phrases, and blocked users.
+
+use std::{fmt::Write as _, time::Duration};
+
+use anyhow::{bail, Context as _, Result};
+use futures_util::StreamExt;
+use rand::{distributions::Standard, Rng};
+use serenity::{
+	builder::{CreateComponents, CreateEmbed},
+	client::Context,
+	collector::ComponentInteractionCollectorBuilder,
+	model::{
+		application::{
+			component::ButtonStyle,
+			interaction::{
+				application_command::ApplicationCommandInteraction as Command,
+				InteractionResponseType, MessageFlags,
+			},
+		},
+		id::{ChannelId, GuildId},
+		mention::Mentionable,
+	},
+};
+
+use crate::{
+	bot::{
+		i18n::{locale, Locale},
+		util::respond_eph,
+	},
+	global::EMBED_COLOR,
+};
+
+/// Number of entries shown on each page of a list.
+const PAGE_SIZE: usize = 15;
+
+/// Maximum number of characters shown of each entry.
+const MAX_ENTRY_LENGTH: usize = 150;
+
+/// How long the page buttons keep working after a list is shown.
+const BUTTON_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// An entry in a paginated list.
+#[derive(Debug, Clone)]
+pub(super) struct ListEntry {
+	/// The text shown for this entry.
+	pub(super) text: String,
+	/// The heading this entry is grouped under, if any.
+	pub(super) group: Option<String>,
+	/// The guild this entry applies to, if known.
+	pub(super) guild_id: Option<GuildId>,
+	/// The channel this entry applies to, if it only applies to one.
+	pub(super) channel_id: Option<ChannelId>,
+}
+
+/// How the entries of a list are ordered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum ListSort {
+	/// Grouped by server and channel, then alphabetically.
+	Location,
+	/// Alphabetically, ignoring server and channel.
+	Name,
+}
+
+/// Which entries of a list are shown, and how they're ordered.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct ListOptions {
+	/// Only show entries in this guild.
+	pub(super) guild_id: Option<GuildId>,
+	/// Only show entries specific to this channel.
+	pub(super) channel_id: Option<ChannelId>,
+	/// How to order the entries.
+	pub(super) sort: ListSort,
+	/// The locale entries are described in.
+	pub(super) locale: &'static Locale,
+}
+
+impl ListOptions {
+	/// Reads the `server`, `channel`, and `sort` options and the locale of a
+	/// list command.
+	///
+	/// Lists only show entries in the current server unless another server is
+	/// given. Returns an error message to show the user if any option is
+	/// invalid.
+	pub(super) fn from_command(command: &Command) -> Result<Self, String> {
+		let option = |name: &str| {
+			command
+				.data
+				.options
+				.iter()
+				.find(|o| o.name == name)
+				.and_then(|o| o.value.as_ref())
+				.and_then(|v| v.as_str())
+				.map(str::trim)
+		};
+
+		let locale = locale(&command.locale);
+
+		let guild_id =
+			match option("server") {
+				Some(id) => Some(id.parse().map(GuildId).map_err(|_| {
+					locale.get("error-invalid-server").to_owned()
+				})?),
+				None => command.guild_id,
+			};
+
+		let channel_id = option("channel")
+			.and_then(|id| id.parse().ok())
+			.map(ChannelId);
+
+		let sort = match option("sort") {
+			Some("name") => ListSort::Name,
+			_ => ListSort::Location,
+		};
+
+		Ok(Self {
+			guild_id,
+			channel_id,
+			sort,
+			locale,
+		})
+	}
+
+	/// Whether the given entry should be shown.
+	fn shows(&self, entry: &ListEntry) -> bool {
+		self.guild_id.is_none_or(|id| entry.guild_id == Some(id))
+			&& self
+				.channel_id
+				.is_none_or(|id| entry.channel_id == Some(id))
+	}
+}
+
+/// Describes where an entry applies, for use as the heading of a group of
+/// entries.
+///
+/// The name of the guild is left out if the list only shows one guild.
+pub(super) fn location(
+	ctx: &Context,
+	options: &ListOptions,
+	guild_id: Option<GuildId>,
+	channel_id: Option<ChannelId>,
+) -> String {
+	let locale = options.locale;
+
+	let guild = guild_id.map(|guild_id| {
+		ctx.cache
+			.guild_field(guild_id, |g| g.name.clone())
+			.unwrap_or_else(|| {
+				locale.format("list-unknown-server", &[("id", &guild_id)])
+			})
+	});
+
+	match (guild, channel_id) {
+		(Some(_), Some(channel_id)) if options.guild_id.is_some() => locale
+			.format("list-in-channel", &[("channel", &channel_id.mention())]),
+		(Some(guild), Some(channel_id)) => {
+			format!("{guild} › <#{channel_id}>")
+		}
+		(None, Some(channel_id)) => {
+			locale.format("list-unknown-channel", &[("id", &channel_id)])
+		}
+		(Some(_), None) if options.guild_id.is_some() => {
+			locale.get("list-server-wide").to_owned()
+		}
+		(Some(guild), None) => guild,
+		(None, None) => locale.get("list-unknown-location").to_owned(),
+	}
+}
+
+/// Shows the entries matching the given options as pages of an embed, with
+/// buttons to move between pages.
+///
+/// Responds with `empty_message` if no entries match.
+pub(super) async fn send_list(
+	ctx: &Context,
+	command: &Command,
+	title: &str,
+	entries: Vec<ListEntry>,
+	options: &ListOptions,
+	empty_message: &str,
+) -> Result<()> {
+	let mut entries = entries
+		.into_iter()
+		.filter(|entry| options.shows(entry))
+		.collect::<Vec<_>>();
+
+	if entries.is_empty() {
+		return respond_eph(ctx, command, empty_message).await;
+	}
+
+	match options.sort {
+		// server-wide entries come before channel-specific ones in each server
+		ListSort::Location => entries.sort_by(|a, b| {
+			(a.guild_id, a.channel_id.is_some(), &a.group)
+				.cmp(&(b.guild_id, b.channel_id.is_some(), &b.group))
+				.then_with(|| a.text.to_lowercase().cmp(&b.text.to_lowercase()))
+		}),
+		ListSort::Name => {
+			entries.sort_by_key(|entry| entry.text.to_lowercase())
+		}
+	}
+
+	let pages = entries.len().div_ceil(PAGE_SIZE) as u64;
+	let locale = locale(&command.locale);
+
+	let nonce = rand::thread_rng()
+		.sample_iter::<char, _>(Standard)
+		.take(90)
+		.collect::<String>();
+
+	let prev_id = format!("prev{}", nonce);
+	let next_id = format!("next{}", nonce);
+
+	let mut page = 0;
+
+	let embed = list_embed(title, &entries, options.sort, page, pages, locale);
+	let components = page_buttons(&prev_id, &next_id, page, pages, locale);
+
+	command
+		.create_interaction_response(ctx, |r| {
+			r.interaction_response_data(|m| {
+				m.flags(MessageFlags::EPHEMERAL)
+					.set_embed(embed)
+					.set_components(components)
+			})
+		})
+		.await
+		.context("Failed to send list")?;
+
+	if pages <= 1 {
+		return Ok(());
+	}
+
+	let mut presses = ComponentInteractionCollectorBuilder::new(ctx)
+		.author_id(command.user.id)
+		.filter({
+			let prev_id = prev_id.clone();
+			let next_id = next_id.clone();
+			move |interaction| {
+				let id = interaction.data.custom_id.as_str();
+				id == prev_id || id == next_id
+			}
+		})
+		.timeout(BUTTON_TIMEOUT)
+		.build();
+
+	while let Some(press) = presses.next().await {
+		page = match press.data.custom_id.as_str() {
+			id if id == prev_id => page.saturating_sub(1),
+			id if id == next_id => (page + 1).min(pages - 1),
+			other => bail!("Unknown list message component ID {}", other),
+		};
+
+		let embed =
+			list_embed(title, &entries, options.sort, page, pages, locale);
+		let components = page_buttons(&prev_id, &next_id, page, pages, locale);
+
+		press
+			.create_interaction_response(ctx, |r| {
+				r.kind(InteractionResponseType::UpdateMessage)
+					.interaction_response_data(|m| {
+						m.set_embed(embed).set_components(components)
+					})
+			})
+			.await
+			.context("Failed to update list page")?;
+	}
+
+	command
+		.edit_original_interaction_response(ctx, |r| r.components(|c| c))
+		.await
+		.context("Failed to remove list page buttons")?;
+
+	Ok(())
+}
+
+/// Builds an embed showing a page of a list.
+fn list_embed(
+	title: &str,
+	entries: &[ListEntry],
+	sort: ListSort,
+	page: u64,
+	pages: u64,
+	locale: &Locale,
+) -> CreateEmbed {
+	let mut description = String::new();
+	let mut last_group = None;
+
+	for entry in entries
+		.iter()
+		.skip(page as usize * PAGE_SIZE)
+		.take(PAGE_SIZE)
+	{
+		let mut text = entry
+			.text
+			.chars()
+			.take(MAX_ENTRY_LENGTH)
+			.collect::<String>();
+
+		if entry.text.chars().count() > MAX_ENTRY_LENGTH {
+			text.push('…');
+		}
+
+		match (sort, &entry.group) {
+			(ListSort::Location, Some(group)) if last_group != Some(group) => {
+				write!(description, "\n__{group}__\n").unwrap();
+				last_group = Some(group);
+			}
+			(ListSort::Name, Some(group)) => {
+				write!(text, " ({group})").unwrap();
+			}
+			_ => {}
+		}
+
+		writeln!(description, "– {text}").unwrap();
+	}
+
+	let mut embed = CreateEmbed::default();
+
+	embed
+		.title(title)
+		.description(description.trim_start())
+		.footer(|f| {
+			f.text(locale.format(
+				"list-footer",
+				&[
+					("page", &(page + 1)),
+					("pages", &pages.max(1)),
+					("total", &entries.len()),
+				],
+			))
+		})
+		.color(EMBED_COLOR);
+
+	embed
+}
+
+/// Builds buttons to move between pages.
+pub(super) fn page_buttons(
+	prev_id: &str,
+	next_id: &str,
+	page: u64,
+	pages: u64,
+	locale: &Locale,
+) -> CreateComponents {
+	let mut components = CreateComponents::default();
+
+	if pages > 1 {
+		components.create_action_row(|row| {
+			row.create_button(|b| {
+				b.style(ButtonStyle::Secondary)
+					.label(locale.get("list-previous"))
+					.custom_id(prev_id)
+					.disabled(page == 0)
+			})
+			.create_button(|b| {
+				b.style(ButtonStyle::Secondary)
+					.label(locale.get("list-next"))
+					.custom_id(next_id)
+					.disabled(page + 1 >= pages)
+			})
+		});
+	}
+
+	components
+}
@@ -0,0 +1,545 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! Command for server admins to control highlighting in their server.
+
+use std::{
+	fmt::Write as _,
+	time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{bail, Context as _, Result};
+use serenity::{
+	client::Context,
+	model::{
+		application::interaction::application_command::{
+			ApplicationCommandInteraction as Command, CommandDataOption,
+		},
+		id::{ChannelId, GuildId, UserId},
+		mention::Mentionable,
+	},
+};
+
+use crate::{
+	bot::{
+		i18n::{locale, Locale},
+		util::respond_eph,
+	},
+	db::{
+		BannedKeyword, ExcludedChannel, GuildSettings, RevokedMember,
+		SettingsChange,
+	},
+	settings::settings,
+};
+
+/// Number of changes shown by `/server-settings audit`.
+const AUDIT_LENGTH: u64 = 15;
+
+/// View or change how highlighting works in a server.
+///
+/// Usage:
+/// - `/server-settings view`
+/// - `/server-settings exclude <channel>`
+/// - `/server-settings include <channel>`
+/// - `/server-settings keyword-limit [limit]`
+/// - `/server-settings min-length [length]`
+/// - `/server-settings highlighting <enabled>`
+/// - `/server-settings ban-keyword <word>`
+/// - `/server-settings unban-keyword <word>`
+/// - `/server-settings revoke <user>`
+/// - `/server-settings restore <user>`
+/// - `/server-settings audit`
+#[tracing::instrument(
+	skip_all,
+	fields(
+		user_id = %command.user.id,
+		channel_id = %command.channel_id,
+		command = %command.data.name,
+	)
+)]
+pub(crate) async fn server_settings(
+	ctx: Context,
+	command: Command,
+) -> Result<()> {
+	let guild_id = require_guild!(&ctx, &command);
+	require_manage_guild!(&ctx, &command);
+	let user_id = command.user.id;
+	let locale = locale(&command.locale);
+
+	let subcommand = command
+		.data
+		.options
+		.first()
+		.context("No server settings subcommand provided")?;
+
+	let response = match subcommand.name.as_str() {
+		"view" => view(guild_id, locale).await?,
+		"audit" => audit(guild_id, locale).await?,
+		"exclude" | "include" => {
+			let channel_id = command
+				.data
+				.resolved
+				.channels
+				.keys()
+				.next()
+				.copied()
+				.context("No channel provided")?;
+
+			set_excluded(
+				guild_id,
+				user_id,
+				channel_id,
+				subcommand.name == "exclude",
+				locale,
+			)
+			.await?
+		}
+		"keyword-limit" => {
+			let limit = integer_option(subcommand, "limit")?;
+			set_keyword_limit(guild_id, user_id, limit, locale).await?
+		}
+		"min-length" => {
+			let length = integer_option(subcommand, "length")?;
+			set_min_length(guild_id, user_id, length, locale).await?
+		}
+		"highlighting" => {
+			let enabled = subcommand
+				.options
+				.iter()
+				.find(|o| o.name == "enabled")
+				.and_then(|o| o.value.as_ref())
+				.context("No enabled option provided")?
+				.as_bool()
+				.context("Enabled option was not a boolean")?;
+
+			set_enabled(guild_id, user_id, enabled, locale).await?
+		}
+		"revoke" | "restore" => {
+			let member_id = command
+				.data
+				.resolved
+				.users
+				.keys()
+				.next()
+				.copied()
+				.context("No user provided")?;
+
+			set_revoked(
+				guild_id,
+				user_id,
+				member_id,
+				subcommand.name == "revoke",
+				locale,
+			)
+			.await?
+		}
+		"ban-keyword" | "unban-keyword" => {
+			let word = subcommand
+				.options
+				.iter()
+				.find(|o| o.name == "word")
+				.and_then(|o| o.value.as_ref())
+				.context("No word provided")?
+				.as_str()
+				.context("Word provided was not a string")?
+				.trim()
+				.to_lowercase();
+
+			set_banned(
+				guild_id,
+				user_id,
+				word,
+				subcommand.name == "ban-keyword",
+				locale,
+			)
+			.await?
+		}
+		other => bail!("Unknown server settings subcommand {}", other),
+	};
+
+	respond_eph(&ctx, &command, response).await
+}
+
+/// Gets an optional integer option of a subcommand.
+fn integer_option(
+	subcommand: &CommandDataOption,
+	name: &str,
+) -> Result<Option<u64>> {
+	subcommand
+		.options
+		.iter()
+		.find(|o| o.name == name)
+		.and_then(|o| o.value.as_ref())
+		.map(|v| v.as_u64().context("Option provided was not an integer"))
+		.transpose()
+}
+
+/// Describes the current settings of the given guild.
+async fn view(guild_id: GuildId, locale: &Locale) -> Result<String> {
+	let guild_settings = GuildSettings::guild_settings(guild_id).await?;
+	let exclusions = ExcludedChannel::guild_exclusions(guild_id).await?;
+	let bans = BannedKeyword::guild_bans(guild_id).await?;
+	let revocations = RevokedMember::guild_revocations(guild_id).await?;
+
+	let mut response = format!("{}\n", locale.get("settings-view-title"));
+
+	writeln!(
+		response,
+		"{}",
+		if guild_settings.disabled {
+			locale.get("settings-view-highlighting-off")
+		} else {
+			locale.get("settings-view-highlighting-on")
+		}
+	)
+	.unwrap();
+
+	let limit = match guild_settings.max_keywords {
+		Some(max) => {
+			locale.format("settings-view-keyword-limit", &[("max", &max)])
+		}
+		None => locale.format(
+			"settings-view-keyword-limit-default",
+			&[("max", &settings().behavior.max_keywords)],
+		),
+	};
+	writeln!(response, "{limit}").unwrap();
+
+	let min_length = match guild_settings.min_keyword_length {
+		Some(min) => {
+			locale.format("settings-view-min-length", &[("min", &min)])
+		}
+		None => locale.format(
+			"settings-view-min-length-default",
+			&[("min", &settings().behavior.min_keyword_length)],
+		),
+	};
+	writeln!(response, "{min_length}").unwrap();
+
+	let none = locale.get("settings-view-none");
+
+	let channels = if exclusions.is_empty() {
+		none.to_owned()
+	} else {
+		exclusions
+			.iter()
+			.map(|exclusion| format!("<#{}>", exclusion.channel_id))
+			.collect::<Vec<_>>()
+			.join(", ")
+	};
+	writeln!(
+		response,
+		"{}",
+		locale.format("settings-view-excluded", &[("channels", &channels)])
+	)
+	.unwrap();
+
+	let words = if bans.is_empty() {
+		none.to_owned()
+	} else {
+		bans.iter()
+			.map(|ban| format!("\"{}\"", ban.word))
+			.collect::<Vec<_>>()
+			.join(", ")
+	};
+	writeln!(
+		response,
+		"{}",
+		locale.format("settings-view-banned", &[("words", &words)])
+	)
+	.unwrap();
+
+	let members = if revocations.is_empty() {
+		none.to_owned()
+	} else {
+		revocations
+			.iter()
+			.map(|revoked| format!("<@{}>", revoked.user_id))
+			.collect::<Vec<_>>()
+			.join(", ")
+	};
+	write!(
+		response,
+		"{}",
+		locale.format("settings-view-revoked", &[("members", &members)])
+	)
+	.unwrap();
+
+	Ok(response)
+}
+
+/// Lists the most recent changes to the given guild's settings.
+///
+/// Descriptions of changes are shown as they were recorded; see
+/// [`record_change`].
+async fn audit(guild_id: GuildId, locale: &Locale) -> Result<String> {
+	let changes = SettingsChange::guild_changes(guild_id, AUDIT_LENGTH).await?;
+
+	if changes.is_empty() {
+		return Ok(locale.get("settings-audit-empty").to_owned());
+	}
+
+	let mut response = locale.get("settings-audit-title").to_owned();
+
+	for change in changes {
+		let time = change
+			.time
+			.duration_since(UNIX_EPOCH)
+			.unwrap_or_default()
+			.as_secs();
+
+		write!(
+			response,
+			"\n<t:{time}:f> <@{}>: {}",
+			change.user_id, change.description
+		)
+		.unwrap();
+	}
+
+	Ok(response)
+}
+
+/// Excludes the given channel from highlighting, or includes it again.
+async fn set_excluded(
+	guild_id: GuildId,
+	user_id: UserId,
+	channel_id: ChannelId,
+	exclude: bool,
+	locale: &Locale,
+) -> Result<String> {
+	let exclusion = ExcludedChannel {
+		guild_id,
+		channel_id,
+	};
+	let excluded = exclusion.clone().exists().await?;
+
+	if exclude == excluded {
+		let key = if exclude {
+			"settings-already-excluded"
+		} else {
+			"settings-not-excluded"
+		};
+		return Ok(locale.format(key, &[("channel", &channel_id.mention())]));
+	}
+
+	let description = if exclude {
+		exclusion.insert().await?;
+		format!("excluded <#{channel_id}> from highlighting")
+	} else {
+		exclusion.delete().await?;
+		format!("included <#{channel_id}> in highlighting again")
+	};
+
+	record_change(guild_id, user_id, &description).await?;
+
+	let key = if exclude {
+		"settings-channel-excluded"
+	} else {
+		"settings-channel-included"
+	};
+	Ok(locale.format(key, &[("channel", &channel_id.mention())]))
+}
+
+/// Sets or clears the guild's keyword limit.
+async fn set_keyword_limit(
+	guild_id: GuildId,
+	user_id: UserId,
+	limit: Option<u64>,
+	locale: &Locale,
+) -> Result<String> {
+	let mut guild_settings = GuildSettings::guild_settings(guild_id).await?;
+	let limit = limit.map(|limit| limit as u32);
+
+	if guild_settings.max_keywords == limit {
+		return Ok(locale.get("settings-same-keyword-limit").to_owned());
+	}
+
+	guild_settings.max_keywords = limit;
+	guild_settings.set().await?;
+
+	let (description, response) = match limit {
+		Some(limit) => (
+			format!("set the keyword limit to {limit}"),
+			locale.format("settings-keyword-limit-set", &[("limit", &limit)]),
+		),
+		None => (
+			"removed the keyword limit".to_owned(),
+			locale.get("settings-keyword-limit-removed").to_owned(),
+		),
+	};
+
+	record_change(guild_id, user_id, &description).await?;
+
+	Ok(response)
+}
+
+/// Sets or resets the guild's minimum keyword length.
+async fn set_min_length(
+	guild_id: GuildId,
+	user_id: UserId,
+	length: Option<u64>,
+	locale: &Locale,
+) -> Result<String> {
+	let mut guild_settings = GuildSettings::guild_settings(guild_id).await?;
+	let length = length.map(|length| length as u32);
+
+	if guild_settings.min_keyword_length == length {
+		return Ok(locale.get("settings-same-min-length").to_owned());
+	}
+
+	guild_settings.min_keyword_length = length;
+	guild_settings.set().await?;
+
+	let (description, response) = match length {
+		Some(length) => (
+			format!("set the minimum keyword length to {length}"),
+			locale.format("settings-min-length-set", &[("length", &length)]),
+		),
+		None => (
+			"reset the minimum keyword length".to_owned(),
+			locale.get("settings-min-length-reset").to_owned(),
+		),
+	};
+
+	record_change(guild_id, user_id, &description).await?;
+
+	Ok(response)
+}
+
+/// Bans keywords containing the given word in the guild, or allows them
+/// again.
+async fn set_banned(
+	guild_id: GuildId,
+	user_id: UserId,
+	word: String,
+	ban: bool,
+	locale: &Locale,
+) -> Result<String> {
+	if word.is_empty() {
+		return Ok(locale.get("settings-no-word").to_owned());
+	}
+
+	let banned_keyword = BannedKeyword {
+		guild_id,
+		word: word.clone(),
+	};
+	let banned = banned_keyword.clone().exists().await?;
+
+	if ban == banned {
+		let key = if ban {
+			"settings-already-banned"
+		} else {
+			"settings-not-banned"
+		};
+		return Ok(locale.format(key, &[("word", &word)]));
+	}
+
+	let description = if ban {
+		banned_keyword.insert().await?;
+		format!("banned keywords containing \"{word}\"")
+	} else {
+		banned_keyword.delete().await?;
+		format!("allowed keywords containing \"{word}\" again")
+	};
+
+	record_change(guild_id, user_id, &description).await?;
+
+	let key = if ban {
+		"settings-word-banned"
+	} else {
+		"settings-word-allowed"
+	};
+	Ok(locale.format(key, &[("word", &word)]))
+}
+
+/// Revokes the given member's highlighting in the guild, or restores it.
+async fn set_revoked(
+	guild_id: GuildId,
+	user_id: UserId,
+	member_id: UserId,
+	revoke: bool,
+	locale: &Locale,
+) -> Result<String> {
+	let revoked_member = RevokedMember {
+		guild_id,
+		user_id: member_id,
+	};
+	let revoked = revoked_member.clone().exists().await?;
+
+	if revoke == revoked {
+		let key = if revoke {
+			"settings-already-revoked"
+		} else {
+			"settings-not-revoked"
+		};
+		return Ok(locale.format(key, &[("member", &member_id.mention())]));
+	}
+
+	let description = if revoke {
+		revoked_member.insert().await?;
+		format!("revoked highlighting for <@{member_id}>")
+	} else {
+		revoked_member.delete().await?;
+		format!("restored highlighting for <@{member_id}>")
+	};
+
+	record_change(guild_id, user_id, &description).await?;
+
+	let key = if revoke {
+		"settings-member-revoked"
+	} else {
+		"settings-member-restored"
+	};
+	Ok(locale.format(key, &[("member", &member_id.mention())]))
+}
+
+/// Turns highlighting in the guild on or off.
+async fn set_enabled(
+	guild_id: GuildId,
+	user_id: UserId,
+	enabled: bool,
+	locale: &Locale,
+) -> Result<String> {
+	let mut guild_settings = GuildSettings::guild_settings(guild_id).await?;
+
+	if guild_settings.disabled != enabled {
+		let key = if enabled {
+			"settings-already-on"
+		} else {
+			"settings-already-off"
+		};
+		return Ok(locale.get(key).to_owned());
+	}
+
+	guild_settings.disabled = !enabled;
+	guild_settings.set().await?;
+
+	let (description, response) = if enabled {
+		("turned highlighting on", "settings-turned-on")
+	} else {
+		("turned highlighting off", "settings-turned-off")
+	};
+
+	record_change(guild_id, user_id, description).await?;
+
+	Ok(locale.get(response).to_owned())
+}
+
+/// Records a change to the guild's settings in its audit log.
+///
+/// Descriptions are stored in English rather than the locale of the member
+/// making the change, since the log is shared by everyone who can view it.
+pub(super) async fn record_change(
+	guild_id: GuildId,
+	user_id: UserId,
+	description: &str,
+) -> Result<()> {
+	SettingsChange {
+		guild_id,
+		user_id,
+		description: description.to_owned(),
+		time: SystemTime::now(),
+	}
+	.insert()
+	.await
+}
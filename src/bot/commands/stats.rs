@@ -0,0 +1,195 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! Command for viewing how often keywords match and notify.
+
+use std::{
+	cmp::Reverse,
+	collections::{BTreeMap, HashMap},
+	fmt::Write as _,
+};
+
+use anyhow::{Context as _, Result};
+use serenity::{
+	client::Context,
+	model::{
+		application::interaction::{
+			application_command::ApplicationCommandInteraction as Command,
+			MessageFlags,
+		},
+		id::GuildId,
+	},
+};
+
+use crate::{
+	bot::{
+		i18n::{locale, Locale},
+		stats::pending_counts,
+		util::respond_eph,
+	},
+	db::{Keyword, KeywordCounts, KeywordKind, KeywordStats},
+	global::EMBED_COLOR,
+};
+
+/// Maximum length of the description of the stats embed.
+const MAX_DESCRIPTION_LENGTH: usize = 4000;
+
+/// Show how often the user's keywords matched and notified.
+///
+/// Shows keywords in the current server, or in all servers when used in DMs.
+/// Keywords that never matched are included so they can be pruned.
+///
+/// Usage: `/stats`
+#[tracing::instrument(
+	skip_all,
+	fields(
+		user_id = %command.user.id,
+		channel_id = %command.channel_id,
+		command = %command.data.name,
+	)
+)]
+pub(crate) async fn stats(ctx: Context, command: Command) -> Result<()> {
+	check_opt_out!(ctx, command);
+	let locale = locale(&command.locale);
+	let user_id = command.user.id;
+
+	let stored = KeywordStats::user_stats(user_id, command.guild_id)
+		.await?
+		.into_iter()
+		.map(|stats| ((stats.guild_id, stats.keyword), stats.counts))
+		.collect::<HashMap<_, _>>();
+
+	let mut keywords_by_guild = BTreeMap::<GuildId, Vec<_>>::new();
+
+	for keyword in Keyword::user_keywords(user_id).await? {
+		let guild_id = match keyword.kind {
+			KeywordKind::Guild(guild_id) => guild_id,
+			KeywordKind::Channel(channel_id) => {
+				match ctx.cache.guild_channel_field(channel_id, |c| c.guild_id)
+				{
+					Some(guild_id) => guild_id,
+					None => continue,
+				}
+			}
+		};
+
+		if command.guild_id.is_some_and(|id| id != guild_id) {
+			continue;
+		}
+
+		let keywords = keywords_by_guild.entry(guild_id).or_default();
+
+		if keywords.iter().any(|(k, _)| *k == keyword.keyword) {
+			continue;
+		}
+
+		let mut counts = stored
+			.get(&(guild_id, keyword.keyword.clone()))
+			.copied()
+			.unwrap_or_default();
+
+		counts += pending_counts(user_id, guild_id, &keyword.keyword);
+
+		keywords.push((keyword.keyword, counts));
+	}
+
+	if keywords_by_guild.is_empty() {
+		return respond_eph(&ctx, &command, locale.get("keywords-empty")).await;
+	}
+
+	let mut description = String::new();
+	let mut omitted = 0;
+
+	for (guild_id, mut keywords) in keywords_by_guild {
+		keywords.sort_by_key(|(keyword, counts)| {
+			(Reverse(counts.matches), keyword.clone())
+		});
+
+		if command.guild_id.is_none() {
+			let guild_name = ctx
+				.cache
+				.guild_field(guild_id, |g| g.name.clone())
+				.unwrap_or_else(|| guild_id.to_string());
+
+			let header = format!("\n__{guild_name}__\n");
+
+			if description.len() + header.len() > MAX_DESCRIPTION_LENGTH {
+				omitted += keywords.len();
+				continue;
+			}
+
+			description.push_str(&header);
+		}
+
+		for (keyword, counts) in keywords {
+			let line = stats_line(&keyword, &counts, locale);
+
+			if description.len() + line.len() > MAX_DESCRIPTION_LENGTH {
+				omitted += 1;
+				continue;
+			}
+
+			description.push_str(&line);
+		}
+	}
+
+	if omitted > 0 {
+		description.push('\n');
+		description
+			.push_str(&locale.format("stats-omitted", &[("count", &omitted)]));
+	}
+
+	command
+		.create_interaction_response(&ctx, |r| {
+			r.interaction_response_data(|m| {
+				m.flags(MessageFlags::EPHEMERAL).embed(|e| {
+					e.title(locale.get("stats-title"))
+						.description(description.trim_start())
+						.footer(|f| f.text(locale.get("stats-footer")))
+						.color(EMBED_COLOR)
+				})
+			})
+		})
+		.await
+		.context("Failed to send keyword stats")?;
+
+	Ok(())
+}
+
+/// Formats the statistics of a keyword as a line of the stats embed.
+fn stats_line(
+	keyword: &str,
+	counts: &KeywordCounts,
+	locale: &Locale,
+) -> String {
+	if counts.matches == 0 {
+		let line =
+			locale.format("stats-never-matched", &[("keyword", &keyword)]);
+		return format!("{line}\n");
+	}
+
+	let mut line = locale.format(
+		"stats-line",
+		&[
+			("keyword", &keyword),
+			("matches", &counts.matches),
+			("notified", &counts.notified),
+		],
+	);
+
+	for (count, key) in [
+		(counts.patience, "stats-patience"),
+		(counts.ignored, "stats-ignored"),
+		(counts.muted, "stats-muted"),
+		(counts.blocked, "stats-blocked"),
+	] {
+		if count > 0 {
+			write!(line, ", {}", locale.format(key, &[("count", &count)]))
+				.unwrap();
+		}
+	}
+
+	line.push('\n');
+
+	line
+}
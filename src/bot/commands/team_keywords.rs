@@ -0,0 +1,345 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! Command for team keywords, which server admins add for every member of a
+//! role.
+
+use std::fmt::Write as _;
+
+use anyhow::{bail, Context as _, Result};
+use serenity::{
+	client::Context,
+	model::{
+		application::interaction::application_command::{
+			ApplicationCommandInteraction as Command, CommandDataOption,
+		},
+		id::{GuildId, RoleId, UserId},
+		mention::Mentionable,
+	},
+};
+
+use super::{keywords::KeywordRules, server_settings::record_change};
+use crate::{
+	bot::{
+		i18n::{locale, Locale},
+		util::{respond_eph, role_members},
+	},
+	db::{TeamKeyword, TeamKeywordOptOut},
+	settings::settings,
+};
+
+/// Most members a role can have to be given team keywords.
+///
+/// Every member of the role is checked for each message in the server, so
+/// large roles, like @everyone, would make highlighting slow.
+const MAX_ROLE_MEMBERS: usize = 1000;
+
+/// Add, remove, list, leave, or rejoin team keywords.
+///
+/// Usage:
+/// - `/team-keywords add <role> <keyword>`
+/// - `/team-keywords remove <role> <keyword>`
+/// - `/team-keywords list`
+/// - `/team-keywords leave <keyword>`
+/// - `/team-keywords rejoin <keyword>`
+#[tracing::instrument(
+	skip_all,
+	fields(
+		user_id = %command.user.id,
+		channel_id = %command.channel_id,
+		command = %command.data.name,
+	)
+)]
+pub(crate) async fn team_keywords(
+	ctx: Context,
+	command: Command,
+) -> Result<()> {
+	let guild_id = require_guild!(&ctx, &command);
+	let locale = locale(&command.locale);
+
+	let subcommand = command
+		.data
+		.options
+		.first()
+		.context("No team keywords subcommand provided")?;
+
+	let keyword = subcommand
+		.options
+		.iter()
+		.find(|o| o.name == "keyword")
+		.and_then(|o| o.value.as_ref())
+		.and_then(|v| v.as_str())
+		.map(|keyword| keyword.trim().to_lowercase());
+
+	let response = match subcommand.name.as_str() {
+		"add" | "remove" => {
+			require_manage_guild!(&ctx, &command);
+
+			let role_id = role_option(subcommand)?;
+			let keyword = keyword.context("No keyword provided")?;
+			let team_keyword = TeamKeyword {
+				guild_id,
+				role_id,
+				keyword,
+			};
+
+			if subcommand.name == "add" {
+				add(&ctx, team_keyword, command.user.id, locale).await?
+			} else {
+				remove(team_keyword, command.user.id, locale).await?
+			}
+		}
+		"list" => list(guild_id, locale).await?,
+		"leave" | "rejoin" => {
+			check_opt_out!(ctx, command);
+
+			let keyword = keyword.context("No keyword provided")?;
+			let roles = command
+				.member
+				.as_ref()
+				.map(|member| member.roles.clone())
+				.unwrap_or_default();
+
+			set_left(
+				guild_id,
+				command.user.id,
+				roles,
+				keyword,
+				subcommand.name == "leave",
+				locale,
+			)
+			.await?
+		}
+		other => bail!("Unknown team keywords subcommand {}", other),
+	};
+
+	respond_eph(&ctx, &command, response).await
+}
+
+/// Gets the role option of a subcommand.
+fn role_option(subcommand: &CommandDataOption) -> Result<RoleId> {
+	subcommand
+		.options
+		.iter()
+		.find(|o| o.name == "role")
+		.and_then(|o| o.value.as_ref())
+		.context("No role provided")?
+		.as_str()
+		.context("Role provided was not a string")?
+		.parse()
+		.map(RoleId)
+		.context("Role provided was not an ID")
+}
+
+/// Adds a team keyword, recording the change in the guild's audit log.
+///
+/// The keyword must follow the same rules as members' own keywords, and the
+/// role can't be @everyone or have more than [`MAX_ROLE_MEMBERS`] members.
+async fn add(
+	ctx: &Context,
+	team_keyword: TeamKeyword,
+	user_id: UserId,
+	locale: &Locale,
+) -> Result<String> {
+	let TeamKeyword {
+		guild_id,
+		role_id,
+		keyword,
+	} = &team_keyword;
+
+	// the @everyone role has the same ID as the guild
+	if role_id.0 == guild_id.0 {
+		return Ok(locale.get("team-keyword-everyone").to_owned());
+	}
+
+	if role_members(ctx, *guild_id, *role_id).len() > MAX_ROLE_MEMBERS {
+		return Ok(locale.format(
+			"team-keyword-role-too-large",
+			&[("role", &role_id.mention()), ("max", &MAX_ROLE_MEMBERS)],
+		));
+	}
+
+	if let Some(message) =
+		KeywordRules::new(*guild_id).await?.check(keyword, locale)
+	{
+		return Ok(message);
+	}
+
+	if team_keyword.clone().exists().await? {
+		return Ok(locale.get("team-keyword-exists").to_owned());
+	}
+
+	let max_keywords = settings().behavior.max_keywords;
+	if TeamKeyword::guild_team_keywords(*guild_id).await?.len()
+		>= max_keywords as usize
+	{
+		return Ok(
+			locale.format("team-keywords-limit", &[("max", &max_keywords)])
+		);
+	}
+
+	let response = locale.format(
+		"team-keyword-added",
+		&[("role", &role_id.mention()), ("keyword", keyword)],
+	);
+	let description =
+		format!("added team keyword \"{keyword}\" for <@&{role_id}>");
+
+	record_change(*guild_id, user_id, &description).await?;
+	team_keyword.insert().await?;
+
+	Ok(response)
+}
+
+/// Removes a team keyword, recording the change in the guild's audit log.
+async fn remove(
+	team_keyword: TeamKeyword,
+	user_id: UserId,
+	locale: &Locale,
+) -> Result<String> {
+	if !team_keyword.clone().exists().await? {
+		return Ok(locale.get("team-keyword-missing").to_owned());
+	}
+
+	let TeamKeyword {
+		guild_id,
+		role_id,
+		keyword,
+	} = &team_keyword;
+
+	let response = locale.format(
+		"team-keyword-removed",
+		&[("role", &role_id.mention()), ("keyword", keyword)],
+	);
+	let description =
+		format!("removed team keyword \"{keyword}\" for <@&{role_id}>");
+
+	record_change(*guild_id, user_id, &description).await?;
+	team_keyword.delete().await?;
+
+	Ok(response)
+}
+
+/// Lists the team keywords of a guild by role.
+async fn list(guild_id: GuildId, locale: &Locale) -> Result<String> {
+	let mut team_keywords = TeamKeyword::guild_team_keywords(guild_id).await?;
+
+	if team_keywords.is_empty() {
+		return Ok(locale.get("team-keywords-empty").to_owned());
+	}
+
+	team_keywords
+		.sort_by(|a, b| (a.role_id, &a.keyword).cmp(&(b.role_id, &b.keyword)));
+
+	let mut response = locale.get("team-keywords-title").to_owned();
+	let mut last_role = None;
+
+	for team_keyword in team_keywords {
+		if last_role != Some(team_keyword.role_id) {
+			write!(response, "\n<@&{}>: ", team_keyword.role_id).unwrap();
+			last_role = Some(team_keyword.role_id);
+		} else {
+			response.push_str(", ");
+		}
+
+		write!(response, "\"{}\"", team_keyword.keyword).unwrap();
+	}
+
+	Ok(response)
+}
+
+/// Stops or resumes notifying a member of a team keyword of any of their
+/// roles.
+async fn set_left(
+	guild_id: GuildId,
+	user_id: UserId,
+	roles: Vec<RoleId>,
+	keyword: String,
+	leave: bool,
+	locale: &Locale,
+) -> Result<String> {
+	let team_keywords = TeamKeyword::role_team_keywords(
+		roles.into_iter().chain([RoleId(guild_id.0)]),
+	)
+	.await?
+	.into_iter()
+	.filter(|team_keyword| team_keyword.keyword == keyword)
+	.collect::<Vec<_>>();
+
+	if team_keywords.is_empty() {
+		return Ok(
+			locale.format("team-keyword-no-role", &[("keyword", &keyword)])
+		);
+	}
+
+	let mut changed = false;
+
+	for team_keyword in team_keywords {
+		let opt_out = TeamKeywordOptOut {
+			user_id,
+			role_id: team_keyword.role_id,
+			keyword: team_keyword.keyword,
+		};
+
+		match (leave, opt_out.clone().exists().await?) {
+			(true, false) => opt_out.insert().await?,
+			(false, true) => opt_out.delete().await?,
+			_ => continue,
+		}
+
+		changed = true;
+	}
+
+	let key = match (leave, changed) {
+		(true, true) => "team-keyword-left",
+		(true, false) => "team-keyword-already-left",
+		(false, true) => "team-keyword-rejoined",
+		(false, false) => "team-keyword-not-left",
+	};
+
+	Ok(locale.format(key, &[("keyword", &keyword)]))
+}
+
+/// Fetches the team keywords that apply to the given user, in the given guild
+/// or in all cached guilds, along with whether they left each of them.
+pub(super) async fn user_team_keywords(
+	ctx: &Context,
+	user_id: UserId,
+	guild_id: Option<GuildId>,
+) -> Result<Vec<(TeamKeyword, bool)>> {
+	let guild_ids = match guild_id {
+		Some(guild_id) => vec![guild_id],
+		None => ctx.cache.guilds(),
+	};
+
+	let roles = guild_ids
+		.into_iter()
+		.filter_map(|guild_id| {
+			let roles = ctx
+				.cache
+				.member_field(guild_id, user_id, |m| m.roles.clone())?;
+
+			Some(roles.into_iter().chain([RoleId(guild_id.0)]))
+		})
+		.flatten()
+		.collect::<Vec<_>>();
+
+	if roles.is_empty() {
+		return Ok(Vec::new());
+	}
+
+	let opt_outs = TeamKeywordOptOut::user_opt_outs(user_id).await?;
+
+	Ok(TeamKeyword::role_team_keywords(roles)
+		.await?
+		.into_iter()
+		.map(|team_keyword| {
+			let left = opt_outs.iter().any(|opt_out| {
+				opt_out.role_id == team_keyword.role_id
+					&& opt_out.keyword == team_keyword.keyword
+			});
+
+			(team_keyword, left)
+		})
+		.collect())
+}
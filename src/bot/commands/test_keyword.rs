@@ -0,0 +1,555 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! Commands for testing whether keywords would notify the user, without
+//! sending any notifications.
+
+use std::fmt::Write as _;
+
+use anyhow::{Context as _, Result};
+use serenity::{
+	client::Context,
+	model::{
+		application::interaction::application_command::ApplicationCommandInteraction as Command,
+		channel::Message,
+		id::{ChannelId, GuildId, RoleId, UserId},
+		mention::Mentionable,
+		user::User,
+	},
+};
+
+use super::keywords::KeywordRules;
+use crate::{
+	bot::{
+		highlighting::{
+			check_keyword, check_keyword_content, check_keyword_in,
+			keyword_match, keyword_matches, KeywordCheck,
+		},
+		i18n::{locale, Locale},
+		util::{author_roles, respond_eph, role_members, thread_parent},
+	},
+	db::{
+		ExcludedChannel, GuildSettings, Ignore, Keyword, KeywordAuthor,
+		KeywordChannel, KeywordKind, OptOut,
+	},
+};
+
+/// Number of bytes of message content shown on either side of a match.
+const SNIPPET_CONTEXT: usize = 40;
+
+/// Maximum length of a response.
+const MAX_RESPONSE_LENGTH: usize = 2000;
+
+/// Test whether a keyword would match some text, as if it were sent in the
+/// current channel.
+///
+/// Usage: `/test-keyword <keyword> <text> [author]`
+#[tracing::instrument(
+	skip_all,
+	fields(
+		user_id = %command.user.id,
+		channel_id = %command.channel_id,
+		command = %command.data.name,
+	)
+)]
+pub(crate) async fn test_keyword(ctx: Context, command: Command) -> Result<()> {
+	check_opt_out!(ctx, command);
+	let guild_id = require_guild!(&ctx, &command);
+	let locale = locale(&command.locale);
+	let user_id = command.user.id;
+	let channel_id = command.channel_id;
+
+	let option = |name: &str| {
+		command
+			.data
+			.options
+			.iter()
+			.find(|o| o.name == name)
+			.and_then(|o| o.value.as_ref())
+			.and_then(|v| v.as_str())
+	};
+
+	let keyword = option("keyword")
+		.context("No keyword to test provided")?
+		.trim()
+		.to_lowercase();
+
+	let text = option("text").context("No text to test provided")?;
+
+	if let Some(error) =
+		KeywordRules::new(guild_id).await?.check(&keyword, locale)
+	{
+		return respond_eph(&ctx, &command, error).await;
+	}
+
+	let content = text.to_lowercase();
+	let ignores = Ignore::user_guild_ignores(user_id, guild_id).await?;
+
+	let check = check_keyword_content(&keyword, channel_id, &content, &ignores);
+
+	if check == KeywordCheck::NoMatch {
+		let response = locale.format("test-no-match", &[("keyword", &keyword)]);
+		return respond_eph(&ctx, &command, response).await;
+	}
+
+	let mut response = String::new();
+
+	if let Some(snippet) = match_snippet(&keyword, text) {
+		let matched = locale.format("test-matched", &[("snippet", &snippet)]);
+		writeln!(response, "{matched}").unwrap();
+	}
+
+	let guild_added = Keyword {
+		keyword: keyword.clone(),
+		user_id,
+		kind: KeywordKind::Guild(guild_id),
+	}
+	.exists()
+	.await?;
+
+	let channel_added = Keyword {
+		keyword: keyword.clone(),
+		user_id,
+		kind: KeywordKind::Channel(channel_id),
+	}
+	.exists()
+	.await?;
+
+	let added = guild_added || channel_added;
+
+	let author = command.data.resolved.users.values().next();
+	let parent_id = thread_parent(&ctx, guild_id, channel_id).await?;
+
+	let reason = match author {
+		Some(author) => author_reason(author, user_id, locale).await?,
+		None => None,
+	};
+
+	let reason = match reason {
+		Some(reason) => Some(reason),
+		None => {
+			excluded_reason(guild_id, channel_id, parent_id, locale).await?
+		}
+	};
+
+	let mut suppressions = Vec::new();
+
+	if let Some(reason) = reason {
+		suppressions.push(reason);
+	} else {
+		let (author_id, author_roles) = match author {
+			Some(author) => {
+				let mut roles = ctx
+					.cache
+					.member_field(guild_id, author.id, |m| m.roles.clone())
+					.unwrap_or_default();
+				roles.push(RoleId(guild_id.0));
+				(author.id, roles)
+			}
+			// without an author, the text is tested as if the bot sent it,
+			// which passes no author filters
+			None => (ctx.cache.current_user_id(), vec![RoleId(guild_id.0)]),
+		};
+
+		let relevant = Keyword::get_relevant_keywords(
+			guild_id,
+			channel_id,
+			parent_id,
+			author_id,
+			&author_roles,
+			|role_id| role_members(&ctx, guild_id, role_id),
+			|relevant| relevant == keyword,
+		)
+		.await?
+		.into_iter()
+		.filter(|relevant| relevant.keyword.user_id == user_id)
+		.min_by_key(|relevant| relevant.muted || relevant.blocked);
+
+		match relevant {
+			Some(relevant) if relevant.blocked => suppressions.push(
+				locale
+					.format("test-blocked", &[("user", &author_id.mention())]),
+			),
+			Some(relevant) if relevant.muted => {
+				suppressions.push(locale.get("test-muted").to_owned())
+			}
+			Some(relevant) => {
+				let mentioned = text.contains(&format!("<@{user_id}>"))
+					|| text.contains(&format!("<@!{user_id}>"));
+
+				let check = check_keyword_in(
+					&ctx,
+					channel_id,
+					mentioned,
+					&content,
+					&relevant.keyword,
+					&ignores,
+				)
+				.await?;
+
+				match check {
+					KeywordCheck::Ignored(phrase) => suppressions.push(
+						locale.format("test-ignored", &[("phrase", &phrase)]),
+					),
+					KeywordCheck::Mentioned => suppressions
+						.push(locale.get("test-mentioned").to_owned()),
+					KeywordCheck::NoPermission => suppressions
+						.push(locale.get("test-cannot-read").to_owned()),
+					KeywordCheck::Notify
+					| KeywordCheck::NoMatch
+					| KeywordCheck::TooOld => {}
+				}
+			}
+			// the keyword was held back before matching, so find out why
+			None if added => {
+				let authors = KeywordAuthor::user_filters(user_id)
+					.await?
+					.into_iter()
+					.filter(|filter| {
+						filter.guild_id == guild_id && filter.keyword == keyword
+					})
+					.map(|filter| filter.author)
+					.collect::<Vec<_>>();
+
+				let channels = KeywordChannel::user_channels(user_id)
+					.await?
+					.into_iter()
+					.filter(|channel| {
+						channel.guild_id == guild_id
+							&& channel.keyword == keyword
+					})
+					.collect::<Vec<_>>();
+
+				let suppression = if !authors.is_empty()
+					&& !authors
+						.iter()
+						.any(|filter| filter.matches(author_id, &author_roles))
+				{
+					let mentions = authors
+						.iter()
+						.map(|filter| filter.mention())
+						.collect::<Vec<_>>();
+
+					locale.format(
+						"test-authors",
+						&[("authors", &mentions.join(", "))],
+					)
+				} else if !channel_added
+					&& !KeywordChannel::allows(&channels, channel_id, parent_id)
+				{
+					locale.get("test-channels").to_owned()
+				} else {
+					locale.get("test-filtered").to_owned()
+				};
+
+				suppressions.push(suppression);
+			}
+			// keywords the user didn't add can't notify them, which is
+			// explained below
+			None => {}
+		}
+	}
+
+	if !suppressions.is_empty() {
+		response.push_str(locale.get("test-would-not-notify"));
+		for suppression in suppressions {
+			write!(response, "\n- {suppression}").unwrap();
+		}
+
+		if !added {
+			response.push_str("\n\n");
+		}
+	} else if added {
+		response.push_str(locale.get("test-would-notify"));
+	}
+
+	if !added {
+		let not_added =
+			locale.format("test-not-added", &[("keyword", &keyword)]);
+		response.push_str(&not_added);
+	}
+
+	respond_eph(&ctx, &command, truncate(response)).await
+}
+
+/// Test which of the user's keywords would notify them about a message.
+///
+/// Usage: message context menu > Apps > Test keywords
+#[tracing::instrument(
+	skip_all,
+	fields(
+		user_id = %command.user.id,
+		channel_id = %command.channel_id,
+		command = %command.data.name,
+	)
+)]
+pub(crate) async fn test_message(ctx: Context, command: Command) -> Result<()> {
+	check_opt_out!(ctx, command);
+	let guild_id = require_guild!(&ctx, &command);
+	let locale = locale(&command.locale);
+
+	let message = command
+		.data
+		.resolved
+		.messages
+		.values()
+		.next()
+		.context("No message to test provided")?;
+
+	let report =
+		message_report(&ctx, command.user.id, guild_id, message, locale)
+			.await?;
+
+	respond_eph(&ctx, &command, truncate(report)).await
+}
+
+/// Describes whether each of the user's keywords would notify them about the
+/// given message if it were sent now, and why not if they wouldn't.
+///
+/// Uses the same checks as when messages are sent, except that patience isn't
+/// considered.
+pub(super) async fn message_report(
+	ctx: &Context,
+	user_id: UserId,
+	guild_id: GuildId,
+	message: &Message,
+	locale: &Locale,
+) -> Result<String> {
+	let author_id = message.author.id;
+	let author = author_id.mention();
+
+	if let Some(reason) =
+		author_reason(&message.author, user_id, locale).await?
+	{
+		return Ok(reason);
+	}
+
+	let parent_id = thread_parent(ctx, guild_id, message.channel_id).await?;
+
+	if let Some(reason) =
+		excluded_reason(guild_id, message.channel_id, parent_id, locale).await?
+	{
+		return Ok(reason);
+	}
+
+	let author_roles = author_roles(ctx, guild_id, message);
+
+	let keywords = Keyword::get_relevant_keywords(
+		guild_id,
+		message.channel_id,
+		parent_id,
+		author_id,
+		&author_roles,
+		|role_id| role_members(ctx, guild_id, role_id),
+		// unmatched keywords are listed in the report too
+		|_| true,
+	)
+	.await?
+	.into_iter()
+	.filter(|relevant| relevant.keyword.user_id == user_id)
+	.collect::<Vec<_>>();
+
+	if keywords.is_empty() {
+		return Ok(locale.get("report-no-keywords").to_owned());
+	}
+
+	let ignores = Ignore::user_guild_ignores(user_id, guild_id).await?;
+	let content = message.content.to_lowercase();
+
+	let mut report = String::new();
+	let mut unmatched = Vec::new();
+
+	for relevant in &keywords {
+		let keyword = &relevant.keyword;
+
+		let reason = if relevant.blocked || relevant.muted {
+			if !keyword_matches(&keyword.keyword, &content) {
+				unmatched.push(keyword.keyword.as_str());
+				continue;
+			}
+
+			if relevant.blocked {
+				locale.format("report-blocked", &[("user", &author)])
+			} else {
+				locale.get("report-muted").to_owned()
+			}
+		} else {
+			let check =
+				check_keyword(ctx, message, &content, keyword, &ignores)
+					.await?;
+
+			match check {
+				KeywordCheck::NoMatch => {
+					unmatched.push(keyword.keyword.as_str());
+					continue;
+				}
+				KeywordCheck::Notify => locale.get("report-notify").to_owned(),
+				KeywordCheck::TooOld => locale.get("report-too-old").to_owned(),
+				KeywordCheck::Mentioned => {
+					locale.get("report-mentioned").to_owned()
+				}
+				KeywordCheck::Ignored(phrase) => {
+					locale.format("report-ignored", &[("phrase", &phrase)])
+				}
+				KeywordCheck::NoPermission => {
+					locale.get("report-no-permission").to_owned()
+				}
+			}
+		};
+
+		write!(report, "**{}**", keyword.keyword).unwrap();
+
+		if let KeywordKind::Channel(channel_id) = keyword.kind {
+			let location = locale.format(
+				"report-in-channel",
+				&[("channel", &channel_id.mention())],
+			);
+			write!(report, " {location}").unwrap();
+		}
+
+		writeln!(report, ": {reason}").unwrap();
+
+		if let Some(snippet) = match_snippet(&keyword.keyword, &message.content)
+		{
+			writeln!(report, "> {snippet}").unwrap();
+		}
+	}
+
+	if report.is_empty() {
+		report.push_str(locale.get("report-none"));
+	} else if !unmatched.is_empty() {
+		report.push_str(&locale.format(
+			"report-unmatched",
+			&[("keywords", &unmatched.join(", "))],
+		));
+	}
+
+	Ok(report)
+}
+
+/// Describes why messages from the given author never notify the user, if
+/// they don't: because the user wrote them, a bot did, or the author opted
+/// out.
+async fn author_reason(
+	author: &User,
+	user_id: UserId,
+	locale: &Locale,
+) -> Result<Option<String>> {
+	if author.id == user_id {
+		return Ok(Some(locale.get("test-own-message").to_owned()));
+	}
+
+	if author.bot {
+		return Ok(Some(locale.get("test-bot").to_owned()));
+	}
+
+	if (OptOut { user_id: author.id }).exists().await? {
+		let mention = author.id.mention();
+		return Ok(Some(
+			locale.format("test-opted-out", &[("user", &mention)]),
+		));
+	}
+
+	Ok(None)
+}
+
+/// Describes why nothing is highlighted in the given channel, if the guild's
+/// administrators disabled highlighting or excluded the channel.
+///
+/// Threads are excluded with their parent, `parent_id`.
+async fn excluded_reason(
+	guild_id: GuildId,
+	channel_id: ChannelId,
+	parent_id: Option<ChannelId>,
+	locale: &Locale,
+) -> Result<Option<String>> {
+	if GuildSettings::guild_settings(guild_id).await?.disabled {
+		return Ok(Some(locale.get("report-disabled").to_owned()));
+	}
+
+	for channel_id in [Some(channel_id), parent_id].into_iter().flatten() {
+		if (ExcludedChannel {
+			guild_id,
+			channel_id,
+		})
+		.exists()
+		.await?
+		{
+			return Ok(Some(locale.format(
+				"report-excluded",
+				&[("channel", &channel_id.mention())],
+			)));
+		}
+	}
+
+	Ok(None)
+}
+
+/// Shows where the keyword matched in the content, with some surrounding
+/// context and the match in bold.
+fn match_snippet(keyword: &str, content: &str) -> Option<String> {
+	let lowercase = content.to_lowercase();
+	let range = keyword_match(keyword, &lowercase)?;
+
+	// lowercasing can change the length of some characters, in which case the
+	// match is only valid in the lowercase content
+	let content = if content.len() == lowercase.len()
+		&& content.is_char_boundary(range.start)
+		&& content.is_char_boundary(range.end)
+	{
+		content
+	} else {
+		&lowercase
+	};
+
+	let mut start = range.start.saturating_sub(SNIPPET_CONTEXT);
+	while !content.is_char_boundary(start) {
+		start -= 1;
+	}
+
+	let mut end = usize::min(range.end + SNIPPET_CONTEXT, content.len());
+	while !content.is_char_boundary(end) {
+		end += 1;
+	}
+
+	Some(format!(
+		"{}{}**{}**{}{}",
+		if start > 0 { "…" } else { "" },
+		escape_markdown(&content[start..range.start]),
+		escape_markdown(&content[range.clone()]),
+		escape_markdown(&content[range.end..end]),
+		if end < content.len() { "…" } else { "" },
+	))
+}
+
+/// Escapes Discord markdown in the given text, and puts it on one line.
+fn escape_markdown(text: &str) -> String {
+	let mut escaped = String::with_capacity(text.len());
+
+	for c in text.chars() {
+		match c {
+			'\\' | '*' | '_' | '~' | '`' | '|' | '>' => {
+				escaped.push('\\');
+				escaped.push(c);
+			}
+			'\n' => escaped.push(' '),
+			_ => escaped.push(c),
+		}
+	}
+
+	escaped
+}
+
+/// Truncates a response to the maximum length of a message.
+pub(super) fn truncate(mut response: String) -> String {
+	if response.len() > MAX_RESPONSE_LENGTH {
+		let mut end = MAX_RESPONSE_LENGTH - '…'.len_utf8();
+		while !response.is_char_boundary(end) {
+			end -= 1;
+		}
+		response.truncate(end);
+		response.push('…');
+	}
+
+	response
+}
@@ -3,16 +3,7 @@
 
 //! Miscellaneous utility functions and macros used by commands.
 
-use std::collections::HashMap;
-
-use anyhow::{Context as _, Result};
-use serenity::{
-	client::Context,
-	model::{
-		channel::{ChannelType, GuildChannel},
-		id::{ChannelId, GuildId},
-	},
-};
+use std::fmt::Write as _;
 
 /// Requires the given command to have come from a guild channel.
 ///
@@ -27,7 +18,8 @@ macro_rules! require_guild {
 				return $crate::bot::util::respond_eph(
 					$ctx,
 					$command,
-					"❌ You must run this command in a server!",
+					$crate::bot::i18n::locale(&$command.locale)
+						.get("error-guild-only"),
 				)
 				.await
 			}
@@ -50,7 +42,35 @@ macro_rules! check_opt_out {
 			return $crate::bot::util::respond_eph(
 				&$ctx,
 				&$command,
-				"❌ You can't use this command after opting out!",
+				$crate::bot::i18n::locale(&$command.locale)
+					.get("error-opted-out"),
+			)
+			.await;
+		}
+	}};
+}
+
+/// Requires the author of the given command to have permission to manage the
+/// server the command was used in.
+///
+/// Displays an error message and returns if the author doesn't have permission
+/// or the command did not come from a guild channel.
+#[macro_export]
+macro_rules! require_manage_guild {
+	($ctx:expr, $command:expr) => {{
+		#[allow(clippy::needless_borrow)]
+		let can_manage = $command
+			.member
+			.as_ref()
+			.and_then(|member| member.permissions)
+			.map_or(false, |permissions| permissions.manage_guild());
+
+		if !can_manage {
+			return $crate::bot::util::respond_eph(
+				$ctx,
+				$command,
+				$crate::bot::i18n::locale(&$command.locale)
+					.get("error-manage-guild"),
 			)
 			.await;
 		}
@@ -83,8 +103,8 @@ macro_rules! require_embed_perms {
 				$crate::bot::util::respond_eph(
 					$ctx,
 					$command,
-					"Sorry, I need permission to embed links to use that \
-					command 😔",
+					$crate::bot::i18n::locale(&$command.locale)
+						.get("error-embed-perms"),
 				)
 				.await
 				.context("Failed to send missing embed permission message")?;
@@ -95,20 +115,20 @@ macro_rules! require_embed_perms {
 	};
 }
 
-/// Convenience function to get a map of all cached text channels in the given
-/// guild.
-pub(crate) fn get_text_channels_in_guild(
-	ctx: &Context,
-	guild_id: GuildId,
-) -> Result<HashMap<ChannelId, GuildChannel>> {
-	let channels = ctx
-		.cache
-		.guild_channels(guild_id)
-		.context("Couldn't get guild to get channels")?;
-	let channels = channels
-		.into_iter()
-		.filter(|(_, channel)| channel.kind == ChannelType::Text)
-		.collect();
-
-	Ok(channels)
+/// Joins the first few items of a list, noting how many more there are.
+pub(crate) fn short_list(items: &[String]) -> String {
+	const SHOWN: usize = 10;
+
+	let mut list = items
+		.iter()
+		.take(SHOWN)
+		.map(|item| item.chars().take(50).collect::<String>())
+		.collect::<Vec<_>>()
+		.join(", ");
+
+	if items.len() > SHOWN {
+		write!(list, ", and {} more", items.len() - SHOWN).unwrap();
+	}
+
+	list
 }
@@ -0,0 +1,195 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! Command for watching every message in channels or threads.
+
+use std::fmt::Write as _;
+
+use anyhow::{bail, Context as _, Result};
+use serenity::{
+	client::Context,
+	model::{
+		application::interaction::application_command::ApplicationCommandInteraction as Command,
+		channel::ChannelType,
+		id::{ChannelId, GuildId, UserId},
+		mention::Mentionable,
+	},
+};
+
+use crate::{
+	bot::{
+		i18n::{locale, Locale},
+		util::{
+			guild_channel, permission_channel, respond_eph,
+			user_can_read_channel,
+		},
+	},
+	db::Watch,
+	settings::settings,
+};
+
+/// Watch a channel or thread, or stop watching or list watched channels.
+///
+/// Usage:
+/// - `/watch channel [channel]`
+/// - `/watch thread [thread]`
+/// - `/watch stop [channel]`
+/// - `/watch list`
+#[tracing::instrument(
+	skip_all,
+	fields(
+		user_id = %command.user.id,
+		channel_id = %command.channel_id,
+		command = %command.data.name,
+	)
+)]
+pub(crate) async fn watch(ctx: Context, command: Command) -> Result<()> {
+	check_opt_out!(ctx, command);
+	let guild_id = require_guild!(&ctx, &command);
+	let user_id = command.user.id;
+	let locale = locale(&command.locale);
+
+	let subcommand = command
+		.data
+		.options
+		.first()
+		.context("No watch subcommand provided")?;
+
+	let channel_id = command
+		.data
+		.resolved
+		.channels
+		.keys()
+		.next()
+		.copied()
+		.unwrap_or(command.channel_id);
+
+	let response = match subcommand.name.as_str() {
+		"channel" | "thread" => {
+			let thread = subcommand.name == "thread";
+			add(&ctx, guild_id, user_id, channel_id, thread, locale).await?
+		}
+		"stop" => stop(user_id, channel_id, locale).await?,
+		"list" => list(guild_id, user_id, locale).await?,
+		other => bail!("Unknown watch subcommand {}", other),
+	};
+
+	respond_eph(&ctx, &command, response).await
+}
+
+/// Adds a watch of the given channel, which should be a thread if `thread` is
+/// true.
+async fn add(
+	ctx: &Context,
+	guild_id: GuildId,
+	user_id: UserId,
+	channel_id: ChannelId,
+	thread: bool,
+	locale: &Locale,
+) -> Result<String> {
+	let args = [("channel", &channel_id.mention() as _)];
+
+	let channel = match guild_channel(ctx, channel_id).await? {
+		Some(channel) if channel.guild_id == guild_id => channel,
+		_ => return Ok(locale.get("watch-other-server").to_owned()),
+	};
+
+	match (thread, channel.thread_metadata.is_some()) {
+		(true, false) => {
+			return Ok(locale.format("watch-not-thread", &args));
+		}
+		(false, true) => {
+			return Ok(locale.get("watch-use-thread").to_owned());
+		}
+		(false, false)
+			if !matches!(
+				channel.kind,
+				ChannelType::Text | ChannelType::News
+			) =>
+		{
+			return Ok(locale.get("watch-text-only").to_owned());
+		}
+		_ => {}
+	}
+
+	let permission_channel = permission_channel(ctx, channel).await?;
+
+	match user_can_read_channel(ctx, &permission_channel, user_id).await {
+		Ok(Some(true)) => {}
+		Ok(Some(false)) | Ok(None) => {
+			return Ok(locale.format("watch-cannot-read", &args));
+		}
+		Err(e) => return Err(e).context("Failed to check permissions"),
+	}
+
+	let watch = Watch {
+		user_id,
+		channel_id,
+		guild_id,
+		thread,
+	};
+
+	if watch.clone().exists().await? {
+		return Ok(locale.format("watch-exists", &args));
+	}
+
+	let max_watches = settings().behavior.max_watches;
+
+	if Watch::user_watch_count(user_id).await? >= max_watches as u64 {
+		return Ok(locale.format("watch-limit", &[("max", &max_watches)]));
+	}
+
+	watch.insert().await?;
+
+	Ok(locale.format("watch-added", &args))
+}
+
+/// Removes the user's watch of the given channel.
+async fn stop(
+	user_id: UserId,
+	channel_id: ChannelId,
+	locale: &Locale,
+) -> Result<String> {
+	let watch = Watch::user_watches(user_id)
+		.await?
+		.into_iter()
+		.find(|watch| watch.channel_id == channel_id);
+
+	let key = match watch {
+		Some(watch) => {
+			watch.delete().await?;
+			"watch-removed"
+		}
+		None => "watch-missing",
+	};
+
+	Ok(locale.format(key, &[("channel", &channel_id.mention())]))
+}
+
+/// Lists the channels and threads the user watches in the guild.
+async fn list(
+	guild_id: GuildId,
+	user_id: UserId,
+	locale: &Locale,
+) -> Result<String> {
+	let watches = Watch::user_watches(user_id)
+		.await?
+		.into_iter()
+		.filter(|watch| watch.guild_id == guild_id)
+		.collect::<Vec<_>>();
+
+	if watches.is_empty() {
+		return Ok(locale.get("watches-empty").to_owned());
+	}
+
+	let mut response = locale.get("watches-title").to_owned();
+
+	for watch in watches {
+		write!(response, "\n<#{}>", watch.channel_id).unwrap();
+		if watch.thread {
+			write!(response, " {}", locale.get("watches-thread")).unwrap();
+		}
+	}
+
+	Ok(response)
+}
@@ -0,0 +1,201 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! Command for finding out why a message did or didn't notify the user.
+
+use std::fmt::Write as _;
+
+use anyhow::{Context as _, Result};
+use lazy_regex::regex_captures;
+use serenity::{
+	client::Context,
+	model::{
+		application::interaction::application_command::ApplicationCommandInteraction as Command,
+		channel::Channel,
+		id::{ChannelId, GuildId, MessageId},
+		mention::Mentionable,
+	},
+};
+
+use super::{
+	history::notification_reason,
+	test_keyword::{message_report, truncate},
+};
+use crate::{
+	bot::{
+		i18n::locale,
+		util::{optional_result, respond_eph, user_can_read_channel},
+	},
+	db::{Notification, OptOut},
+};
+
+/// Explain whether the user's keywords would notify them about a message, and
+/// whether they were notified.
+///
+/// Usage: `/why <message link>`
+#[tracing::instrument(
+	skip_all,
+	fields(
+		user_id = %command.user.id,
+		channel_id = %command.channel_id,
+		command = %command.data.name,
+	)
+)]
+pub(crate) async fn why(ctx: Context, command: Command) -> Result<()> {
+	let user_id = command.user.id;
+	let locale = locale(&command.locale);
+
+	let link = command
+		.data
+		.options
+		.first()
+		.and_then(|o| o.value.as_ref())
+		.context("No message link provided")?
+		.as_str()
+		.context("Message link provided was not a string")?
+		.trim();
+
+	let (guild_id, channel_id, message_id) = match parse_message_link(link) {
+		Some(ids) => ids,
+		None => {
+			return respond_eph(&ctx, &command, locale.get("why-invalid-link"))
+				.await
+		}
+	};
+
+	if (OptOut { user_id }).exists().await? {
+		return respond_eph(&ctx, &command, locale.get("why-opted-out")).await;
+	}
+
+	let channel = match ctx.cache.guild_channel(channel_id) {
+		Some(channel) => Some(channel),
+		None => match optional_result(ctx.http.get_channel(channel_id.0).await)
+			.context("Failed to fetch channel of message")?
+		{
+			Some(Channel::Guild(channel)) => Some(channel),
+			_ => None,
+		},
+	};
+
+	let channel = match channel {
+		Some(channel) if channel.guild_id == guild_id => channel,
+		_ => {
+			return respond_eph(
+				&ctx,
+				&command,
+				locale.get("why-channel-missing"),
+			)
+			.await
+		}
+	};
+
+	match user_can_read_channel(&ctx, &channel, user_id).await? {
+		Some(true) => {}
+		Some(false) => {
+			return respond_eph(&ctx, &command, locale.get("why-cannot-read"))
+				.await
+		}
+		None => {
+			return respond_eph(&ctx, &command, locale.get("why-not-member"))
+				.await
+		}
+	}
+
+	respond_eph(&ctx, &command, locale.get("why-checking")).await?;
+
+	let message =
+		match optional_result(channel_id.message(&ctx, message_id).await)
+			.context("Failed to fetch message")?
+		{
+			Some(message) => message,
+			None => {
+				command
+					.edit_original_interaction_response(&ctx, |r| {
+						r.content(locale.get("why-message-missing"))
+					})
+					.await
+					.context("Failed to edit diagnostics response")?;
+
+				return Ok(());
+			}
+		};
+
+	let mut response =
+		locale.format("why-header", &[("link", &message.link())]);
+	response.push_str("\n\n");
+
+	response.push_str(
+		&message_report(&ctx, user_id, guild_id, &message, locale).await?,
+	);
+
+	let notifications =
+		Notification::user_notifications_of_message(user_id, message_id)
+			.await?;
+
+	if notifications.is_empty() {
+		write!(response, "\n\n{}", locale.get("why-not-notified")).unwrap();
+	} else {
+		let keywords = notifications
+			.iter()
+			.map(|notification| notification_reason(notification, locale))
+			.collect::<Vec<_>>()
+			.join(", ");
+
+		let notified =
+			locale.format("why-notified", &[("keywords", &keywords)]);
+		write!(response, "\n\n{notified}").unwrap();
+
+		if let Some(thread_id) = notifications
+			.iter()
+			.find_map(|notification| notification.fallback_thread)
+		{
+			let fallback = locale
+				.format("why-fallback", &[("thread", &thread_id.mention())]);
+			write!(response, "\n{fallback}").unwrap();
+		}
+	}
+
+	let response = truncate(response);
+
+	command
+		.edit_original_interaction_response(&ctx, |r| r.content(response))
+		.await
+		.context("Failed to edit diagnostics response")?;
+
+	Ok(())
+}
+
+/// Parses the guild, channel, and message IDs out of a message link.
+fn parse_message_link(link: &str) -> Option<(GuildId, ChannelId, MessageId)> {
+	let (_, guild_id, channel_id, message_id) = regex_captures!(
+		r"^https://(?:(?:canary|ptb)\.)?discord(?:app)?\.com/channels/([0-9]+)/([0-9]+)/([0-9]+)/?$",
+		link
+	)?;
+
+	Some((
+		GuildId(guild_id.parse().ok()?),
+		ChannelId(channel_id.parse().ok()?),
+		MessageId(message_id.parse().ok()?),
+	))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn message_link() {
+		assert_eq!(
+			parse_message_link("https://discord.com/channels/1/2/3"),
+			Some((GuildId(1), ChannelId(2), MessageId(3)))
+		);
+		assert_eq!(
+			parse_message_link("https://ptb.discordapp.com/channels/1/2/3/"),
+			Some((GuildId(1), ChannelId(2), MessageId(3)))
+		);
+		assert_eq!(
+			parse_message_link("https://discord.com/channels/@me/2/3"),
+			None
+		);
+	}
+}
@@ -0,0 +1,580 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! Delivery of notifications by email through a hoster-configured SMTP server.
+
+use std::{collections::HashMap, mem, sync::Mutex, time::Duration};
+
+use anyhow::{anyhow, Context as _, Result};
+use indoc::formatdoc;
+use lettre::{
+	message::{Mailbox, MultiPart},
+	transport::smtp::{authentication::Credentials, Error as SmtpError},
+	Address, AsyncSmtpTransport, AsyncTransport, Message as Email,
+	Tokio1Executor,
+};
+use once_cell::sync::{Lazy, OnceCell};
+use serenity::{
+	client::Context,
+	model::id::{MessageId, UserId},
+};
+use tokio::time::interval;
+use tracing::{debug, error, info_span};
+
+use super::notifier::{NotificationContent, Notifier, NotifyError};
+use crate::{
+	db::{EmailAddress, UserState, UserStateKind},
+	global::EMBED_COLOR,
+	settings::{settings, SmtpSecurity, SmtpSettings},
+};
+
+type Transport = AsyncSmtpTransport<Tokio1Executor>;
+
+/// SMTP transport and sender mailbox used to send emails.
+struct Mailer {
+	transport: Transport,
+	from: Mailbox,
+}
+
+/// Global mailer, if email delivery is configured.
+static MAILER: OnceCell<Mailer> = OnceCell::new();
+
+/// Maximum number of notifications kept for a user's next digest.
+///
+/// Once a digest is full, the oldest notifications are dropped to make room for
+/// new ones.
+const MAX_DIGEST_NOTIFICATIONS: usize = 50;
+
+/// Notifications waiting to be sent in each user's next digest.
+///
+/// Digests are only kept in memory, so any that haven't been sent yet are lost
+/// if the bot restarts.
+static DIGESTS: Lazy<Mutex<HashMap<UserId, Vec<NotificationContent>>>> =
+	Lazy::new(Default::default);
+
+/// Adds notifications to the user's next digest, dropping the oldest ones if
+/// it's full.
+fn queue_digest(
+	user_id: UserId,
+	notifications: impl IntoIterator<Item = NotificationContent>,
+) {
+	let mut digests = DIGESTS.lock().expect("Digests poisoned");
+	let digest = digests.entry(user_id).or_default();
+
+	digest.extend(notifications);
+
+	if let Some(excess) = digest.len().checked_sub(MAX_DIGEST_NOTIFICATIONS) {
+		digest.drain(..excess);
+	}
+}
+
+/// Whether the hoster configured an SMTP server to send emails with.
+pub(crate) fn enabled() -> bool {
+	MAILER.get().is_some()
+}
+
+/// Builds an SMTP transport according to the given settings.
+fn build_transport(smtp: &SmtpSettings) -> Result<Transport> {
+	let mut builder = match smtp.security {
+		SmtpSecurity::StartTls => Transport::starttls_relay(&smtp.host)?,
+		SmtpSecurity::Tls => Transport::relay(&smtp.host)?,
+		SmtpSecurity::None => Transport::builder_dangerous(&smtp.host),
+	};
+
+	if let Some(port) = smtp.port {
+		builder = builder.port(port);
+	}
+
+	if let Some(username) = &smtp.username {
+		builder = builder.credentials(Credentials::new(
+			username.clone(),
+			smtp.password.clone().unwrap_or_default(),
+		));
+	}
+
+	Ok(builder.timeout(Some(Duration::from_secs(30))).build())
+}
+
+/// Initializes the mailer if SMTP is configured.
+pub(crate) fn init() -> Result<()> {
+	let smtp = match &settings().smtp {
+		Some(smtp) => smtp,
+		None => return Ok(()),
+	};
+
+	let mailer = Mailer {
+		transport: build_transport(smtp)
+			.context("Failed to create SMTP transport")?,
+		from: smtp
+			.from
+			.parse()
+			.context("Invalid smtp.from mailbox in configuration")?,
+	};
+
+	let _ = MAILER.set(mailer);
+
+	Ok(())
+}
+
+/// Builds an email with both plain text and HTML bodies.
+fn build_email(
+	from: Mailbox,
+	to: Address,
+	subject: &str,
+	text: String,
+	html: String,
+) -> Result<Email> {
+	Email::builder()
+		.from(from)
+		.to(Mailbox::new(None, to))
+		.subject(subject)
+		.multipart(MultiPart::alternative_plain_html(text, html))
+		.context("Failed to build email")
+}
+
+/// Sends an email to the given address using the global mailer.
+async fn send_email(
+	to: &str,
+	subject: &str,
+	text: String,
+	html: String,
+) -> Result<(), NotifyError> {
+	let mailer = MAILER
+		.get()
+		.ok_or_else(|| anyhow!("Email delivery is not configured"))?;
+
+	let to = to
+		.parse::<Address>()
+		.map_err(|_| NotifyError::Undeliverable)?;
+
+	let email = build_email(mailer.from.clone(), to, subject, text, html)?;
+
+	mailer
+		.transport
+		.send(email)
+		.await
+		.map(drop)
+		.map_err(classify_smtp_error)
+}
+
+/// Converts an error from the SMTP server into a [`NotifyError`].
+fn classify_smtp_error(e: SmtpError) -> NotifyError {
+	if e.is_permanent() {
+		NotifyError::Undeliverable
+	} else if e.is_transient() || e.is_timeout() {
+		NotifyError::Transient(e.into())
+	} else {
+		NotifyError::Other(
+			anyhow::Error::from(e).context("Failed to send email"),
+		)
+	}
+}
+
+/// Sends a code to the given address to verify the user owns it.
+pub(crate) async fn send_verification_code(
+	address: &str,
+	code: &str,
+) -> Result<(), NotifyError> {
+	let text = formatdoc!(
+		"
+		Your verification code is {code}
+
+		Use /email code:{code} on Discord to start receiving highlights \
+		notifications at this address.
+
+		If you didn't request this, you can ignore this email."
+	);
+
+	let html = formatdoc!(
+		r#"
+		<p>Your verification code is <strong>{code}</strong></p>
+		<p>Use <code>/email code:{code}</code> on Discord to start receiving
+		highlights notifications at this address.</p>
+		<p>If you didn't request this, you can ignore this email.</p>"#
+	);
+
+	send_email(address, "Your highlights verification code", text, html).await
+}
+
+/// Escapes text for inclusion in HTML.
+fn escape_html(text: &str) -> String {
+	let mut escaped = String::with_capacity(text.len());
+
+	for c in text.chars() {
+		match c {
+			'&' => escaped.push_str("&amp;"),
+			'<' => escaped.push_str("&lt;"),
+			'>' => escaped.push_str("&gt;"),
+			'"' => escaped.push_str("&quot;"),
+			'\'' => escaped.push_str("&#39;"),
+			c => escaped.push(c),
+		}
+	}
+
+	escaped
+}
+
+/// Renders a notification as plain text, with the same content as its embed.
+fn notification_text(notification: &NotificationContent) -> String {
+	formatdoc!(
+		"
+		{title}
+
+		{content}
+
+		{author} in #{channel} ({guild}) at {timestamp}
+		{url}",
+		title = notification.title,
+		content = notification.content,
+		author = notification.author_name,
+		channel = notification.channel_name,
+		guild = notification.guild_name,
+		timestamp = notification.timestamp,
+		url = notification.message_url(),
+	)
+}
+
+/// Renders a notification as HTML, with the same content as its embed.
+fn notification_html(notification: &NotificationContent) -> String {
+	let guild_icon = match &notification.guild_icon {
+		Some(url) => format!(
+			r#"<img src="{}" alt="" width="20" height="20" style="vertical-align:middle;border-radius:50%"> "#,
+			escape_html(url)
+		),
+		None => String::new(),
+	};
+
+	formatdoc!(
+		r#"
+		<div style="border-left:4px solid #{color:06x};padding:8px 12px;margin:8px 0;font-family:sans-serif">
+		<p style="font-weight:bold">{guild_icon}{title}</p>
+		<p style="white-space:pre-wrap">{content}</p>
+		<p style="color:#666;font-size:small"><img src="{avatar}" alt="" width="16" height="16" style="vertical-align:middle;border-radius:50%"> {author} in #{channel} ({guild}) at {timestamp} &middot; <a href="{url}">Jump to message</a></p>
+		</div>"#,
+		color = EMBED_COLOR,
+		title = escape_html(&notification.title),
+		content = escape_html(&notification.content),
+		avatar = escape_html(&notification.author_avatar),
+		author = escape_html(&notification.author_name),
+		channel = escape_html(&notification.channel_name),
+		guild = escape_html(&notification.guild_name),
+		timestamp = notification.timestamp,
+		url = escape_html(&notification.message_url()),
+	)
+}
+
+/// Sends a digest of the given notifications to the given address.
+async fn send_digest(
+	address: &str,
+	notifications: &[NotificationContent],
+) -> Result<(), NotifyError> {
+	let subject = match notifications.len() {
+		1 => "1 new highlight".to_owned(),
+		n => format!("{n} new highlights"),
+	};
+
+	let text = notifications
+		.iter()
+		.map(notification_text)
+		.collect::<Vec<_>>()
+		.join("\n\n---\n\n");
+
+	let html = notifications
+		.iter()
+		.map(notification_html)
+		.collect::<Vec<_>>()
+		.join("\n");
+
+	send_email(address, &subject, text, html).await
+}
+
+/// Sends every pending digest.
+async fn flush_digests() {
+	let digests = mem::take(&mut *DIGESTS.lock().expect("Digests poisoned"));
+
+	for (user_id, notifications) in digests {
+		let result = async {
+			let address = match EmailAddress::verified_email(user_id).await? {
+				Some(email) => email.address,
+				None => return Err(NotifyError::Undeliverable),
+			};
+
+			send_digest(&address, &notifications).await
+		}
+		.await;
+
+		let result = match result {
+			Ok(()) => UserState::clear(user_id).await,
+			Err(NotifyError::Undeliverable) => {
+				UserState {
+					user_id,
+					state: UserStateKind::EmailFailed,
+				}
+				.set()
+				.await
+			}
+			Err(NotifyError::Transient(e)) => {
+				// try again with the next digest; newer notifications go after
+				// these
+				let newer = DIGESTS
+					.lock()
+					.expect("Digests poisoned")
+					.remove(&user_id)
+					.unwrap_or_default();
+				queue_digest(user_id, notifications.into_iter().chain(newer));
+				Err(e)
+			}
+			Err(NotifyError::Other(e)) => Err(e),
+		};
+
+		if let Err(e) = result {
+			error!("Failed to send digest to {user_id}: {e:?}");
+		}
+	}
+}
+
+/// Starts periodically sending digest emails, if email delivery is enabled.
+pub(crate) fn start_digests() {
+	let smtp = match (&settings().smtp, enabled()) {
+		(Some(smtp), true) => smtp,
+		_ => return,
+	};
+
+	debug!("Starting email digests");
+	tokio::spawn(async move {
+		let span = info_span!(parent: None, "email_digests");
+		let _entered = span.enter();
+		let mut timer = interval(smtp.digest_interval);
+		// the first tick completes immediately
+		timer.tick().await;
+		loop {
+			timer.tick().await;
+			flush_digests().await;
+		}
+	});
+}
+
+/// Delivers notifications by email to the user's verified address.
+///
+/// Emails can't be edited, so notifications sent this way are never updated
+/// or cleared.
+pub(crate) struct EmailNotifier {
+	/// Whether to queue notifications for the next digest instead of sending
+	/// them immediately.
+	pub(crate) digest: bool,
+}
+
+#[serenity::async_trait]
+impl Notifier for EmailNotifier {
+	async fn send(
+		&self,
+		_: &Context,
+		user_id: UserId,
+		notification: &NotificationContent,
+	) -> Result<Option<MessageId>, NotifyError> {
+		if !enabled() {
+			return Err(NotifyError::Undeliverable);
+		}
+
+		let address = match EmailAddress::verified_email(user_id).await? {
+			Some(email) => email.address,
+			None => return Err(NotifyError::Undeliverable),
+		};
+
+		if self.digest {
+			queue_digest(user_id, [notification.clone()]);
+
+			return Ok(None);
+		}
+
+		send_email(
+			&address,
+			&notification.title,
+			notification_text(notification),
+			notification_html(notification),
+		)
+		.await
+		.map(|()| None)
+	}
+
+	async fn edit(
+		&self,
+		_: &Context,
+		_: UserId,
+		_: MessageId,
+		_: &NotificationContent,
+	) -> Result<()> {
+		Ok(())
+	}
+
+	async fn clear(
+		&self,
+		_: &Context,
+		_: UserId,
+		_: MessageId,
+		_: &str,
+	) -> Result<()> {
+		Ok(())
+	}
+
+	fn failure_state(&self) -> UserStateKind {
+		UserStateKind::EmailFailed
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use serenity::model::{
+		id::{ChannelId, GuildId},
+		Timestamp,
+	};
+	use tokio::{
+		io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+		net::TcpListener,
+		sync::oneshot,
+	};
+
+	use super::*;
+	use crate::bot::i18n::default_locale;
+
+	fn notification() -> NotificationContent {
+		NotificationContent {
+			keywords: vec!["rust".to_owned()],
+			title: "Keyword \"rust\" seen in #general (Test Server)".to_owned(),
+			guild_id: GuildId(1),
+			guild_name: "Test Server".to_owned(),
+			guild_icon: None,
+			channel_id: ChannelId(2),
+			channel_name: "general".to_owned(),
+			message_id: MessageId(3),
+			content: "I <3 rust & crabs".to_owned(),
+			author_name: "ferris".to_owned(),
+			author_avatar: "https://cdn.discordapp.com/embed/avatars/0.png"
+				.to_owned(),
+			timestamp: Timestamp::from_unix_timestamp(1_700_000_000).unwrap(),
+			locale: default_locale(),
+		}
+	}
+
+	/// Accepts one SMTP connection and returns the data of the first email
+	/// sent over it.
+	async fn smtp_sink(listener: TcpListener) -> String {
+		let (stream, _) = listener.accept().await.unwrap();
+		let (read, mut write) = stream.into_split();
+		let mut lines = BufReader::new(read).lines();
+
+		write.write_all(b"220 sink ESMTP\r\n").await.unwrap();
+
+		let mut data = String::new();
+		let mut in_data = false;
+
+		while let Some(line) = lines.next_line().await.unwrap() {
+			if in_data {
+				if line == "." {
+					write.write_all(b"250 OK\r\n").await.unwrap();
+					return data;
+				}
+				data.push_str(&line);
+				data.push('\n');
+				continue;
+			}
+
+			let reply: &[u8] = match &line.to_ascii_uppercase()[..4] {
+				"EHLO" | "HELO" => b"250 sink\r\n",
+				"DATA" => {
+					in_data = true;
+					b"354 Go ahead\r\n"
+				}
+				"QUIT" => b"221 Bye\r\n",
+				_ => b"250 OK\r\n",
+			};
+			write.write_all(reply).await.unwrap();
+		}
+
+		data
+	}
+
+	#[test]
+	fn html_escaping() {
+		assert_eq!(
+			escape_html(r#"<a href="x">'&'</a>"#),
+			"&lt;a href=&quot;x&quot;&gt;&#39;&amp;&#39;&lt;/a&gt;"
+		);
+	}
+
+	#[test]
+	fn digests_are_bounded() {
+		let user_id = UserId(4);
+
+		for i in 0..MAX_DIGEST_NOTIFICATIONS + 5 {
+			let mut notification = notification();
+			notification.message_id = MessageId(i as u64);
+			queue_digest(user_id, [notification]);
+		}
+
+		let digest = DIGESTS
+			.lock()
+			.expect("Digests poisoned")
+			.remove(&user_id)
+			.expect("No digest queued");
+
+		assert_eq!(digest.len(), MAX_DIGEST_NOTIFICATIONS);
+		assert_eq!(digest[0].message_id, MessageId(5));
+	}
+
+	#[test]
+	fn notification_renderings() {
+		let notification = notification();
+
+		let text = notification_text(&notification);
+		assert!(text.starts_with(&notification.title));
+		assert!(text.contains("I <3 rust & crabs"));
+		assert!(text.contains("https://discord.com/channels/1/2/3"));
+
+		let html = notification_html(&notification);
+		assert!(html.contains("I &lt;3 rust &amp; crabs"));
+		assert!(html.contains("ferris in #general (Test Server)"));
+		assert!(html.contains(r#"href="https://discord.com/channels/1/2/3""#));
+	}
+
+	#[tokio::test]
+	async fn send_to_smtp_sink() {
+		let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+		let port = listener.local_addr().unwrap().port();
+
+		let (tx, rx) = oneshot::channel();
+		tokio::spawn(async move {
+			let _ = tx.send(smtp_sink(listener).await);
+		});
+
+		let transport = build_transport(&SmtpSettings {
+			host: "127.0.0.1".to_owned(),
+			port: Some(port),
+			username: None,
+			password: None,
+			from: "Highlights <bot@example.com>".to_owned(),
+			security: SmtpSecurity::None,
+			digest_interval: Duration::from_secs(60),
+		})
+		.unwrap();
+
+		let notification = notification();
+		let email = build_email(
+			"Highlights <bot@example.com>".parse().unwrap(),
+			"user@example.com".parse().unwrap(),
+			&notification.title,
+			notification_text(&notification),
+			notification_html(&notification),
+		)
+		.unwrap();
+
+		transport.send(email).await.unwrap();
+
+		let data = rx.await.unwrap();
+		assert!(data.contains("To: user@example.com"));
+		assert!(data.contains("Content-Type: multipart/alternative"));
+		assert!(data.contains("Content-Type: text/plain"));
+		assert!(data.contains("Content-Type: text/html"));
+		assert!(data.contains("rust"));
+	}
+}
@@ -6,25 +6,22 @@
 use std::{
 	cmp::min,
 	collections::HashMap,
-	fmt::Write as _,
 	ops::Range,
-	time::{Duration, SystemTime, UNIX_EPOCH},
+	time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
 
 use anyhow::{anyhow, bail, Context as _, Error, Result};
 use futures_util::{
 	stream, stream::FuturesUnordered, StreamExt, TryFutureExt, TryStreamExt,
 };
-use indoc::indoc;
 use lazy_regex::regex;
 use serenity::{
-	builder::{CreateEmbed, CreateMessage, EditMessage},
 	client::Context,
 	http::{error::ErrorResponse, HttpError, StatusCode},
 	model::{
 		application::interaction::application_command::ApplicationCommandInteraction as Command,
-		channel::{Channel, Message},
-		id::{GuildId, MessageId, UserId},
+		channel::{Channel, ChannelType, Message, ReactionType},
+		id::{ChannelId, GuildId, MessageId, UserId},
 	},
 	prelude::TypeMapKey,
 	Error as SerenityError,
@@ -37,9 +34,23 @@ use tokio::{
 use tracing::{debug, error, info_span};
 
 use crate::{
-	bot::util::{followup_eph, user_can_read_channel},
-	db::{Ignore, Keyword, Notification, UserState, UserStateKind},
-	global::{DISCORD_EPOCH, EMBED_COLOR, ERROR_COLOR, NOTIFICATION_RETRIES},
+	bot::{
+		i18n::{locale, user_locale, Locale},
+		notifier::{
+			notifier, NotificationContent, Notifier, NotifyError,
+			ThreadNotifier,
+		},
+		stats::{self, KeywordEvent},
+		util::{
+			followup_eph, guild_channel, optional_result, permission_channel,
+			user_can_read_channel,
+		},
+	},
+	db::{
+		Delivery, FallbackChannel, FallbackThread, Ignore, Keyword,
+		Notification, NotificationTrigger, UserState, UserStateKind,
+	},
+	global::{DISCORD_EPOCH, NOTIFICATION_RETRIES},
 	settings::settings,
 };
 
@@ -52,15 +63,41 @@ impl TypeMapKey for CachedMessages {
 	type Value = HashMap<MessageId, String>;
 }
 
+/// The result of checking if a keyword should be highlighted in a message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum KeywordCheck {
+	/// The keyword should be highlighted.
+	Notify,
+	/// The message is older than the configured notification lifetime.
+	TooOld,
+	/// The message mentions the user, so they'll be notified anyway.
+	Mentioned,
+	/// The keyword doesn't appear in the message.
+	NoMatch,
+	/// The keyword appears in the message, but so does this ignored phrase.
+	Ignored(String),
+	/// The user can't read the channel of the message.
+	NoPermission,
+}
+
+impl KeywordCheck {
+	/// Whether the keyword appeared in a message the user can read,
+	/// regardless of whether it should be highlighted.
+	pub(crate) fn matched(&self) -> bool {
+		matches!(self, Self::Notify | Self::Ignored(_))
+	}
+}
+
 /// Checks if the provided keyword should be highlighted anywhere in the given
 /// message.
 ///
-/// First each [`Ignore`] is checked to determine if it appears in the message.
-/// If any do appear, then the keyword shouldn't be highlighted and `Ok
-/// (false)` is returned. Next, the keyword is similarly searched for in the
-/// message content. If it is found, the permissions of the user are checked to
-/// ensure they can read the message. If they can read the message, `Ok
-/// (true)` is returned.
+/// First the age of the message and mentions of the user are checked. Next,
+/// the keyword is searched for in the message content. If it is found, the
+/// permissions of the user are checked to ensure they can read the message,
+/// and then each [`Ignore`] that applies to the keyword in the message's
+/// channel is checked to determine if it appears in the message. If none do,
+/// `Ok(KeywordCheck::Notify)` is returned; otherwise, the reason the keyword
+/// shouldn't be highlighted is returned.
 ///
 /// `Err(e)` is returned if an error occurs checking permissions, or if the
 /// message did not originate in a guild channel.
@@ -73,76 +110,188 @@ impl TypeMapKey for CachedMessages {
 		channel_id = %message.channel_id,
 	)
 )]
-pub(crate) async fn should_notify_keyword(
+pub(crate) async fn check_keyword(
 	ctx: &Context,
 	message: &Message,
 	content: &str,
 	keyword: &Keyword,
 	ignores: &[Ignore],
-) -> Result<bool> {
+) -> Result<KeywordCheck> {
 	if let Some(lifetime) = settings().behavior.notification_lifetime {
 		let creation = (message.id.0 >> 22) + DISCORD_EPOCH;
 		let now =
 			SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis() as u64;
 		let age = Duration::from_millis(now.saturating_sub(creation));
 		if age > lifetime {
-			return Ok(false);
+			return Ok(KeywordCheck::TooOld);
 		}
 	}
 
-	if message
+	let mentioned = message
 		.mentions
 		.iter()
-		.any(|mention| mention.id == keyword.user_id)
-	{
+		.any(|mention| mention.id == keyword.user_id);
+
+	check_keyword_in(
+		ctx,
+		message.channel_id,
+		mentioned,
+		content,
+		keyword,
+		ignores,
+	)
+	.await
+}
+
+/// Checks if the provided keyword should be highlighted in a message with the
+/// given content sent in the given channel, which mentions the user if
+/// `mentioned` is true.
+///
+/// Performs the same checks as [`check_keyword`], except for the age of the
+/// message, so text that wasn't sent can be tested.
+pub(crate) async fn check_keyword_in(
+	ctx: &Context,
+	channel_id: ChannelId,
+	mentioned: bool,
+	content: &str,
+	keyword: &Keyword,
+	ignores: &[Ignore],
+) -> Result<KeywordCheck> {
+	if mentioned {
 		debug!("Message mentions user, not processing keyword");
-		return Ok(false);
+		return Ok(KeywordCheck::Mentioned);
 	}
 
-	for ignore in ignores {
-		if keyword_matches(&ignore.phrase, content) {
-			debug!("Matching ignore found, not processing keyword");
-			return Ok(false);
-		}
+	let check =
+		check_keyword_content(&keyword.keyword, channel_id, content, ignores);
+
+	if check == KeywordCheck::NoMatch {
+		return Ok(check);
 	}
 
-	if !keyword_matches(&keyword.keyword, content) {
-		debug!("Keyword didn't match");
-		return Ok(false);
+	if !user_can_read_in(ctx, channel_id, keyword.user_id).await? {
+		return Ok(KeywordCheck::NoPermission);
 	}
 
-	let channel = match ctx.cache.guild_channel(message.channel_id) {
+	Ok(check)
+}
+
+/// Checks if the given user can read the channel the given message was sent
+/// in.
+///
+/// `Err(e)` is returned if an error occurs checking permissions, or if the
+/// message did not originate in a guild channel.
+pub(crate) async fn user_can_read_message(
+	ctx: &Context,
+	message: &Message,
+	user_id: UserId,
+) -> Result<bool> {
+	user_can_read_in(ctx, message.channel_id, user_id).await
+}
+
+/// Checks if the given user can read the given channel.
+///
+/// `Err(e)` is returned if an error occurs checking permissions, or if the
+/// channel isn't a guild channel.
+async fn user_can_read_in(
+	ctx: &Context,
+	channel_id: ChannelId,
+	user_id: UserId,
+) -> Result<bool> {
+	let channel = match ctx.cache.guild_channel(channel_id) {
 		Some(c) => c,
-		None => match ctx.http.get_channel(message.channel_id.0).await? {
+		None => match ctx.http.get_channel(channel_id.0).await? {
 			Channel::Guild(c) => c,
 			_ => {
 				return Err(anyhow!(
 					"Channel {} wasn't a guild channel",
-					message.channel_id
+					channel_id
 				))
 			}
 		},
 	};
 
-	match user_can_read_channel(ctx, &channel, keyword.user_id).await {
+	match user_can_read_channel(ctx, &channel, user_id).await {
 		Ok(Some(true)) => Ok(true),
 		Ok(Some(false)) | Ok(None) => Ok(false),
 		Err(e) => Err(e).context("Failed to check permissions"),
 	}
 }
 
+/// Checks if the provided keyword should be highlighted in the given message
+/// content, considering only the content itself.
+///
+/// Returns [`KeywordCheck::NoMatch`] if the keyword doesn't appear,
+/// [`KeywordCheck::Ignored`] if it does but so does one of the ignored
+/// phrases that apply to it in the given channel, and [`KeywordCheck::Notify`]
+/// otherwise.
+pub(crate) fn check_keyword_content(
+	keyword: &str,
+	channel_id: ChannelId,
+	content: &str,
+	ignores: &[Ignore],
+) -> KeywordCheck {
+	if !keyword_matches(keyword, content) {
+		debug!("Keyword didn't match");
+		return KeywordCheck::NoMatch;
+	}
+
+	for ignore in ignores
+		.iter()
+		.filter(|ignore| ignore.applies(channel_id, keyword))
+	{
+		if keyword_matches(&ignore.phrase, content) {
+			debug!("Matching ignore found, not processing keyword");
+			return KeywordCheck::Ignored(ignore.phrase.clone());
+		}
+	}
+
+	KeywordCheck::Notify
+}
+
+/// Waits for the configured patience duration for a message or reaction from
+/// the user in the given channel.
+///
+/// Returns `true` if the user sent a message or reaction in that time.
+async fn await_patience(
+	ctx: &Context,
+	channel_id: ChannelId,
+	user_id: UserId,
+) -> bool {
+	let reply_or_reaction;
+
+	let reply = channel_id
+		.await_reply(ctx)
+		.author_id(user_id)
+		.timeout(settings().behavior.patience);
+
+	let reaction = channel_id
+		.await_reaction(ctx)
+		.author_id(user_id)
+		.timeout(settings().behavior.patience);
+
+	debug!("Waiting for reaction or reply");
+
+	select! {
+		reaction = reaction => reply_or_reaction = reaction.map(|_| ()),
+		reply = reply => reply_or_reaction = reply.map(|_| ()),
+	}
+
+	reply_or_reaction.is_some()
+}
+
 /// Notifies the user about the keyword provided in the message provided.
 ///
 /// This will first wait for the configured patience duration for a message or
 /// reaction from the user of the keyword. If they don't send a message or
-/// reaction in that time, then an embed is created to notify them and sent in
-/// a DM channel.
+/// reaction in that time, then a notification is created and sent using their
+/// chosen delivery method.
 ///
 /// Uses [`CachedMessages`] to determine if a message was edited or deleted
 /// while waiting to send a notification.
 ///
-/// Uses [`build_notification_message`] to build the notification message to
-/// send, and uses [`send_notification_message`] to send the message.
+/// Uses [`build_notification_content`] to build the notification to send, and
+/// uses [`send_notification_message`] to send it.
 #[tracing::instrument(
 	skip_all,
 	fields(
@@ -168,28 +317,17 @@ pub(crate) async fn notify_keywords(
 		.expect("No message cache")
 		.insert(message.id, message.content.clone());
 
-	let reply_or_reaction;
-
-	let reply = message
-		.channel_id
-		.await_reply(&ctx)
-		.author_id(user_id)
-		.timeout(settings().behavior.patience);
-
-	let reaction = message
-		.channel_id
-		.await_reaction(&ctx)
-		.author_id(user_id)
-		.timeout(settings().behavior.patience);
-
-	debug!("Waiting for reaction or reply");
-
-	select! {
-		reaction = reaction => reply_or_reaction = reaction.map(|_| ()),
-		reply = reply => reply_or_reaction = reply.map(|_| ()),
-	}
-
-	if reply_or_reaction.is_none() {
+	if await_patience(&ctx, message.channel_id, user_id).await {
+		debug!("Recipient interacted within patience duration");
+		for keyword in &keywords {
+			stats::record(
+				user_id,
+				guild_id,
+				&keyword.keyword,
+				KeywordEvent::Patience,
+			);
+		}
+	} else {
 		debug!("Recipient did not interact within patience duration");
 		let result: Result<()> = async {
 			let content = match ctx
@@ -214,15 +352,17 @@ pub(crate) async fn notify_keywords(
 			let keywords = stream::iter(keywords)
 				.map(Ok::<_, Error>) // convert to a TryStream
 				.try_filter_map(|keyword| async {
-					Ok(should_notify_keyword(
+					let check = check_keyword(
 						&ctx,
 						&message,
 						&message.content.to_lowercase(),
 						&keyword,
 						&ignores,
 					)
-					.await?
-					.then_some(keyword.keyword))
+					.await?;
+
+					Ok((check == KeywordCheck::Notify)
+						.then_some(keyword.keyword))
 				})
 				.try_collect::<TinyVec<[String; 2]>>()
 				.await?;
@@ -232,15 +372,21 @@ pub(crate) async fn notify_keywords(
 				return Ok(());
 			}
 
-			let message_to_send =
-				build_notification_message(&ctx, &message, &keywords, guild_id)
-					.await?;
+			let notification = build_notification_content(
+				&ctx,
+				&message,
+				&keywords,
+				guild_id,
+				user_locale(user_id).await?,
+			)
+			.await?;
 
 			send_notification_message(
 				&ctx,
 				user_id,
 				message.id,
-				message_to_send,
+				notification,
+				NotificationTrigger::Keyword,
 				keywords,
 			)
 			.await
@@ -253,65 +399,544 @@ pub(crate) async fn notify_keywords(
 	}
 }
 
-/// Builds a notification message.
+/// [`TypeMapKey`] for [`Client::data`](serenity::client::Client::data) to
+/// remember when users were last notified about each user they follow, so
+/// [`notify_follow`] can rate limit notifications.
+pub(crate) struct FollowCooldowns;
+
+impl TypeMapKey for FollowCooldowns {
+	type Value = HashMap<(UserId, UserId), Instant>;
+}
+
+/// Notifies the user about a message by a user they follow.
 ///
-/// Uses [`build_notification_embed`] to create the embed to include in the
-/// message.
-async fn build_notification_message(
-	ctx: &Context,
-	message: &Message,
-	keywords: &[String],
+/// Nothing is sent if the user was notified about the same followed user
+/// within the configured follow cooldown, if the message mentions the user,
+/// or if the user can't read the channel of the message.
+#[tracing::instrument(
+	skip_all,
+	fields(
+		author_id = %message.author.id,
+		recipient_id = %user_id,
+		message_id = %message.id,
+		channel_id = %message.channel_id,
+		guild_id = %guild_id,
+	)
+)]
+pub(crate) async fn notify_follow(
+	ctx: Context,
+	message: Message,
+	user_id: UserId,
 	guild_id: GuildId,
-) -> Result<CreateMessage<'static>> {
-	let embed =
-		build_notification_embed(ctx, message, keywords, guild_id).await?;
+) {
+	let result: Result<()> = async {
+		if message.mentions.iter().any(|mention| mention.id == user_id) {
+			debug!("Message mentions follower, not notifying");
+			return Ok(());
+		}
 
-	let mut msg = CreateMessage::default();
+		let key = (user_id, message.author.id);
+		let cooldown = settings().behavior.follow_cooldown;
 
-	msg.embed(|e| {
-		*e = embed;
-		e
-	});
+		let cooling_down = ctx
+			.data
+			.read()
+			.await
+			.get::<FollowCooldowns>()
+			.expect("No follow cooldowns")
+			.get(&key)
+			.is_some_and(|last| last.elapsed() < cooldown);
+
+		if cooling_down {
+			debug!("Follower was notified recently, not notifying");
+			return Ok(());
+		}
+
+		let channel = match ctx.cache.guild_channel(message.channel_id) {
+			Some(c) => c,
+			None => match ctx.http.get_channel(message.channel_id.0).await? {
+				Channel::Guild(c) => c,
+				_ => {
+					return Err(anyhow!(
+						"Channel {} wasn't a guild channel",
+						message.channel_id
+					))
+				}
+			},
+		};
+
+		match user_can_read_channel(&ctx, &channel, user_id).await {
+			Ok(Some(true)) => {}
+			Ok(Some(false)) | Ok(None) => {
+				debug!("Follower can't read channel, not notifying");
+				return Ok(());
+			}
+			Err(e) => return Err(e).context("Failed to check permissions"),
+		}
+
+		ctx.data
+			.write()
+			.await
+			.get_mut::<FollowCooldowns>()
+			.expect("No follow cooldowns")
+			.insert(key, Instant::now());
+
+		let locale = user_locale(user_id).await?;
+
+		let mut notification =
+			build_notification_content(&ctx, &message, &[], guild_id, locale)
+				.await?;
+
+		notification.title = locale.format(
+			"notification-follow",
+			&[
+				("author", &message.author.name),
+				("channel", &notification.channel_name),
+				("server", &notification.guild_name),
+			],
+		);
+
+		send_notification_message(
+			&ctx,
+			user_id,
+			message.id,
+			notification,
+			NotificationTrigger::Follow,
+			TinyVec::new(),
+		)
+		.await
+	}
+	.await;
 
-	Ok(msg)
+	if let Err(error) = result {
+		error!("{:?}", error);
+	}
 }
 
-/// Builds a notification message edit to update a notification for an edited
-/// message.
+/// The state of notifications about one watched channel or thread for one
+/// user.
+#[derive(Debug, Default)]
+pub(crate) struct WatchBurst {
+	/// When the user was last notified about the channel, if at all.
+	last_notified: Option<Instant>,
+	/// The number of messages waiting to be summarized in the next
+	/// notification, or zero if no notification is pending.
+	pending: u32,
+	/// The latest message waiting to be summarized in the next notification.
+	latest: Option<MessageId>,
+}
+
+/// [`TypeMapKey`] for [`Client::data`](serenity::client::Client::data) to
+/// track notifications about watched channels, so [`notify_watch`] can rate
+/// limit them and coalesce bursts of messages.
+pub(crate) struct WatchBursts;
+
+impl TypeMapKey for WatchBursts {
+	type Value = HashMap<(UserId, ChannelId), WatchBurst>;
+}
+
+/// Notifies the user about a message in a channel or thread they watch.
 ///
-/// Uses [`build_notification_embed`] to create the embed to include in the
-/// message.
-async fn build_notification_edit(
+/// If a notification about the channel is already pending, the message is
+/// added to it instead. Otherwise, a notification is sent once the configured
+/// watch interval has passed since the last one and the user hasn't sent a
+/// message or reaction in the channel within the patience duration. Messages
+/// sent in the channel while waiting are summarized in the same notification.
+///
+/// Nothing is sent if the user can't read the channel, or if the only message
+/// was deleted or mentions the user.
+#[tracing::instrument(
+	skip_all,
+	fields(
+		author_id = %message.author.id,
+		recipient_id = %user_id,
+		message_id = %message.id,
+		channel_id = %message.channel_id,
+		guild_id = %guild_id,
+	)
+)]
+pub(crate) async fn notify_watch(
+	ctx: Context,
+	message: Message,
+	user_id: UserId,
+	guild_id: GuildId,
+) {
+	let key = (user_id, message.channel_id);
+	let interval = settings().behavior.watch_interval;
+
+	let wait = {
+		let mut data = ctx.data.write().await;
+		let burst = data
+			.get_mut::<WatchBursts>()
+			.expect("No watch bursts")
+			.entry(key)
+			.or_default();
+
+		burst.latest = Some(message.id);
+
+		if burst.pending > 0 {
+			debug!("Adding message to pending watch notification");
+			burst.pending += 1;
+			return;
+		}
+
+		burst.pending = 1;
+
+		burst.last_notified.map_or(Duration::ZERO, |last| {
+			interval.saturating_sub(last.elapsed())
+		})
+	};
+
+	if !wait.is_zero() {
+		debug!("Waiting for watch interval to pass");
+		sleep(wait).await;
+	}
+
+	let interacted = await_patience(&ctx, message.channel_id, user_id).await;
+
+	let (count, latest) = {
+		let mut data = ctx.data.write().await;
+		let burst = data
+			.get_mut::<WatchBursts>()
+			.expect("No watch bursts")
+			.entry(key)
+			.or_default();
+
+		if !interacted {
+			burst.last_notified = Some(Instant::now());
+		}
+
+		(
+			std::mem::take(&mut burst.pending),
+			burst.latest.take().unwrap_or(message.id),
+		)
+	};
+
+	if interacted {
+		debug!("Recipient interacted within patience duration");
+		return;
+	}
+
+	let result: Result<()> = async {
+		if count == 1
+			&& message.mentions.iter().any(|mention| mention.id == user_id)
+		{
+			debug!("Message mentions watcher, not notifying");
+			return Ok(());
+		}
+
+		if !recipient_can_read(&ctx, message.channel_id, user_id).await? {
+			return Ok(());
+		}
+
+		// notify about the first message of the burst, or the latest if the
+		// first was deleted
+		let mut current = None;
+		for message_id in [message.id, latest] {
+			current = optional_result(
+				ctx.http
+					.get_message(message.channel_id.0, message_id.0)
+					.await,
+			)?;
+			if current.is_some() {
+				break;
+			}
+		}
+
+		let current = match current {
+			Some(current) => current,
+			None => {
+				debug!("Watched messages were deleted, not notifying");
+				return Ok(());
+			}
+		};
+
+		let locale = user_locale(user_id).await?;
+
+		let mut notification =
+			build_notification_content(&ctx, &current, &[], guild_id, locale)
+				.await?;
+
+		notification.title = if count == 1 {
+			locale.format(
+				"notification-watch",
+				&[
+					("channel", &notification.channel_name),
+					("server", &notification.guild_name),
+				],
+			)
+		} else {
+			locale.format(
+				"notification-watch-burst",
+				&[
+					("count", &count),
+					("channel", &notification.channel_name),
+					("server", &notification.guild_name),
+				],
+			)
+		};
+
+		send_notification_message(
+			&ctx,
+			user_id,
+			current.id,
+			notification,
+			NotificationTrigger::Watch,
+			TinyVec::new(),
+		)
+		.await
+	}
+	.await;
+
+	if let Err(error) = result {
+		error!("{:?}", error);
+	}
+}
+
+/// Gets the key reaction alerts for the given emoji are stored with: the
+/// emoji itself if it's a unicode emoji, or its ID if it's a custom emoji.
+pub(crate) fn emoji_key(emoji: &ReactionType) -> Option<String> {
+	match emoji {
+		ReactionType::Unicode(emoji) => Some(emoji.clone()),
+		ReactionType::Custom { id, .. } => Some(id.to_string()),
+		_ => None,
+	}
+}
+
+/// Checks if the user can read the given channel or thread.
+async fn recipient_can_read(
+	ctx: &Context,
+	channel_id: ChannelId,
+	user_id: UserId,
+) -> Result<bool> {
+	let channel = guild_channel(ctx, channel_id).await?.with_context(|| {
+		format!("Channel {} wasn't a guild channel", channel_id)
+	})?;
+	let channel = permission_channel(ctx, channel).await?;
+
+	match user_can_read_channel(ctx, &channel, user_id).await {
+		Ok(Some(true)) => Ok(true),
+		Ok(Some(false)) | Ok(None) => {
+			debug!("Recipient can't read channel, not notifying");
+			Ok(false)
+		}
+		Err(e) => Err(e).context("Failed to check permissions"),
+	}
+}
+
+/// Checks that the user can read the channel of the given message and that
+/// the message still exists, fetching its current version.
+///
+/// Returns `None` if the user can't read the channel or the message was
+/// deleted.
+async fn readable_message(
 	ctx: &Context,
 	message: &Message,
-	keywords: &[String],
+	user_id: UserId,
+) -> Result<Option<Message>> {
+	if !recipient_can_read(ctx, message.channel_id, user_id).await? {
+		return Ok(None);
+	}
+
+	optional_result(
+		ctx.http
+			.get_message(message.channel_id.0, message.id.0)
+			.await,
+	)
+	.context("Failed to fetch message")
+}
+
+/// Notifies the user about a reply to one of their messages that doesn't
+/// mention them.
+///
+/// Like keywords, nothing is sent if the user sends a message or reaction in
+/// the channel within the patience duration, or if the reply was deleted or
+/// the user can't read the channel.
+#[tracing::instrument(
+	skip_all,
+	fields(
+		author_id = %message.author.id,
+		recipient_id = %user_id,
+		message_id = %message.id,
+		channel_id = %message.channel_id,
+		guild_id = %guild_id,
+	)
+)]
+pub(crate) async fn notify_reply(
+	ctx: Context,
+	message: Message,
+	user_id: UserId,
 	guild_id: GuildId,
-) -> Result<EditMessage<'static>> {
-	let embed =
-		build_notification_embed(ctx, message, keywords, guild_id).await?;
+) {
+	if await_patience(&ctx, message.channel_id, user_id).await {
+		debug!("Recipient interacted within patience duration");
+		return;
+	}
+
+	let result: Result<()> = async {
+		let message = match readable_message(&ctx, &message, user_id).await? {
+			Some(message) => message,
+			None => return Ok(()),
+		};
+
+		let locale = user_locale(user_id).await?;
+
+		let mut notification =
+			build_notification_content(&ctx, &message, &[], guild_id, locale)
+				.await?;
 
-	let mut msg = EditMessage::default();
+		notification.title = locale.format(
+			"notification-reply",
+			&[
+				("author", &message.author.name),
+				("channel", &notification.channel_name),
+				("server", &notification.guild_name),
+			],
+		);
+
+		send_notification_message(
+			&ctx,
+			user_id,
+			message.id,
+			notification,
+			NotificationTrigger::Reply,
+			TinyVec::new(),
+		)
+		.await
+	}
+	.await;
+
+	if let Err(error) = result {
+		error!("{:?}", error);
+	}
+}
 
-	msg.embed(|e| {
-		*e = embed;
-		e
-	});
+/// [`TypeMapKey`] for [`Client::data`](serenity::client::Client::data) to
+/// collect reactions to users' messages while [`notify_reaction`] waits to
+/// send a notification, so they can be sent together.
+///
+/// Each pending notification stores the emoji and reactor names of the
+/// reactions it will include.
+pub(crate) struct ReactionBursts;
 
-	Ok(msg)
+impl TypeMapKey for ReactionBursts {
+	type Value = HashMap<(UserId, MessageId), Vec<(String, String)>>;
 }
 
-/// Builds a notification embed.
+/// Maximum number of reactions listed in one notification.
+const MAX_LISTED_REACTIONS: usize = 5;
+
+/// Notifies the author of a message about a reaction to it.
 ///
-/// Includes:
-/// - List of keywords
-/// - Channel name
-/// - Guild name
-/// - Guild icon
-/// - Message content
-/// - Message link
-/// - Author username
-/// - Author avatar
-/// - Message timestamp
+/// If a notification about reactions to the message is already pending, the
+/// reaction is added to it instead. Otherwise, the notification is sent once
+/// the patience duration has passed, unless the author sent a message or
+/// reaction in the channel in that time.
+#[tracing::instrument(
+	skip_all,
+	fields(
+		recipient_id = %message.author.id,
+		message_id = %message.id,
+		channel_id = %message.channel_id,
+		guild_id = %guild_id,
+	)
+)]
+pub(crate) async fn notify_reaction(
+	ctx: Context,
+	message: Message,
+	emoji: String,
+	reactor_name: String,
+	guild_id: GuildId,
+) {
+	let user_id = message.author.id;
+	let key = (user_id, message.id);
+
+	{
+		let mut data = ctx.data.write().await;
+		let bursts = data
+			.get_mut::<ReactionBursts>()
+			.expect("No reaction bursts");
+
+		if let Some(reactions) = bursts.get_mut(&key) {
+			debug!("Adding reaction to pending notification");
+			reactions.push((emoji, reactor_name));
+			return;
+		}
+
+		bursts.insert(key, vec![(emoji, reactor_name)]);
+	}
+
+	let interacted = await_patience(&ctx, message.channel_id, user_id).await;
+
+	let reactions = ctx
+		.data
+		.write()
+		.await
+		.get_mut::<ReactionBursts>()
+		.expect("No reaction bursts")
+		.remove(&key)
+		.unwrap_or_default();
+
+	if interacted {
+		debug!("Recipient interacted within patience duration");
+		return;
+	}
+
+	let result: Result<()> = async {
+		let message = match readable_message(&ctx, &message, user_id).await? {
+			Some(message) => message,
+			None => return Ok(()),
+		};
+
+		let locale = user_locale(user_id).await?;
+
+		let mut listed = reactions
+			.iter()
+			.take(MAX_LISTED_REACTIONS)
+			.map(|(emoji, name)| format!("{emoji} {name}"))
+			.collect::<Vec<_>>()
+			.join(", ");
+
+		if reactions.len() > MAX_LISTED_REACTIONS {
+			listed.push_str(", …");
+		}
+
+		let mut notification =
+			build_notification_content(&ctx, &message, &[], guild_id, locale)
+				.await?;
+
+		notification.title = locale.format(
+			"notification-reactions",
+			&[
+				("reactions", &listed),
+				("channel", &notification.channel_name),
+				("server", &notification.guild_name),
+			],
+		);
+
+		send_notification_message(
+			&ctx,
+			user_id,
+			message.id,
+			notification,
+			NotificationTrigger::Reaction,
+			TinyVec::new(),
+		)
+		.await
+	}
+	.await;
+
+	if let Err(error) = result {
+		error!("{:?}", error);
+	}
+}
+
+/// Builds the content of a notification.
+///
+/// Looks up the channel and guild names of the message so that the
+/// notification can be rendered by any
+/// [`Notifier`](super::notifier::Notifier). The notification is written in
+/// the given locale, which should be the recipient's.
 #[tracing::instrument(
 	skip_all,
 	fields(
@@ -320,17 +945,13 @@ async fn build_notification_edit(
 		channel_id = %message.channel_id,
 	)
 )]
-async fn build_notification_embed(
+async fn build_notification_content(
 	ctx: &Context,
 	message: &Message,
 	keywords: &[String],
 	guild_id: GuildId,
-) -> Result<CreateEmbed> {
-	let message_link = format!(
-		"[(Link)](https://discord.com/channels/{}/{}/{})",
-		guild_id, message.channel_id, message.id
-	);
-
+	locale: &'static Locale,
+) -> Result<NotificationContent> {
 	let channel_name = match ctx
 		.cache
 		.guild_channel_field(message.channel_id, |c| c.name.clone())
@@ -357,59 +978,68 @@ async fn build_notification_embed(
 		.guild_field(guild_id, |g| (g.name.clone(), g.icon_url()))
 		.context("Couldn't get guild for keyword")?;
 	let title = if keywords.len() == 1 {
-		format!(
-			"Keyword \"{}\" seen in #{} ({})",
-			keywords[0], channel_name, guild_name
+		locale.format(
+			"notification-keyword",
+			&[
+				("keyword", &keywords[0]),
+				("channel", &channel_name),
+				("server", &guild_name),
+			],
 		)
 	} else {
-		let mut iter = keywords.iter();
-		let first = iter.next().unwrap();
-		let mut title =
-			iter.fold(format!("Keywords \"{}\"", first), |mut s, keyword| {
-				write!(s, ", \"{}\"", keyword).unwrap();
-				s
-			});
-
-		write!(title, " seen in #{} ({})", channel_name, guild_name).unwrap();
-		title
+		let keywords = keywords
+			.iter()
+			.map(|keyword| format!("\"{}\"", keyword))
+			.collect::<Vec<_>>()
+			.join(", ");
+
+		locale.format(
+			"notification-keywords",
+			&[
+				("keywords", &keywords),
+				("channel", &channel_name),
+				("server", &guild_name),
+			],
+		)
 	};
-	let channel_mention = format!("<#{}>", message.channel_id);
-
-	let mut embed = CreateEmbed::default();
-
-	embed
-		.description(&message.content)
-		.timestamp(message.timestamp)
-		.author(|a| {
-			a.name(title);
-			if let Some(url) = guild_icon {
-				a.icon_url(url);
-			}
-			a
-		})
-		.field("Channel", channel_mention, true)
-		.field("Message", message_link, true)
-		.footer(|f| {
-			f.icon_url(
-				message
-					.author
-					.avatar_url()
-					.unwrap_or_else(|| message.author.default_avatar_url()),
-			)
-			.text(&message.author.name)
-		})
-		.color(EMBED_COLOR);
 
-	Ok(embed)
+	Ok(NotificationContent {
+		keywords: keywords.to_vec(),
+		title,
+		guild_id,
+		guild_name,
+		guild_icon,
+		channel_id: message.channel_id,
+		channel_name,
+		message_id: message.id,
+		content: message.content.clone(),
+		author_name: message.author.name.clone(),
+		author_avatar: message
+			.author
+			.avatar_url()
+			.unwrap_or_else(|| message.author.default_avatar_url()),
+		timestamp: message.timestamp,
+		locale,
+	})
 }
 
 /// Sends a notification about a highlighted keyword.
 ///
-/// If sending the notification fails because of an internal server error, it is
-/// retried up to five times with a delay of two seconds.
+/// The notification is delivered with the
+/// [`Notifier`](super::notifier::Notifier) for the user's chosen
+/// [delivery method](Delivery). If it can be edited later, a [`Notification`]
+/// is recorded for each of the given keywords, or a single one if it wasn't
+/// caused by keywords.
+///
+/// If sending the notification fails because of a transient error like an
+/// internal server error, it is retried up to five times with a delay of two
+/// seconds.
 ///
-/// If sending the notification fails with `"Cannot send messages to this
-/// user"`, a corresponding [`UserState`] is created.
+/// If the notification is undeliverable (for example, sending a DM fails with
+/// `"Cannot send messages to this user"`), a corresponding [`UserState`] is
+/// created. If DMs can't be sent to the user but the guild has a
+/// [`FallbackChannel`], the notification is sent in the user's private thread
+/// there instead.
 ///
 /// Any other errors are logged as is.
 #[tracing::instrument(
@@ -423,67 +1053,94 @@ async fn send_notification_message(
 	ctx: &Context,
 	user_id: UserId,
 	message_id: MessageId,
-	message_to_send: CreateMessage<'static>,
+	notification: NotificationContent,
+	trigger: NotificationTrigger,
 	keywords: TinyVec<[String; 2]>,
 ) -> Result<()> {
-	let dm_channel = user_id
-		.create_dm_channel(&ctx)
-		.await
-		.context("Failed to create DM channel to notify user")?;
+	let delivery = Delivery::user_delivery(user_id).await?.method;
+	let mut notifier = notifier(delivery.clone());
+	let mut fallback_thread = None;
 
 	let mut result = Ok(());
 
 	for _ in 0..NOTIFICATION_RETRIES {
-		match dm_channel
-			.send_message(&ctx, |m| {
-				*m = message_to_send.clone();
-				m
-			})
-			.await
-		{
+		match notifier.send(ctx, user_id, &notification).await {
 			Ok(sent_message) => {
 				result = Ok(());
-				UserState::clear(user_id).await?;
-				for keyword in keywords {
-					let notification = Notification {
-						original_message: message_id,
-						notification_message: sent_message.id,
-						keyword,
+				for keyword in &keywords {
+					stats::record(
 						user_id,
+						notification.guild_id,
+						keyword,
+						KeywordEvent::Notified,
+					);
+				}
+				UserState::clear(user_id).await?;
+				if let Some(sent_message) = sent_message {
+					let rows = match trigger {
+						NotificationTrigger::Keyword => keywords
+							.into_iter()
+							.map(|keyword| (keyword, None))
+							.collect(),
+						_ => vec![(String::new(), Some(notification.title))],
 					};
-					notification.insert().await?;
+
+					for (keyword, title) in rows {
+						let notification = Notification {
+							original_message: message_id,
+							notification_message: sent_message,
+							keyword,
+							user_id,
+							fallback_thread,
+							guild_id: Some(notification.guild_id),
+							channel_id: Some(notification.channel_id),
+							content: Some(notification.content.clone()),
+							delivery: delivery.clone(),
+							trigger,
+							title,
+						};
+						notification.insert().await?;
+					}
 				}
 				break;
 			}
 
-			Err(SerenityError::Http(err)) => match &*err {
-				HttpError::UnsuccessfulRequest(ErrorResponse {
-					status_code,
-					..
-				}) if status_code.is_server_error() => {
-					result = Err(SerenityError::Http(err).into());
-				}
+			Err(NotifyError::Transient(err)) => {
+				result = Err(err);
+			}
 
-				HttpError::UnsuccessfulRequest(ErrorResponse {
-					error, ..
-				}) if error.message == "Cannot send messages to this user" => {
-					let user_state = UserState {
+			Err(NotifyError::Undeliverable) => {
+				if fallback_thread.is_none()
+					&& matches!(
+						notifier.failure_state(),
+						UserStateKind::CannotDm
+					) {
+					if let Some(thread_id) = open_fallback_thread(
+						ctx,
 						user_id,
-						state: UserStateKind::CannotDm,
-					};
-
-					user_state.set().await?;
-
-					result = Ok(());
-					break;
+						notification.guild_id,
+					)
+					.await?
+					{
+						debug!("Sending notification in fallback thread");
+						notifier = Box::new(ThreadNotifier { thread_id });
+						fallback_thread = Some(thread_id);
+						continue;
+					}
 				}
 
-				_ => return Err(SerenityError::Http(err).into()),
-			},
+				let user_state = UserState {
+					user_id,
+					state: notifier.failure_state(),
+				};
+
+				user_state.set().await?;
 
-			Err(err) => {
-				Err(err).context("Failed to send notification message")?
+				result = Ok(());
+				break;
 			}
+
+			Err(NotifyError::Other(err)) => return Err(err),
 		}
 
 		sleep(Duration::from_secs(2)).await;
@@ -492,44 +1149,176 @@ async fn send_notification_message(
 	result
 }
 
-/// Deletes the given notification messages sent to the corresponding users.
+/// Gets the user's private thread in the guild's [`FallbackChannel`], creating
+/// it if necessary.
+///
+/// Returns `None` if the guild has no fallback channel.
 #[tracing::instrument(skip(ctx))]
+async fn open_fallback_thread(
+	ctx: &Context,
+	user_id: UserId,
+	guild_id: GuildId,
+) -> Result<Option<ChannelId>> {
+	let fallback = match FallbackChannel::guild_fallback(guild_id).await? {
+		Some(fallback) => fallback,
+		None => return Ok(None),
+	};
+
+	if let Some(thread) = FallbackThread::user_thread(user_id, guild_id).await?
+	{
+		let exists = ctx.cache.guild_channel(thread.thread_id).is_some()
+			|| optional_result(ctx.http.get_channel(thread.thread_id.0).await)?
+				.is_some();
+
+		if exists {
+			return Ok(Some(thread.thread_id));
+		}
+	}
+
+	let username = user_id.to_user(ctx).await?.name;
+	let name = user_locale(user_id)
+		.await?
+		.format("fallback-thread-name", &[("username", &username)]);
+
+	let thread = match optional_result(
+		fallback
+			.channel_id
+			.create_private_thread(ctx, |t| {
+				t.name(name)
+					.kind(ChannelType::PrivateThread)
+					.auto_archive_duration(10080)
+			})
+			.await,
+	)
+	.context("Failed to create fallback thread")?
+	{
+		Some(thread) => thread,
+		// the fallback channel was deleted
+		None => return Ok(None),
+	};
+
+	thread
+		.id
+		.add_thread_member(ctx, user_id)
+		.await
+		.context("Failed to add user to fallback thread")?;
+
+	FallbackThread {
+		user_id,
+		guild_id,
+		thread_id: thread.id,
+	}
+	.set()
+	.await?;
+
+	Ok(Some(thread.id))
+}
+
+/// Gets the [`Notifier`](super::notifier::Notifier) that can edit or clear the
+/// given sent notification.
+///
+/// This uses the delivery method the notification was sent with, or its
+/// fallback thread, rather than the user's current delivery method.
+fn sent_notifier(notification: &Notification) -> Box<dyn Notifier> {
+	match notification.fallback_thread {
+		Some(thread_id) => Box::new(ThreadNotifier { thread_id }),
+		None => notifier(notification.delivery.clone()),
+	}
+}
+
+/// Deletes the given sent notification messages.
+#[tracing::instrument(skip_all)]
 pub(crate) async fn clear_sent_notifications(
 	ctx: &Context,
-	notification_messages: &[(UserId, MessageId)],
+	notifications: &[Notification],
 ) {
-	for &(user_id, message_id) in notification_messages {
-		if let Err(e) = clear_sent_notification(
-			ctx,
-			user_id,
-			message_id,
-			"*Original message deleted*",
-		)
-		.await
+	for notification in notifications {
+		if let Err(e) =
+			clear_sent_notification(ctx, notification, "notification-deleted")
+				.await
 		{
 			error!("{:?}", e);
 		}
 	}
 }
 
-/// Replaces the given direct message with the given placeholder.
-#[tracing::instrument(skip(ctx, placeholder))]
+/// Replaces the given sent notification message with the placeholder with the
+/// given key, in the recipient's locale.
+#[tracing::instrument(
+	skip_all,
+	fields(
+		user_id = %notification.user_id,
+		message_id = %notification.notification_message,
+	)
+)]
 async fn clear_sent_notification(
 	ctx: &Context,
-	user_id: UserId,
-	message_id: MessageId,
-	placeholder: impl ToString,
+	notification: &Notification,
+	placeholder: &'static str,
 ) -> Result<()> {
-	let dm_channel = user_id.create_dm_channel(ctx).await?;
+	let placeholder = user_locale(notification.user_id).await?.get(placeholder);
 
-	dm_channel
-		.edit_message(ctx, message_id, |m| {
-			m.embed(|e| e.description(placeholder).color(ERROR_COLOR))
-		})
+	sent_notifier(notification)
+		.clear(
+			ctx,
+			notification.user_id,
+			notification.notification_message,
+			placeholder,
+		)
 		.await
-		.context("Failed to edit notification message")?;
+}
 
-	Ok(())
+/// How a sent notification message should change after the original message
+/// is edited.
+#[derive(Debug, PartialEq, Eq)]
+enum NotificationUpdate {
+	/// Edit the notification to show the new content with the given keywords,
+	/// and with the given title if it wasn't caused by keywords.
+	Edit {
+		keywords: TinyVec<[String; 2]>,
+		title: Option<String>,
+	},
+	/// Clear the notification, since the message no longer contains any of its
+	/// keywords.
+	Clear,
+}
+
+/// Decides how to update a sent notification message after an edit, given
+/// the notifications recorded for it and the new content of the original
+/// message.
+///
+/// Notifications caused by keywords are only kept if the new content still
+/// contains at least one of their keywords; other notifications are always
+/// kept.
+fn notification_update(
+	sent: &[Notification],
+	lowercase_content: &str,
+) -> NotificationUpdate {
+	if let Some(notification) = sent
+		.iter()
+		.find(|n| n.trigger != NotificationTrigger::Keyword)
+	{
+		return NotificationUpdate::Edit {
+			keywords: TinyVec::new(),
+			title: notification.title.clone(),
+		};
+	}
+
+	let keywords = sent
+		.iter()
+		.map(|notification| &notification.keyword)
+		.filter(|keyword| keyword_matches(keyword, lowercase_content))
+		.cloned()
+		.collect::<TinyVec<[String; 2]>>();
+
+	if keywords.is_empty() {
+		NotificationUpdate::Clear
+	} else {
+		NotificationUpdate::Edit {
+			keywords,
+			title: None,
+		}
+	}
 }
 
 /// Updates sent notifications after a message edit.
@@ -537,7 +1326,8 @@ async fn clear_sent_notification(
 /// Edits the content of each notification to reflect the new content of the
 /// original message if the original message still contains the keyword the
 /// notification was created for. Deletes the notification if the new content
-/// no longer contains the keyword.
+/// no longer contains the keyword. Notifications that weren't caused by
+/// keywords, like those about watched channels, are always edited.
 #[tracing::instrument(
 	skip_all,
 	fields(
@@ -561,44 +1351,46 @@ pub(crate) async fn update_sent_notifications(
 		HashMap::new(),
 		|mut map, notification| {
 			map.entry(notification.notification_message)
-				.or_insert_with(|| {
-					(notification.user_id, TinyVec::<[String; 2]>::new())
-				})
-				.1
-				.push(notification.keyword);
+				.or_insert_with(Vec::new)
+				.push(notification);
 			map
 		},
 	);
 
-	for (message_id, (user_id, keywords)) in notifications_by_message {
-		let keywords = keywords
-			.into_iter()
-			.filter(|keyword| keyword_matches(keyword, &lowercase_content))
-			.collect::<TinyVec<[String; 2]>>();
+	for (message_id, sent) in notifications_by_message {
+		let (keywords, title) =
+			match notification_update(&sent, &lowercase_content) {
+				NotificationUpdate::Edit { keywords, title } => {
+					(keywords, title)
+				}
+				NotificationUpdate::Clear => {
+					to_delete.extend(sent.into_iter().next());
+					continue;
+				}
+			};
 
-		if keywords.is_empty() {
-			to_delete.push((user_id, message_id));
-			continue;
-		}
+		let sent = &sent[0];
+		let user_id = sent.user_id;
 
 		let result: Result<()> = async {
-			let message_to_send =
-				build_notification_edit(ctx, &message, &keywords, guild_id)
-					.await?;
+			let mut notification = build_notification_content(
+				ctx,
+				&message,
+				&keywords,
+				guild_id,
+				user_locale(user_id).await?,
+			)
+			.await?;
 
-			let dm_channel = user_id
-				.create_dm_channel(ctx)
-				.await
-				.context("Failed to create DM channel")?;
+			if let Some(title) = title {
+				notification.title = title;
+			}
 
-			dm_channel
-				.edit_message(ctx, message_id, |m| {
-					*m = message_to_send;
-					m
-				})
+			sent_notifier(sent)
+				.edit(ctx, user_id, message_id, &notification)
 				.await?;
 
-			Ok(())
+			Notification::update_content(message_id, notification.content).await
 		}
 		.await;
 
@@ -609,19 +1401,30 @@ pub(crate) async fn update_sent_notifications(
 
 	clear_sent_notifications(ctx, &to_delete).await;
 
-	for (_, notification_message) in to_delete {
-		if let Err(e) =
-			Notification::delete_notification_message(notification_message)
-				.await
+	for notification in to_delete {
+		if let Err(e) = Notification::delete_notification_message(
+			notification.notification_message,
+		)
+		.await
 		{
 			error!("Failed to delete notification message: {:?}", e);
 		}
 	}
 }
 
+/// Checks if the keyword appears in the message content.
+pub(crate) fn keyword_matches(keyword: &str, content: &str) -> bool {
+	keyword_match(keyword, content).is_some()
+}
+
 /// Finds a match of the keyword in the message content.
+///
+/// Returns the byte range of the first match, if any.
 #[tracing::instrument(skip_all)]
-fn keyword_matches(keyword: &str, content: &str) -> bool {
+pub(crate) fn keyword_match(
+	keyword: &str,
+	content: &str,
+) -> Option<Range<usize>> {
 	fn overlaps_with_mention(range: Range<usize>, content: &str) -> bool {
 		regex!(r"<(@!?|&|#|a?:[a-zA-Z0-9_]*:)[0-9]+>")
 			.find_iter(content)
@@ -655,14 +1458,14 @@ fn keyword_matches(keyword: &str, content: &str) -> bool {
 				}
 			})
 			.map(|(index, _)| index..index + keyword.len())
-			.any(|range| !overlaps_with_mention(range, content))
+			.find(|range| !overlaps_with_mention(range.clone(), content))
 	} else if non_alpha_num.is_match(keyword) {
 		// if the keyword contains non-alphanumeric characters, it could appear
 		// anywhere
 		content
 			.match_indices(keyword)
 			.map(|(i, _)| i..i + keyword.len())
-			.any(|range| !overlaps_with_mention(range, content))
+			.find(|range| !overlaps_with_mention(range.clone(), content))
 	} else {
 		// otherwise, it is only alphanumeric and could appear between
 		// non-alphanumeric text
@@ -676,14 +1479,14 @@ fn keyword_matches(keyword: &str, content: &str) -> bool {
 
 				substring_index..substring_index + keyword.len()
 			})
-			.any(|range| !overlaps_with_mention(range, content))
+			.find(|range| !overlaps_with_mention(range.clone(), content))
 	}
 }
 
 /// Checks the state of the last notification of the user.
 ///
 /// If the last notification failed, send a message warning the user they should
-/// enable DMs. Clears the user state afterwards.
+/// fix their delivery settings. Clears the user state afterwards.
 #[tracing::instrument(
 	skip_all,
 	fields(
@@ -701,7 +1504,14 @@ pub(crate) async fn check_notify_user_state(
 		None => return Ok(()),
 	};
 
-	warn_for_failed_dm(ctx, command).await?;
+	let key = match user_state.state {
+		UserStateKind::CannotDm => "delivery-failed-dm",
+		UserStateKind::WebhookFailed => "delivery-failed-webhook",
+		UserStateKind::HttpFailed => "delivery-failed-http",
+		UserStateKind::EmailFailed => "delivery-failed-email",
+	};
+
+	followup_eph(ctx, command, locale(&command.locale).get(key)).await?;
 
 	user_state.delete().await?;
 
@@ -724,12 +1534,7 @@ pub(crate) async fn warn_for_failed_dm(
 	followup_eph(
 		ctx,
 		command,
-		indoc!(
-			"
-			⚠️ I failed to DM you to notify you of your last highlighted \
-			keyword. Make sure you have DMs enabled in at least one server \
-			that we share."
-		),
+		locale(&command.locale).get("delivery-failed-dm"),
 	)
 	.await
 }
@@ -752,12 +1557,46 @@ pub(super) fn start_notification_clearing(ctx: Context) {
 	}
 }
 
+/// Periodically deletes notifications older than
+/// [`history_retention`](crate::settings::BehaviorSettings::history_retention)
+/// from users' history, if configured.
+pub(super) fn start_history_pruning() {
+	if let Some(retention) = settings().behavior.history_retention {
+		debug!("Starting history pruning");
+		tokio::spawn(async move {
+			let span = info_span!(parent: None, "history_pruning");
+			let _entered = span.enter();
+			let mut timer = interval(Duration::from_secs(60 * 60));
+			// notifications that haven't been cleared yet still need to be
+			// cleared with notification_lifetime
+			let only_cleared =
+				settings().behavior.notification_lifetime.is_some();
+			loop {
+				timer.tick().await;
+				let cutoff_time = SystemTime::now() - retention;
+				if let Err(e) = Notification::delete_history_before(
+					cutoff_time,
+					only_cleared,
+				)
+				.await
+				{
+					error!("Failed to prune notification history: {e}\n{e:?}");
+				}
+			}
+		});
+	}
+}
+
 async fn clear_old_notifications(
 	ctx: &Context,
 	lifetime: Duration,
 ) -> Result<()> {
 	debug!("Clearing old notifications");
 	let cutoff_time = SystemTime::now() - lifetime;
+	let keep_history = settings()
+		.behavior
+		.history_retention
+		.is_some_and(|retention| retention > lifetime);
 
 	loop {
 		let notifications =
@@ -781,9 +1620,8 @@ async fn clear_old_notifications(
 			.map(|notification| {
 				clear_sent_notification(
 					ctx,
-					notification.user_id,
-					notification.notification_message,
-					"*Notification expired*",
+					notification,
+					"notification-expired",
 				)
 				.or_else(|e| async move {
 					match e.downcast_ref::<SerenityError>() {
@@ -803,7 +1641,11 @@ async fn clear_old_notifications(
 			.try_for_each(|_| async { Ok(()) })
 			.await?;
 
-		Notification::delete_notifications(sent_ids).await?;
+		if keep_history {
+			Notification::mark_cleared(sent_ids).await?;
+		} else {
+			Notification::delete_notifications(sent_ids).await?;
+		}
 
 		debug!("Waiting before clearing more notifications");
 
@@ -814,6 +1656,7 @@ async fn clear_old_notifications(
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::db::DeliveryMethod;
 
 	#[test]
 	fn keyword_match_basic() {
@@ -836,4 +1679,130 @@ mod tests {
 
 		assert!(!keyword_matches("ဥပမာ", "စမ်းသပ်မှုဥပမာ"));
 	}
+
+	#[test]
+	fn keyword_match_range() {
+		assert_eq!(keyword_match("bar", "foo bar baz"), Some(4..7));
+		assert_eq!(keyword_match("bar", "<#123> bar"), Some(7..10));
+		assert_eq!(keyword_match("123", "<@123> foo"), None);
+	}
+
+	#[test]
+	fn scoped_ignores() {
+		let scoped = |channel_id: Option<u64>, keyword: Option<&str>| Ignore {
+			phrase: "meme".to_owned(),
+			user_id: UserId(1),
+			guild_id: GuildId(2),
+			channel_id: channel_id.map(ChannelId),
+			keyword: keyword.map(str::to_owned),
+		};
+
+		let content = "foo bar meme";
+		let ignored = KeywordCheck::Ignored("meme".to_owned());
+
+		for ignore in [
+			scoped(None, None),
+			scoped(Some(3), None),
+			scoped(None, Some("foo")),
+			scoped(Some(3), Some("foo")),
+		] {
+			assert_eq!(
+				check_keyword_content("foo", ChannelId(3), content, &[ignore]),
+				ignored,
+			);
+		}
+
+		for ignore in [
+			scoped(Some(4), None),
+			scoped(None, Some("bar")),
+			scoped(Some(4), Some("foo")),
+		] {
+			assert_eq!(
+				check_keyword_content("foo", ChannelId(3), content, &[ignore]),
+				KeywordCheck::Notify,
+			);
+		}
+	}
+
+	fn sent_notification(
+		trigger: NotificationTrigger,
+		keyword: &str,
+		title: Option<&str>,
+	) -> Notification {
+		Notification {
+			original_message: MessageId(1),
+			notification_message: MessageId(2),
+			keyword: keyword.to_owned(),
+			user_id: UserId(3),
+			fallback_thread: None,
+			guild_id: Some(GuildId(4)),
+			channel_id: Some(ChannelId(5)),
+			content: Some("foo bar".to_owned()),
+			delivery: DeliveryMethod::DirectMessage,
+			trigger,
+			title: title.map(str::to_owned),
+		}
+	}
+
+	#[test]
+	fn edits_reach_watch_notifications() {
+		let watch = sent_notification(
+			NotificationTrigger::Watch,
+			"",
+			Some("New messages in #general"),
+		);
+
+		assert_eq!(
+			notification_update(&[watch], "something else entirely"),
+			NotificationUpdate::Edit {
+				keywords: TinyVec::new(),
+				title: Some("New messages in #general".to_owned()),
+			},
+		);
+	}
+
+	#[test]
+	fn edits_reach_reply_notifications() {
+		let reply = sent_notification(
+			NotificationTrigger::Reply,
+			"",
+			Some("someone replied to your message"),
+		);
+
+		assert_eq!(
+			notification_update(&[reply], ""),
+			NotificationUpdate::Edit {
+				keywords: TinyVec::new(),
+				title: Some("someone replied to your message".to_owned()),
+			},
+		);
+	}
+
+	#[test]
+	fn edits_clear_unmatched_keyword_notifications() {
+		let sent = [
+			sent_notification(NotificationTrigger::Keyword, "foo", None),
+			sent_notification(NotificationTrigger::Keyword, "bar", None),
+		];
+
+		assert_eq!(
+			notification_update(&sent, "bar baz"),
+			NotificationUpdate::Edit {
+				keywords: ["bar".to_owned()].into_iter().collect(),
+				title: None,
+			},
+		);
+		assert_eq!(
+			notification_update(&sent, "baz"),
+			NotificationUpdate::Clear,
+		);
+	}
+
+	#[test]
+	fn unreadable_matches_not_counted() {
+		assert!(KeywordCheck::Notify.matched());
+		assert!(KeywordCheck::Ignored("meme".to_owned()).matched());
+		assert!(!KeywordCheck::NoPermission.matched());
+		assert!(!KeywordCheck::NoMatch.matched());
+	}
 }
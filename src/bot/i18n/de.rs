@@ -0,0 +1,2654 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! German messages.
+
+use indoc::indoc;
+
+pub(super) static MESSAGES: &[(&str, &str)] = &[
+	// errors shared by many commands
+	(
+		"error-guild-only",
+		"❌ Du musst diesen Befehl auf einem Server verwenden!",
+	),
+	(
+		"error-opted-out",
+		"❌ Du kannst diesen Befehl nach dem Abmelden nicht verwenden!",
+	),
+	(
+		"error-manage-guild",
+		"❌ Du brauchst die Berechtigung „Server verwalten“, um diesen Befehl \
+		zu verwenden!",
+	),
+	(
+		"error-embed-perms",
+		"Tut mir leid, ich brauche die Berechtigung, Links einzubetten, um \
+		diesen Befehl zu verwenden 😔",
+	),
+	("error-invalid-server", "❌ Ungültige Server-ID!"),
+	("error-text-channel", "❌ Bitte gib einen Textkanal an!"),
+	("error-cannot-read", "❌ Ich kann {channel} nicht lesen!"),
+	// keywords
+	(
+		"keyword-limit",
+		"Du kannst nicht mehr als {max} Stichwörter erstellen!",
+	),
+	(
+		"keyword-too-short",
+		"❌ Stichwörter müssen mindestens {min} Zeichen lang sein!",
+	),
+	(
+		"keyword-guild-limit",
+		"❌ Auf diesem Server sind nur {max} Stichwörter pro Mitglied erlaubt!",
+	),
+	(
+		"keyword-too-long",
+		"❌ Stichwörter dürfen höchstens {max} Zeichen lang sein!",
+	),
+	(
+		"keyword-banned",
+		"❌ Auf diesem Server sind keine Stichwörter mit \"{word}\" erlaubt!",
+	),
+	("keyword-invalid", "❌ Dieses Stichwort kannst du nicht hinzufügen!"),
+	(
+		"keyword-flagged",
+		"⚠️ (nicht mehr erlaubt; wird nicht hervorgehoben)",
+	),
+	("keyword-authors", "(nur von {authors})"),
+	("keyword-channels-only", "(nur in {channels})"),
+	("keyword-channels-except", "(nicht in {channels})"),
+	(
+		"keyword-revoked",
+		"❌ Die Admins dieses Servers erlauben dir keine Stichwörter hier!",
+	),
+	(
+		"keyword-channels-invalid",
+		"❌ Erwähne die Kanäle für das Stichwort, etwa #general #help!",
+	),
+	(
+		"keyword-channels-limit",
+		"❌ Du kannst ein Stichwort nicht in mehr als {max} Kanälen auf einmal \
+		hinzufügen!",
+	),
+	(
+		"keyword-channels-missing",
+		"❌ Gib ein Stichwort ein, das du in mehreren Kanälen hinzufügen willst!",
+	),
+	(
+		"keyword-channels-conflict",
+		"❌ Verwende entweder `channel` oder `channels`, nicht beide!",
+	),
+	("keyword-exists", "❌ Du hast dieses Stichwort bereits hinzugefügt!"),
+	("keyword-missing", "❌ Du hast dieses Stichwort nicht hinzugefügt!"),
+	(
+		"keyword-test-dm",
+		"Testnachricht; wenn du das lesen kannst, kann ich dir erfolgreich \
+		Benachrichtigungen senden!",
+	),
+	("keywords-title", "Deine Stichwörter"),
+	(
+		"keywords-empty",
+		"❌ Du hast noch keine Stichwörter hinzugefügt!",
+	),
+	(
+		"keyword-authors-limit",
+		"❌ Stichwörter können nicht auf mehr als {max} Benutzer und Rollen \
+		beschränkt werden!",
+	),
+	// keyword forms
+	("keyword-form-add-title", "Stichwort hinzufügen"),
+	("keyword-form-edit-title", "Stichwort bearbeiten"),
+	("keyword-form-label-keyword", "Stichwort"),
+	(
+		"keyword-form-label-channel",
+		"Kanal (leer lassen für den ganzen Server)",
+	),
+	(
+		"keyword-form-label-channels",
+		"Nur in diesen Kanälen (serverweit)",
+	),
+	(
+		"keyword-form-label-excluded-channels",
+		"Nicht in diesen Kanälen (serverweit)",
+	),
+	(
+		"keyword-form-label-authors",
+		"Nur von diesen Benutzern oder Rollen",
+	),
+	(
+		"keyword-form-excluded-limit",
+		"❌ Stichwörter können nicht von mehr als {max} Kanälen \
+		ausgeschlossen werden!",
+	),
+	(
+		"keyword-form-channel-conflict",
+		"❌ Ein Stichwort kann nicht gleichzeitig auf {channel} beschränkt \
+		und davon ausgeschlossen sein!",
+	),
+	(
+		"keyword-form-channel-options",
+		"❌ Nur serverweite Stichwörter können auf Kanäle beschränkt oder von \
+		ihnen ausgeschlossen werden!",
+	),
+	(
+		"keyword-form-unknown-text-channel",
+		"❌ Auf diesem Server gibt es keinen Textkanal namens `{channel}`!",
+	),
+	(
+		"keyword-form-unknown-channel",
+		"❌ Auf diesem Server gibt es keinen Kanal namens `{channel}`!",
+	),
+	(
+		"keyword-form-unknown-role",
+		"❌ Auf diesem Server gibt es keine Rolle namens `{role}`!",
+	),
+	("keyword-form-unchanged", "❌ Du hast nichts geändert!"),
+	("keyword-form-in-server", "auf diesem Server"),
+	("keyword-form-in-channel", "in {channel}"),
+	(
+		"keyword-form-added",
+		"✅ „{keyword}“ {location} hinzugefügt",
+	),
+	(
+		"keyword-form-moved",
+		"✅ „{old_keyword}“ {old_location} zu „{keyword}“ {location} \
+		geändert",
+	),
+	(
+		"keyword-form-changed",
+		"✅ Die Optionen von „{keyword}“ {location} wurden geändert",
+	),
+	// team keywords
+	("keywords-team-group", "Team-Stichwörter von {role}"),
+	(
+		"keywords-team-group-in",
+		"{server} › Team-Stichwörter von {role}",
+	),
+	("keywords-left", "(verlassen)"),
+	("team-keywords-title", "**Team-Stichwörter**"),
+	(
+		"team-keywords-empty",
+		"Dieser Server hat keine Team-Stichwörter.",
+	),
+	(
+		"team-keywords-limit",
+		"❌ Server können nicht mehr als {max} Team-Stichwörter haben!",
+	),
+	(
+		"team-keyword-everyone",
+		"❌ Team-Stichwörter können nicht für @everyone hinzugefügt werden; \
+		wähle eine kleinere Rolle!",
+	),
+	(
+		"team-keyword-role-too-large",
+		"❌ Team-Stichwörter können nicht für Rollen mit mehr als {max} \
+		Mitgliedern hinzugefügt werden, und {role} hat mehr!",
+	),
+	(
+		"team-keyword-exists",
+		"❌ Diese Rolle hat dieses Team-Stichwort bereits!",
+	),
+	(
+		"team-keyword-missing",
+		"❌ Diese Rolle hat dieses Team-Stichwort nicht!",
+	),
+	(
+		"team-keyword-added",
+		"✅ Mitglieder von {role} werden über „{keyword}“ benachrichtigt",
+	),
+	(
+		"team-keyword-removed",
+		"✅ Mitglieder von {role} werden nicht mehr über „{keyword}“ \
+		benachrichtigt",
+	),
+	(
+		"team-keyword-no-role",
+		"❌ Keine deiner Rollen hat das Team-Stichwort „{keyword}“!",
+	),
+	(
+		"team-keyword-left",
+		"✅ Du wirst nicht mehr über das Team-Stichwort „{keyword}“ \
+		benachrichtigt",
+	),
+	(
+		"team-keyword-already-left",
+		"❌ Du hast das Team-Stichwort „{keyword}“ bereits verlassen!",
+	),
+	(
+		"team-keyword-rejoined",
+		"✅ Du wirst wieder über das Team-Stichwort „{keyword}“ \
+		benachrichtigt",
+	),
+	(
+		"team-keyword-not-left",
+		"❌ Du hast das Team-Stichwort „{keyword}“ nicht verlassen!",
+	),
+	// keyword packs
+	("keywords-pack-group", "Stichwortpaket „{pack}“"),
+	("keywords-pack-group-in", "{server} › Stichwortpaket „{pack}“"),
+	("packs-title", "**Stichwortpakete**"),
+	("packs-empty", "Dieser Server hat keine Stichwortpakete."),
+	("packs-subscribed", " (abonniert)"),
+	(
+		"pack-name-length",
+		"❌ Paketnamen müssen zwischen 1 und {max} Zeichen lang sein!",
+	),
+	("pack-missing", "❌ Es gibt kein Paket namens „{pack}“!"),
+	(
+		"pack-nothing-added",
+		"❌ Dem Paket „{pack}“ wurde nichts hinzugefügt.",
+	),
+	("pack-added", "✅ {keywords} zum Paket „{pack}“ hinzugefügt."),
+	(
+		"pack-skipped",
+		"Übersprungen, weil sie zu kurz oder zu lang, hier verboten, \
+		ungültig, bereits im Paket oder über dem Limit von {max} \
+		Stichwörtern sind: {keywords}",
+	),
+	(
+		"pack-nothing-removed",
+		"❌ Keines dieser Stichwörter ist im Paket „{pack}“!",
+	),
+	("pack-removed", "✅ {keywords} aus dem Paket „{pack}“ entfernt."),
+	(
+		"pack-emptied",
+		"✅ Alle Stichwörter wurden aus dem Paket „{pack}“ entfernt, daher \
+		wurde es gelöscht.",
+	),
+	("pack-deleted", "✅ Das Paket „{pack}“ wurde gelöscht."),
+	(
+		"pack-already-subscribed",
+		"❌ Du hast dieses Paket bereits abonniert!",
+	),
+	(
+		"pack-over-limit",
+		"❌ Mit diesem Paket würdest du das Limit von {max} Stichwörtern \
+		überschreiten!",
+	),
+	("pack-subscribed", "✅ „{pack}“ abonniert: {keywords}"),
+	("pack-not-subscribed", "❌ Du hast dieses Paket nicht abonniert!"),
+	("pack-unsubscribed", "✅ „{pack}“ abbestellt"),
+	// follows
+	("follow-self", "❌ Du kannst dir nicht selbst folgen!"),
+	(
+		"follow-never-highlighted",
+		"❌ Nachrichten von {user} werden nie hervorgehoben!",
+	),
+	(
+		"follow-other-server",
+		"❌ Du kannst Benutzern nur in Kanälen dieses Servers folgen!",
+	),
+	("follow-cannot-read", "❌ Du kannst {channel} nicht lesen!"),
+	("follow-exists", "❌ Du folgst {user} in {channel} bereits!"),
+	(
+		"follow-limit",
+		"❌ Du kannst Benutzern nicht in mehr als {max} Kanälen folgen!",
+	),
+	(
+		"follow-added",
+		"✅ Du wirst über Nachrichten von {user} in {channel} benachrichtigt",
+	),
+	(
+		"follow-removed",
+		"✅ Du wirst nicht mehr über Nachrichten von {user} in {channel} \
+		benachrichtigt",
+	),
+	("follow-missing", "❌ Du folgst {user} in {channel} nicht!"),
+	(
+		"follow-removed-all",
+		"✅ Du wirst nicht mehr über Nachrichten von {user} benachrichtigt",
+	),
+	("follow-missing-all", "❌ Du folgst {user} nicht!"),
+	("follows-entry", "{user} in {channel}"),
+	("follows-title", "Benutzer, denen du folgst"),
+	("follows-empty", "❌ Du folgst niemandem!"),
+	// keyword authors
+	(
+		"keyword-authors-title",
+		"**Auf Autoren beschränkte Stichwörter**",
+	),
+	(
+		"keyword-authors-empty",
+		"Du hast auf diesem Server keine Stichwörter auf bestimmte Autoren \
+		beschränkt.",
+	),
+	(
+		"keyword-authors-missing",
+		"❌ Du musst einen Benutzer oder eine Rolle auswählen!",
+	),
+	(
+		"keyword-authors-no-keyword",
+		"❌ Du hast das Stichwort „{keyword}“ auf diesem Server nicht!",
+	),
+	(
+		"keyword-authors-exist",
+		"❌ „{keyword}“ ist bereits auf diese Autoren beschränkt!",
+	),
+	(
+		"keyword-authors-added",
+		"✅ „{keyword}“ benachrichtigt dich jetzt über Nachrichten von \
+		{authors}",
+	),
+	(
+		"keyword-authors-not-found",
+		"❌ „{keyword}“ ist nicht auf diese Autoren beschränkt!",
+	),
+	(
+		"keyword-authors-removed",
+		"✅ „{keyword}“ ist nicht mehr auf Nachrichten von {authors} \
+		beschränkt",
+	),
+	// watches
+	(
+		"watch-other-server",
+		"❌ Du kannst nur Kanäle dieses Servers beobachten!",
+	),
+	("watch-not-thread", "❌ {channel} ist kein Thread!"),
+	(
+		"watch-use-thread",
+		"❌ Verwende `/watch thread`, um Threads zu beobachten!",
+	),
+	("watch-text-only", "❌ Du kannst nur Textkanäle beobachten!"),
+	("watch-cannot-read", "❌ Du kannst {channel} nicht lesen!"),
+	("watch-exists", "❌ Du beobachtest {channel} bereits!"),
+	(
+		"watch-limit",
+		"❌ Du kannst nicht mehr als {max} Kanäle und Threads beobachten!",
+	),
+	(
+		"watch-added",
+		"✅ Du wirst über neue Nachrichten in {channel} benachrichtigt",
+	),
+	(
+		"watch-removed",
+		"✅ Du wirst nicht mehr über neue Nachrichten in {channel} \
+		benachrichtigt",
+	),
+	("watch-missing", "❌ Du beobachtest {channel} nicht!"),
+	("watches-title", "**Beobachtete Kanäle und Threads**"),
+	(
+		"watches-empty",
+		"Du beobachtest auf diesem Server keine Kanäle oder Threads.",
+	),
+	("watches-thread", "(Thread)"),
+	// reply and reaction alerts
+	(
+		"reply-alerts-already-enabled",
+		"❌ Antwortbenachrichtigungen sind bereits aktiviert!",
+	),
+	(
+		"reply-alerts-already-disabled",
+		"❌ Antwortbenachrichtigungen sind bereits deaktiviert!",
+	),
+	(
+		"reply-alerts-enabled",
+		"✅ Du wirst über Antworten auf deine Nachrichten benachrichtigt, die \
+		dich nicht erwähnen",
+	),
+	(
+		"reply-alerts-disabled",
+		"✅ Du wirst nicht mehr über Antworten benachrichtigt, die dich nicht \
+		erwähnen",
+	),
+	("reaction-alerts-title", "**Reaktionsbenachrichtigungen**"),
+	(
+		"reaction-alerts-empty",
+		"Du hast keine Reaktionsbenachrichtigungen hinzugefügt.",
+	),
+	(
+		"reaction-alert-invalid",
+		"❌ Das ist kein Emoji! Verwende ein einzelnes Unicode- oder \
+		Server-Emoji.",
+	),
+	(
+		"reaction-alert-exists",
+		"❌ Du hast bereits eine Reaktionsbenachrichtigung für {emoji} \
+		hinzugefügt!",
+	),
+	(
+		"reaction-alerts-limit",
+		"❌ Du kannst nicht mehr als {max} Reaktionsbenachrichtigungen \
+		hinzufügen!",
+	),
+	(
+		"reaction-alert-added",
+		"✅ Du wirst benachrichtigt, wenn jemand mit {emoji} auf deine \
+		Nachrichten reagiert",
+	),
+	(
+		"reaction-alert-missing",
+		"❌ Du hast keine Reaktionsbenachrichtigung für {emoji} hinzugefügt!",
+	),
+	(
+		"reaction-alert-removed",
+		"✅ Du wirst nicht mehr über {emoji}-Reaktionen auf deine Nachrichten \
+		benachrichtigt",
+	),
+	// keyword exclusions
+	(
+		"keyword-exclusions-title",
+		"**Von Kanälen ausgeschlossene Stichwörter**",
+	),
+	(
+		"keyword-exclusions-empty",
+		"Du hast auf diesem Server keine Stichwörter von Kanälen \
+		ausgeschlossen.",
+	),
+	(
+		"keyword-exclusion-other-server",
+		"❌ Du kannst Stichwörter nur von Kanälen dieses Servers \
+		ausschließen!",
+	),
+	(
+		"keyword-exclusion-no-keyword",
+		"❌ Du hast das serverweite Stichwort „{keyword}“ auf diesem Server \
+		nicht!",
+	),
+	(
+		"keyword-exclusion-exists",
+		"❌ „{keyword}“ ist bereits von {channel} ausgeschlossen!",
+	),
+	(
+		"keyword-exclusion-added-in",
+		"❌ „{keyword}“ wurde in {channel} hinzugefügt; entferne es und füge \
+		es stattdessen in den anderen Kanälen hinzu!",
+	),
+	(
+		"keyword-exclusions-limit",
+		"❌ Stichwörter können nicht von mehr als {max} Kanälen \
+		ausgeschlossen werden!",
+	),
+	(
+		"keyword-exclusion-added",
+		"✅ „{keyword}“ benachrichtigt dich nicht mehr über Nachrichten in \
+		{channel}",
+	),
+	(
+		"keyword-exclusion-missing",
+		"❌ „{keyword}“ ist nicht von {channel} ausgeschlossen!",
+	),
+	(
+		"keyword-exclusions-missing",
+		"❌ „{keyword}“ ist von keinem Kanal ausgeschlossen!",
+	),
+	(
+		"keyword-exclusions-removed",
+		"✅ „{keyword}“ benachrichtigt dich wieder über Nachrichten in \
+		{channels}",
+	),
+	// ignored phrases
+	(
+		"ignore-too-short",
+		"❌ Ignorierte Ausdrücke müssen mindestens 3 Zeichen lang sein!",
+	),
+	("ignore-exists", "❌ Du ignorierst diesen Ausdruck bereits!"),
+	("ignore-missing", "❌ Du ignorierst diesen Ausdruck nicht!"),
+	("ignore-keyword", "(nur für \"{keyword}\")"),
+	("ignores-title", "Deine ignorierten Ausdrücke"),
+	("ignores-empty", "❌ Du ignorierst keine Ausdrücke!"),
+	(
+		"remove-server-empty",
+		"❌ Du hattest auf diesem Server keine Stichwörter oder ignorierten \
+		Ausdrücke!",
+	),
+	// lists
+	("list-footer", "Seite {page} von {pages} · {total} insgesamt"),
+	("list-previous", "Zurück"),
+	("list-next", "Weiter"),
+	("list-unknown", "Nicht gefunden (gelöscht?)"),
+	("list-in-channel", "In {channel}"),
+	("list-server-wide", "Serverweit"),
+	("list-unknown-server", "<Unbekannter Server> ({id})"),
+	("list-unknown-channel", "Unbekannter Kanal ({id})"),
+	("list-unknown-location", "Unbekannt"),
+	// bulk forms and /manage
+	("bulk-nothing-added", "❌ Es wurde nichts hinzugefügt."),
+	("bulk-added", "✅ Hinzugefügt: {entries}"),
+	("bulk-duplicate", "Bereits hinzugefügt: {entries}"),
+	("bulk-invalid", "Ungültig: {entries}"),
+	("bulk-over-limit", "Über dem Stichwortlimit: {entries}"),
+	(
+		"bulk-invalid-hint",
+		"Einträge müssen mindestens 3 Zeichen lang sein, und Stichwörter \
+		dürfen keine Erwähnungen oder Emojis enthalten.",
+	),
+	("bulk-keywords-title", "Stichwörter hinzufügen"),
+	(
+		"bulk-keywords-label",
+		"Stichwörter, getrennt durch Kommas oder Zeilenumbrüche",
+	),
+	("bulk-ignores-title", "Ausdrücke ignorieren"),
+	(
+		"bulk-ignores-label",
+		"Ausdrücke, getrennt durch Kommas oder Zeilenumbrüche",
+	),
+	(
+		"from-message-empty",
+		"❌ Diese Nachricht enthält keinen Text zum Hinzufügen!",
+	),
+	("from-message-title", "Stichwort hinzufügen"),
+	(
+		"from-message-label",
+		"Stichwort (kürze es auf den gewünschten Teil)",
+	),
+	("from-message-added", "✅ \"{keyword}\" hinzugefügt"),
+	(
+		"manage-empty",
+		"❌ Du hast auf diesem Server noch keine Stichwörter hinzugefügt!",
+	),
+	(
+		"manage-none-left",
+		"Du hast auf diesem Server keine Stichwörter mehr.",
+	),
+	(
+		"manage-none-selected",
+		"❌ Du hast keine Stichwörter zum Entfernen ausgewählt.",
+	),
+	("manage-removed", "✅ Entfernt: {keywords}"),
+	(
+		"manage-instructions",
+		"Wähle Stichwörter zum Entfernen aus und drücke dann „Auswahl \
+		entfernen“. (Seite {page} von {pages})",
+	),
+	("manage-placeholder", "Wähle Stichwörter zum Entfernen aus"),
+	("manage-channel", "Nur in #{channel}"),
+	("manage-remove", "Auswahl entfernen ({count})"),
+	// mutes
+	("mute-exists", "❌ Du hast {channel} bereits stummgeschaltet!"),
+	("mute-added", "✅ {channel} stummgeschaltet"),
+	("mute-missing", "❌ Du hast {channel} nicht stummgeschaltet!"),
+	("mute-removed", "✅ Stummschaltung von {channel} aufgehoben"),
+	("mutes-title", "Deine stummgeschalteten Kanäle"),
+	("mutes-empty", "❌ Du hast keine Kanäle stummgeschaltet!"),
+	// blocks
+	("block-self", "❌ Du kannst dich nicht selbst blockieren!"),
+	("block-exists", "❌ Du hast {user} bereits blockiert!"),
+	(
+		"block-hidden",
+		"✅ Deine Nachrichten werden jetzt in den Hervorhebungen von {user} \
+		ausgeblendet",
+	),
+	(
+		"block-unhidden",
+		"✅ Deine Nachrichten können die Stichwörter von {user} wieder \
+		hervorheben, aber {user} bleibt blockiert",
+	),
+	(
+		"block-added-hidden",
+		"✅ {user} blockiert und deine Nachrichten in den Hervorhebungen \
+		ausgeblendet",
+	),
+	("block-added", "✅ {user} blockiert"),
+	("unblock-self", "❌ Du kannst dich nicht selbst entblocken!"),
+	("block-missing", "❌ Du hast {user} nicht blockiert!"),
+	("block-removed", "✅ Blockierung von {user} aufgehoben"),
+	("blocks-hidden", "(deine Nachrichten sind für sie ausgeblendet)"),
+	("blocks-title", "Deine blockierten Nutzer"),
+	("blocks-empty", "Du hast keine Nutzer blockiert!"),
+	// opting out
+	("opt-out-exists", "❌ Du hast dich bereits abgemeldet!"),
+	(
+		"opt-out-warning",
+		"⚠️ Willst du dich wirklich abmelden?\n\n\
+		Alle deine Stichwörter, stummgeschalteten Kanäle, blockierten Nutzer \
+		und ignorierten Ausdrücke gehen für immer verloren. Verwende vorher \
+		`/export`, wenn du eine Sicherung behalten willst.\n\n\
+		Du kannst dann keine Benachrichtigungen mehr erhalten.\n\n\
+		Andere erhalten keine Benachrichtigungen mehr über deine Nachrichten.",
+	),
+	("opt-out-confirm", "Abmelden"),
+	("opt-out-cancel", "Abbrechen"),
+	("opt-out-timeout", "Zeit abgelaufen."),
+	("opt-out-done", "✅ Du wurdest abgemeldet"),
+	("opt-out-cancelled", "✅ Du wurdest nicht abgemeldet"),
+	("opt-in-missing", "❌ Du hast dich nicht abgemeldet!"),
+	// delivery
+	(
+		"delivery-current-dm",
+		"Deine Benachrichtigungen werden per Direktnachricht zugestellt.",
+	),
+	(
+		"delivery-current-webhook",
+		"Deine Benachrichtigungen werden über einen Discord-Webhook \
+		zugestellt.",
+	),
+	(
+		"delivery-current-http",
+		"Deine Benachrichtigungen werden an einen HTTP-Endpunkt zugestellt.",
+	),
+	(
+		"delivery-current-email",
+		"Deine Benachrichtigungen werden per E-Mail zugestellt.",
+	),
+	(
+		"delivery-current-digest",
+		"Deine Benachrichtigungen werden in E-Mail-Zusammenfassungen \
+		zugestellt.",
+	),
+	(
+		"delivery-email-unverified",
+		"❌ Du musst zuerst mit `/email` eine E-Mail-Adresse hinzufügen und \
+		bestätigen!",
+	),
+	("delivery-url-invalid", "❌ Das ist keine gültige URL!"),
+	(
+		"delivery-url-missing",
+		"❌ Du musst eine URL angeben, an die Benachrichtigungen zugestellt \
+		werden sollen!",
+	),
+	(
+		"delivery-webhook-invalid",
+		"❌ Das ist keine gültige Discord-Webhook-URL!",
+	),
+	(
+		"delivery-http-disabled",
+		"❌ HTTP-Zustellung ist bei diesem Bot nicht aktiviert!",
+	),
+	(
+		"delivery-http-scheme",
+		"❌ Bitte gib eine HTTP- oder HTTPS-URL an!",
+	),
+	(
+		"delivery-set-dm",
+		"✅ Deine Benachrichtigungen werden per Direktnachricht zugestellt",
+	),
+	(
+		"delivery-set-webhook",
+		"✅ Deine Benachrichtigungen werden über deinen Webhook zugestellt",
+	),
+	(
+		"delivery-set-http",
+		"✅ Deine Benachrichtigungen werden an deinen HTTP-Endpunkt \
+		zugestellt",
+	),
+	(
+		"delivery-set-email",
+		"✅ Deine Benachrichtigungen werden per E-Mail zugestellt",
+	),
+	(
+		"delivery-set-digest",
+		"✅ Deine Benachrichtigungen werden in regelmäßigen \
+		E-Mail-Zusammenfassungen zugestellt",
+	),
+	(
+		"delivery-failed-dm",
+		"⚠️ Ich konnte dir keine DM über dein letztes hervorgehobenes \
+		Stichwort senden. Stelle sicher, dass du DMs auf mindestens einem \
+		Server erlaubst, den wir teilen.",
+	),
+	(
+		"delivery-failed-webhook",
+		"⚠️ Ich konnte dich nicht über deinen Webhook über dein letztes \
+		hervorgehobenes Stichwort benachrichtigen. Er wurde vielleicht \
+		gelöscht; verwende `/delivery`, um einen neuen festzulegen.",
+	),
+	(
+		"delivery-failed-http",
+		"⚠️ Ich konnte dich nicht über deinen HTTP-Endpunkt über dein \
+		letztes hervorgehobenes Stichwort benachrichtigen. Stelle sicher, \
+		dass er JSON-POST-Anfragen annimmt, oder ändere ihn mit `/delivery`.",
+	),
+	(
+		"delivery-failed-email",
+		"⚠️ Ich konnte dich nicht per E-Mail über dein letztes \
+		hervorgehobenes Stichwort benachrichtigen. Stelle sicher, dass deine \
+		Adresse E-Mails empfangen kann, oder ändere sie mit `/email`.",
+	),
+	// email
+	(
+		"email-disabled",
+		"❌ E-Mail-Benachrichtigungen sind bei diesem Bot nicht aktiviert!",
+	),
+	("email-missing", "❌ Du hast keine E-Mail-Adresse hinzugefügt!"),
+	(
+		"email-removed-delivery",
+		"✅ Deine E-Mail-Adresse wurde entfernt; deine Benachrichtigungen \
+		werden per Direktnachricht zugestellt",
+	),
+	("email-removed", "✅ Deine E-Mail-Adresse wurde entfernt"),
+	(
+		"email-verified",
+		"✅ Deine E-Mail-Adresse wurde bestätigt! Verwende `/delivery`, um \
+		Benachrichtigungen per E-Mail zu erhalten.",
+	),
+	("email-code-incorrect", "❌ Dieser Code ist falsch!"),
+	(
+		"email-code-expired",
+		"❌ Du hast keinen gültigen Bestätigungscode; verwende `/email` mit \
+		deiner Adresse, um einen neuen zu erhalten.",
+	),
+	(
+		"email-too-many-attempts",
+		"❌ Du hast zu viele falsche Codes eingegeben; bitte versuche es \
+		morgen erneut.",
+	),
+	("email-current", "Deine E-Mail-Adresse ist `{address}`."),
+	(
+		"email-current-unverified",
+		"Deine E-Mail-Adresse `{address}` wurde noch nicht bestätigt.",
+	),
+	("email-none", "Du hast keine E-Mail-Adresse hinzugefügt."),
+	("email-invalid", "❌ Das ist keine gültige E-Mail-Adresse!"),
+	("email-sending", "📧 Bestätigungscode wird gesendet..."),
+	(
+		"email-cooldown",
+		"❌ Ich habe dir gerade einen Code gesendet; bitte warte {seconds} \
+		Sekunden, bevor du einen neuen anforderst.",
+	),
+	(
+		"email-sent",
+		"📧 Ich habe einen Bestätigungscode an `{address}` gesendet. \
+		Verwende `/email` mit dem Code, um deine Adresse zu bestätigen.",
+	),
+	(
+		"email-undeliverable",
+		"❌ Ich konnte keine E-Mail an diese Adresse senden!",
+	),
+	(
+		"email-send-failed",
+		"❌ Ich konnte gerade keine E-Mail senden; bitte versuche es später \
+		erneut.",
+	),
+	// fallback channels
+	(
+		"fallback-disabled",
+		"✅ Auf diesem Server werden keine Benachrichtigungen mehr gesendet, \
+		wenn Direktnachrichten fehlschlagen",
+	),
+	("fallback-missing", "❌ Dieser Server hat keinen Ausweichkanal!"),
+	(
+		"fallback-current",
+		"Benachrichtigungen, die nicht per Direktnachricht gesendet werden \
+		können, werden in privaten Threads in {channel} gesendet.",
+	),
+	("fallback-none", "Dieser Server hat keinen Ausweichkanal."),
+	(
+		"fallback-permissions",
+		"❌ Ich brauche die Berechtigung, {channel} zu lesen und dort private \
+		Threads zu erstellen!",
+	),
+	(
+		"fallback-set",
+		"✅ Benachrichtigungen, die nicht per Direktnachricht gesendet werden \
+		können, werden in privaten Threads in {channel} gesendet",
+	),
+	// history
+	(
+		"history-empty",
+		"Du hast keine passenden Benachrichtigungen!",
+	),
+	(
+		"history-empty-unrecorded",
+		"Du hast keine passenden Benachrichtigungen! Per HTTP oder E-Mail \
+		zugestellte Benachrichtigungen werden nicht gespeichert und erscheinen \
+		daher nicht in deinem Verlauf.",
+	),
+	(
+		"history-unrecorded",
+		"Per HTTP oder E-Mail zugestellte Benachrichtigungen werden nicht \
+		gespeichert, daher können neuere fehlen.",
+	),
+	(
+		"history-invalid-date",
+		"❌ Ungültiges Datum für {name}! Bitte verwende das Format JJJJ-MM-TT.",
+	),
+	("history-search-empty", "❌ Bitte gib einen Suchtext an!"),
+	("history-title", "Benachrichtigungsverlauf"),
+	("history-entry", "**{keyword}** in {location} · {time}"),
+	("history-reason-follow", "gefolgte Person"),
+	("history-reason-watch", "beobachteter Kanal"),
+	("history-reason-reply", "Antwort"),
+	("history-reason-reaction", "Reaktionen"),
+	("history-jump", "Springen"),
+	("history-unknown-channel", "unbekannter Kanal"),
+	("history-footer", "Seite {page} von {pages}"),
+	// stats
+	("stats-title", "Stichwortstatistik"),
+	(
+		"stats-footer",
+		"Unterdrückte Benachrichtigungen: Geduld = du warst im Kanal aktiv, \
+		ignoriert = ein ignorierter Ausdruck kam vor",
+	),
+	("stats-omitted", "…und {count} weitere"),
+	("stats-never-matched", "**{keyword}**: nie gefunden"),
+	(
+		"stats-line",
+		"**{keyword}**: {matches}× gefunden, {notified}× benachrichtigt",
+	),
+	("stats-patience", "Geduld {count}"),
+	("stats-ignored", "ignoriert {count}"),
+	("stats-muted", "stummgeschaltet {count}"),
+	("stats-blocked", "blockiert {count}"),
+	// testing keywords
+	(
+		"test-no-match",
+		"❌ \"{keyword}\" kommt in diesem Text nicht vor. Stichwörter passen \
+		nur auf ganze Wörter, außer sie enthalten Symbole oder Leerzeichen.",
+	),
+	("test-matched", "Gefunden: {snippet}"),
+	(
+		"test-ignored",
+		"🔕 Dein ignorierter Ausdruck \"{phrase}\" kommt auch in diesem Text \
+		vor",
+	),
+	("test-muted", "🔇 Du hast diesen Kanal stummgeschaltet"),
+	(
+		"test-own-message",
+		"👤 Über deine eigenen Nachrichten wirst du nie benachrichtigt",
+	),
+	("test-bot", "🤖 Nachrichten von Bots werden nie hervorgehoben"),
+	(
+		"test-opted-out",
+		"🚪 {user} hat sich abgemeldet, daher werden deren Nachrichten nie \
+		hervorgehoben",
+	),
+	("test-blocked", "⛔ Du hast {user} blockiert"),
+	(
+		"test-authors",
+		"👥 Dieses Stichwort benachrichtigt dich nur über Nachrichten von \
+		{authors}",
+	),
+	(
+		"test-channels",
+		"📍 Dein serverweites Stichwort ist von diesem Kanal ausgeschlossen \
+		oder nur in anderen Kanälen hinzugefügt",
+	),
+	(
+		"test-cannot-read",
+		"🔒 Du kannst in diesem Kanal keine Nachrichten lesen",
+	),
+	(
+		"test-mentioned",
+		"🔔 Der Text erwähnt dich, daher benachrichtigt dich Discord",
+	),
+	(
+		"test-filtered",
+		"🚫 Dieses Stichwort wird hier für dich nicht hervorgehoben; die \
+		Admins des Servers haben dir das Hervorheben vielleicht entzogen, oder \
+		der Autor verbirgt seine Nachrichten vor dir",
+	),
+	(
+		"test-would-notify",
+		"✅ Du würdest benachrichtigt, außer du bist kurz nach dem Senden der \
+		Nachricht in diesem Kanal aktiv.",
+	),
+	("test-would-not-notify", "❌ Du würdest nicht benachrichtigt:"),
+	(
+		"test-not-added",
+		"Du hast \"{keyword}\" hier noch nicht hinzugefügt; verwende `/add`, \
+		um darüber benachrichtigt zu werden.",
+	),
+	(
+		"report-disabled",
+		"⏸️ Die Admins des Servers haben das Hervorheben hier ausgeschaltet.",
+	),
+	(
+		"report-excluded",
+		"🚧 Die Admins des Servers haben {channel} vom Hervorheben \
+		ausgeschlossen.",
+	),
+	(
+		"report-no-keywords",
+		"❌ Du hast keine Stichwörter auf diesem Server oder in diesem Kanal.",
+	),
+	("report-blocked", "⛔ gefunden, aber du hast {user} blockiert"),
+	(
+		"report-muted",
+		"🔇 gefunden, aber du hast den Kanal stummgeschaltet",
+	),
+	("report-notify", "✅ würde dich benachrichtigen"),
+	(
+		"report-too-old",
+		"⌛ gefunden, aber die Nachricht ist zu alt für eine Benachrichtigung",
+	),
+	(
+		"report-mentioned",
+		"🔔 gefunden, aber die Nachricht erwähnt dich, daher benachrichtigt \
+		dich Discord",
+	),
+	(
+		"report-ignored",
+		"🔕 gefunden, aber dein ignorierter Ausdruck \"{phrase}\" kommt auch \
+		vor",
+	),
+	(
+		"report-no-permission",
+		"🔒 gefunden, aber du kannst den Kanal nicht lesen",
+	),
+	("report-in-channel", "(in {channel})"),
+	(
+		"report-none",
+		"❌ Keines deiner Stichwörter kommt in dieser Nachricht vor.",
+	),
+	(
+		"report-unmatched",
+		"Nicht in der Nachricht vorgekommen: {keywords}",
+	),
+	// diagnosing notifications
+	(
+		"why-invalid-link",
+		"❌ Bitte gib einen Link zu einer Nachricht auf einem Server an! Du \
+		bekommst ihn, indem du die Nachricht rechtsklickst (oder lange \
+		drückst) und „Nachrichtenlink kopieren“ wählst.",
+	),
+	(
+		"why-opted-out",
+		"🚪 Du hast dich abgemeldet, daher wirst du nie benachrichtigt. \
+		Verwende `/opt-in`, um dich wieder anzumelden.",
+	),
+	("why-channel-missing", "❌ Ich kann diesen Kanal nicht finden!"),
+	(
+		"why-cannot-read",
+		"🔒 Du kannst diesen Kanal nicht lesen, daher wirst du nicht über \
+		Nachrichten darin benachrichtigt.",
+	),
+	("why-not-member", "❌ Du bist kein Mitglied dieses Servers!"),
+	("why-checking", "🔍 Nachricht wird überprüft..."),
+	(
+		"why-message-missing",
+		"❌ Ich kann diese Nachricht nicht finden! Sie wurde vielleicht \
+		gelöscht.",
+	),
+	(
+		"why-header",
+		"Überprüfe [diese Nachricht]({link}) mit deinen aktuellen \
+		Stichwörtern und Einstellungen:",
+	),
+	(
+		"why-not-notified",
+		"📭 Du wurdest über diese Nachricht nicht benachrichtigt. Du wirst \
+		auch nicht benachrichtigt, wenn du kurz nach dem Senden im Kanal \
+		aktiv warst oder deine Stichwörter oder Einstellungen damals anders \
+		waren.",
+	),
+	(
+		"why-notified",
+		"📬 Du wurdest über diese Nachricht benachrichtigt für: {keywords}",
+	),
+	(
+		"why-fallback",
+		"Sie wurde in {thread} gesendet, weil ich dir keine Direktnachricht \
+		senden konnte.",
+	),
+	// copying
+	(
+		"copy-same-server",
+		"❌ Du kannst die Stichwörter eines Servers nicht auf denselben Server \
+		kopieren!",
+	),
+	(
+		"copy-server-empty",
+		"❌ Du hast auf diesem Server keine serverweiten Stichwörter oder \
+		ignorierten Ausdrücke!",
+	),
+	("copy-unknown-server", "dem Server mit der ID {id}"),
+	(
+		"copy-same-channel",
+		"❌ Du kannst die Stichwörter eines Kanals nicht in denselben Kanal \
+		kopieren!",
+	),
+	("copy-channel-empty", "❌ Du hast keine Stichwörter in {channel}!"),
+	("copy-keywords-one", "1 Stichwort"),
+	("copy-keywords", "{count} Stichwörter"),
+	("copy-ignores-one", "1 ignorierter Ausdruck"),
+	("copy-ignores", "{count} ignorierte Ausdrücke"),
+	("copy-both", "{keywords} und {ignores}"),
+	("copy-done", "✅ {items} aus {source} kopiert."),
+	("copy-nothing", "❌ Aus {source} wurde nichts kopiert."),
+	(
+		"copy-skipped-duplicates",
+		"Übersprungen, weil du sie bereits hinzugefügt hast: {list}",
+	),
+	(
+		"copy-skipped-invalid",
+		"Übersprungen, weil dieser Server sie nicht erlaubt: {list}",
+	),
+	(
+		"copy-skipped-limit",
+		"Übersprungen, weil du dein Stichwortlimit erreicht hast: {list}",
+	),
+	// export and import
+	(
+		"export-empty",
+		"❌ Du hast keine Stichwörter, ignorierten Ausdrücke, \
+		Stummschaltungen oder Blockierungen zum Exportieren!",
+	),
+	(
+		"export-done",
+		"📦 Hier sind deine Stichwörter, ignorierten Ausdrücke, \
+		Stummschaltungen und Blockierungen. Verwende `/import` mit dieser \
+		Datei, um sie wiederherzustellen.",
+	),
+	("import-too-large", "❌ Diese Datei ist zu groß!"),
+	(
+		"import-newer",
+		"❌ Diese Datei wurde von einer neueren Version des Bots exportiert!",
+	),
+	(
+		"import-invalid",
+		"❌ Diese Datei ist kein gültiger Export! Verwende eine mit `/export` \
+		erstellte Datei.",
+	),
+	("import-nothing", "✅ Du hast bereits alles aus dieser Datei!"),
+	(
+		"import-preview",
+		"Das Importieren dieser Datei nimmt diese Änderungen vor:",
+	),
+	("import-more", "…und {count} weitere Änderungen"),
+	("import-confirm", "Importieren"),
+	("import-cancel", "Abbrechen"),
+	("import-timeout", "Zeit abgelaufen; nichts wurde importiert."),
+	("import-done", "✅ Importiert!"),
+	("import-cancelled", "Nichts wurde importiert."),
+	("import-kind-keywords", "Stichwörter"),
+	("import-kind-keyword-channels", "Stichwortkanäle"),
+	("import-kind-keyword-authors", "Stichwortautoren"),
+	("import-kind-ignores", "ignorierte Ausdrücke"),
+	("import-kind-mutes", "Stummschaltungen"),
+	("import-kind-blocks", "Blockierungen"),
+	("import-added", "{kind}: {count} hinzugefügt"),
+	("import-removed", "{count} entfernt"),
+	(
+		"import-skipped-invalid",
+		"⚠️ Übersprungen, weil sie ungültig oder nicht erlaubt sind: {list}",
+	),
+	(
+		"import-skipped-inaccessible",
+		"⚠️ Übersprungen, weil du nicht auf diesem Server bist oder diesen \
+		Kanal nicht lesen kannst: {list}",
+	),
+	(
+		"import-skipped-limit",
+		"⚠️ Übersprungen, weil du dort keine weiteren Stichwörter hinzufügen \
+		kannst: {list}",
+	),
+	("import-entry-keyword", "Stichwort \"{keyword}\""),
+	(
+		"import-entry-keyword-channel",
+		"Kanal {channel} des Stichworts \"{keyword}\"",
+	),
+	(
+		"import-entry-keyword-author",
+		"Autorenfilter des Stichworts \"{keyword}\"",
+	),
+	("import-entry-ignore", "ignorierter Ausdruck \"{phrase}\""),
+	("import-entry-mute", "Stummschaltung von Kanal {channel}"),
+	("import-entry-self-block", "Blockierung von dir selbst"),
+	("import-unknown-server", "Server {id}"),
+	("import-unknown-channel", "Kanal {id}"),
+	("import-unknown-user", "Nutzer {id}"),
+	("import-unknown-role", "Rolle {id}"),
+	("import-diff-keyword", "Stichwort \"{keyword}\" in {location}"),
+	(
+		"import-diff-keyword-only-in",
+		"Stichwort \"{keyword}\" in {server} nur in {channel}",
+	),
+	(
+		"import-diff-keyword-not-in",
+		"Stichwort \"{keyword}\" in {server} nicht in {channel}",
+	),
+	(
+		"import-diff-keyword-author",
+		"Stichwort \"{keyword}\" in {server} nur von {author}",
+	),
+	("import-diff-ignore", "ignoriere \"{phrase}\" in {location}"),
+	(
+		"import-diff-ignore-keyword",
+		"ignoriere \"{phrase}\" in {location} für \"{keyword}\"",
+	),
+	("import-diff-mute", "schalte {channel} stumm"),
+	("import-diff-block", "blockiere {user}"),
+	(
+		"import-diff-block-hidden",
+		"blockiere {user} und blende deine Nachrichten aus",
+	),
+	// ping and about
+	("ping-pong", "🏓 Pong!"),
+	("ping-latency", "Gateway-Latenz: {latency}"),
+	("about-source", "Quellcode"),
+	("about-author", "Autor"),
+	("about-invite", "Einladung"),
+	("about-invite-link", "[Füge mich deinem Server hinzu]({url})"),
+	("about-uptime", "Laufzeit"),
+	("uptime-days", "{days} Tage"),
+	("uptime-days-hours", "{days} Tage, {hours} Stunden"),
+	("uptime-hours-minutes", "{hours} Stunden, {minutes} Minuten"),
+	("uptime-minutes-seconds", "{minutes} Minuten, {seconds} Sekunden"),
+	("uptime-seconds", "{seconds} Sekunden"),
+	// help
+	("help-title", "{name} – Hilfe"),
+	(
+		"help-description",
+		"Verwende `/help [command]`, um mehr über einen bestimmten Befehl zu \
+		erfahren",
+	),
+	("help-command-title", "Hilfe – {name}"),
+	("help-examples-title", "Beispiele"),
+	("help-unknown", "❌ Es gibt keinen Befehl namens `{name}`!"),
+	// server settings
+	("settings-view-title", "**Servereinstellungen**"),
+	("settings-view-highlighting-on", "Hervorheben: an"),
+	("settings-view-highlighting-off", "Hervorheben: ausgeschaltet"),
+	(
+		"settings-view-keyword-limit",
+		"Stichwortlimit: {max} pro Mitglied",
+	),
+	(
+		"settings-view-keyword-limit-default",
+		"Stichwortlimit: {max} pro Mitglied (das Limit des Bots)",
+	),
+	(
+		"settings-view-min-length",
+		"Mindestlänge für Stichwörter: {min} Zeichen",
+	),
+	(
+		"settings-view-min-length-default",
+		"Mindestlänge für Stichwörter: {min} Zeichen (Standard)",
+	),
+	("settings-view-excluded", "Ausgeschlossene Kanäle: {channels}"),
+	("settings-view-banned", "Verbotene Stichwörter: {words}"),
+	(
+		"settings-view-revoked",
+		"Mitglieder ohne Hervorheben: {members}",
+	),
+	("settings-view-none", "keine"),
+	(
+		"settings-audit-empty",
+		"Bisher hat niemand die Einstellungen dieses Servers geändert.",
+	),
+	(
+		"settings-audit-title",
+		"**Letzte Änderungen an den Servereinstellungen**",
+	),
+	(
+		"settings-already-excluded",
+		"❌ {channel} ist bereits ausgeschlossen!",
+	),
+	("settings-not-excluded", "❌ {channel} ist nicht ausgeschlossen!"),
+	(
+		"settings-channel-excluded",
+		"✅ Nachrichten in {channel} werden nicht mehr hervorgehoben",
+	),
+	(
+		"settings-channel-included",
+		"✅ Nachrichten in {channel} werden wieder hervorgehoben",
+	),
+	(
+		"settings-same-keyword-limit",
+		"❌ Das ist bereits das Stichwortlimit!",
+	),
+	(
+		"settings-keyword-limit-set",
+		"✅ Mitglieder können hier jetzt bis zu {limit} Stichwörter hinzufügen",
+	),
+	(
+		"settings-keyword-limit-removed",
+		"✅ Das Stichwortlimit dieses Servers wurde entfernt",
+	),
+	(
+		"settings-same-min-length",
+		"❌ Das ist bereits die Mindestlänge für Stichwörter!",
+	),
+	(
+		"settings-min-length-set",
+		"✅ Stichwörter, die hier hinzugefügt werden, müssen jetzt mindestens \
+		{length} Zeichen lang sein",
+	),
+	(
+		"settings-min-length-reset",
+		"✅ Die Mindestlänge für Stichwörter dieses Servers wurde \
+		zurückgesetzt",
+	),
+	("settings-no-word", "❌ Du musst ein Wort angeben!"),
+	("settings-already-banned", "❌ „{word}“ ist bereits verboten!"),
+	("settings-not-banned", "❌ „{word}“ ist nicht verboten!"),
+	(
+		"settings-word-banned",
+		"✅ Mitglieder können hier keine Stichwörter mit „{word}“ mehr \
+		hinzufügen; bestehende benachrichtigen sie nicht mehr und werden in \
+		ihren Stichwortlisten markiert",
+	),
+	(
+		"settings-word-allowed",
+		"✅ Mitglieder können wieder Stichwörter mit „{word}“ hinzufügen",
+	),
+	(
+		"settings-already-revoked",
+		"❌ {member} kann das Hervorheben hier bereits nicht verwenden!",
+	),
+	(
+		"settings-not-revoked",
+		"❌ {member} kann das Hervorheben hier bereits verwenden!",
+	),
+	(
+		"settings-member-revoked",
+		"✅ {member} wird nicht mehr über Stichwörter auf diesem Server \
+		benachrichtigt und kann hier keine Stichwörter hinzufügen",
+	),
+	(
+		"settings-member-restored",
+		"✅ {member} kann das Hervorheben hier wieder verwenden",
+	),
+	("settings-already-on", "❌ Das Hervorheben ist bereits an!"),
+	(
+		"settings-already-off",
+		"❌ Das Hervorheben ist bereits ausgeschaltet!",
+	),
+	(
+		"settings-turned-on",
+		"✅ Nachrichten auf diesem Server werden wieder hervorgehoben",
+	),
+	(
+		"settings-turned-off",
+		"✅ Nachrichten auf diesem Server werden nicht mehr hervorgehoben",
+	),
+	// notifications
+	(
+		"notification-keyword",
+		"Stichwort \"{keyword}\" in #{channel} ({server}) gesehen",
+	),
+	(
+		"notification-keywords",
+		"Stichwörter {keywords} in #{channel} ({server}) gesehen",
+	),
+	(
+		"notification-follow",
+		"Neue Nachricht von {author} in #{channel} ({server})",
+	),
+	("notification-watch", "Neue Nachricht in #{channel} ({server})"),
+	(
+		"notification-watch-burst",
+		"{count} neue Nachrichten in #{channel} ({server})",
+	),
+	(
+		"notification-reply",
+		"{author} hat auf deine Nachricht in #{channel} ({server}) geantwortet",
+	),
+	(
+		"notification-reactions",
+		"Reaktionen auf deine Nachricht in #{channel} ({server}): {reactions}",
+	),
+	("notification-channel", "Kanal"),
+	("notification-message", "Nachricht"),
+	("notification-link", "(Link)"),
+	("notification-deleted", "*Ursprüngliche Nachricht gelöscht*"),
+	("notification-expired", "*Benachrichtigung abgelaufen*"),
+	("fallback-thread-name", "Highlights für {username}"),
+	// command descriptions
+	(
+		"command-add",
+		"Füge ein Stichwort hinzu, das auf diesem Server oder in einem Kanal \
+		hervorgehoben wird",
+	),
+	("command-remove", "Entferne ein Stichwort auf diesem Server"),
+	(
+		"command-add-advanced",
+		"Füge ein Stichwort hinzu und wähle alle Optionen in einem Formular",
+	),
+	(
+		"command-edit-keyword",
+		"Ändere ein Stichwort oder seine Optionen",
+	),
+	(
+		"command-mute",
+		"Schalte einen Kanal stumm, damit Server-Stichwörter dort nicht \
+		hervorgehoben werden",
+	),
+	(
+		"command-unmute",
+		"Hebe die Stummschaltung eines Kanals auf",
+	),
+	(
+		"command-block",
+		"Blockiere einen Benutzer, damit seine Nachrichten dich nicht \
+		benachrichtigen",
+	),
+	("command-unblock", "Hebe die Blockierung eines Benutzers auf"),
+	(
+		"command-ignore",
+		"Füge einen Ausdruck hinzu, der auf diesem Server ignoriert wird",
+	),
+	(
+		"command-unignore",
+		"Entferne einen ignorierten Ausdruck auf diesem Server",
+	),
+	("command-keywords", "Zeige deine Stichwörter an"),
+	("command-mutes", "Zeige deine stummgeschalteten Kanäle an"),
+	("command-blocks", "Zeige deine blockierten Benutzer an"),
+	("command-ignores", "Zeige deine ignorierten Ausdrücke an"),
+	(
+		"command-remove-server",
+		"Entferne alle Stichwörter und ignorierten Ausdrücke eines Servers",
+	),
+	(
+		"command-manage",
+		"Wähle mehrere Stichwörter auf diesem Server aus, um sie zu entfernen",
+	),
+	(
+		"command-copy-server",
+		"Kopiere deine Stichwörter und ignorierten Ausdrücke von einem anderen \
+		Server",
+	),
+	(
+		"command-copy-channel",
+		"Kopiere deine kanalspezifischen Stichwörter in einen anderen Kanal",
+	),
+	(
+		"command-history",
+		"Zeige oder durchsuche deine erhaltenen Benachrichtigungen",
+	),
+	(
+		"command-test-keyword",
+		"Teste, ob ein Stichwort dich über einen Text benachrichtigen würde",
+	),
+	(
+		"command-why",
+		"Finde heraus, warum du über eine Nachricht (nicht) benachrichtigt \
+		wurdest",
+	),
+	(
+		"command-stats",
+		"Sieh dir an, wie oft deine Stichwörter zutreffen und dich \
+		benachrichtigen",
+	),
+	(
+		"command-delivery",
+		"Wähle, wie du Benachrichtigungen erhältst",
+	),
+	(
+		"command-email",
+		"Füge eine E-Mail-Adresse für Benachrichtigungen hinzu",
+	),
+	(
+		"command-fallback",
+		"Wähle, wohin Benachrichtigungen gehen, wenn DMs fehlschlagen",
+	),
+	(
+		"command-server-settings",
+		"Zeige oder ändere, wie Highlights auf diesem Server funktionieren",
+	),
+	(
+		"command-team-keywords",
+		"Füge Stichwörter hinzu, die alle Mitglieder einer Rolle benachrichtigen",
+	),
+	(
+		"command-keyword-pack",
+		"Veröffentliche Stichwortpakete, die Mitglieder abonnieren können",
+	),
+	(
+		"command-subscribe",
+		"Abonniere ein Stichwortpaket auf diesem Server",
+	),
+	(
+		"command-unsubscribe",
+		"Kündige ein Stichwortpaket auf diesem Server",
+	),
+	(
+		"command-keyword-authors",
+		"Lass ein Stichwort nur in Nachrichten bestimmter Benutzer oder Rollen \
+		hervorheben",
+	),
+	(
+		"command-keyword-exclusions",
+		"Hebe ein serverweites Stichwort in bestimmten Kanälen nicht mehr hervor",
+	),
+	(
+		"command-follow",
+		"Werde über jede Nachricht eines Benutzers in einem Kanal benachrichtigt",
+	),
+	(
+		"command-unfollow",
+		"Werde nicht mehr über die Nachrichten eines Benutzers benachrichtigt",
+	),
+	("command-follows", "Zeige die Benutzer an, denen du folgst"),
+	(
+		"command-watch",
+		"Werde über jede Nachricht in einem Kanal oder Thread benachrichtigt",
+	),
+	(
+		"command-reply-alerts",
+		"Werde über Antworten auf deine Nachrichten benachrichtigt, die dich \
+		nicht erwähnen",
+	),
+	(
+		"command-reaction-alerts",
+		"Werde über bestimmte Reaktionen auf deine Nachrichten benachrichtigt",
+	),
+	(
+		"command-export",
+		"Exportiere deine Stichwörter, Ausdrücke, Stummschaltungen und \
+		Blockierungen",
+	),
+	(
+		"command-import",
+		"Importiere Stichwörter, Ausdrücke, Stummschaltungen und Blockierungen \
+		aus einer Datei",
+	),
+	("command-opt-out", "Melde dich vom Hervorheben ab"),
+	(
+		"command-opt-in",
+		"Melde dich nach dem Abmelden wieder zum Hervorheben an",
+	),
+	(
+		"command-help",
+		"Zeige diese Hilfe oder die Hilfe zu einem bestimmten Befehl an",
+	),
+	("command-ping", "Zeige den Ping des Bots an"),
+	(
+		"command-about",
+		"Zeige Informationen über diesen Bot an, inklusive Einladungslink",
+	),
+	// context menu names
+	("command-Test keywords", "Stichwörter testen"),
+	("command-Add keyword from message…", "Stichwort aus Nachricht…"),
+	("command-Block author", "Autor blockieren"),
+	("command-Block user", "Benutzer blockieren"),
+	// command help
+	(
+		"help-add",
+		indoc!(
+			"
+			Verwende `/add [keyword]`, um ein Stichwort hinzuzufügen, das \
+			auf diesem Server hervorgehoben wird. Du wirst (per DM) über \
+			alle Nachrichten mit deinen Stichwörtern benachrichtigt (außer \
+			über Nachrichten in stummgeschalteten Kanälen oder mit \
+			ignorierten Ausdrücken).
+
+			Groß- und Kleinschreibung spielt bei Stichwörtern keine Rolle.
+
+			Mit `/add [keyword] [channel]` kannst du ein Stichwort auch nur \
+			in bestimmten Kanälen hinzufügen. Über so hinzugefügte \
+			Stichwörter wirst du nur benachrichtigt, wenn sie in den \
+			angegebenen Kanälen vorkommen (nicht anderswo).
+
+			Um ein Stichwort als einen Eintrag in mehreren Kanälen \
+			hinzuzufügen, erwähne sie alle in der Option `channels`, etwa \
+			`/add [keyword] channels: #help #questions`. Wenn du das \
+			Stichwort mit `/remove [keyword]` entfernst, wird es aus allen \
+			entfernt. Um ein Stichwort stattdessen überall außer in \
+			bestimmten Kanälen hinzuzufügen, sieh dir `/help \
+			keyword-exclusions` an.
+
+			Um ein Stichwort aus einer Nachricht hinzuzufügen, klicke mit \
+			der rechten Maustaste auf die Nachricht (oder halte sie \
+			gedrückt), wähle Apps > Stichwort aus Nachricht… und kürze den \
+			Text auf das gewünschte Stichwort.
+
+			Um mehrere Stichwörter auf einmal hinzuzufügen, verwende `/add` \
+			ohne Stichwort (und optional mit einem Kanal) und gib sie durch \
+			Kommas oder Zeilenumbrüche getrennt ein.
+
+			Um stattdessen alle Optionen eines Stichworts in einem Formular \
+			festzulegen, verwende `/add-advanced`. Mit `/edit-keyword` \
+			kannst du die Optionen eines Stichworts später ändern.
+
+			Mit `/remove [keyword]` kannst du Stichwörter später entfernen; \
+			mehr dazu unter `/help remove`. Um mehrere Stichwörter auf \
+			einmal zu entfernen, verwende `/manage`.
+
+			Mit `/keywords` kannst du deine aktuellen Stichwörter anzeigen."
+		),
+	),
+	(
+		"help-examples-add",
+		indoc!(
+			"
+			Das Stichwort \"rust\" auf diesem Server hinzufügen:
+			/add `keyword:` rust
+
+			Das Stichwort \"optimize\" nur im Kanal #javascript hinzufügen:
+			/add `keyword:` optimize `channel:` javascript
+
+			Das Stichwort \"deploy\" nur in den Kanälen #ops und #releases \
+			hinzufügen:
+			/add `keyword:` deploy `channels:` #ops #releases
+
+			Das Stichwort \"hello world\" auf diesem Server hinzufügen:
+			/add `keyword:` hello world
+
+			Mehrere Stichwörter auf einmal auf diesem Server hinzufügen:
+			/add"
+		),
+	),
+	(
+		"help-remove",
+		indoc!(
+			"
+			Verwende `/remove [keyword]`, um ein Stichwort zu entfernen, das \
+			du zuvor mit `/add` auf diesem Server hinzugefügt hast.
+
+			Groß- und Kleinschreibung spielt bei Stichwörtern keine Rolle.
+
+			Mit `/remove [keyword] [channel]` kannst du auch ein Stichwort \
+			entfernen, das du in bestimmten Kanälen hinzugefügt hast. Das \
+			Stichwort muss in Anführungszeichen stehen; mit `\\\"` kannst du \
+			ein Stichwort entfernen, das ein Anführungszeichen enthält.
+
+			Mit `/keywords` kannst du deine aktuellen Stichwörter anzeigen."
+		),
+	),
+	(
+		"help-examples-remove",
+		indoc!(
+			"
+			Das Stichwort \"node\" auf diesem Server entfernen:
+			/remove `keyword:` node
+
+			Das Stichwort \"go\" im Kanal #general entfernen:
+			/remove `keyword: `go `channel:` #general"
+		),
+	),
+	(
+		"help-add-advanced",
+		indoc!(
+			"
+			Verwende `/add-advanced`, um auf diesem Server ein Stichwort \
+			über ein Formular mit allen Optionen des Stichworts \
+			hinzuzufügen, statt über Befehlsoptionen.
+
+			Gib das Stichwort ein und optional einen Kanal mit Namen (etwa \
+			`#general`) oder ID, um das Stichwort nur in diesem Kanal \
+			hervorzuheben. Lass den Kanal leer, um das Stichwort überall auf \
+			dem Server hervorzuheben.
+
+			Ein serverweites Stichwort kann auch auf mehrere Kanäle \
+			beschränkt oder von einigen ausgeschlossen werden, indem du sie \
+			durch Kommas oder Leerzeichen getrennt angibst. Um nur \
+			Nachrichten bestimmter Benutzer oder Rollen hervorzuheben, gib \
+			ihre Erwähnungen oder IDs oder die Namen der Rollen durch Kommas \
+			getrennt an.
+
+			Mehr über Stichwörter erfährst du unter `/help add`. Mit \
+			`/edit-keyword` kannst du die Optionen eines Stichworts später \
+			ändern."
+		),
+	),
+	(
+		"help-edit-keyword",
+		indoc!(
+			"
+			Verwende `/edit-keyword [keyword]`, um eines deiner Stichwörter \
+			auf diesem Server zu ändern, ohne es zu entfernen und neu \
+			hinzuzufügen. Es öffnet sich ein Formular mit den aktuellen \
+			Optionen des Stichworts, die du ändern und absenden kannst.
+
+			Um ein Stichwort zu bearbeiten, das du in einem bestimmten Kanal \
+			hinzugefügt hast, verwende `/edit-keyword [keyword] [channel]`.
+
+			Wenn du den Kanal im Formular leerst, wird das Stichwort \
+			serverweit; wenn du einen Kanal einträgst, gilt es nur in diesem \
+			Kanal. Die Kanäle, auf die das Stichwort beschränkt oder von \
+			denen es ausgeschlossen ist, und die Autoren, auf die es \
+			beschränkt ist, kannst du im selben Formular ändern; wie du sie \
+			eingibst, steht unter `/help add-advanced`. Die Statistiken des \
+			Stichworts bleiben beim Ändern erhalten."
+		),
+	),
+	(
+		"help-examples-edit-keyword",
+		indoc!(
+			"
+			Das serverweite Stichwort \"rust\" bearbeiten:
+			/edit-keyword `keyword:` rust
+
+			Das Stichwort \"optimize\" im Kanal #javascript bearbeiten:
+			/edit-keyword `keyword:` optimize `channel:` #javascript"
+		),
+	),
+	(
+		"help-keyword-authors",
+		indoc!(
+			"
+			Verwende `/keyword-authors add [keyword] [user] [role]`, um über \
+			eines deiner Stichwörter auf diesem Server nur benachrichtigt zu \
+			werden, wenn bestimmte Benutzer oder Mitglieder bestimmter \
+			Rollen es schreiben. Ein auf mehrere Autoren beschränktes \
+			Stichwort benachrichtigt dich, wenn einer von ihnen es schreibt. \
+			Die Beschränkung gilt für das Stichwort überall auf diesem \
+			Server, auch in Kanälen, in denen du es hinzugefügt hast.
+
+			Verwende `/keyword-authors remove [keyword] [user] [role]`, um \
+			eine Beschränkung aufzuheben, oder lass Benutzer und Rolle weg, \
+			um alle aufzuheben.
+
+			Verwende `/keyword-authors list`, um deine beschränkten \
+			Stichwörter auf diesem Server anzuzeigen."
+		),
+	),
+	(
+		"help-examples-keyword-authors",
+		indoc!(
+			"
+			Über \"release\" nur benachrichtigt werden, wenn ein Mitglied \
+			von @Maintainers es schreibt:
+			/keyword-authors add `keyword:` release `role:` @Maintainers
+
+			Wieder über \"release\" von allen benachrichtigt werden:
+			/keyword-authors remove `keyword:` release"
+		),
+	),
+	(
+		"help-keyword-exclusions",
+		indoc!(
+			"
+			Verwende `/keyword-exclusions add [keyword] [channel]`, um in \
+			einem Kanal nicht mehr über eines deiner serverweiten \
+			Stichwörter benachrichtigt zu werden, überall sonst aber schon. \
+			Anders als beim Stummschalten des Kanals sind deine anderen \
+			Stichwörter davon nicht betroffen.
+
+			Verwende `/keyword-exclusions remove [keyword] [channel]`, um \
+			einen Ausschluss aufzuheben, oder lass den Kanal weg, um alle \
+			aufzuheben.
+
+			Verwende `/keyword-exclusions list`, um deine ausgeschlossenen \
+			Stichwörter auf diesem Server anzuzeigen."
+		),
+	),
+	(
+		"help-examples-keyword-exclusions",
+		indoc!(
+			"
+			Über \"docker\" überall außer in #docker benachrichtigt werden:
+			/keyword-exclusions add `keyword:` docker `channel:` #docker"
+		),
+	),
+	(
+		"help-mute",
+		indoc!(
+			"
+			Verwende `/mute [channel]`, um die angegebenen Kanäle \
+			stummzuschalten und keine Benachrichtigungen über deine \
+			serverweiten Stichwörter dort zu erhalten.
+
+			Über kanalspezifische Stichwörter in stummgeschalteten Kanälen \
+			wirst du weiterhin benachrichtigt. Mehr über kanalspezifische \
+			Stichwörter erfährst du unter `/help add`.
+
+			Mit `/unmute [channels]` kannst du die Stummschaltung später \
+			aufheben.
+
+			Mit `/mutes` kannst du deine stummgeschalteten Kanäle anzeigen."
+		),
+	),
+	(
+		"help-examples-mute",
+		indoc!(
+			"
+			Den Kanal #memes stummschalten:
+			/mute `channel:` #memes"
+		),
+	),
+	(
+		"help-unmute",
+		indoc!(
+			"
+			Verwende `/unmute [channel]`, um die Stummschaltung von Kanälen \
+			aufzuheben und wieder über deine Stichwörter dort benachrichtigt \
+			zu werden.
+
+			Mit `/mutes` kannst du deine stummgeschalteten Kanäle anzeigen."
+		),
+	),
+	(
+		"help-examples-unmute",
+		indoc!(
+			"
+			Die Stummschaltung des Kanals #rust aufheben:
+			/unmute `channel:` #rust"
+		),
+	),
+	(
+		"help-block",
+		indoc!(
+			"
+			Verwende `/block [user]`, um die angegebenen Benutzer zu \
+			blockieren und keine Benachrichtigungen über deine Stichwörter \
+			in ihren Nachrichten zu erhalten.
+
+			Du kannst auch mit der rechten Maustaste auf einen Benutzer \
+			klicken (oder ihn gedrückt halten) und Apps > Benutzer \
+			blockieren wählen, oder bei einer Nachricht Apps > Autor \
+			blockieren.
+
+			Setze `hide-messages`, damit deine Nachrichten auch die \
+			Stichwörter des blockierten Benutzers nicht hervorheben und er \
+			nicht über Benachrichtigungen verfolgen kann, was du schreibst. \
+			Verwende `/block` erneut mit `hide-messages:False`, um deine \
+			Nachrichten nicht mehr zu verbergen, aber die Blockierung \
+			beizubehalten.
+
+			Mit `/unblock [user]` kannst du Benutzer später wieder \
+			freigeben.
+
+			Mit `/blocks` kannst du deine blockierten Benutzer anzeigen."
+		),
+	),
+	(
+		"help-examples-block",
+		indoc!(
+			"
+			AnnoyingUser blockieren:
+			/block `user:` @AnnoyingUser
+
+			Stalker blockieren und deine Nachrichten vor seinen Highlights \
+			verbergen:
+			/block `user:` @Stalker `hide-messages:` True"
+		),
+	),
+	(
+		"help-unblock",
+		indoc!(
+			"
+			Verwende `/unblock [user]`, um die Blockierung eines Benutzers \
+			aufzuheben und wieder über deine Stichwörter in seinen \
+			Nachrichten benachrichtigt zu werden.
+
+			Mit `/blocks` kannst du deine blockierten Benutzer anzeigen."
+		),
+	),
+	(
+		"help-examples-unblock",
+		indoc!(
+			"
+			Die Blockierung von RedemptionArc aufheben:
+			/unblock `user:` @RedemptionArc"
+		),
+	),
+	(
+		"help-follow",
+		indoc!(
+			"
+			Verwende `/follow [user] [channel]`, um über jede Nachricht \
+			benachrichtigt zu werden, die der Benutzer im Kanal sendet, etwa \
+			bei Ankündigungen oder Maintainern. Lass den Kanal weg, um ihm \
+			im aktuellen Kanal zu folgen; folge ihm erneut, um weitere \
+			Kanäle hinzuzufügen.
+
+			Über einen Benutzer wirst du höchstens alle paar Minuten \
+			benachrichtigt, sodass viele Nachrichten hintereinander nur eine \
+			Benachrichtigung senden. Du wirst nicht benachrichtigt, wenn du \
+			den Kanal stummgeschaltet oder den Benutzer blockiert hast, und \
+			Benutzer können verhindern, dass du ihnen folgst, indem sie dich \
+			mit `hide-messages` blockieren.
+
+			Mit `/unfollow [user]` kannst du Benutzern nicht mehr folgen, \
+			und mit `/follows` anzeigen, wem du folgst."
+		),
+	),
+	(
+		"help-examples-follow",
+		indoc!(
+			"
+			Maintainer in #announcements folgen:
+			/follow `user:` @Maintainer `channel:` #announcements"
+		),
+	),
+	(
+		"help-unfollow",
+		indoc!(
+			"
+			Verwende `/unfollow [user]`, um einem Benutzer in keinem Kanal \
+			mehr zu folgen, oder `/unfollow [user] [channel]`, um ihm in \
+			einem Kanal nicht mehr zu folgen.
+
+			Mit `/follows` kannst du anzeigen, wem du folgst."
+		),
+	),
+	(
+		"help-examples-unfollow",
+		indoc!(
+			"
+			Maintainer nicht mehr folgen:
+			/unfollow `user:` @Maintainer"
+		),
+	),
+	(
+		"help-watch",
+		indoc!(
+			"
+			Verwende `/watch channel [channel]`, um über jede neue Nachricht \
+			in einem ruhigen Kanal benachrichtigt zu werden, oder `/watch \
+			thread [thread]`, um über jede neue Nachricht in einem Thread \
+			benachrichtigt zu werden. Lass Kanal oder Thread weg, um den \
+			aktuellen zu beobachten.
+
+			Wie bei Stichwörtern wirst du nicht benachrichtigt, wenn du kurz \
+			danach eine Nachricht oder Reaktion im Kanal sendest. Über einen \
+			Kanal wirst du höchstens alle paar Minuten benachrichtigt; \
+			Nachrichten dazwischen werden in einer Benachrichtigung \
+			zusammengefasst, damit ein aktiver Thread deine DMs nicht \
+			überflutet.
+
+			Verwende `/watch stop [channel]`, um einen Kanal oder Thread \
+			nicht mehr zu beobachten, und `/watch list`, um anzuzeigen, was \
+			du auf diesem Server beobachtest."
+		),
+	),
+	(
+		"help-examples-watch",
+		indoc!(
+			"
+			Den Kanal #releases beobachten:
+			/watch channel `channel:` #releases
+
+			Den aktuellen Thread nicht mehr beobachten:
+			/watch stop"
+		),
+	),
+	(
+		"help-reply-alerts",
+		indoc!(
+			"
+			Verwende `/reply-alerts enabled: True`, um benachrichtigt zu \
+			werden, wenn jemand auf eine deiner Nachrichten antwortet und \
+			die Erwähnung ausschaltet. Über Antworten, die dich erwähnen, \
+			benachrichtigt dich Discord bereits.
+
+			Wie bei Stichwörtern wirst du nicht benachrichtigt, wenn du kurz \
+			nach der Antwort eine Nachricht oder Reaktion im Kanal sendest, \
+			oder wenn du die antwortende Person blockiert oder den Kanal \
+			stummgeschaltet hast.
+
+			Verwende `/reply-alerts enabled: False`, um diese \
+			Benachrichtigungen abzuschalten."
+		),
+	),
+	(
+		"help-reaction-alerts",
+		indoc!(
+			"
+			Verwende `/reaction-alerts add [emoji]`, um benachrichtigt zu \
+			werden, wenn jemand mit dem Emoji auf eine deiner Nachrichten \
+			reagiert. Sowohl Unicode- als auch Server-Emojis funktionieren.
+
+			Reaktionen auf dieselbe Nachricht werden gesammelt, während auf \
+			die Benachrichtigung gewartet wird, sodass mehrere Reaktionen \
+			nur eine Benachrichtigung senden. Wie bei Stichwörtern wirst du \
+			nicht benachrichtigt, wenn du kurz danach eine Nachricht oder \
+			Reaktion im Kanal sendest.
+
+			Verwende `/reaction-alerts remove [emoji]`, um über ein Emoji \
+			nicht mehr benachrichtigt zu werden, und `/reaction-alerts \
+			list`, um die Emojis anzuzeigen, über die du benachrichtigt \
+			wirst."
+		),
+	),
+	(
+		"help-examples-reaction-alerts",
+		indoc!(
+			"
+			Benachrichtigt werden, wenn jemand mit ❓ auf deine Nachrichten \
+			reagiert:
+			/reaction-alerts add `emoji:` ❓"
+		),
+	),
+	(
+		"help-ignore",
+		indoc!(
+			"
+			Verwende `/ignore [phrase]`, um einen Ausdruck hinzuzufügen, der \
+			auf diesem Server ignoriert wird.
+
+			Über Nachrichten mit ignorierten Ausdrücken wirst du nicht \
+			benachrichtigt, selbst wenn sie eines deiner Stichwörter \
+			enthalten.
+
+			Groß- und Kleinschreibung spielt bei Ausdrücken keine Rolle.
+
+			Um mehrere Ausdrücke auf einmal zu ignorieren, verwende \
+			`/ignore` ohne Ausdruck und gib sie durch Kommas oder \
+			Zeilenumbrüche getrennt ein.
+
+			Mit der Option `channel` ignorierst du einen Ausdruck nur in \
+			einem Kanal, mit der Option `keyword` nur für eines deiner \
+			Stichwörter. Ein für ein Stichwort ignorierter Ausdruck hält \
+			deine anderen Stichwörter nicht davon ab, dich zu \
+			benachrichtigen.
+
+			Mit `/unignore [phrase]` kannst du ignorierte Ausdrücke später \
+			entfernen; mehr dazu unter `/help unignore`.
+
+			Mit `/ignores` kannst du deine ignorierten Ausdrücke anzeigen."
+		),
+	),
+	(
+		"help-examples-ignore",
+		indoc!(
+			"
+			Nachrichten mit \"meme\" auf diesem Server ignorieren:
+			/ignore `phrase:` meme
+
+			Nachrichten mit \"hello world\" auf diesem Server ignorieren:
+			/ignore `phrase:` hello world
+
+			Nachrichten mit \"meme\" in #general ignorieren:
+			/ignore `phrase:` meme `channel:` #general
+
+			Nicht über \"rust\" in Nachrichten mit \"game\" benachrichtigt \
+			werden:
+			/ignore `phrase:` game `keyword:` rust"
+		),
+	),
+	(
+		"help-unignore",
+		indoc!(
+			"
+			Verwende `/unignore [phrase]`, um einen Ausdruck zu entfernen, \
+			den du zuvor auf diesem Server ignoriert hast.
+
+			Wenn du den Ausdruck nur in einem Kanal oder für ein Stichwort \
+			ignoriert hast, gib denselben `channel` oder dasselbe `keyword` \
+			an, um ihn zu entfernen.
+
+			Groß- und Kleinschreibung spielt bei Ausdrücken keine Rolle.
+
+			Mit `/ignores` kannst du deine ignorierten Ausdrücke anzeigen."
+		),
+	),
+	(
+		"help-examples-unignore",
+		indoc!(
+			"
+			Nachrichten mit \"haskell\" auf diesem Server nicht mehr \
+			ignorieren:
+			/unignore `phrase:` haskell
+
+			Nachrichten mit \"map-reduce\" auf diesem Server nicht mehr \
+			ignorieren:
+			/unignore `phrase:` map-reduce
+
+			Nachrichten mit \"meme\" in #general nicht mehr ignorieren:
+			/unignore `phrase:` meme `channel:` #general"
+		),
+	),
+	(
+		"help-keywords",
+		indoc!(
+			"
+			Verwende `/keywords`, um deine aktuellen Stichwörter anzuzeigen.
+
+			Auf einem Server zeigt `keywords` nur die Stichwörter an, die du \
+			auf diesem Server hinzugefügt hast, einschließlich aller \
+			kanalspezifischen Stichwörter dort.
+
+			Team-Stichwörter, die die Admins des Servers für deine Rollen \
+			hinzugefügt haben, stehen unter eigenen Überschriften. Unter \
+			`/help team-keywords` erfährst du, wie du sie verlässt.
+
+			Bei Stichwörtern, die du mit `/keyword-authors` auf bestimmte \
+			Autoren beschränkt hast, stehen diese Autoren dabei.
+
+			Stichwörter, die der Bot oder der Server nicht mehr erlaubt, \
+			etwa weil ein Admin ein enthaltenes Wort gesperrt hat, sind mit \
+			⚠️ markiert. Sie werden nicht mehr hervorgehoben, und du \
+			solltest sie entfernen.
+
+			Lange Listen werden auf Seiten aufgeteilt; mit den Knöpfen unter \
+			der Liste wechselst du zwischen ihnen.
+
+			Wenn der Bot keine Informationen zu einem Server findet, auf dem \
+			du Stichwörter hast, steht seine ID in Klammern, damit du sie \
+			bei Bedarf mit `remove-server` entfernen kannst. Mehr dazu unter \
+			`/help remove-server`.
+
+			Mit den Optionen `server` und `channel` zeigst du nur \
+			Stichwörter auf einem anderen Server oder in einem bestimmten \
+			Kanal an, und mit `sort` sortierst du sie alphabetisch statt \
+			danach, wo sie gelten."
+		),
+	),
+	(
+		"help-examples-keywords",
+		indoc!(
+			"
+			Deine Stichwörter im Kanal #general anzeigen:
+			/keywords `channel:` #general
+
+			Alle deine Stichwörter alphabetisch in DMs mit dem Bot anzeigen:
+			/keywords `sort:` By name"
+		),
+	),
+	(
+		"help-mutes",
+		indoc!(
+			"
+			Verwende `/mutes`, um deine stummgeschalteten Kanäle anzuzeigen.
+
+			Auf einem Server zeigt `mutes` nur die Kanäle an, die du auf \
+			diesem Server stummgeschaltet hast.
+
+			In DMs mit dem Bot zeigt `mutes` die stummgeschalteten Kanäle \
+			auf allen Servern an, einschließlich gelöschter Kanäle oder \
+			Kanäle auf Servern, auf denen der Bot nicht mehr ist. Wenn der \
+			Bot keine Informationen zu einem stummgeschalteten Kanal findet, \
+			steht seine ID in Klammern.
+
+			Mit der Option `server` zeigst du Stummschaltungen auf einem \
+			anderen Server an."
+		),
+	),
+	(
+		"help-blocks",
+		indoc!(
+			"
+			Verwende `/blocks`, um deine blockierten Benutzer anzuzeigen."
+		),
+	),
+	(
+		"help-follows",
+		indoc!(
+			"
+			Verwende `/follows`, um die Benutzer anzuzeigen, denen du \
+			folgst, und die Kanäle, in denen du ihnen folgst.
+
+			Auf einem Server zeigt `follows` nur an, wem du auf diesem \
+			Server folgst. Mit der Option `server` zeigst du an, wem du auf \
+			einem anderen Server folgst."
+		),
+	),
+	(
+		"help-ignores",
+		indoc!(
+			"
+			Verwende `/ignores`, um deine ignorierten Ausdrücke anzuzeigen.
+
+			Auf einem Server zeigt `ignores` nur die Ausdrücke an, die du \
+			auf diesem Server ignorierst. Nur in einem Kanal ignorierte \
+			Ausdrücke stehen unter diesem Kanal, und nur für ein Stichwort \
+			ignorierte Ausdrücke sind damit markiert.
+
+			In DMs mit dem Bot zeigt `ignores` die ignorierten Ausdrücke auf \
+			allen Servern an, einschließlich Servern, auf denen der Bot \
+			nicht mehr ist.
+
+			Wenn der Bot keine Informationen zu einem Server findet, auf dem \
+			du Ausdrücke ignorierst, steht seine ID in Klammern, damit du \
+			die Ausdrücke dort bei Bedarf mit `remove-server` entfernen \
+			kannst.
+
+			Mit der Option `server` zeigst du ignorierte Ausdrücke auf einem \
+			anderen Server an."
+		),
+	),
+	(
+		"help-remove-server",
+		indoc!(
+			"
+			Verwende `/remove-server [server ID]`, um alle Stichwörter \
+			**und** ignorierten Ausdrücke auf dem Server mit der angegebenen \
+			ID zu entfernen.
+
+			Kanalspezifische Stichwörter auf diesem Server werden dabei \
+			nicht entfernt; dafür kannst du den normalen Befehl `remove` \
+			verwenden.
+
+			Normalerweise ist das nicht nötig. Wenn du aber keinen Server \
+			mehr mit dem Bot teilst, auf dem du Stichwörter hinzugefügt \
+			hast, kannst du deine Stichwortliste aufräumen: Verwende \
+			`keywords` in DMs, um alle Stichwörter zu sehen, und diesen \
+			Befehl, um Server-IDs zu entfernen, die der Bot nicht findet."
+		),
+	),
+	(
+		"help-examples-remove-server",
+		indoc!(
+			"
+			Alle serverweiten Stichwörter und ignorierten Ausdrücke auf dem \
+			Server mit der ID 126029834632 entfernen:
+			/remove-server `server:` 126029834632"
+		),
+	),
+	(
+		"help-manage",
+		indoc!(
+			"
+			Verwende `/manage`, um ein Menü deiner Stichwörter auf diesem \
+			Server zu sehen, einschließlich kanalspezifischer Stichwörter. \
+			Wähle die Stichwörter aus, die du entfernen willst, über \
+			beliebig viele Seiten, und drücke dann „Auswahl entfernen“."
+		),
+	),
+	(
+		"help-copy-server",
+		indoc!(
+			"
+			Verwende `/copy-server [server ID]`, um alle deine serverweiten \
+			Stichwörter und ignorierten Ausdrücke vom Server mit der \
+			angegebenen ID auf diesen Server zu kopieren.
+
+			Stichwörter und ignorierte Ausdrücke, die du hier schon \
+			hinzugefügt hast, werden übersprungen, ebenso Stichwörter, die \
+			das Stichwortlimit überschreiten würden.
+
+			Verwende `/keywords` in DMs mit dem Bot, um die IDs der Server \
+			zu sehen, auf denen du Stichwörter hinzugefügt hast."
+		),
+	),
+	(
+		"help-examples-copy-server",
+		indoc!(
+			"
+			Stichwörter und ignorierte Ausdrücke vom Server mit der ID \
+			126029834632 kopieren:
+			/copy-server `server:` 126029834632"
+		),
+	),
+	(
+		"help-copy-channel",
+		indoc!(
+			"
+			Verwende `/copy-channel [from] [to]`, um alle deine Stichwörter \
+			eines Kanals in einen anderen Kanal zu kopieren. Wenn du keinen \
+			Zielkanal angibst, werden sie in den aktuellen Kanal kopiert.
+
+			Stichwörter, die du im Kanal schon hinzugefügt hast, werden \
+			übersprungen, ebenso Stichwörter, die das Stichwortlimit \
+			überschreiten würden."
+		),
+	),
+	(
+		"help-examples-copy-channel",
+		indoc!(
+			"
+			Stichwörter von #general nach #help kopieren:
+			/copy-channel `from:` #general `to:` #help"
+		),
+	),
+	(
+		"help-history",
+		indoc!(
+			"
+			Verwende `/history list`, um deine letzten Benachrichtigungen \
+			mit Stichwort, Kanal, Zeit und einem Link zur Nachricht \
+			anzuzeigen.
+
+			Verwende `/history search [text]`, um Benachrichtigungen über \
+			Nachrichten mit dem angegebenen Text zu finden.
+
+			Beide lassen sich auf ein bestimmtes Stichwort, einen Server \
+			(per ID) oder Nachrichten `after` (ab) oder `before` (vor) einem \
+			Datum im Format JJJJ-MM-TT beschränken.
+
+			Nur Benachrichtigungen, die als Discord-Nachrichten gesendet \
+			wurden, werden gespeichert, auch solche über einen Webhook; per \
+			HTTP, E-Mail oder in einer Zusammenfassung zugestellte \
+			erscheinen nicht in deinem Verlauf. Benachrichtigungen werden \
+			nur so lange aufbewahrt, wie der Betreiber des Bots es zulässt."
+		),
+	),
+	(
+		"help-examples-history",
+		indoc!(
+			"
+			Deine letzten Benachrichtigungen anzeigen:
+			/history list
+
+			Benachrichtigungen über das Stichwort \"rust\" aus dem Oktober \
+			2026 anzeigen:
+			/history list `keyword:` rust `after:` 2026-10-01 `before:` \
+			2026-11-01
+
+			Benachrichtigungen über Nachrichten finden, die ein Release \
+			erwähnen:
+			/history search `text:` release"
+		),
+	),
+	(
+		"help-test-keyword",
+		indoc!(
+			"
+			Verwende `/test-keyword [keyword] [text]`, um zu sehen, ob du \
+			über das Stichwort benachrichtigt würdest, wenn der Text in \
+			diesem Kanal gesendet würde, ohne dass Benachrichtigungen \
+			gesendet werden. Gib `author` an, um es auch so zu prüfen, als \
+			hätte dieser Benutzer ihn gesendet.
+
+			Du siehst, wo das Stichwort gefunden wurde und warum du nicht \
+			benachrichtigt würdest, etwa wegen eines ignorierten Ausdrucks, \
+			weil dieser Kanal stummgeschaltet ist oder der Autor blockiert \
+			ist.
+
+			Um deine Stichwörter an einer bereits gesendeten Nachricht zu \
+			testen, klicke mit der rechten Maustaste auf die Nachricht (oder \
+			halte sie gedrückt) und wähle Apps > Stichwörter testen."
+		),
+	),
+	(
+		"help-examples-test-keyword",
+		indoc!(
+			"
+			Testen, ob \"rust\" auf eine Nachricht zutreffen würde:
+			/test-keyword `keyword:` rust `text:` I'm learning Rust!
+
+			So testen, als hätte SomeUser die Nachricht gesendet:
+			/test-keyword `keyword:` rust `text:` rust 2.0 when? `author:` \
+			@SomeUser"
+		),
+	),
+	(
+		"help-why",
+		indoc!(
+			"
+			Verwende `/why [message link]`, um eine Nachricht mit deinen \
+			aktuellen Stichwörtern und Einstellungen abzugleichen. Für jedes \
+			deiner Stichwörter siehst du, ob es in der Nachricht vorkommt, \
+			und wenn ja, was eine Benachrichtigung verhindern würde: ein \
+			ignorierter Ausdruck, ein stummgeschalteter Kanal, ein \
+			blockierter Autor, die Abmeldung des Autors oder dass du den \
+			Kanal nicht lesen kannst.
+
+			Außerdem siehst du, ob du tatsächlich über die Nachricht \
+			benachrichtigt wurdest.
+
+			Um einen Nachrichtenlink zu erhalten, klicke mit der rechten \
+			Maustaste auf die Nachricht (oder halte sie gedrückt) und wähle \
+			„Nachrichtenlink kopieren“."
+		),
+	),
+	(
+		"help-examples-why",
+		indoc!(
+			"
+			Eine Nachricht prüfen:
+			/why `message:` https://discord.com/channels/1234/5678/9012"
+		),
+	),
+	(
+		"help-stats",
+		indoc!(
+			"
+			Verwende `/stats`, um zu sehen, auf wie viele Nachrichten jedes \
+			deiner Stichwörter auf diesem Server zugetroffen hat und wie \
+			viele Benachrichtigungen dafür gesendet wurden. Verwende den \
+			Befehl in DMs, um deine Stichwörter auf allen Servern zu sehen.
+
+			Treffer ohne Benachrichtigung werden nach dem Grund \
+			aufgeschlüsselt:
+			- `patience`: du warst kurz nach der Nachricht im Kanal aktiv
+			- `ignored`: einer deiner ignorierten Ausdrücke kam in der \
+			Nachricht vor
+			- `muted`: du hast den Kanal stummgeschaltet
+			- `blocked`: du hast den Autor blockiert
+
+			Stichwörter, die nie zugetroffen haben, werden ebenfalls \
+			aufgeführt, damit du nicht benötigte entfernen kannst. Die \
+			Statistiken können einige Minuten brauchen, um sich zu \
+			aktualisieren."
+		),
+	),
+	(
+		"help-delivery",
+		indoc!(
+			"
+			Verwende `/delivery`, um zu sehen, wie deine Benachrichtigungen \
+			derzeit zugestellt werden.
+
+			Verwende `/delivery [method] [url]`, um die Zustellung zu \
+			ändern:
+			- `dm`: Direktnachrichten vom Bot (Standard)
+			- `webhook`: Nachrichten über einen von dir erstellten \
+			Discord-Webhook, angegeben mit seiner URL
+			- `http`: JSON-POST-Anfragen an eine URL deiner Wahl, für \
+			Dienste wie ntfy oder Gotify (falls vom Betreiber des Bots \
+			aktiviert)
+			- `email`: E-Mails an die Adresse, die du mit `/email` bestätigt \
+			hast (falls vom Betreiber des Bots aktiviert)
+			- `email-digest`: wie `email`, aber Benachrichtigungen werden \
+			gesammelt und regelmäßig zusammen gesendet
+
+			Wenn eine Benachrichtigung nicht zugestellt werden kann, wirst \
+			du beim nächsten Befehl gewarnt.
+
+			Per HTTP oder E-Mail gesendete Benachrichtigungen können nicht \
+			aktualisiert oder entfernt werden, wenn die ursprüngliche \
+			Nachricht bearbeitet oder gelöscht wird."
+		),
+	),
+	(
+		"help-examples-delivery",
+		indoc!(
+			"
+			Benachrichtigungen über einen Webhook erhalten:
+			/delivery `method:` webhook `url:` \
+			https://discord.com/api/webhooks/...
+
+			Benachrichtigungen wieder per DM erhalten:
+			/delivery `method:` dm"
+		),
+	),
+	(
+		"help-email",
+		indoc!(
+			"
+			Verwende `/email [address]`, um eine E-Mail-Adresse für \
+			Benachrichtigungen hinzuzufügen. Ich sende einen \
+			Bestätigungscode an diese Adresse; bestätige mit `/email \
+			[code]`, dass sie dir gehört. Du kannst einmal pro Minute einen \
+			neuen Code anfordern.
+
+			Sobald deine Adresse bestätigt ist, kannst du mit `/delivery` \
+			Benachrichtigungen oder regelmäßige Zusammenfassungen per E-Mail \
+			erhalten.
+
+			Verwende `/email`, um deine aktuelle Adresse zu sehen, oder \
+			`/email remove:True`, um sie zu entfernen.
+
+			E-Mail-Benachrichtigungen sind nur verfügbar, wenn der Betreiber \
+			des Bots sie aktiviert hat."
+		),
+	),
+	(
+		"help-examples-email",
+		indoc!(
+			"
+			Die Adresse ferris@example.com hinzufügen:
+			/email `address:` ferris@example.com
+
+			Deine Adresse mit dem erhaltenen Code bestätigen:
+			/email `code:` 123456"
+		),
+	),
+	(
+		"help-fallback",
+		indoc!(
+			"
+			Verwende `/fallback [channel]`, um einen Kanal zu wählen, in dem \
+			ich für jedes Mitglied mit geschlossenen DMs einen privaten \
+			Thread erstelle und seine Benachrichtigungen von diesem Server \
+			stattdessen dort sende.
+
+			Verwende `/fallback`, um den aktuellen Ersatzkanal zu sehen, \
+			oder `/fallback disable:True`, um keine Benachrichtigungen mehr \
+			auf diesem Server zu senden.
+
+			Du brauchst die Berechtigung „Server verwalten“, um diesen \
+			Befehl zu verwenden."
+		),
+	),
+	(
+		"help-examples-fallback",
+		indoc!(
+			"
+			Benachrichtigungen in privaten Threads in #highlights senden:
+			/fallback `channel:` #highlights
+
+			Keine Benachrichtigungen mehr auf diesem Server senden:
+			/fallback `disable:` True"
+		),
+	),
+	(
+		"help-server-settings",
+		indoc!(
+			"
+			Verwende `/server-settings view`, um die Einstellungen dieses \
+			Servers zu sehen.
+
+			Verwende `/server-settings exclude [channel]`, um Nachrichten in \
+			einem Kanal für alle nicht mehr hervorzuheben, etwa in \
+			Mitarbeiter- oder Personalkanälen, und `/server-settings include \
+			[channel]`, um sie wieder hervorzuheben.
+
+			Verwende `/server-settings keyword-limit [limit]`, um zu \
+			begrenzen, wie viele Stichwörter jedes Mitglied auf diesem \
+			Server hinzufügen kann, und `/server-settings min-length \
+			[length]`, um längere Stichwörter zu verlangen. Lass den Wert \
+			weg, um zum Standard zurückzukehren.
+
+			Verwende `/server-settings highlighting enabled:False`, um das \
+			Hervorheben auf diesem Server ganz abzuschalten.
+
+			Verwende `/server-settings ban-keyword [word]`, um Mitgliedern \
+			Stichwörter mit einem bestimmten Wort zu verbieten, etwa dem \
+			Namen eines Mitglieds, und `/server-settings unban-keyword \
+			[word]`, um sie wieder zu erlauben. Vorhandene Stichwörter mit \
+			dem Wort benachrichtigen nicht mehr und werden in `/keywords` \
+			markiert.
+
+			Verwende `/server-settings revoke [user]`, um einem Mitglied \
+			Benachrichtigungen über Stichwörter und das Hinzufügen von \
+			Stichwörtern auf diesem Server zu entziehen, etwa wenn es \
+			Highlights nutzt, um andere zu belästigen, und `/server-settings \
+			restore [user]`, um das rückgängig zu machen.
+
+			Verwende `/server-settings audit`, um zu sehen, wer diese \
+			Einstellungen zuletzt geändert hat.
+
+			Du brauchst die Berechtigung „Server verwalten“, um diesen \
+			Befehl zu verwenden."
+		),
+	),
+	(
+		"help-examples-server-settings",
+		indoc!(
+			"
+			Nachrichten in #staff nicht mehr hervorheben:
+			/server-settings exclude `channel:` #staff
+
+			Jedem Mitglied 20 Stichwörter auf diesem Server erlauben:
+			/server-settings keyword-limit `limit:` 20
+
+			Das Hervorheben auf diesem Server abschalten:
+			/server-settings highlighting `enabled:` False
+
+			Mitgliedern Stichwörter mit \"alice\" verbieten:
+			/server-settings ban-keyword `word:` alice"
+		),
+	),
+	(
+		"help-team-keywords",
+		indoc!(
+			"
+			Verwende `/team-keywords add [role] [keyword]`, um alle \
+			Mitglieder einer Rolle über ein Stichwort zu benachrichtigen, \
+			ohne dass jedes es selbst hinzufügt, und `/team-keywords remove \
+			[role] [keyword]`, um das zu beenden. Mitglieder werden wie bei \
+			ihren eigenen Stichwörtern benachrichtigt, ihre \
+			Stummschaltungen, Blockierungen und ignorierten Ausdrücke gelten \
+			also weiterhin. Du brauchst die Berechtigung „Server verwalten“, \
+			um Team-Stichwörter hinzuzufügen oder zu entfernen.
+
+			Verwende `/team-keywords list`, um die Team-Stichwörter dieses \
+			Servers zu sehen.
+
+			Verwende `/team-keywords leave [keyword]`, um über ein \
+			Team-Stichwort deiner Rollen nicht mehr benachrichtigt zu \
+			werden, und `/team-keywords rejoin [keyword]`, um wieder \
+			benachrichtigt zu werden. Deine Team-Stichwörter werden auch in \
+			`/keywords` angezeigt."
+		),
+	),
+	(
+		"help-examples-team-keywords",
+		indoc!(
+			"
+			Alle in @oncall über Ausfälle benachrichtigen:
+			/team-keywords add `role:` @oncall `keyword:` outage
+
+			Nicht mehr über ein Team-Stichwort benachrichtigt werden:
+			/team-keywords leave `keyword:` outage"
+		),
+	),
+	(
+		"help-keyword-pack",
+		indoc!(
+			"
+			Verwende `/keyword-pack add [name] [keywords]`, um durch Kommas \
+			getrennte Stichwörter zu einem Paket hinzuzufügen, das dabei \
+			erstellt wird, falls es noch nicht existiert. Mitglieder können \
+			das Paket mit `/subscribe` abonnieren, um über alle seine \
+			Stichwörter benachrichtigt zu werden, auch über später \
+			hinzugefügte.
+
+			Verwende `/keyword-pack remove [name] [keywords]`, um \
+			Stichwörter aus einem Paket zu entfernen, und `/keyword-pack \
+			delete [name]`, um ein Paket ganz zu löschen.
+
+			Verwende `/keyword-pack list`, um die Pakete dieses Servers zu \
+			sehen.
+
+			Du brauchst die Berechtigung „Server verwalten“, um Pakete zu \
+			ändern."
+		),
+	),
+	(
+		"help-examples-keyword-pack",
+		indoc!(
+			"
+			Ein Paket mit Frontend-Stichwörtern veröffentlichen:
+			/keyword-pack add `name:` frontend `keywords:` react, vite, css
+
+			Das Paket löschen:
+			/keyword-pack delete `name:` frontend"
+		),
+	),
+	(
+		"help-subscribe",
+		indoc!(
+			"
+			Verwende `/subscribe [pack]`, um über alle Stichwörter in einem \
+			der Pakete benachrichtigt zu werden, die die Admins dieses \
+			Servers veröffentlicht haben. Wenn sich das Paket ändert, wirst \
+			du automatisch über seine neuen Stichwörter benachrichtigt.
+
+			Paket-Stichwörter stehen in `/keywords` und zählen nicht zu \
+			deinem Stichwortlimit, sofern der Betreiber des Bots nichts \
+			anderes festgelegt hat. Verwende `/keyword-pack list`, um die \
+			verfügbaren Pakete zu sehen."
+		),
+	),
+	(
+		"help-examples-subscribe",
+		indoc!(
+			"
+			Das Paket frontend abonnieren:
+			/subscribe `pack:` frontend"
+		),
+	),
+	(
+		"help-unsubscribe",
+		indoc!(
+			"
+			Verwende `/unsubscribe [pack]`, um nicht mehr über die \
+			Stichwörter eines abonnierten Pakets benachrichtigt zu werden."
+		),
+	),
+	(
+		"help-examples-unsubscribe",
+		indoc!(
+			"
+			Das Paket frontend kündigen:
+			/unsubscribe `pack:` frontend"
+		),
+	),
+	(
+		"help-export",
+		indoc!(
+			"
+			Verwende `/export`, um eine JSON-Datei mit all deinen \
+			Stichwörtern, Kanal-Stichwörtern, den Kanälen und Autoren, auf \
+			die deine Stichwörter beschränkt sind, ignorierten Ausdrücken, \
+			stummgeschalteten Kanälen und blockierten Benutzern zu erhalten.
+
+			Bewahre sie als Sicherung auf, etwa bevor du `/opt-out` \
+			verwendest, oder lade sie mit `/import` in eine andere Instanz \
+			dieses Bots."
+		),
+	),
+	(
+		"help-import",
+		indoc!(
+			"
+			Verwende `/import [file]` mit einer von `/export` erstellten \
+			Datei, um ihre Stichwörter, ignorierten Ausdrücke, \
+			stummgeschalteten Kanäle und blockierten Benutzer zu deinen \
+			hinzuzufügen. Vor dem Import siehst du eine Vorschau der \
+			Änderungen.
+
+			Verwende `/import [file] replace:True`, um außerdem alles zu \
+			entfernen, was nicht in der Datei steht.
+
+			Stichwörter, die ein Server nicht erlaubt, werden übersprungen, \
+			ebenso Stichwörter, die dein Stichwortlimit oder das des Servers \
+			überschreiten würden, und alles auf Servern, auf denen du nicht \
+			bist, oder in Kanälen, die du nicht lesen kannst."
+		),
+	),
+	(
+		"help-examples-import",
+		indoc!(
+			"
+			Eine Sicherung importieren:
+			/import `file:` highlights.json
+
+			Deine Einstellungen genau an eine Sicherung angleichen:
+			/import `file:` highlights.json `replace:` True"
+		),
+	),
+	(
+		"help-opt-out",
+		indoc!(
+			"
+			Verwende `/opt-out`, um dich vom Hervorheben abzumelden.
+
+			Wenn du dich abmeldest, werden deine Stichwörter und anderen \
+			Einstellungen gelöscht, und andere werden nicht mehr über deine \
+			Nachrichten benachrichtigt.
+
+			Mit `/opt-in` kannst du dich später wieder anmelden, aber beim \
+			Abmelden gelöschte Informationen können nicht wiederhergestellt \
+			werden."
+		),
+	),
+	(
+		"help-opt-in",
+		indoc!(
+			"
+			Verwende `/opt-in`, um dich nach einer Abmeldung wieder \
+			anzumelden.
+
+			Dieser Befehl hat keine Wirkung, wenn du dich nicht mit \
+			`/opt-out` abgemeldet hast.
+
+			Mehr dazu unter `/help opt-out`."
+		),
+	),
+	(
+		"help-help",
+		indoc!(
+			"
+			Verwende `/help`, um eine Liste der Befehle mit kurzen \
+			Beschreibungen zu sehen.
+			Verwende `/help [command]`, um weitere Informationen zum \
+			angegebenen Befehl zu sehen.
+			Verwende `/about`, um Informationen über diesen Bot zu sehen."
+		),
+	),
+	(
+		"help-examples-help",
+		indoc!(
+			"
+			Die Liste der Befehle anzeigen:
+			/help
+
+			Die Hilfe zum Befehl `add` anzeigen:
+			/help `command:` add"
+		),
+	),
+	(
+		"help-ping",
+		indoc!(
+			"
+			Zeige den Ping des Bots an, einschließlich der aktuellen API-, \
+			Befehls- und Datenbanklatenz."
+		),
+	),
+	(
+		"help-about",
+		indoc!(
+			"
+			Zeige einige Informationen über diesen Bot an, etwa seine \
+			Version, einen Link zum Quellcode und einen Einladungslink."
+		),
+	),
+];
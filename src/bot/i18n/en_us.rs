@@ -0,0 +1,1131 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! American English messages, the default locale.
+//!
+//! Command descriptions aren't included here; they're written alongside each
+//! command's help text.
+
+pub(super) static MESSAGES: &[(&str, &str)] = &[
+	// errors shared by many commands
+	(
+		"error-guild-only",
+		"❌ You must run this command in a server!",
+	),
+	(
+		"error-opted-out",
+		"❌ You can't use this command after opting out!",
+	),
+	(
+		"error-manage-guild",
+		"❌ You need the Manage Server permission to use this command!",
+	),
+	(
+		"error-embed-perms",
+		"Sorry, I need permission to embed links to use that command 😔",
+	),
+	("error-invalid-server", "❌ Invalid server ID!"),
+	("error-text-channel", "❌ Please provide a text channel!"),
+	("error-cannot-read", "❌ I can't read {channel}!"),
+	// keywords
+	(
+		"keyword-limit",
+		"You can't create more than {max} keywords!",
+	),
+	(
+		"keyword-too-short",
+		"❌ You can't highlight keywords shorter than {min} characters!",
+	),
+	(
+		"keyword-guild-limit",
+		"❌ This server only allows {max} keywords per member!",
+	),
+	(
+		"keyword-too-long",
+		"❌ You can't add keywords longer than {max} characters!",
+	),
+	(
+		"keyword-banned",
+		"❌ This server doesn't allow keywords containing \"{word}\"!",
+	),
+	("keyword-invalid", "❌ You can't add that keyword!"),
+	("keyword-flagged", "⚠️ (no longer allowed; not highlighted)"),
+	("keyword-authors", "(only from {authors})"),
+	("keyword-channels-only", "(only in {channels})"),
+	("keyword-channels-except", "(not in {channels})"),
+	(
+		"keyword-revoked",
+		"❌ This server's admins don't allow you to add keywords here!",
+	),
+	(
+		"keyword-channels-invalid",
+		"❌ Mention the channels to add the keyword in, like #general #help!",
+	),
+	(
+		"keyword-channels-limit",
+		"❌ You can't add a keyword in more than {max} channels at once!",
+	),
+	(
+		"keyword-channels-missing",
+		"❌ Enter a keyword to add in several channels!",
+	),
+	(
+		"keyword-channels-conflict",
+		"❌ Use either `channel` or `channels`, not both!",
+	),
+	("keyword-exists", "❌ You already added that keyword!"),
+	("keyword-missing", "❌ You haven't added that keyword!"),
+	(
+		"keyword-test-dm",
+		"Test message; if you can read this, I can send you notifications \
+		successfully!",
+	),
+	("keywords-title", "Your keywords"),
+	("keywords-empty", "❌ You haven't added any keywords yet!"),
+	(
+		"keyword-authors-limit",
+		"❌ Keywords can't be restricted to more than {max} users and roles!",
+	),
+	// keyword forms
+	("keyword-form-add-title", "Add keyword"),
+	("keyword-form-edit-title", "Edit keyword"),
+	("keyword-form-label-keyword", "Keyword"),
+	(
+		"keyword-form-label-channel",
+		"Channel (leave empty for the whole server)",
+	),
+	(
+		"keyword-form-label-channels",
+		"Only in these channels (server-wide)",
+	),
+	(
+		"keyword-form-label-excluded-channels",
+		"Not in these channels (server-wide)",
+	),
+	("keyword-form-label-authors", "Only from these users or roles"),
+	(
+		"keyword-form-excluded-limit",
+		"❌ Keywords can't be excluded from more than {max} channels!",
+	),
+	(
+		"keyword-form-channel-conflict",
+		"❌ A keyword can't be both limited to and excluded from {channel}!",
+	),
+	(
+		"keyword-form-channel-options",
+		"❌ Only server-wide keywords can be limited to or excluded from \
+		channels!",
+	),
+	(
+		"keyword-form-unknown-text-channel",
+		"❌ There's no text channel named `{channel}` in this server!",
+	),
+	(
+		"keyword-form-unknown-channel",
+		"❌ There's no channel named `{channel}` in this server!",
+	),
+	(
+		"keyword-form-unknown-role",
+		"❌ There's no role named `{role}` in this server!",
+	),
+	("keyword-form-unchanged", "❌ You didn't change anything!"),
+	("keyword-form-in-server", "in this server"),
+	("keyword-form-in-channel", "in {channel}"),
+	("keyword-form-added", "✅ Added \"{keyword}\" {location}"),
+	(
+		"keyword-form-moved",
+		"✅ Changed \"{old_keyword}\" {old_location} to \"{keyword}\" \
+		{location}",
+	),
+	(
+		"keyword-form-changed",
+		"✅ Changed the options of \"{keyword}\" {location}",
+	),
+	// team keywords
+	("keywords-team-group", "Team keywords of {role}"),
+	("keywords-team-group-in", "{server} › team keywords of {role}"),
+	("keywords-left", "(left)"),
+	("team-keywords-title", "**Team keywords**"),
+	("team-keywords-empty", "This server doesn't have any team keywords."),
+	(
+		"team-keywords-limit",
+		"❌ Servers can't have more than {max} team keywords!",
+	),
+	(
+		"team-keyword-everyone",
+		"❌ Team keywords can't be added for @everyone; choose a smaller \
+		role!",
+	),
+	(
+		"team-keyword-role-too-large",
+		"❌ Team keywords can't be added for roles with more than {max} \
+		members, and {role} has more!",
+	),
+	("team-keyword-exists", "❌ That role already has that team keyword!"),
+	("team-keyword-missing", "❌ That role doesn't have that team keyword!"),
+	(
+		"team-keyword-added",
+		"✅ Members of {role} will be notified of \"{keyword}\"",
+	),
+	(
+		"team-keyword-removed",
+		"✅ Members of {role} will no longer be notified of \"{keyword}\"",
+	),
+	(
+		"team-keyword-no-role",
+		"❌ None of your roles have the team keyword \"{keyword}\"!",
+	),
+	(
+		"team-keyword-left",
+		"✅ You won't be notified of the team keyword \"{keyword}\"",
+	),
+	(
+		"team-keyword-already-left",
+		"❌ You already left the team keyword \"{keyword}\"!",
+	),
+	(
+		"team-keyword-rejoined",
+		"✅ You'll be notified of the team keyword \"{keyword}\" again",
+	),
+	(
+		"team-keyword-not-left",
+		"❌ You haven't left the team keyword \"{keyword}\"!",
+	),
+	// keyword packs
+	("keywords-pack-group", "Keyword pack \"{pack}\""),
+	("keywords-pack-group-in", "{server} › keyword pack \"{pack}\""),
+	("packs-title", "**Keyword packs**"),
+	("packs-empty", "This server doesn't have any keyword packs."),
+	("packs-subscribed", " (subscribed)"),
+	(
+		"pack-name-length",
+		"❌ Pack names must be between 1 and {max} characters long!",
+	),
+	("pack-missing", "❌ There's no pack named \"{pack}\"!"),
+	("pack-nothing-added", "❌ Nothing was added to the pack \"{pack}\"."),
+	("pack-added", "✅ Added {keywords} to the pack \"{pack}\"."),
+	(
+		"pack-skipped",
+		"Skipped because they're too short or too long, banned here, \
+		invalid, already in the pack, or over the limit of {max} keywords: \
+		{keywords}",
+	),
+	(
+		"pack-nothing-removed",
+		"❌ None of those keywords are in the pack \"{pack}\"!",
+	),
+	("pack-removed", "✅ Removed {keywords} from the pack \"{pack}\"."),
+	(
+		"pack-emptied",
+		"✅ Removed all keywords from the pack \"{pack}\", so it was deleted.",
+	),
+	("pack-deleted", "✅ Deleted the pack \"{pack}\"."),
+	(
+		"pack-already-subscribed",
+		"❌ You already subscribed to that pack!",
+	),
+	(
+		"pack-over-limit",
+		"❌ That pack would take you over the limit of {max} keywords!",
+	),
+	("pack-subscribed", "✅ Subscribed to \"{pack}\": {keywords}"),
+	("pack-not-subscribed", "❌ You haven't subscribed to that pack!"),
+	("pack-unsubscribed", "✅ Unsubscribed from \"{pack}\""),
+	// follows
+	("follow-self", "❌ You can't follow yourself!"),
+	(
+		"follow-never-highlighted",
+		"❌ {user}'s messages are never highlighted!",
+	),
+	(
+		"follow-other-server",
+		"❌ You can only follow users in channels of this server!",
+	),
+	("follow-cannot-read", "❌ You can't read {channel}!"),
+	("follow-exists", "❌ You already follow {user} in {channel}!"),
+	(
+		"follow-limit",
+		"❌ You can't follow users in more than {max} channels!",
+	),
+	(
+		"follow-added",
+		"✅ You'll be notified of {user}'s messages in {channel}",
+	),
+	(
+		"follow-removed",
+		"✅ You won't be notified of {user}'s messages in {channel} anymore",
+	),
+	("follow-missing", "❌ You don't follow {user} in {channel}!"),
+	(
+		"follow-removed-all",
+		"✅ You won't be notified of {user}'s messages anymore",
+	),
+	("follow-missing-all", "❌ You don't follow {user}!"),
+	("follows-entry", "{user} in {channel}"),
+	("follows-title", "Users you follow"),
+	("follows-empty", "❌ You don't follow anyone!"),
+	// keyword authors
+	(
+		"keyword-authors-title",
+		"**Keywords restricted to authors**",
+	),
+	(
+		"keyword-authors-empty",
+		"You haven't restricted any keywords to particular authors in this \
+		server.",
+	),
+	("keyword-authors-missing", "❌ You need to choose a user or a role!"),
+	(
+		"keyword-authors-no-keyword",
+		"❌ You don't have the keyword \"{keyword}\" in this server!",
+	),
+	(
+		"keyword-authors-exist",
+		"❌ \"{keyword}\" is already restricted to those authors!",
+	),
+	(
+		"keyword-authors-added",
+		"✅ \"{keyword}\" will now notify you of messages from {authors}",
+	),
+	(
+		"keyword-authors-not-found",
+		"❌ \"{keyword}\" isn't restricted to those authors!",
+	),
+	(
+		"keyword-authors-removed",
+		"✅ \"{keyword}\" is no longer restricted to messages from {authors}",
+	),
+	// watches
+	(
+		"watch-other-server",
+		"❌ You can only watch channels of this server!",
+	),
+	("watch-not-thread", "❌ {channel} isn't a thread!"),
+	("watch-use-thread", "❌ Use `/watch thread` to watch threads!"),
+	("watch-text-only", "❌ You can only watch text channels!"),
+	("watch-cannot-read", "❌ You can't read {channel}!"),
+	("watch-exists", "❌ You already watch {channel}!"),
+	(
+		"watch-limit",
+		"❌ You can't watch more than {max} channels and threads!",
+	),
+	("watch-added", "✅ You'll be notified of new messages in {channel}"),
+	(
+		"watch-removed",
+		"✅ You won't be notified of new messages in {channel} anymore",
+	),
+	("watch-missing", "❌ You don't watch {channel}!"),
+	("watches-title", "**Watched channels and threads**"),
+	(
+		"watches-empty",
+		"You don't watch any channels or threads in this server.",
+	),
+	("watches-thread", "(thread)"),
+	// reply and reaction alerts
+	("reply-alerts-already-enabled", "❌ Reply alerts are already enabled!"),
+	(
+		"reply-alerts-already-disabled",
+		"❌ Reply alerts are already disabled!",
+	),
+	(
+		"reply-alerts-enabled",
+		"✅ You'll be notified of replies to your messages that don't mention \
+		you",
+	),
+	(
+		"reply-alerts-disabled",
+		"✅ You won't be notified of replies that don't mention you anymore",
+	),
+	("reaction-alerts-title", "**Reaction alerts**"),
+	("reaction-alerts-empty", "You haven't added any reaction alerts."),
+	(
+		"reaction-alert-invalid",
+		"❌ That's not an emoji! Use a single unicode or server emoji.",
+	),
+	(
+		"reaction-alert-exists",
+		"❌ You already added a reaction alert for {emoji}!",
+	),
+	(
+		"reaction-alerts-limit",
+		"❌ You can't add more than {max} reaction alerts!",
+	),
+	(
+		"reaction-alert-added",
+		"✅ You'll be notified when someone reacts to your messages with \
+		{emoji}",
+	),
+	(
+		"reaction-alert-missing",
+		"❌ You haven't added a reaction alert for {emoji}!",
+	),
+	(
+		"reaction-alert-removed",
+		"✅ You won't be notified of {emoji} reactions to your messages \
+		anymore",
+	),
+	// keyword exclusions
+	(
+		"keyword-exclusions-title",
+		"**Keywords excluded from channels**",
+	),
+	(
+		"keyword-exclusions-empty",
+		"You haven't excluded any keywords from channels in this server.",
+	),
+	(
+		"keyword-exclusion-other-server",
+		"❌ You can only exclude keywords from channels of this server!",
+	),
+	(
+		"keyword-exclusion-no-keyword",
+		"❌ You don't have the server-wide keyword \"{keyword}\" in this \
+		server!",
+	),
+	(
+		"keyword-exclusion-exists",
+		"❌ \"{keyword}\" is already excluded from {channel}!",
+	),
+	(
+		"keyword-exclusion-added-in",
+		"❌ \"{keyword}\" was added in {channel}; remove it and add it in the \
+		other channels instead!",
+	),
+	(
+		"keyword-exclusions-limit",
+		"❌ Keywords can't be excluded from more than {max} channels!",
+	),
+	(
+		"keyword-exclusion-added",
+		"✅ \"{keyword}\" won't notify you of messages in {channel} anymore",
+	),
+	(
+		"keyword-exclusion-missing",
+		"❌ \"{keyword}\" isn't excluded from {channel}!",
+	),
+	(
+		"keyword-exclusions-missing",
+		"❌ \"{keyword}\" isn't excluded from any channels!",
+	),
+	(
+		"keyword-exclusions-removed",
+		"✅ \"{keyword}\" will notify you of messages in {channels} again",
+	),
+	// ignored phrases
+	(
+		"ignore-too-short",
+		"❌ You can't ignore phrases shorter than 3 characters!",
+	),
+	("ignore-exists", "❌ You already ignored that phrase!"),
+	("ignore-missing", "❌ You haven't ignored that phrase!"),
+	("ignore-keyword", "(only for \"{keyword}\")"),
+	("ignores-title", "Your ignored phrases"),
+	("ignores-empty", "❌ You haven't ignored any phrases!"),
+	(
+		"remove-server-empty",
+		"❌ You didn't have any keywords or ignores in that server!",
+	),
+	// lists
+	("list-footer", "Page {page} of {pages} · {total} total"),
+	("list-previous", "Previous"),
+	("list-next", "Next"),
+	("list-unknown", "Couldn't find (deleted?)"),
+	("list-in-channel", "In {channel}"),
+	("list-server-wide", "Server-wide"),
+	("list-unknown-server", "<Unknown server> ({id})"),
+	("list-unknown-channel", "Unknown channel ({id})"),
+	("list-unknown-location", "Unknown"),
+	// bulk forms and /manage
+	("bulk-nothing-added", "❌ Nothing was added."),
+	("bulk-added", "✅ Added: {entries}"),
+	("bulk-duplicate", "Already added: {entries}"),
+	("bulk-invalid", "Invalid: {entries}"),
+	("bulk-over-limit", "Over the keyword limit: {entries}"),
+	(
+		"bulk-invalid-hint",
+		"Entries must be at least 3 characters long, and keywords can't \
+		contain mentions or emojis.",
+	),
+	("bulk-keywords-title", "Add keywords"),
+	("bulk-keywords-label", "Keywords, separated by commas or new lines"),
+	("bulk-ignores-title", "Ignore phrases"),
+	("bulk-ignores-label", "Phrases, separated by commas or new lines"),
+	(
+		"from-message-empty",
+		"❌ That message doesn't have any text to add!",
+	),
+	("from-message-title", "Add keyword"),
+	(
+		"from-message-label",
+		"Keyword (edit it down to the part you want)",
+	),
+	("from-message-added", "✅ Added \"{keyword}\""),
+	(
+		"manage-empty",
+		"❌ You haven't added any keywords in this server yet!",
+	),
+	(
+		"manage-none-left",
+		"You don't have any keywords left in this server.",
+	),
+	(
+		"manage-none-selected",
+		"❌ You didn't select any keywords to remove.",
+	),
+	("manage-removed", "✅ Removed: {keywords}"),
+	(
+		"manage-instructions",
+		"Select keywords to remove, then press Remove selected. (Page {page} \
+		of {pages})",
+	),
+	("manage-placeholder", "Select keywords to remove"),
+	("manage-channel", "Only in #{channel}"),
+	("manage-remove", "Remove selected ({count})"),
+	// mutes
+	("mute-exists", "❌ You've already muted {channel}!"),
+	("mute-added", "✅ Muted {channel}"),
+	("mute-missing", "❌ You haven't muted {channel}!"),
+	("mute-removed", "✅ Unmuted {channel}"),
+	("mutes-title", "Your muted channels"),
+	("mutes-empty", "❌ You haven't muted any channels!"),
+	// blocks
+	("block-self", "❌ You can't block yourself!"),
+	("block-exists", "❌ You already blocked {user}!"),
+	(
+		"block-hidden",
+		"✅ Your messages are now hidden from {user}'s highlights",
+	),
+	(
+		"block-unhidden",
+		"✅ Your messages can highlight {user}'s keywords again, but they're \
+		still blocked",
+	),
+	(
+		"block-added-hidden",
+		"✅ Blocked {user} and hid your messages from their highlights",
+	),
+	("block-added", "✅ Blocked {user}"),
+	("unblock-self", "❌ You can't unblock yourself!"),
+	("block-missing", "❌ You haven't blocked {user}!"),
+	("block-removed", "✅ Unblocked {user}"),
+	("blocks-hidden", "(your messages are hidden from them)"),
+	("blocks-title", "Your blocked users"),
+	("blocks-empty", "You haven't blocked any users!"),
+	// opting out
+	("opt-out-exists", "❌ You already opted out!"),
+	(
+		"opt-out-warning",
+		"⚠️ Are you sure you want to opt out?\n\n\
+		All of your keywords, muted channels, blocked users, and ignored \
+		phrases will be lost forever. Use `/export` first if you want to keep \
+		a backup.\n\n\
+		You will no longer be able to receive notifications.\n\n\
+		Others will not receive notifications about your messages.",
+	),
+	("opt-out-confirm", "Opt out"),
+	("opt-out-cancel", "Cancel"),
+	("opt-out-timeout", "Timed out."),
+	("opt-out-done", "✅ You have been opted out"),
+	("opt-out-cancelled", "✅ You have not been opted out"),
+	("opt-in-missing", "❌ You haven't opted out!"),
+	// delivery
+	(
+		"delivery-current-dm",
+		"Your notifications are delivered by direct message.",
+	),
+	(
+		"delivery-current-webhook",
+		"Your notifications are delivered through a Discord webhook.",
+	),
+	(
+		"delivery-current-http",
+		"Your notifications are delivered to an HTTP endpoint.",
+	),
+	("delivery-current-email", "Your notifications are delivered by email."),
+	(
+		"delivery-current-digest",
+		"Your notifications are delivered in email digests.",
+	),
+	(
+		"delivery-email-unverified",
+		"❌ You need to add and verify an email address with `/email` first!",
+	),
+	("delivery-url-invalid", "❌ That isn't a valid URL!"),
+	(
+		"delivery-url-missing",
+		"❌ You need to provide a URL to deliver notifications to!",
+	),
+	(
+		"delivery-webhook-invalid",
+		"❌ That isn't a valid Discord webhook URL!",
+	),
+	(
+		"delivery-http-disabled",
+		"❌ HTTP delivery isn't enabled on this bot!",
+	),
+	("delivery-http-scheme", "❌ Please provide an HTTP or HTTPS URL!"),
+	(
+		"delivery-set-dm",
+		"✅ Your notifications will be delivered by direct message",
+	),
+	(
+		"delivery-set-webhook",
+		"✅ Your notifications will be delivered through your webhook",
+	),
+	(
+		"delivery-set-http",
+		"✅ Your notifications will be delivered to your HTTP endpoint",
+	),
+	(
+		"delivery-set-email",
+		"✅ Your notifications will be delivered by email",
+	),
+	(
+		"delivery-set-digest",
+		"✅ Your notifications will be delivered in periodic email digests",
+	),
+	(
+		"delivery-failed-dm",
+		"⚠️ I failed to DM you to notify you of your last highlighted \
+		keyword. Make sure you have DMs enabled in at least one server that \
+		we share.",
+	),
+	(
+		"delivery-failed-webhook",
+		"⚠️ I failed to notify you of your last highlighted keyword through \
+		your webhook. It may have been deleted; use `/delivery` to set a new \
+		one.",
+	),
+	(
+		"delivery-failed-http",
+		"⚠️ I failed to notify you of your last highlighted keyword through \
+		your HTTP endpoint. Make sure it accepts JSON POST requests, or use \
+		`/delivery` to change it.",
+	),
+	(
+		"delivery-failed-email",
+		"⚠️ I failed to notify you of your last highlighted keyword by \
+		email. Make sure your address can receive mail, or use `/email` to \
+		change it.",
+	),
+	// email
+	(
+		"email-disabled",
+		"❌ Email notifications aren't enabled on this bot!",
+	),
+	("email-missing", "❌ You haven't added an email address!"),
+	(
+		"email-removed-delivery",
+		"✅ Your email address was removed; your notifications will be \
+		delivered by direct message",
+	),
+	("email-removed", "✅ Your email address was removed"),
+	(
+		"email-verified",
+		"✅ Your email address was verified! Use `/delivery` to start \
+		receiving notifications by email.",
+	),
+	("email-code-incorrect", "❌ That code is incorrect!"),
+	(
+		"email-code-expired",
+		"❌ You don't have a valid verification code; use `/email` with your \
+		address to get a new one.",
+	),
+	(
+		"email-too-many-attempts",
+		"❌ You've entered too many incorrect codes; please try again \
+		tomorrow.",
+	),
+	("email-current", "Your email address is `{address}`."),
+	(
+		"email-current-unverified",
+		"Your email address `{address}` hasn't been verified yet.",
+	),
+	("email-none", "You haven't added an email address."),
+	("email-invalid", "❌ That isn't a valid email address!"),
+	("email-sending", "📧 Sending a verification code..."),
+	(
+		"email-cooldown",
+		"❌ I just sent you a code; please wait {seconds} seconds before \
+		requesting another.",
+	),
+	(
+		"email-sent",
+		"📧 I sent a verification code to `{address}`. Use `/email` with the \
+		code to verify your address.",
+	),
+	(
+		"email-undeliverable",
+		"❌ I couldn't send an email to that address!",
+	),
+	(
+		"email-send-failed",
+		"❌ I couldn't send an email right now; please try again later.",
+	),
+	// fallback channels
+	(
+		"fallback-disabled",
+		"✅ Notifications will no longer be sent in this server when DMs fail",
+	),
+	("fallback-missing", "❌ This server doesn't have a fallback channel!"),
+	(
+		"fallback-current",
+		"Notifications that can't be sent by DM are sent in private threads \
+		in {channel}.",
+	),
+	("fallback-none", "This server doesn't have a fallback channel."),
+	(
+		"fallback-permissions",
+		"❌ I need permission to read {channel} and create private threads \
+		there!",
+	),
+	(
+		"fallback-set",
+		"✅ Notifications that can't be sent by DM will be sent in private \
+		threads in {channel}",
+	),
+	// history
+	(
+		"history-empty",
+		"You don't have any notifications matching that!",
+	),
+	(
+		"history-empty-unrecorded",
+		"You don't have any notifications matching that! Notifications \
+		delivered by HTTP or email aren't recorded, so they don't appear in \
+		your history.",
+	),
+	(
+		"history-unrecorded",
+		"Notifications delivered by HTTP or email aren't recorded, so recent \
+		ones may be missing.",
+	),
+	(
+		"history-invalid-date",
+		"❌ Invalid date for {name}! Please use the format YYYY-MM-DD.",
+	),
+	("history-search-empty", "❌ Please provide text to search for!"),
+	("history-title", "Notification history"),
+	("history-entry", "**{keyword}** in {location} · {time}"),
+	("history-reason-follow", "followed user"),
+	("history-reason-watch", "watched channel"),
+	("history-reason-reply", "reply"),
+	("history-reason-reaction", "reactions"),
+	("history-jump", "Jump"),
+	("history-unknown-channel", "unknown channel"),
+	("history-footer", "Page {page} of {pages}"),
+	// stats
+	("stats-title", "Keyword statistics"),
+	(
+		"stats-footer",
+		"Suppressed notifications: patience = you were active in the channel, \
+		ignored = an ignored phrase appeared",
+	),
+	("stats-omitted", "…and {count} more"),
+	("stats-never-matched", "**{keyword}**: never matched"),
+	(
+		"stats-line",
+		"**{keyword}**: matched {matches}, notified {notified}",
+	),
+	("stats-patience", "patience {count}"),
+	("stats-ignored", "ignored {count}"),
+	("stats-muted", "muted {count}"),
+	("stats-blocked", "blocked {count}"),
+	// testing keywords
+	(
+		"test-no-match",
+		"❌ \"{keyword}\" doesn't appear in that text. Keywords only match \
+		whole words, unless they contain symbols or spaces.",
+	),
+	("test-matched", "Matched: {snippet}"),
+	(
+		"test-ignored",
+		"🔕 Your ignored phrase \"{phrase}\" also appears in that text",
+	),
+	("test-muted", "🔇 You muted this channel"),
+	("test-own-message", "👤 You're never notified of your own messages"),
+	("test-bot", "🤖 Messages from bots are never highlighted"),
+	(
+		"test-opted-out",
+		"🚪 {user} opted out, so their messages are never highlighted",
+	),
+	("test-blocked", "⛔ You blocked {user}"),
+	(
+		"test-authors",
+		"👥 This keyword only notifies you of messages from {authors}",
+	),
+	(
+		"test-channels",
+		"📍 Your server-wide keyword is excluded from this channel, or only \
+		added in other channels",
+	),
+	("test-cannot-read", "🔒 You can't read messages in this channel"),
+	(
+		"test-mentioned",
+		"🔔 The text mentions you, so Discord notifies you instead",
+	),
+	(
+		"test-filtered",
+		"🚫 This keyword isn't highlighted for you here; the server's admins \
+		may have revoked your highlighting, or the author hid their messages \
+		from you",
+	),
+	(
+		"test-would-notify",
+		"✅ You would be notified, unless you're active in this channel soon \
+		after the message is sent.",
+	),
+	("test-would-not-notify", "❌ You wouldn't be notified:"),
+	(
+		"test-not-added",
+		"You haven't added \"{keyword}\" here yet; use `/add` to be notified \
+		about it.",
+	),
+	(
+		"report-disabled",
+		"⏸️ The server's admins turned off highlighting here.",
+	),
+	(
+		"report-excluded",
+		"🚧 The server's admins excluded {channel} from highlighting.",
+	),
+	(
+		"report-no-keywords",
+		"❌ You don't have any keywords in this server or channel.",
+	),
+	("report-blocked", "⛔ matched, but you blocked {user}"),
+	("report-muted", "🔇 matched, but you muted the channel"),
+	("report-notify", "✅ would notify you"),
+	(
+		"report-too-old",
+		"⌛ matched, but the message is too old to notify about",
+	),
+	(
+		"report-mentioned",
+		"🔔 matched, but the message mentions you, so Discord notifies you \
+		instead",
+	),
+	(
+		"report-ignored",
+		"🔕 matched, but your ignored phrase \"{phrase}\" also appears",
+	),
+	("report-no-permission", "🔒 matched, but you can't read the channel"),
+	("report-in-channel", "(in {channel})"),
+	("report-none", "❌ None of your keywords appear in that message."),
+	("report-unmatched", "Didn't appear in the message: {keywords}"),
+	// diagnosing notifications
+	(
+		"why-invalid-link",
+		"❌ Please provide a link to a message in a server! You can get one \
+		by right clicking (or long pressing) the message and choosing Copy \
+		Message Link.",
+	),
+	(
+		"why-opted-out",
+		"🚪 You opted out, so you're never notified. Use `/opt-in` to opt \
+		back in.",
+	),
+	("why-channel-missing", "❌ I can't find that channel!"),
+	(
+		"why-cannot-read",
+		"🔒 You can't read that channel, so you aren't notified about \
+		messages in it.",
+	),
+	("why-not-member", "❌ You aren't a member of that server!"),
+	("why-checking", "🔍 Checking that message..."),
+	(
+		"why-message-missing",
+		"❌ I can't find that message! It may have been deleted.",
+	),
+	(
+		"why-header",
+		"Checking [that message]({link}) with your current keywords and \
+		settings:",
+	),
+	(
+		"why-not-notified",
+		"📭 You weren't sent a notification about that message. You also \
+		aren't notified if you were active in the channel soon after it was \
+		sent, or if your keywords or settings were different then.",
+	),
+	(
+		"why-notified",
+		"📬 You were sent a notification about that message for: {keywords}",
+	),
+	(
+		"why-fallback",
+		"It was sent in {thread} because I couldn't DM you.",
+	),
+	// copying
+	(
+		"copy-same-server",
+		"❌ You can't copy a server's keywords to the same server!",
+	),
+	(
+		"copy-server-empty",
+		"❌ You don't have any server-wide keywords or ignored phrases in that \
+		server!",
+	),
+	("copy-unknown-server", "the server with ID {id}"),
+	(
+		"copy-same-channel",
+		"❌ You can't copy a channel's keywords to the same channel!",
+	),
+	("copy-channel-empty", "❌ You don't have any keywords in {channel}!"),
+	("copy-keywords-one", "1 keyword"),
+	("copy-keywords", "{count} keywords"),
+	("copy-ignores-one", "1 ignored phrase"),
+	("copy-ignores", "{count} ignored phrases"),
+	("copy-both", "{keywords} and {ignores}"),
+	("copy-done", "✅ Copied {items} from {source}."),
+	("copy-nothing", "❌ Nothing was copied from {source}."),
+	(
+		"copy-skipped-duplicates",
+		"Skipped because you already added them: {list}",
+	),
+	(
+		"copy-skipped-invalid",
+		"Skipped because this server doesn't allow them: {list}",
+	),
+	(
+		"copy-skipped-limit",
+		"Skipped because you reached your keyword limit: {list}",
+	),
+	// export and import
+	(
+		"export-empty",
+		"❌ You don't have any keywords, ignores, mutes, or blocks to export!",
+	),
+	(
+		"export-done",
+		"📦 Here are your keywords, ignores, mutes, and blocks. Use `/import` \
+		with this file to restore them.",
+	),
+	("import-too-large", "❌ That file is too large!"),
+	(
+		"import-newer",
+		"❌ That file was exported by a newer version of the bot!",
+	),
+	(
+		"import-invalid",
+		"❌ That file isn't a valid export! Use a file created with `/export`.",
+	),
+	("import-nothing", "✅ You already have everything in that file!"),
+	("import-preview", "Importing this file will make these changes:"),
+	("import-more", "…and {count} more changes"),
+	("import-confirm", "Import"),
+	("import-cancel", "Cancel"),
+	("import-timeout", "Timed out; nothing was imported."),
+	("import-done", "✅ Imported!"),
+	("import-cancelled", "Nothing was imported."),
+	("import-kind-keywords", "keywords"),
+	("import-kind-keyword-channels", "keyword channels"),
+	("import-kind-keyword-authors", "keyword authors"),
+	("import-kind-ignores", "ignores"),
+	("import-kind-mutes", "mutes"),
+	("import-kind-blocks", "blocks"),
+	("import-added", "{kind}: {count} added"),
+	("import-removed", "{count} removed"),
+	(
+		"import-skipped-invalid",
+		"⚠️ Skipped because they're invalid or not allowed: {list}",
+	),
+	(
+		"import-skipped-inaccessible",
+		"⚠️ Skipped because you're not in that server or can't read that \
+		channel: {list}",
+	),
+	(
+		"import-skipped-limit",
+		"⚠️ Skipped because you can't add more keywords there: {list}",
+	),
+	("import-entry-keyword", "keyword \"{keyword}\""),
+	(
+		"import-entry-keyword-channel",
+		"channel {channel} of keyword \"{keyword}\"",
+	),
+	(
+		"import-entry-keyword-author",
+		"author filter of keyword \"{keyword}\"",
+	),
+	("import-entry-ignore", "ignore \"{phrase}\""),
+	("import-entry-mute", "mute of channel {channel}"),
+	("import-entry-self-block", "block of yourself"),
+	("import-unknown-server", "server {id}"),
+	("import-unknown-channel", "channel {id}"),
+	("import-unknown-user", "user {id}"),
+	("import-unknown-role", "role {id}"),
+	("import-diff-keyword", "keyword \"{keyword}\" in {location}"),
+	(
+		"import-diff-keyword-only-in",
+		"keyword \"{keyword}\" in {server} only in {channel}",
+	),
+	(
+		"import-diff-keyword-not-in",
+		"keyword \"{keyword}\" in {server} not in {channel}",
+	),
+	(
+		"import-diff-keyword-author",
+		"keyword \"{keyword}\" in {server} only from {author}",
+	),
+	("import-diff-ignore", "ignore \"{phrase}\" in {location}"),
+	(
+		"import-diff-ignore-keyword",
+		"ignore \"{phrase}\" in {location} for \"{keyword}\"",
+	),
+	("import-diff-mute", "mute {channel}"),
+	("import-diff-block", "block {user}"),
+	("import-diff-block-hidden", "block {user} and hide your messages"),
+	// ping and about
+	("ping-pong", "🏓 Pong!"),
+	("ping-latency", "Gateway latency: {latency}"),
+	("about-source", "Source"),
+	("about-author", "Author"),
+	("about-invite", "Invite"),
+	("about-invite-link", "[Add me to your server]({url})"),
+	("about-uptime", "Uptime"),
+	("uptime-days", "{days} days"),
+	("uptime-days-hours", "{days} days, {hours} hours"),
+	("uptime-hours-minutes", "{hours} hours, {minutes} minutes"),
+	("uptime-minutes-seconds", "{minutes} minutes, {seconds} seconds"),
+	("uptime-seconds", "{seconds} seconds"),
+	// help
+	("help-title", "{name} – Help"),
+	(
+		"help-description",
+		"Use `/help [command]` to see more information about a specified \
+		command",
+	),
+	("help-command-title", "Help – {name}"),
+	("help-examples-title", "Example Usage"),
+	("help-unknown", "❌ There's no command named `{name}`!"),
+	// server settings
+	("settings-view-title", "**Server settings**"),
+	("settings-view-highlighting-on", "Highlighting: on"),
+	("settings-view-highlighting-off", "Highlighting: turned off"),
+	("settings-view-keyword-limit", "Keyword limit: {max} per member"),
+	(
+		"settings-view-keyword-limit-default",
+		"Keyword limit: {max} per member (the bot's limit)",
+	),
+	(
+		"settings-view-min-length",
+		"Minimum keyword length: {min} characters",
+	),
+	(
+		"settings-view-min-length-default",
+		"Minimum keyword length: {min} characters (the default)",
+	),
+	("settings-view-excluded", "Excluded channels: {channels}"),
+	("settings-view-banned", "Banned keywords: {words}"),
+	("settings-view-revoked", "Members without highlighting: {members}"),
+	("settings-view-none", "none"),
+	(
+		"settings-audit-empty",
+		"No one has changed this server's settings yet.",
+	),
+	("settings-audit-title", "**Recent changes to server settings**"),
+	("settings-already-excluded", "❌ {channel} is already excluded!"),
+	("settings-not-excluded", "❌ {channel} isn't excluded!"),
+	(
+		"settings-channel-excluded",
+		"✅ Messages in {channel} won't be highlighted",
+	),
+	(
+		"settings-channel-included",
+		"✅ Messages in {channel} will be highlighted again",
+	),
+	("settings-same-keyword-limit", "❌ That's already the keyword limit!"),
+	(
+		"settings-keyword-limit-set",
+		"✅ Members can now add up to {limit} keywords here",
+	),
+	(
+		"settings-keyword-limit-removed",
+		"✅ Removed this server's keyword limit",
+	),
+	(
+		"settings-same-min-length",
+		"❌ That's already the minimum keyword length!",
+	),
+	(
+		"settings-min-length-set",
+		"✅ Keywords added here must now be at least {length} characters long",
+	),
+	(
+		"settings-min-length-reset",
+		"✅ Reset this server's minimum keyword length",
+	),
+	("settings-no-word", "❌ You need to provide a word!"),
+	("settings-already-banned", "❌ \"{word}\" is already banned!"),
+	("settings-not-banned", "❌ \"{word}\" isn't banned!"),
+	(
+		"settings-word-banned",
+		"✅ Members can't add keywords containing \"{word}\" here anymore; \
+		existing ones won't notify them and will be flagged in their keyword \
+		lists",
+	),
+	(
+		"settings-word-allowed",
+		"✅ Members can add keywords containing \"{word}\" again",
+	),
+	(
+		"settings-already-revoked",
+		"❌ {member} already can't use highlighting here!",
+	),
+	(
+		"settings-not-revoked",
+		"❌ {member} can already use highlighting here!",
+	),
+	(
+		"settings-member-revoked",
+		"✅ {member} won't be notified of keywords in this server, and can't \
+		add keywords here",
+	),
+	(
+		"settings-member-restored",
+		"✅ {member} can use highlighting here again",
+	),
+	("settings-already-on", "❌ Highlighting is already on!"),
+	("settings-already-off", "❌ Highlighting is already turned off!"),
+	(
+		"settings-turned-on",
+		"✅ Messages in this server will be highlighted again",
+	),
+	(
+		"settings-turned-off",
+		"✅ Messages in this server won't be highlighted",
+	),
+	// notifications
+	(
+		"notification-keyword",
+		"Keyword \"{keyword}\" seen in #{channel} ({server})",
+	),
+	(
+		"notification-keywords",
+		"Keywords {keywords} seen in #{channel} ({server})",
+	),
+	(
+		"notification-follow",
+		"New message from {author} in #{channel} ({server})",
+	),
+	("notification-watch", "New message in #{channel} ({server})"),
+	(
+		"notification-watch-burst",
+		"{count} new messages in #{channel} ({server})",
+	),
+	(
+		"notification-reply",
+		"{author} replied to your message in #{channel} ({server})",
+	),
+	(
+		"notification-reactions",
+		"Reactions to your message in #{channel} ({server}): {reactions}",
+	),
+	("notification-channel", "Channel"),
+	("notification-message", "Message"),
+	("notification-link", "(Link)"),
+	("notification-deleted", "*Original message deleted*"),
+	("notification-expired", "*Notification expired*"),
+	("fallback-thread-name", "Highlights for {username}"),
+];
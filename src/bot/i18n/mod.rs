@@ -0,0 +1,242 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! Translations of command descriptions, responses, and notifications.
+//!
+//! Each shipped locale has a catalog of messages by key. Messages missing from
+//! a catalog fall back to the default locale, American English.
+
+mod de;
+mod en_us;
+
+use std::{
+	collections::HashMap,
+	fmt::{Display, Write as _},
+};
+
+use anyhow::Result;
+use once_cell::sync::Lazy;
+use serenity::model::id::UserId;
+
+use crate::db::{OptOut, UserLocale};
+
+/// A language responses can be shown in.
+#[derive(Debug)]
+pub(crate) struct Locale {
+	/// Discord's name for this locale, like `en-US`.
+	pub(crate) tag: &'static str,
+	/// Messages in this locale by key.
+	messages: Lazy<HashMap<&'static str, &'static str>>,
+}
+
+/// All shipped locales; the first is the default.
+static LOCALES: [Locale; 2] = [
+	Locale {
+		tag: "en-US",
+		messages: Lazy::new(|| en_us::MESSAGES.iter().copied().collect()),
+	},
+	Locale {
+		tag: "de",
+		messages: Lazy::new(|| de::MESSAGES.iter().copied().collect()),
+	},
+];
+
+/// All shipped locales, starting with the default.
+pub(crate) fn locales() -> &'static [Locale] {
+	&LOCALES
+}
+
+/// The locale used for messages missing from other locales.
+pub(crate) fn default_locale() -> &'static Locale {
+	&LOCALES[0]
+}
+
+/// Finds the shipped locale best matching the given Discord locale.
+///
+/// Locales of the same language are used if there isn't an exact match, so
+/// `en-GB` uses `en-US` and `de-AT` would use `de`. Falls back to the default
+/// locale.
+pub(crate) fn locale(tag: &str) -> &'static Locale {
+	let language = |tag: &str| {
+		tag.split('-')
+			.next()
+			.map(str::to_ascii_lowercase)
+			.unwrap_or_default()
+	};
+
+	LOCALES
+		.iter()
+		.find(|locale| locale.tag.eq_ignore_ascii_case(tag))
+		.or_else(|| {
+			LOCALES
+				.iter()
+				.find(|locale| language(locale.tag) == language(tag))
+		})
+		.unwrap_or_else(default_locale)
+}
+
+/// Finds the locale the given user last used the bot in.
+///
+/// Notifications are shown in this locale, since they're sent outside of any
+/// interaction.
+pub(crate) async fn user_locale(user_id: UserId) -> Result<&'static Locale> {
+	Ok(match UserLocale::user_locale(user_id).await? {
+		Some(tag) => locale(&tag),
+		None => default_locale(),
+	})
+}
+
+/// Records the locale a user is using the bot in, unless they've opted out.
+pub(crate) async fn save_user_locale(user_id: UserId, tag: &str) -> Result<()> {
+	if (OptOut { user_id }).exists().await? {
+		return Ok(());
+	}
+
+	UserLocale {
+		user_id,
+		locale: tag.to_owned(),
+	}
+	.set()
+	.await
+}
+
+impl Locale {
+	/// Gets the message with the given key in this locale, if it's
+	/// translated.
+	pub(crate) fn try_get(&self, key: &str) -> Option<&'static str> {
+		self.messages.get(key).copied()
+	}
+
+	/// Gets the message with the given key in this locale.
+	///
+	/// Falls back to the default locale if the message isn't translated, and
+	/// to the key itself if the message doesn't exist.
+	pub(crate) fn get(&self, key: &'static str) -> &'static str {
+		self.try_get(key)
+			.or_else(|| default_locale().try_get(key))
+			.unwrap_or(key)
+	}
+
+	/// Gets the message with the given key in this locale, replacing each
+	/// `{name}` in it with the corresponding argument.
+	///
+	/// Placeholders without a corresponding argument are left as they are.
+	pub(crate) fn format(
+		&self,
+		key: &'static str,
+		args: &[(&str, &(dyn Display + Sync))],
+	) -> String {
+		let mut message = String::new();
+		let mut rest = self.get(key);
+
+		while let Some(start) = rest.find('{') {
+			message.push_str(&rest[..start]);
+			rest = &rest[start..];
+
+			let arg = rest.find('}').and_then(|end| {
+				args.iter()
+					.find(|(name, _)| *name == &rest[1..end])
+					.map(|(_, value)| (end, value))
+			});
+
+			match arg {
+				Some((end, value)) => {
+					write!(message, "{value}").unwrap();
+					rest = &rest[end + 1..];
+				}
+				None => {
+					message.push('{');
+					rest = &rest[1..];
+				}
+			}
+		}
+
+		message.push_str(rest);
+		message
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::collections::HashSet;
+
+	use super::*;
+
+	#[test]
+	fn every_key_in_every_locale() {
+		for locale in locales() {
+			for (key, _) in en_us::MESSAGES {
+				assert!(
+					locale.try_get(key).is_some(),
+					"{key} is missing from {}",
+					locale.tag,
+				);
+			}
+		}
+	}
+
+	#[test]
+	fn no_duplicate_keys() {
+		for catalog in [en_us::MESSAGES, de::MESSAGES] {
+			let mut keys = HashSet::new();
+			for (key, _) in catalog {
+				assert!(keys.insert(key), "{key} is defined twice");
+			}
+		}
+	}
+
+	#[test]
+	fn placeholders_match() {
+		let placeholders = |message: &str| {
+			message
+				.split('{')
+				.skip(1)
+				.filter_map(|s| s.split_once('}').map(|(name, _)| name))
+				.map(str::to_owned)
+				.collect::<HashSet<_>>()
+		};
+
+		for locale in locales() {
+			for (key, message) in en_us::MESSAGES {
+				assert_eq!(
+					placeholders(message),
+					placeholders(locale.get(key)),
+					"{key} has different placeholders in {}",
+					locale.tag,
+				);
+			}
+		}
+	}
+
+	#[test]
+	fn fallback() {
+		assert_eq!(locale("de").tag, "de");
+		assert_eq!(locale("DE").tag, "de");
+		assert_eq!(locale("de-AT").tag, "de");
+		assert_eq!(locale("en-GB").tag, "en-US");
+		assert_eq!(locale("ja").tag, "en-US");
+		assert_eq!(locale("").tag, "en-US");
+		assert_eq!(locale("de").get("no-such-key"), "no-such-key");
+	}
+
+	#[test]
+	fn format() {
+		assert_eq!(
+			locale("en-US").format("keyword-limit", &[("max", &100)]),
+			"You can't create more than 100 keywords!"
+		);
+
+		// arguments aren't searched for placeholders
+		assert_eq!(
+			locale("en-US").format(
+				"notification-keyword",
+				&[
+					("keyword", &"{channel}"),
+					("channel", &"general"),
+					("server", &"Test"),
+				],
+			),
+			"Keyword \"{channel}\" seen in #general (Test)"
+		);
+	}
+}
@@ -6,7 +6,11 @@
 #[macro_use]
 mod util;
 mod commands;
+mod email;
 mod highlighting;
+mod i18n;
+mod notifier;
+mod stats;
 
 use std::{collections::HashMap, sync::Arc, time::Instant};
 
@@ -26,9 +30,9 @@ use serenity::{
 	model::{
 		application::interaction::{
 			application_command::ApplicationCommandInteraction as Command,
-			Interaction, MessageFlags,
+			autocomplete::AutocompleteInteraction, Interaction, MessageFlags,
 		},
-		channel::Message,
+		channel::{Message, Reaction},
 		event::MessageUpdateEvent,
 		gateway::{Activity, GatewayIntents, Ready},
 		id::{ChannelId, GuildId, MessageId},
@@ -37,16 +41,26 @@ use serenity::{
 	Error as SerenityError,
 };
 use tinyvec::TinyVec;
+use tokio::join;
 use tracing::{
 	debug, error,
 	field::{display, Empty},
 	info, info_span, Span,
 };
 
-use self::highlighting::CachedMessages;
+use self::{
+	highlighting::{
+		emoji_key, CachedMessages, FollowCooldowns, KeywordCheck,
+		ReactionBursts, WatchBursts,
+	},
+	stats::KeywordEvent,
+	util::{author_roles, role_members, thread_parent},
+};
 use crate::{
 	bot::highlighting::start_notification_clearing,
-	db::{Ignore, Keyword, Notification},
+	db::{
+		Follow, Ignore, Keyword, Notification, ReactionAlert, ReplyAlert, Watch,
+	},
 	global::ERROR_COLOR,
 	settings::settings,
 };
@@ -56,10 +70,16 @@ struct Handler;
 
 #[serenity::async_trait]
 impl EventHandler for Handler {
-	/// Message listener to check for keywords.
+	/// Message listener to check for keywords, followed users, watched
+	/// channels, and replies.
 	///
-	/// Calls [`handle_keywords`] for any non-bot messages in a guild to check
-	/// if there are any keywords to notify others of.
+	/// Calls [`handle_keywords`], [`handle_follows`], [`handle_watches`], and
+	/// [`handle_reply`] for any non-bot messages in a guild to check if there
+	/// are any keywords, followers, watchers, or replied-to users to notify
+	/// others of.
+	///
+	/// Messages in threads are handled with the thread's parent, whose
+	/// exclusions apply to them too.
 	async fn message(&self, ctx: Context, message: Message) {
 		if message.author.bot {
 			return;
@@ -70,7 +90,33 @@ impl EventHandler for Handler {
 			None => return,
 		};
 
-		handle_keywords(&ctx, &message, guild_id).await;
+		let parent_id =
+			match thread_parent(&ctx, guild_id, message.channel_id).await {
+				Ok(parent_id) => parent_id,
+				Err(e) => {
+					error!("{:?}", e.context("Failed to find thread parent"));
+					return;
+				}
+			};
+
+		join!(
+			handle_keywords(&ctx, &message, guild_id, parent_id),
+			handle_follows(&ctx, &message, guild_id, parent_id),
+			handle_watches(&ctx, &message, guild_id, parent_id),
+			handle_reply(&ctx, &message, guild_id, parent_id),
+		);
+	}
+
+	/// Reaction listener to notify users of reactions to their messages.
+	///
+	/// Calls [`handle_reaction`] for any reactions in a guild.
+	async fn reaction_add(&self, ctx: Context, reaction: Reaction) {
+		let guild_id = match reaction.guild_id {
+			Some(id) => id,
+			None => return,
+		};
+
+		handle_reaction(ctx, reaction, guild_id).await;
 	}
 
 	/// Message listener to check messages for notifications to delete.
@@ -120,12 +166,15 @@ impl EventHandler for Handler {
 
 	/// Responds to slash commands.
 	async fn interaction_create(&self, ctx: Context, interaction: Interaction) {
-		let command = match interaction {
-			Interaction::ApplicationCommand(cmd) => cmd,
-			_ => return,
-		};
-
-		handle_command(ctx, command).await;
+		match interaction {
+			Interaction::ApplicationCommand(command) => {
+				handle_command(ctx, command).await
+			}
+			Interaction::Autocomplete(autocomplete) => {
+				handle_autocomplete(ctx, autocomplete).await
+			}
+			_ => {}
+		}
 	}
 }
 
@@ -221,7 +270,7 @@ async fn handle_update(
 		}
 	};
 
-	span.record("author_id", &display(message.author.id));
+	span.record("author_id", display(message.author.id));
 
 	highlighting::update_sent_notifications(
 		&ctx,
@@ -260,12 +309,7 @@ async fn handle_deletion(
 
 	let notifications =
 		match Notification::notifications_of_message(message_id).await {
-			Ok(n) => n
-				.into_iter()
-				.map(|notification| {
-					(notification.user_id, notification.notification_message)
-				})
-				.collect::<Vec<_>>(),
+			Ok(n) => n,
 			Err(e) => {
 				error!("{:?}", e);
 				return;
@@ -285,13 +329,231 @@ async fn handle_deletion(
 	}
 }
 
+/// Notifies the followers of the author of a message.
+///
+/// Queries for followers to notify with
+/// [`relevant_followers`](Follow::relevant_followers), then calls
+/// [`notify_follow`](highlighting::notify_follow) for each of them.
+async fn handle_follows(
+	ctx: &Context,
+	message: &Message,
+	guild_id: GuildId,
+	parent_id: Option<ChannelId>,
+) {
+	let res: Result<()> = async move {
+		let followers = Follow::relevant_followers(
+			guild_id,
+			message.channel_id,
+			parent_id,
+			message.author.id,
+		)
+		.await?;
+
+		let futures = FuturesUnordered::new();
+
+		for user_id in followers {
+			debug!("Notifying follower {user_id}");
+
+			futures.push(highlighting::notify_follow(
+				ctx.clone(),
+				message.clone(),
+				user_id,
+				guild_id,
+			));
+		}
+
+		futures.for_each(|_| async move {}).await;
+
+		Ok(())
+	}
+	.await;
+
+	if let Err(e) = res.context("Failed to handle follows") {
+		error!("{:?}", e);
+	}
+}
+
+/// Notifies the users watching the channel of a message.
+///
+/// Queries for watchers to notify with
+/// [`relevant_watchers`](Watch::relevant_watchers), then calls
+/// [`notify_watch`](highlighting::notify_watch) for each of them.
+async fn handle_watches(
+	ctx: &Context,
+	message: &Message,
+	guild_id: GuildId,
+	parent_id: Option<ChannelId>,
+) {
+	let res: Result<()> = async move {
+		let watchers = Watch::relevant_watchers(
+			guild_id,
+			message.channel_id,
+			parent_id,
+			message.author.id,
+		)
+		.await?;
+
+		let futures = FuturesUnordered::new();
+
+		for user_id in watchers {
+			debug!("Notifying watcher {user_id}");
+
+			futures.push(highlighting::notify_watch(
+				ctx.clone(),
+				message.clone(),
+				user_id,
+				guild_id,
+			));
+		}
+
+		futures.for_each(|_| async move {}).await;
+
+		Ok(())
+	}
+	.await;
+
+	if let Err(e) = res.context("Failed to handle watches") {
+		error!("{:?}", e);
+	}
+}
+
+/// Notifies the author of the message replied to by a message, if the reply
+/// doesn't mention them.
+///
+/// Checks if the author wants to be notified with
+/// [`should_notify`](ReplyAlert::should_notify), then calls
+/// [`notify_reply`](highlighting::notify_reply).
+async fn handle_reply(
+	ctx: &Context,
+	message: &Message,
+	guild_id: GuildId,
+	parent_id: Option<ChannelId>,
+) {
+	let replied = match &message.referenced_message {
+		Some(replied) => replied,
+		None => return,
+	};
+
+	let user_id = replied.author.id;
+
+	if replied.author.bot
+		|| user_id == message.author.id
+		|| message.mentions.iter().any(|mention| mention.id == user_id)
+	{
+		return;
+	}
+
+	let res = (ReplyAlert { user_id })
+		.should_notify(
+			guild_id,
+			message.channel_id,
+			parent_id,
+			message.author.id,
+		)
+		.await;
+
+	match res.context("Failed to handle reply") {
+		Ok(true) => {
+			debug!("Notifying {user_id} of reply");
+			highlighting::notify_reply(
+				ctx.clone(),
+				message.clone(),
+				user_id,
+				guild_id,
+			)
+			.await;
+		}
+		Ok(false) => {}
+		Err(e) => error!("{:?}", e),
+	}
+}
+
+/// Notifies the author of a message of a reaction to it, if they added a
+/// reaction alert for its emoji.
+///
+/// Checks if anyone added a reaction alert for the emoji before fetching the
+/// message, then checks if its author wants to be notified with
+/// [`should_notify`](ReactionAlert::should_notify) and calls
+/// [`notify_reaction`](highlighting::notify_reaction).
+async fn handle_reaction(ctx: Context, reaction: Reaction, guild_id: GuildId) {
+	let res: Result<()> = async {
+		let (reactor_id, emoji) =
+			match (reaction.user_id, emoji_key(&reaction.emoji)) {
+				(Some(reactor_id), Some(emoji)) => (reactor_id, emoji),
+				_ => return Ok(()),
+			};
+
+		if !ReactionAlert::emoji_alerted(&emoji).await? {
+			return Ok(());
+		}
+
+		let message = reaction
+			.message(&ctx)
+			.await
+			.context("Failed to fetch reacted message")?;
+
+		if message.author.bot || message.author.id == reactor_id {
+			return Ok(());
+		}
+
+		let reactor = reaction
+			.user(&ctx)
+			.await
+			.context("Failed to fetch reactor")?;
+
+		if reactor.bot {
+			return Ok(());
+		}
+
+		let alert = ReactionAlert {
+			user_id: message.author.id,
+			emoji,
+		};
+
+		let parent_id =
+			thread_parent(&ctx, guild_id, reaction.channel_id).await?;
+
+		if !alert
+			.should_notify(guild_id, reaction.channel_id, parent_id, reactor_id)
+			.await?
+		{
+			return Ok(());
+		}
+
+		debug!("Notifying {} of reaction", message.author.id);
+
+		highlighting::notify_reaction(
+			ctx.clone(),
+			message,
+			reaction.emoji.to_string(),
+			reactor.name,
+			guild_id,
+		)
+		.await;
+
+		Ok(())
+	}
+	.await;
+
+	if let Err(e) = res.context("Failed to handle reaction") {
+		error!("{:?}", e);
+	}
+}
+
 /// Handles any keywords present in a message.
 ///
 /// This function queries for any keywords that could be relevant to the sent
 /// message with [`get_relevant_keywords`](Keyword::get_relevant_keywords),
 /// collects [`Ignore`]s for any users with those keywords. It then
 /// calls [`notify_keywords`](highlighting::notify_keywords).
-async fn handle_keywords(ctx: &Context, message: &Message, guild_id: GuildId) {
+///
+/// Matches and suppressed keywords are counted with [`stats::record`].
+async fn handle_keywords(
+	ctx: &Context,
+	message: &Message,
+	guild_id: GuildId,
+	parent_id: Option<ChannelId>,
+) {
 	let res: Result<()> = async move {
 		let channel_id = message.channel_id;
 
@@ -310,19 +572,59 @@ async fn handle_keywords(ctx: &Context, message: &Message, guild_id: GuildId) {
 
 		debug!("Getting keywords");
 
-		let keywords_by_user = Keyword::get_relevant_keywords(
+		let mut keywords_by_user = HashMap::new();
+
+		let author_roles = author_roles(ctx, guild_id, message);
+
+		for relevant in Keyword::get_relevant_keywords(
 			guild_id,
 			channel_id,
+			parent_id,
 			message.author.id,
+			&author_roles,
+			|role_id| role_members(ctx, guild_id, role_id),
+			|keyword| highlighting::keyword_matches(keyword, lowercase_content),
 		)
 		.await?
-		.into_iter()
-		.fold(HashMap::new(), |mut map, keyword| {
-			map.entry(keyword.user_id)
+		{
+			let keyword = relevant.keyword;
+
+			if relevant.muted || relevant.blocked {
+				// only count matches in channels the user can read, so stats
+				// don't reveal activity in other channels
+				if highlighting::user_can_read_message(
+					ctx,
+					message,
+					keyword.user_id,
+				)
+				.await?
+				{
+					let event = if relevant.blocked {
+						KeywordEvent::Blocked
+					} else {
+						KeywordEvent::Muted
+					};
+					stats::record(
+						keyword.user_id,
+						guild_id,
+						&keyword.keyword,
+						KeywordEvent::Matched,
+					);
+					stats::record(
+						keyword.user_id,
+						guild_id,
+						&keyword.keyword,
+						event,
+					);
+				}
+				continue;
+			}
+
+			keywords_by_user
+				.entry(keyword.user_id)
 				.or_insert_with(|| tinyvec::tiny_vec![[Keyword; 2]])
 				.push(keyword);
-			map
-		});
+		}
 
 		let mut ignores_by_user = HashMap::new();
 
@@ -341,15 +643,34 @@ async fn handle_keywords(ctx: &Context, message: &Message, guild_id: GuildId) {
 			let keywords = stream::iter(keywords)
 				.map(Ok::<_, anyhow::Error>) // convert to a TryStream
 				.try_filter_map(|keyword| async move {
-					Ok(highlighting::should_notify_keyword(
+					let check = highlighting::check_keyword(
 						ctx,
 						message,
 						lowercase_content,
 						&keyword,
 						ignores,
 					)
-					.await?
-					.then_some(keyword))
+					.await?;
+
+					if check.matched() {
+						stats::record(
+							user_id,
+							guild_id,
+							&keyword.keyword,
+							KeywordEvent::Matched,
+						);
+					}
+
+					if let KeywordCheck::Ignored(_) = check {
+						stats::record(
+							user_id,
+							guild_id,
+							&keyword.keyword,
+							KeywordEvent::Ignored,
+						);
+					}
+
+					Ok((check == KeywordCheck::Notify).then_some(keyword))
 				})
 				.try_collect::<TinyVec<[Keyword; 2]>>()
 				.await?;
@@ -400,6 +721,10 @@ async fn handle_command(ctx: Context, command: Command) {
 
 	let _entered = span.enter();
 
+	if let Err(e) = i18n::save_user_locale(user_id, &command.locale).await {
+		error!("Failed to save user locale: {:?}", e);
+	}
+
 	let result = {
 		use std::future::Future;
 
@@ -428,6 +753,8 @@ async fn handle_command(ctx: Context, command: Command) {
 		match &*name {
 			"add" => spawn_command(ctx, command, add),
 			"remove" => spawn_command(ctx, command, remove),
+			"add-advanced" => spawn_command(ctx, command, add_advanced),
+			"edit-keyword" => spawn_command(ctx, command, edit_keyword),
 			"mute" => spawn_command(ctx, command, mute),
 			"unmute" => spawn_command(ctx, command, unmute),
 			"ignore" => spawn_command(ctx, command, ignore),
@@ -439,6 +766,39 @@ async fn handle_command(ctx: Context, command: Command) {
 			"mutes" => spawn_command(ctx, command, mutes),
 			"ignores" => spawn_command(ctx, command, ignores),
 			"blocks" => spawn_command(ctx, command, blocks),
+			"delivery" => spawn_command(ctx, command, delivery),
+			"manage" => spawn_command(ctx, command, manage),
+			"copy-server" => spawn_command(ctx, command, copy_server),
+			"copy-channel" => spawn_command(ctx, command, copy_channel),
+			"email" => spawn_command(ctx, command, email),
+			"export" => spawn_command(ctx, command, export),
+			"import" => spawn_command(ctx, command, import),
+			"fallback" => spawn_command(ctx, command, fallback),
+			"server-settings" => spawn_command(ctx, command, server_settings),
+			"team-keywords" => spawn_command(ctx, command, team_keywords),
+			"keyword-pack" => spawn_command(ctx, command, keyword_pack),
+			"subscribe" => spawn_command(ctx, command, subscribe),
+			"unsubscribe" => spawn_command(ctx, command, unsubscribe),
+			"keyword-authors" => spawn_command(ctx, command, keyword_authors),
+			"keyword-exclusions" => {
+				spawn_command(ctx, command, keyword_exclusions)
+			}
+			"follow" => spawn_command(ctx, command, follow),
+			"unfollow" => spawn_command(ctx, command, unfollow),
+			"follows" => spawn_command(ctx, command, follows),
+			"watch" => spawn_command(ctx, command, watch),
+			"reply-alerts" => spawn_command(ctx, command, reply_alerts),
+			"reaction-alerts" => spawn_command(ctx, command, reaction_alerts),
+			"history" => spawn_command(ctx, command, history),
+			"stats" => spawn_command(ctx, command, stats),
+			"test-keyword" => spawn_command(ctx, command, test_keyword),
+			"Test keywords" => spawn_command(ctx, command, test_message),
+			"Add keyword from message…" => {
+				spawn_command(ctx, command, add_from_message)
+			}
+			"Block author" => spawn_command(ctx, command, block_author),
+			"Block user" => spawn_command(ctx, command, block),
+			"why" => spawn_command(ctx, command, why),
 			"opt-out" => spawn_command(ctx, command, opt_out),
 			"opt-in" => spawn_command(ctx, command, opt_in),
 			"help" => spawn_command(ctx, command, help),
@@ -532,6 +892,33 @@ async fn handle_command(ctx: Context, command: Command) {
 	}
 }
 
+/// Suggests values for an option of a slash [`command`](commands) as the user
+/// types it.
+async fn handle_autocomplete(
+	ctx: Context,
+	interaction: AutocompleteInteraction,
+) {
+	let span = info_span!(
+		parent: None,
+		"autocomplete",
+		interaction_id = %interaction.id,
+		author_id = %interaction.user.id,
+		channel_id = %interaction.channel_id,
+		guild_id = ?interaction.guild_id,
+	);
+
+	let _entered = span.enter();
+
+	let result = tokio::spawn(commands::autocomplete(ctx, interaction))
+		.await
+		.map_err(anyhow::Error::from)
+		.and_then(|r| r);
+
+	if let Err(e) = result {
+		error!("{:?}", e);
+	}
+}
+
 /// [`TypeMapKey`] to store a reference to the [`ShardManager`] for retrieving
 /// latency.
 struct Shards;
@@ -542,6 +929,11 @@ impl TypeMapKey for Shards {
 
 /// Initializes the Discord client.
 pub(crate) async fn init() -> Result<()> {
+	email::init()?;
+	email::start_digests();
+	highlighting::start_history_pruning();
+	stats::start_flushing();
+
 	let mut client = Client::builder(
 		&settings().bot.token,
 		GatewayIntents::MESSAGE_CONTENT
@@ -560,6 +952,9 @@ pub(crate) async fn init() -> Result<()> {
 		let mut data = client.data.write().await;
 
 		data.insert::<CachedMessages>(HashMap::new());
+		data.insert::<FollowCooldowns>(HashMap::new());
+		data.insert::<WatchBursts>(HashMap::new());
+		data.insert::<ReactionBursts>(HashMap::new());
 		data.insert::<Shards>(client.shard_manager.clone());
 	}
 
@@ -0,0 +1,588 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! Backends for delivering notifications to users.
+//!
+//! Each [`DeliveryMethod`] a user can choose has a corresponding [`Notifier`]
+//! that knows how to send, edit, and clear notifications through it.
+
+use std::time::Duration;
+
+use anyhow::{anyhow, Context as _, Error, Result};
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use serenity::{
+	builder::CreateEmbed,
+	client::Context,
+	http::{error::ErrorResponse, HttpError},
+	json::{JsonMap, Value},
+	model::{
+		channel::Embed,
+		id::{ChannelId, GuildId, MessageId, UserId},
+		Timestamp,
+	},
+	prelude::Mentionable,
+	utils::parse_webhook,
+	Error as SerenityError,
+};
+use url::Url;
+
+use super::{email::EmailNotifier, i18n::Locale};
+use crate::{
+	db::{DeliveryMethod, UserStateKind},
+	global::{EMBED_COLOR, ERROR_COLOR},
+};
+
+/// Contents of a notification, independent of how it's delivered.
+#[derive(Debug, Clone)]
+pub(crate) struct NotificationContent {
+	/// Keywords that were highlighted.
+	pub(crate) keywords: Vec<String>,
+	/// Summary of the keywords and where they were seen.
+	pub(crate) title: String,
+	/// ID of the guild the message was sent in.
+	pub(crate) guild_id: GuildId,
+	/// Name of the guild the message was sent in.
+	pub(crate) guild_name: String,
+	/// URL of the icon of the guild the message was sent in.
+	pub(crate) guild_icon: Option<String>,
+	/// ID of the channel the message was sent in.
+	pub(crate) channel_id: ChannelId,
+	/// Name of the channel the message was sent in.
+	pub(crate) channel_name: String,
+	/// ID of the highlighted message.
+	pub(crate) message_id: MessageId,
+	/// Content of the highlighted message.
+	pub(crate) content: String,
+	/// Username of the author of the highlighted message.
+	pub(crate) author_name: String,
+	/// URL of the avatar of the author of the highlighted message.
+	pub(crate) author_avatar: String,
+	/// When the highlighted message was sent.
+	pub(crate) timestamp: Timestamp,
+	/// Locale of the recipient, used for the embed's labels.
+	pub(crate) locale: &'static Locale,
+}
+
+impl NotificationContent {
+	/// Link to the highlighted message.
+	pub(crate) fn message_url(&self) -> String {
+		format!(
+			"https://discord.com/channels/{}/{}/{}",
+			self.guild_id, self.channel_id, self.message_id
+		)
+	}
+
+	/// Builds a notification embed.
+	///
+	/// Includes:
+	/// - List of keywords
+	/// - Channel name
+	/// - Guild name
+	/// - Guild icon
+	/// - Message content
+	/// - Message link
+	/// - Author username
+	/// - Author avatar
+	/// - Message timestamp
+	pub(crate) fn embed(&self) -> CreateEmbed {
+		let mut embed = CreateEmbed::default();
+
+		embed
+			.description(&self.content)
+			.timestamp(self.timestamp)
+			.author(|a| {
+				a.name(&self.title);
+				if let Some(url) = &self.guild_icon {
+					a.icon_url(url);
+				}
+				a
+			})
+			.field(
+				self.locale.get("notification-channel"),
+				format!("<#{}>", self.channel_id),
+				true,
+			)
+			.field(
+				self.locale.get("notification-message"),
+				format!(
+					"[{}]({})",
+					self.locale.get("notification-link"),
+					self.message_url()
+				),
+				true,
+			)
+			.footer(|f| f.icon_url(&self.author_avatar).text(&self.author_name))
+			.color(EMBED_COLOR);
+
+		embed
+	}
+
+	/// Builds the JSON body sent by [`HttpNotifier`].
+	///
+	/// `title` and `message` are understood by common push services like
+	/// Gotify and ntfy; the remaining fields are for custom receivers.
+	fn payload(&self) -> HttpPayload<'_> {
+		HttpPayload {
+			title: &self.title,
+			message: &self.content,
+			url: self.message_url(),
+			keywords: &self.keywords,
+			guild_id: self.guild_id.to_string(),
+			guild_name: &self.guild_name,
+			channel_id: self.channel_id.to_string(),
+			channel_name: &self.channel_name,
+			message_id: self.message_id.to_string(),
+			author: &self.author_name,
+			timestamp: self.timestamp.to_string(),
+		}
+	}
+}
+
+/// JSON body of a notification sent by [`HttpNotifier`].
+#[derive(Debug, Serialize)]
+struct HttpPayload<'a> {
+	title: &'a str,
+	message: &'a str,
+	url: String,
+	keywords: &'a [String],
+	guild_id: String,
+	guild_name: &'a str,
+	channel_id: String,
+	channel_name: &'a str,
+	message_id: String,
+	author: &'a str,
+	timestamp: String,
+}
+
+/// Reasons a notification could not be sent.
+#[derive(Debug)]
+pub(crate) enum NotifyError {
+	/// The notification can't be delivered with the user's current settings;
+	/// retrying won't help.
+	Undeliverable,
+	/// The notification failed for a reason that may be resolved by retrying,
+	/// like an internal server error.
+	Transient(Error),
+	/// Any other error.
+	Other(Error),
+}
+
+impl From<Error> for NotifyError {
+	fn from(e: Error) -> Self {
+		Self::Other(e)
+	}
+}
+
+/// A backend capable of delivering notifications to a user.
+#[serenity::async_trait]
+pub(crate) trait Notifier: Send + Sync {
+	/// Sends a notification to the given user.
+	///
+	/// Returns the ID of the sent message if the backend supports editing it
+	/// later with [`edit`](Notifier::edit) and [`clear`](Notifier::clear).
+	async fn send(
+		&self,
+		ctx: &Context,
+		user_id: UserId,
+		notification: &NotificationContent,
+	) -> Result<Option<MessageId>, NotifyError>;
+
+	/// Replaces a previously sent notification with updated content.
+	async fn edit(
+		&self,
+		ctx: &Context,
+		user_id: UserId,
+		message_id: MessageId,
+		notification: &NotificationContent,
+	) -> Result<()>;
+
+	/// Replaces a previously sent notification with the given placeholder.
+	async fn clear(
+		&self,
+		ctx: &Context,
+		user_id: UserId,
+		message_id: MessageId,
+		placeholder: &str,
+	) -> Result<()>;
+
+	/// State to record for the user if a notification is
+	/// [undeliverable](NotifyError::Undeliverable).
+	fn failure_state(&self) -> UserStateKind;
+}
+
+/// Gets the [`Notifier`] corresponding to the given delivery method.
+pub(crate) fn notifier(method: DeliveryMethod) -> Box<dyn Notifier> {
+	match method {
+		DeliveryMethod::DirectMessage => Box::new(DirectMessageNotifier),
+		DeliveryMethod::Webhook(url) => Box::new(WebhookNotifier { url }),
+		DeliveryMethod::Http(url) => Box::new(HttpNotifier { url }),
+		DeliveryMethod::Email { digest } => Box::new(EmailNotifier { digest }),
+	}
+}
+
+/// Builds a placeholder embed replacing a cleared notification.
+fn placeholder_embed(placeholder: &str) -> CreateEmbed {
+	let mut embed = CreateEmbed::default();
+	embed.description(placeholder).color(ERROR_COLOR);
+	embed
+}
+
+/// Converts an error from sending a Discord message into a [`NotifyError`].
+///
+/// `undeliverable` should return true for errors that indicate the message
+/// can never be delivered.
+fn classify_discord_error(
+	err: SerenityError,
+	undeliverable: impl FnOnce(&ErrorResponse) -> bool,
+) -> NotifyError {
+	match err {
+		SerenityError::Http(inner) => match &*inner {
+			HttpError::UnsuccessfulRequest(response)
+				if response.status_code.is_server_error() =>
+			{
+				NotifyError::Transient(SerenityError::Http(inner).into())
+			}
+			HttpError::UnsuccessfulRequest(response)
+				if undeliverable(response) =>
+			{
+				NotifyError::Undeliverable
+			}
+			_ => NotifyError::Other(SerenityError::Http(inner).into()),
+		},
+		err => NotifyError::Other(
+			Error::from(err).context("Failed to send notification message"),
+		),
+	}
+}
+
+/// Delivers notifications in direct messages from the bot.
+pub(crate) struct DirectMessageNotifier;
+
+#[serenity::async_trait]
+impl Notifier for DirectMessageNotifier {
+	async fn send(
+		&self,
+		ctx: &Context,
+		user_id: UserId,
+		notification: &NotificationContent,
+	) -> Result<Option<MessageId>, NotifyError> {
+		let dm_channel = user_id
+			.create_dm_channel(ctx)
+			.await
+			.context("Failed to create DM channel to notify user")?;
+
+		let embed = notification.embed();
+
+		dm_channel
+			.send_message(ctx, |m| m.set_embed(embed))
+			.await
+			.map(|sent| Some(sent.id))
+			.map_err(|e| {
+				classify_discord_error(e, |response| {
+					response.error.message
+						== "Cannot send messages to this user"
+				})
+			})
+	}
+
+	async fn edit(
+		&self,
+		ctx: &Context,
+		user_id: UserId,
+		message_id: MessageId,
+		notification: &NotificationContent,
+	) -> Result<()> {
+		let dm_channel = user_id
+			.create_dm_channel(ctx)
+			.await
+			.context("Failed to create DM channel")?;
+
+		let embed = notification.embed();
+
+		dm_channel
+			.edit_message(ctx, message_id, |m| m.set_embed(embed))
+			.await?;
+
+		Ok(())
+	}
+
+	async fn clear(
+		&self,
+		ctx: &Context,
+		user_id: UserId,
+		message_id: MessageId,
+		placeholder: &str,
+	) -> Result<()> {
+		let dm_channel = user_id.create_dm_channel(ctx).await?;
+
+		dm_channel
+			.edit_message(ctx, message_id, |m| {
+				m.set_embed(placeholder_embed(placeholder))
+			})
+			.await
+			.context("Failed to edit notification message")?;
+
+		Ok(())
+	}
+
+	fn failure_state(&self) -> UserStateKind {
+		UserStateKind::CannotDm
+	}
+}
+
+/// Delivers notifications through a Discord webhook provided by the user.
+pub(crate) struct WebhookNotifier {
+	url: Url,
+}
+
+impl WebhookNotifier {
+	/// Gets the webhook ID and token from the URL.
+	fn webhook(&self) -> Result<(u64, &str)> {
+		parse_webhook(&self.url)
+			.ok_or_else(|| anyhow!("Invalid webhook URL stored for user"))
+	}
+
+	/// Builds the JSON body of a webhook message containing the given embed.
+	fn body(embed: CreateEmbed) -> JsonMap {
+		let mut map = JsonMap::new();
+		map.insert(
+			"embeds".to_owned(),
+			Value::from(vec![Embed::fake(|e| {
+				*e = embed;
+				e
+			})]),
+		);
+		map
+	}
+}
+
+#[serenity::async_trait]
+impl Notifier for WebhookNotifier {
+	async fn send(
+		&self,
+		ctx: &Context,
+		_: UserId,
+		notification: &NotificationContent,
+	) -> Result<Option<MessageId>, NotifyError> {
+		let (id, token) = self.webhook()?;
+
+		let mut body = Self::body(notification.embed());
+		body.insert(
+			"username".to_owned(),
+			Value::from(ctx.cache.current_user_field(|u| u.name.clone())),
+		);
+
+		ctx.http
+			.execute_webhook(id, token, true, &body)
+			.await
+			.map(|sent| sent.map(|m| m.id))
+			.map_err(|e| {
+				// the webhook was deleted or its token was revoked
+				classify_discord_error(e, |response| {
+					matches!(response.status_code.as_u16(), 401 | 404)
+				})
+			})
+	}
+
+	async fn edit(
+		&self,
+		ctx: &Context,
+		_: UserId,
+		message_id: MessageId,
+		notification: &NotificationContent,
+	) -> Result<()> {
+		let (id, token) = self.webhook()?;
+
+		ctx.http
+			.edit_webhook_message(
+				id,
+				token,
+				message_id.0,
+				&Self::body(notification.embed()),
+			)
+			.await?;
+
+		Ok(())
+	}
+
+	async fn clear(
+		&self,
+		ctx: &Context,
+		_: UserId,
+		message_id: MessageId,
+		placeholder: &str,
+	) -> Result<()> {
+		let (id, token) = self.webhook()?;
+
+		ctx.http
+			.edit_webhook_message(
+				id,
+				token,
+				message_id.0,
+				&Self::body(placeholder_embed(placeholder)),
+			)
+			.await
+			.context("Failed to edit notification message")?;
+
+		Ok(())
+	}
+
+	fn failure_state(&self) -> UserStateKind {
+		UserStateKind::WebhookFailed
+	}
+}
+
+/// Delivers notifications in a private thread in a guild's fallback channel,
+/// for users that can't receive DMs.
+pub(crate) struct ThreadNotifier {
+	pub(crate) thread_id: ChannelId,
+}
+
+#[serenity::async_trait]
+impl Notifier for ThreadNotifier {
+	async fn send(
+		&self,
+		ctx: &Context,
+		user_id: UserId,
+		notification: &NotificationContent,
+	) -> Result<Option<MessageId>, NotifyError> {
+		let embed = notification.embed();
+
+		self.thread_id
+			.send_message(ctx, |m| {
+				m.content(user_id.mention())
+					.allowed_mentions(|a| a.users([user_id]))
+					.set_embed(embed)
+			})
+			.await
+			.map(|sent| Some(sent.id))
+			.map_err(|e| {
+				// the thread or channel was deleted, or the bot lost access
+				classify_discord_error(e, |response| {
+					matches!(response.status_code.as_u16(), 403 | 404)
+				})
+			})
+	}
+
+	async fn edit(
+		&self,
+		ctx: &Context,
+		user_id: UserId,
+		message_id: MessageId,
+		notification: &NotificationContent,
+	) -> Result<()> {
+		let embed = notification.embed();
+
+		self.thread_id
+			.edit_message(ctx, message_id, |m| {
+				m.content(user_id.mention()).set_embed(embed)
+			})
+			.await?;
+
+		Ok(())
+	}
+
+	async fn clear(
+		&self,
+		ctx: &Context,
+		_: UserId,
+		message_id: MessageId,
+		placeholder: &str,
+	) -> Result<()> {
+		self.thread_id
+			.edit_message(ctx, message_id, |m| {
+				m.content("").set_embed(placeholder_embed(placeholder))
+			})
+			.await
+			.context("Failed to edit notification message")?;
+
+		Ok(())
+	}
+
+	fn failure_state(&self) -> UserStateKind {
+		UserStateKind::CannotDm
+	}
+}
+
+/// Client used to send HTTP notifications.
+///
+/// Redirects aren't followed, so requests can't be sent to a different host
+/// than the one in the URL the user provided.
+static HTTP_CLIENT: Lazy<reqwest::Client> = Lazy::new(|| {
+	reqwest::Client::builder()
+		.timeout(Duration::from_secs(10))
+		.redirect(reqwest::redirect::Policy::none())
+		.user_agent(concat!(
+			env!("CARGO_PKG_NAME"),
+			"/",
+			env!("CARGO_PKG_VERSION")
+		))
+		.build()
+		.expect("Failed to build HTTP notification client")
+});
+
+/// Delivers notifications as JSON HTTP POST requests to a URL provided by the
+/// user, for services like ntfy or Gotify.
+///
+/// Notifications sent this way can't be edited or cleared.
+pub(crate) struct HttpNotifier {
+	url: Url,
+}
+
+#[serenity::async_trait]
+impl Notifier for HttpNotifier {
+	async fn send(
+		&self,
+		_: &Context,
+		_: UserId,
+		notification: &NotificationContent,
+	) -> Result<Option<MessageId>, NotifyError> {
+		let response = HTTP_CLIENT
+			.post(self.url.clone())
+			.json(&notification.payload())
+			.send()
+			.await;
+
+		match response {
+			Ok(response) if response.status().is_success() => Ok(None),
+			Ok(response) if response.status().is_server_error() => {
+				Err(NotifyError::Transient(anyhow!(
+					"HTTP notification endpoint returned {}",
+					response.status()
+				)))
+			}
+			Ok(_) => Err(NotifyError::Undeliverable),
+			Err(e) if e.is_timeout() || e.is_connect() => {
+				Err(NotifyError::Transient(e.into()))
+			}
+			Err(e) => Err(NotifyError::Other(
+				Error::from(e).context("Failed to send HTTP notification"),
+			)),
+		}
+	}
+
+	async fn edit(
+		&self,
+		_: &Context,
+		_: UserId,
+		_: MessageId,
+		_: &NotificationContent,
+	) -> Result<()> {
+		Ok(())
+	}
+
+	async fn clear(
+		&self,
+		_: &Context,
+		_: UserId,
+		_: MessageId,
+		_: &str,
+	) -> Result<()> {
+		Ok(())
+	}
+
+	fn failure_state(&self) -> UserStateKind {
+		UserStateKind::HttpFailed
+	}
+}
@@ -0,0 +1,237 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! Counting how often keywords match and notify.
+//!
+//! Counts are aggregated in memory and periodically added to the
+//! [`KeywordStats`] in the DB, so handling a message doesn't write to the DB.
+
+use std::{
+	collections::HashMap, future::Future, mem, sync::Mutex, time::Duration,
+};
+
+use anyhow::Result;
+use futures_util::lock::Mutex as AsyncMutex;
+use once_cell::sync::Lazy;
+use serenity::{
+	client::Context,
+	model::id::{GuildId, UserId},
+};
+use tokio::time::interval;
+use tracing::{debug, error, info_span};
+
+use crate::db::{Keyword, KeywordCounts, KeywordKind, KeywordStats};
+
+/// How often counts are added to the DB.
+const FLUSH_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+/// The user, guild, and keyword counts are kept for.
+type StatsKey = (UserId, GuildId, String);
+
+/// Counts not yet added to the DB, by user, guild, and keyword.
+static PENDING: Lazy<Mutex<HashMap<StatsKey, KeywordCounts>>> =
+	Lazy::new(Default::default);
+
+/// Held while counts are being added to the DB, so counts that are being
+/// discarded can't be added back by a flush that's already in progress.
+static FLUSHING: Lazy<AsyncMutex<()>> = Lazy::new(Default::default);
+
+/// Something that happened to a keyword in a message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum KeywordEvent {
+	/// The keyword appeared in a message.
+	Matched,
+	/// A notification was sent for the keyword.
+	Notified,
+	/// The user interacted with the channel within the patience duration.
+	Patience,
+	/// An ignored phrase appeared in the message.
+	Ignored,
+	/// The user muted the channel of the message.
+	Muted,
+	/// The user blocked the author of the message.
+	Blocked,
+}
+
+/// Counts an event for the given user's keyword in the given guild.
+pub(crate) fn record(
+	user_id: UserId,
+	guild_id: GuildId,
+	keyword: &str,
+	event: KeywordEvent,
+) {
+	let mut pending = PENDING.lock().expect("Pending stats poisoned");
+
+	let counts = pending
+		.entry((user_id, guild_id, keyword.to_owned()))
+		.or_default();
+
+	let counter = match event {
+		KeywordEvent::Matched => &mut counts.matches,
+		KeywordEvent::Notified => &mut counts.notified,
+		KeywordEvent::Patience => &mut counts.patience,
+		KeywordEvent::Ignored => &mut counts.ignored,
+		KeywordEvent::Muted => &mut counts.muted,
+		KeywordEvent::Blocked => &mut counts.blocked,
+	};
+
+	*counter += 1;
+}
+
+/// Adds all pending counts to the DB.
+///
+/// If that fails, the counts are kept to be added next time.
+async fn flush() {
+	let _flushing = FLUSHING.lock().await;
+
+	let pending =
+		mem::take(&mut *PENDING.lock().expect("Pending stats poisoned"));
+
+	if pending.is_empty() {
+		return;
+	}
+
+	debug!("Flushing stats of {} keywords", pending.len());
+
+	let stats = pending
+		.iter()
+		.map(|((user_id, guild_id, keyword), &counts)| KeywordStats {
+			user_id: *user_id,
+			guild_id: *guild_id,
+			keyword: keyword.clone(),
+			counts,
+		})
+		.collect();
+
+	if let Err(e) = KeywordStats::add_all(stats).await {
+		error!("Failed to save keyword stats: {e}\n{e:?}");
+
+		let mut current = PENDING.lock().expect("Pending stats poisoned");
+		for (key, counts) in pending {
+			*current.entry(key).or_default() += counts;
+		}
+	}
+}
+
+/// Periodically adds pending counts to the DB.
+pub(super) fn start_flushing() {
+	debug!("Starting keyword stats flushing");
+	tokio::spawn(async move {
+		let span = info_span!(parent: None, "keyword_stats_flushing");
+		let _entered = span.enter();
+		let mut timer = interval(FLUSH_INTERVAL);
+		// the first tick completes immediately
+		timer.tick().await;
+		loop {
+			timer.tick().await;
+			flush().await;
+		}
+	});
+}
+
+/// Gets the counts for the given keyword that haven't been added to the DB
+/// yet.
+pub(crate) fn pending_counts(
+	user_id: UserId,
+	guild_id: GuildId,
+	keyword: &str,
+) -> KeywordCounts {
+	PENDING
+		.lock()
+		.expect("Pending stats poisoned")
+		.get(&(user_id, guild_id, keyword.to_owned()))
+		.copied()
+		.unwrap_or_default()
+}
+
+/// Deletes stored counts with `delete`, then discards pending counts whose
+/// keys match `discard`, while no counts are being added to the DB.
+async fn forget<T>(
+	discard: impl Fn(&StatsKey) -> bool,
+	delete: impl Future<Output = Result<T>>,
+) -> Result<T> {
+	let _flushing = FLUSHING.lock().await;
+
+	let result = delete.await;
+
+	PENDING
+		.lock()
+		.expect("Pending stats poisoned")
+		.retain(|key, _| !discard(key));
+
+	result
+}
+
+/// Deletes a user's data with `delete` as they opt out, discarding their
+/// pending counts so they aren't added back to the DB afterwards.
+pub(crate) async fn forget_user<T>(
+	user_id: UserId,
+	delete: impl Future<Output = Result<T>>,
+) -> Result<T> {
+	forget(|(id, _, _)| *id == user_id, delete).await
+}
+
+/// Renames a user's keyword in a guild with `rename`, which moves its stored
+/// counts to the new text, then moves its pending counts there too.
+pub(crate) async fn keyword_renamed<T>(
+	user_id: UserId,
+	guild_id: GuildId,
+	old: &str,
+	new: &str,
+	rename: impl Future<Output = Result<T>>,
+) -> Result<T> {
+	let _flushing = FLUSHING.lock().await;
+
+	let result = rename.await?;
+
+	let mut pending = PENDING.lock().expect("Pending stats poisoned");
+
+	if let Some(counts) = pending.remove(&(user_id, guild_id, old.to_owned())) {
+		*pending
+			.entry((user_id, guild_id, new.to_owned()))
+			.or_default() += counts;
+	}
+
+	Ok(result)
+}
+
+/// Deletes the counts of a keyword that was removed, unless the user still
+/// has the same keyword in the same guild.
+///
+/// Nothing is deleted if the guild of a channel-specific keyword isn't known.
+pub(crate) async fn keyword_removed(
+	ctx: &Context,
+	keyword: &Keyword,
+) -> Result<()> {
+	let guild_of = |kind: KeywordKind| match kind {
+		KeywordKind::Guild(guild_id) => Some(guild_id),
+		KeywordKind::Channel(channel_id) => {
+			ctx.cache.guild_channel_field(channel_id, |c| c.guild_id)
+		}
+	};
+
+	let guild_id = match guild_of(keyword.kind) {
+		Some(guild_id) => guild_id,
+		None => return Ok(()),
+	};
+
+	let still_added = Keyword::user_keywords(keyword.user_id)
+		.await?
+		.into_iter()
+		.any(|other| {
+			other.keyword == keyword.keyword
+				&& guild_of(other.kind) == Some(guild_id)
+		});
+
+	if still_added {
+		return Ok(());
+	}
+
+	let user_id = keyword.user_id;
+
+	forget(
+		|(u, g, k)| *u == user_id && *g == guild_id && *k == keyword.keyword,
+		KeywordStats::delete(user_id, guild_id, &keyword.keyword),
+	)
+	.await
+}
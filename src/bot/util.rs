@@ -14,9 +14,9 @@ use serenity::{
 			application_command::ApplicationCommandInteraction as Command,
 			MessageFlags,
 		},
-		channel::GuildChannel,
+		channel::{Channel, GuildChannel, Message},
 		guild::{Guild, PartialGuild},
-		id::UserId,
+		id::{ChannelId, GuildId, RoleId, UserId},
 	},
 	prelude::HttpError,
 	Error as SerenityError,
@@ -146,11 +146,125 @@ pub(crate) async fn user_can_read_channel(
 	Ok(Some(permissions.view_channel()))
 }
 
+/// Gets the guild channel or thread with the given ID, from the cache if
+/// possible.
+///
+/// Returns `None` if the channel doesn't exist or isn't a guild channel.
+pub(crate) async fn guild_channel(
+	ctx: &Context,
+	channel_id: ChannelId,
+) -> Result<Option<GuildChannel>> {
+	if let Some(channel) = ctx.cache.guild_channel(channel_id) {
+		return Ok(Some(channel));
+	}
+
+	Ok(
+		match optional_result(ctx.http.get_channel(channel_id.0).await)? {
+			Some(Channel::Guild(channel)) => Some(channel),
+			_ => None,
+		},
+	)
+}
+
+/// Gets the channel whose permissions apply to the given channel: its parent
+/// if it's a thread, or the channel itself otherwise.
+pub(crate) async fn permission_channel(
+	ctx: &Context,
+	channel: GuildChannel,
+) -> Result<GuildChannel> {
+	match (&channel.thread_metadata, channel.parent_id) {
+		(Some(_), Some(parent_id)) => {
+			guild_channel(ctx, parent_id).await?.with_context(|| {
+				format!("Parent of thread {} not found", channel.id)
+			})
+		}
+		_ => Ok(channel),
+	}
+}
+
+/// Finds the parent of the given channel of the given guild, if it's a
+/// thread.
+///
+/// Threads inherit server exclusions and keyword exclusions from their
+/// parent, as well as permissions.
+pub(crate) async fn thread_parent(
+	ctx: &Context,
+	guild_id: GuildId,
+	channel_id: ChannelId,
+) -> Result<Option<ChannelId>> {
+	// threads are cached with their guild rather than with other channels
+	let thread = ctx
+		.cache
+		.guild_field(guild_id, |g| {
+			g.threads.iter().find(|t| t.id == channel_id).cloned()
+		})
+		.flatten();
+
+	let channel = match thread {
+		Some(thread) => thread,
+		None => match guild_channel(ctx, channel_id).await? {
+			Some(channel) => channel,
+			None => return Ok(None),
+		},
+	};
+
+	let parent = permission_channel(ctx, channel).await?;
+
+	Ok((parent.id != channel_id).then_some(parent.id))
+}
+
+/// Finds the cached members of the given guild with the given role.
+///
+/// Bots are left out, since they're never notified.
+pub(crate) fn role_members(
+	ctx: &Context,
+	guild_id: GuildId,
+	role_id: RoleId,
+) -> Vec<UserId> {
+	ctx.cache
+		.guild_field(guild_id, |g| {
+			g.members
+				.values()
+				.filter(|member| !member.user.bot)
+				// the @everyone role has the same ID as the guild
+				.filter(|member| {
+					role_id.0 == guild_id.0 || member.roles.contains(&role_id)
+				})
+				.map(|member| member.user.id)
+				.collect()
+		})
+		.unwrap_or_default()
+}
+
+/// Finds the roles of the author of the given message in the given guild.
+///
+/// Uses the member sent with the message if there is one, and the cache
+/// otherwise. The @everyone role is included.
+pub(crate) fn author_roles(
+	ctx: &Context,
+	guild_id: GuildId,
+	message: &Message,
+) -> Vec<RoleId> {
+	let mut roles = match &message.member {
+		Some(member) => member.roles.clone(),
+		None => ctx
+			.cache
+			.member_field(guild_id, message.author.id, |m| m.roles.clone())
+			.unwrap_or_default(),
+	};
+
+	// the @everyone role has the same ID as the guild
+	roles.push(RoleId(guild_id.0));
+
+	roles
+}
+
 /// Makes the result of an HTTP call optional.
 ///
 /// If the given `Result` is an `Err` containing an error with a 404 HTTP error,
 /// `Ok(None)` is returned. Otherwise, the `Result` is returned, `Ok(x)` being
 /// replaced with `Ok(Some(x))`.
+#[allow(clippy::result_large_err)]
 pub(crate) fn optional_result<T>(
 	res: Result<T, SerenityError>,
 ) -> Result<Option<T>, SerenityError> {
@@ -0,0 +1,103 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! Handling for words server administrators don't allow in keywords.
+
+use anyhow::Result;
+use futures_util::TryStreamExt;
+use sea_orm::{
+	entity::prelude::{
+		DeriveActiveModelBehavior, DeriveEntityModel, DerivePrimaryKey,
+		DeriveRelation, EntityTrait, EnumIter, PrimaryKeyTrait,
+	},
+	ColumnTrait, IntoActiveModel, QueryFilter,
+};
+use serenity::model::id::GuildId;
+
+use super::{connection, DbInt, IdDbExt};
+
+#[derive(
+	Clone, Debug, PartialEq, Eq, DeriveEntityModel, DeriveActiveModelBehavior,
+)]
+#[sea_orm(table_name = "banned_keywords")]
+pub struct Model {
+	#[sea_orm(primary_key)]
+	pub(crate) guild_id: DbInt,
+	#[sea_orm(primary_key)]
+	pub(crate) word: String,
+}
+
+#[derive(Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+/// A word a guild's administrators don't allow in keywords, like a member's
+/// name.
+#[derive(Debug, Clone)]
+pub(crate) struct BannedKeyword {
+	/// The guild the word is banned in.
+	pub(crate) guild_id: GuildId,
+	/// The banned word, which keywords can't contain.
+	pub(crate) word: String,
+}
+
+impl BannedKeyword {
+	/// Fetches the banned words of the guild with the given ID from the DB.
+	#[tracing::instrument]
+	pub(crate) async fn guild_bans(guild_id: GuildId) -> Result<Vec<Self>> {
+		Entity::find()
+			.filter(Column::GuildId.eq(guild_id.into_db()))
+			.stream(connection())
+			.await?
+			.map_err(Into::into)
+			.map_ok(Self::from)
+			.try_collect()
+			.await
+	}
+
+	/// Checks if this word is banned in the DB.
+	#[tracing::instrument]
+	pub(crate) async fn exists(self) -> Result<bool> {
+		Ok(Entity::find_by_id((self.guild_id.into_db(), self.word))
+			.one(connection())
+			.await?
+			.is_some())
+	}
+
+	/// Inserts this ban into the DB.
+	#[tracing::instrument]
+	pub(crate) async fn insert(self) -> Result<()> {
+		Entity::insert(Model::from(self).into_active_model())
+			.exec(connection())
+			.await?;
+
+		Ok(())
+	}
+
+	/// Deletes this ban from the DB.
+	#[tracing::instrument]
+	pub(crate) async fn delete(self) -> Result<()> {
+		Entity::delete(Model::from(self).into_active_model())
+			.exec(connection())
+			.await?;
+
+		Ok(())
+	}
+}
+
+impl From<Model> for BannedKeyword {
+	fn from(model: Model) -> Self {
+		Self {
+			guild_id: GuildId::from_db(model.guild_id),
+			word: model.word,
+		}
+	}
+}
+
+impl From<BannedKeyword> for Model {
+	fn from(ban: BannedKeyword) -> Self {
+		Self {
+			guild_id: ban.guild_id.into_db(),
+			word: ban.word,
+		}
+	}
+}
@@ -11,6 +11,7 @@ use sea_orm::{
 		DerivePrimaryKey, DeriveRelation, EntityTrait, EnumIter,
 		PrimaryKeyTrait,
 	},
+	sea_query::OnConflict,
 	ColumnTrait, Condition, IntoActiveModel, QueryFilter, QuerySelect,
 };
 use serenity::model::id::UserId;
@@ -26,6 +27,7 @@ pub struct Model {
 	pub(crate) user_id: DbInt,
 	#[sea_orm(primary_key)]
 	pub(crate) blocked_id: DbInt,
+	pub(crate) hide_messages: bool,
 }
 
 #[derive(Debug, EnumIter, DeriveRelation)]
@@ -38,6 +40,9 @@ pub(crate) struct Block {
 	pub(crate) user_id: UserId,
 	/// The user who was blocked.
 	pub(crate) blocked_id: UserId,
+	/// Whether messages of the user who blocked them are hidden from the
+	/// blocked user's highlights too.
+	pub(crate) hide_messages: bool,
 }
 
 impl Block {
@@ -64,6 +69,34 @@ impl Block {
 		Ok(())
 	}
 
+	/// Adds this blocked user to the DB, or updates whether messages are
+	/// hidden from them if they're already blocked.
+	#[tracing::instrument]
+	pub(crate) async fn set(self) -> Result<()> {
+		Entity::insert(Model::from(self).into_active_model())
+			.on_conflict(
+				OnConflict::columns([Column::UserId, Column::BlockedId])
+					.update_column(Column::HideMessages)
+					.to_owned(),
+			)
+			.exec(connection())
+			.await?;
+
+		Ok(())
+	}
+
+	/// Fetches this block from the DB, if the user is blocked.
+	#[tracing::instrument]
+	pub(crate) async fn get(self) -> Result<Option<Self>> {
+		Ok(Entity::find_by_id((
+			self.user_id.into_db(),
+			self.blocked_id.into_db(),
+		))
+		.one(connection())
+		.await?
+		.map(Self::from))
+	}
+
 	/// Checks if this block exists in the DB.
 	#[tracing::instrument]
 	pub(crate) async fn exists(self) -> Result<bool> {
@@ -104,6 +137,7 @@ impl From<Model> for Block {
 		Self {
 			user_id: UserId::from_db(model.user_id),
 			blocked_id: UserId::from_db(model.blocked_id),
+			hide_messages: model.hide_messages,
 		}
 	}
 }
@@ -113,6 +147,7 @@ impl From<Block> for Model {
 		Self {
 			user_id: mute.user_id.into_db(),
 			blocked_id: mute.blocked_id.into_db(),
+			hide_messages: mute.hide_messages,
 		}
 	}
 }
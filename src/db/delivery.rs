@@ -0,0 +1,179 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! Handling for users' preferred notification delivery methods.
+
+use anyhow::{bail, Context as _, Result};
+use sea_orm::{
+	entity::prelude::{
+		DeriveActiveModelBehavior, DeriveEntityModel, DerivePrimaryKey,
+		DeriveRelation, EntityTrait, EnumIter, PrimaryKeyTrait,
+	},
+	sea_query::OnConflict,
+	IntoActiveModel,
+};
+use serenity::model::id::UserId;
+use url::Url;
+
+use super::{connection, DbInt, IdDbExt};
+
+#[derive(
+	Clone, Debug, PartialEq, Eq, DeriveEntityModel, DeriveActiveModelBehavior,
+)]
+#[sea_orm(table_name = "delivery_methods")]
+pub struct Model {
+	#[sea_orm(primary_key)]
+	pub(crate) user_id: DbInt,
+	pub(crate) method: u8,
+	pub(crate) target: Option<String>,
+}
+
+#[derive(Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+/// A user's chosen method of receiving notifications.
+#[derive(Debug, Clone)]
+pub(crate) struct Delivery {
+	/// The user that chose this delivery method.
+	pub(crate) user_id: UserId,
+	/// How notifications should be delivered to the user.
+	pub(crate) method: DeliveryMethod,
+}
+
+/// Ways notifications can be delivered.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum DeliveryMethod {
+	/// Direct messages from the bot; the default.
+	DirectMessage,
+	/// Messages sent through a Discord webhook URL provided by the user.
+	Webhook(Url),
+	/// JSON HTTP POST requests to a URL provided by the user.
+	Http(Url),
+	/// Emails sent to the user's verified email address, either one per
+	/// notification or periodically as a digest.
+	Email { digest: bool },
+}
+
+impl DeliveryMethod {
+	const DIRECT_MESSAGE: u8 = 0;
+	const WEBHOOK: u8 = 1;
+	const HTTP: u8 = 2;
+	const EMAIL: u8 = 3;
+	const EMAIL_DIGEST: u8 = 4;
+
+	/// Gets the number identifying this kind of delivery method in the DB.
+	pub(super) fn kind(&self) -> u8 {
+		match self {
+			Self::DirectMessage => Self::DIRECT_MESSAGE,
+			Self::Webhook(_) => Self::WEBHOOK,
+			Self::Http(_) => Self::HTTP,
+			Self::Email { digest: false } => Self::EMAIL,
+			Self::Email { digest: true } => Self::EMAIL_DIGEST,
+		}
+	}
+
+	/// Gets the URL notifications are delivered to, if any.
+	pub(super) fn target(&self) -> Option<&Url> {
+		match self {
+			Self::DirectMessage | Self::Email { .. } => None,
+			Self::Webhook(url) | Self::Http(url) => Some(url),
+		}
+	}
+
+	/// Builds a delivery method from its [`kind`](Self::kind) and
+	/// [`target`](Self::target) as stored in the DB.
+	pub(super) fn from_db(kind: u8, target: Option<&str>) -> Result<Self> {
+		let target = || -> Result<Url> {
+			target
+				.context("Delivery method has no target")?
+				.parse()
+				.context("Delivery method target is not a valid URL")
+		};
+
+		Ok(match kind {
+			Self::DIRECT_MESSAGE => Self::DirectMessage,
+			Self::WEBHOOK => Self::Webhook(target()?),
+			Self::HTTP => Self::Http(target()?),
+			Self::EMAIL => Self::Email { digest: false },
+			Self::EMAIL_DIGEST => Self::Email { digest: true },
+			other => bail!("Unknown delivery method: {other}"),
+		})
+	}
+}
+
+impl Delivery {
+	/// Fetches the delivery method of the user with the given ID from the DB.
+	///
+	/// Returns [`DeliveryMethod::DirectMessage`] if the user never chose a
+	/// delivery method.
+	#[tracing::instrument]
+	pub(crate) async fn user_delivery(user_id: UserId) -> Result<Self> {
+		let delivery = Entity::find_by_id(user_id.into_db())
+			.one(connection())
+			.await?
+			.map(Self::try_from)
+			.transpose()?
+			.unwrap_or(Self {
+				user_id,
+				method: DeliveryMethod::DirectMessage,
+			});
+
+		Ok(delivery)
+	}
+
+	/// Sets the delivery method of the user in the DB.
+	///
+	/// Setting [`DeliveryMethod::DirectMessage`] removes any stored method, so
+	/// that the user uses the default again.
+	#[tracing::instrument]
+	pub(crate) async fn set(self) -> Result<()> {
+		if self.method == DeliveryMethod::DirectMessage {
+			return Self::clear(self.user_id).await;
+		}
+
+		Entity::insert(Model::from(self).into_active_model())
+			.on_conflict(
+				OnConflict::column(Column::UserId)
+					.update_columns([Column::Method, Column::Target])
+					.to_owned(),
+			)
+			.exec(connection())
+			.await?;
+
+		Ok(())
+	}
+
+	/// Clears any delivery method of the user with the given ID.
+	#[tracing::instrument]
+	pub(crate) async fn clear(user_id: UserId) -> Result<()> {
+		Entity::delete_by_id(user_id.into_db())
+			.exec(connection())
+			.await?;
+
+		Ok(())
+	}
+}
+
+impl TryFrom<Model> for Delivery {
+	type Error = anyhow::Error;
+
+	fn try_from(model: Model) -> Result<Self> {
+		Ok(Self {
+			user_id: UserId::from_db(model.user_id),
+			method: DeliveryMethod::from_db(
+				model.method,
+				model.target.as_deref(),
+			)?,
+		})
+	}
+}
+
+impl From<Delivery> for Model {
+	fn from(delivery: Delivery) -> Self {
+		Model {
+			user_id: delivery.user_id.into_db(),
+			method: delivery.method.kind(),
+			target: delivery.method.target().map(Url::to_string),
+		}
+	}
+}
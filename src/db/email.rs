@@ -0,0 +1,254 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! Handling for users' email addresses and their verification.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::Result;
+use sea_orm::{
+	entity::prelude::{
+		DeriveActiveModelBehavior, DeriveEntityModel, DerivePrimaryKey,
+		DeriveRelation, EntityTrait, EnumIter, PrimaryKeyTrait,
+	},
+	sea_query::OnConflict,
+	ActiveValue, IntoActiveModel,
+};
+use serenity::model::id::UserId;
+use sha2::{Digest, Sha256};
+
+use super::{
+	connection,
+	email_limit::{now, EmailLimit},
+	DbInt, IdDbExt,
+};
+
+#[derive(
+	Clone, Debug, PartialEq, Eq, DeriveEntityModel, DeriveActiveModelBehavior,
+)]
+#[sea_orm(table_name = "email_addresses")]
+pub struct Model {
+	#[sea_orm(primary_key)]
+	pub(crate) user_id: DbInt,
+	pub(crate) address: String,
+	pub(crate) verified: bool,
+	pub(crate) code: Option<String>,
+	pub(crate) code_expires: Option<i64>,
+	pub(crate) attempts: i32,
+}
+
+#[derive(Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+/// Number of incorrect codes a user can enter before they need a new one.
+const MAX_ATTEMPTS: i32 = 5;
+
+/// An email address a user has added.
+#[derive(Debug, Clone)]
+pub(crate) struct EmailAddress {
+	/// The address notifications are sent to.
+	pub(crate) address: String,
+	/// Whether the user confirmed they own this address.
+	pub(crate) verified: bool,
+}
+
+/// Result of attempting to verify an email address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Verification {
+	/// The address was verified.
+	Verified,
+	/// The code was incorrect.
+	Incorrect,
+	/// There is no code to check against, or it expired.
+	Expired,
+	/// The user entered too many incorrect codes recently.
+	TooManyAttempts,
+}
+
+/// Result of requesting a verification code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CodeRequest {
+	/// The code was stored and can be sent.
+	Accepted,
+	/// The user was sent a code too recently, and has to wait this long before
+	/// requesting another.
+	TooSoon(Duration),
+	/// The user entered too many incorrect codes recently.
+	TooManyAttempts,
+}
+
+/// Hashes a verification code for storage.
+///
+/// The user's ID is included so the same code doesn't hash the same for
+/// different users.
+fn hash_code(user_id: UserId, code: &str) -> String {
+	let digest = Sha256::new()
+		.chain_update(user_id.0.to_be_bytes())
+		.chain_update(code.trim())
+		.finalize();
+
+	format!("{digest:x}")
+}
+
+impl EmailAddress {
+	/// Fetches the email address of the user with the given ID from the DB.
+	#[tracing::instrument]
+	pub(crate) async fn user_email(user_id: UserId) -> Result<Option<Self>> {
+		Ok(Entity::find_by_id(user_id.into_db())
+			.one(connection())
+			.await?
+			.map(Self::from))
+	}
+
+	/// Fetches the email address of the user with the given ID from the DB,
+	/// only if it has been verified.
+	#[tracing::instrument]
+	pub(crate) async fn verified_email(
+		user_id: UserId,
+	) -> Result<Option<Self>> {
+		Ok(Self::user_email(user_id)
+			.await?
+			.filter(|email| email.verified))
+	}
+
+	/// Stores an unverified email address for the user, replacing any previous
+	/// address, along with a hash of the code that must be entered to verify
+	/// it.
+	///
+	/// Nothing is stored if the user was sent a code too recently or entered
+	/// too many incorrect codes.
+	#[tracing::instrument(skip(code))]
+	pub(crate) async fn set_pending(
+		user_id: UserId,
+		address: String,
+		code: &str,
+		valid_for: Duration,
+	) -> Result<CodeRequest> {
+		let limit = EmailLimit::user_limit(user_id).await?;
+
+		if let Some(wait) = limit.resend_wait()? {
+			return Ok(CodeRequest::TooSoon(wait));
+		}
+
+		if limit.exhausted() {
+			return Ok(CodeRequest::TooManyAttempts);
+		}
+
+		let expires = SystemTime::now()
+			.checked_add(valid_for)
+			.unwrap_or_else(SystemTime::now)
+			.duration_since(UNIX_EPOCH)?
+			.as_secs() as i64;
+
+		let model = Model {
+			user_id: user_id.into_db(),
+			address,
+			verified: false,
+			code: Some(hash_code(user_id, code)),
+			code_expires: Some(expires),
+			attempts: 0,
+		};
+
+		Entity::insert(model.into_active_model())
+			.on_conflict(
+				OnConflict::column(Column::UserId)
+					.update_columns([
+						Column::Address,
+						Column::Verified,
+						Column::Code,
+						Column::CodeExpires,
+						Column::Attempts,
+					])
+					.to_owned(),
+			)
+			.exec(connection())
+			.await?;
+
+		limit.record_sent(user_id).await?;
+
+		Ok(CodeRequest::Accepted)
+	}
+
+	/// Checks the given code against the user's pending verification code,
+	/// marking their address as verified if it matches.
+	///
+	/// After too many incorrect codes, the pending code is discarded. Incorrect
+	/// codes also count towards a limit across all of the user's codes, which
+	/// isn't reset by requesting a new one.
+	#[tracing::instrument(skip(code))]
+	pub(crate) async fn verify(
+		user_id: UserId,
+		code: &str,
+	) -> Result<Verification> {
+		let limit = EmailLimit::user_limit(user_id).await?;
+
+		if limit.exhausted() {
+			return Ok(Verification::TooManyAttempts);
+		}
+
+		let model = match Entity::find_by_id(user_id.into_db())
+			.one(connection())
+			.await?
+		{
+			Some(model) => model,
+			None => return Ok(Verification::Expired),
+		};
+
+		let now = now()?;
+
+		let expected = match (&model.code, model.code_expires) {
+			(Some(expected), Some(expires)) if expires > now => {
+				expected.clone()
+			}
+			_ => return Ok(Verification::Expired),
+		};
+
+		let mut active = model.clone().into_active_model();
+
+		let correct = expected == hash_code(user_id, code);
+
+		let result = if correct {
+			active.verified = ActiveValue::Set(true);
+			active.code = ActiveValue::Set(None);
+			active.code_expires = ActiveValue::Set(None);
+			active.attempts = ActiveValue::Set(0);
+			Verification::Verified
+		} else if model.attempts + 1 >= MAX_ATTEMPTS {
+			active.code = ActiveValue::Set(None);
+			active.code_expires = ActiveValue::Set(None);
+			Verification::Expired
+		} else {
+			active.attempts = ActiveValue::Set(model.attempts + 1);
+			Verification::Incorrect
+		};
+
+		Entity::update(active).exec(connection()).await?;
+
+		if !correct {
+			limit.record_attempt(user_id).await?;
+		}
+
+		Ok(result)
+	}
+
+	/// Deletes the email address of the user with the given ID.
+	///
+	/// Returns whether there was an address to delete.
+	#[tracing::instrument]
+	pub(crate) async fn delete(user_id: UserId) -> Result<bool> {
+		let result = Entity::delete_by_id(user_id.into_db())
+			.exec(connection())
+			.await?;
+
+		Ok(result.rows_affected > 0)
+	}
+}
+
+impl From<Model> for EmailAddress {
+	fn from(model: Model) -> Self {
+		Self {
+			address: model.address,
+			verified: model.verified,
+		}
+	}
+}
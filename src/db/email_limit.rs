@@ -0,0 +1,159 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! Handling for limits on how often users can request and enter email
+//! verification codes.
+//!
+//! These are stored separately from email addresses so that removing and
+//! re-adding an address doesn't reset them.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::Result;
+use sea_orm::{
+	entity::prelude::{
+		DeriveActiveModelBehavior, DeriveEntityModel, DerivePrimaryKey,
+		DeriveRelation, EntityTrait, EnumIter, PrimaryKeyTrait,
+	},
+	sea_query::OnConflict,
+	IntoActiveModel,
+};
+use serenity::model::id::UserId;
+
+use super::{connection, DbInt, IdDbExt};
+
+#[derive(
+	Clone, Debug, PartialEq, Eq, DeriveEntityModel, DeriveActiveModelBehavior,
+)]
+#[sea_orm(table_name = "email_verification_limits")]
+pub struct Model {
+	#[sea_orm(primary_key)]
+	pub(crate) user_id: DbInt,
+	pub(crate) last_sent: i64,
+	pub(crate) window_start: i64,
+	pub(crate) attempts: i32,
+}
+
+#[derive(Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+/// How long a user has to wait between requesting verification codes.
+pub(super) const RESEND_COOLDOWN: Duration = Duration::from_secs(60);
+
+/// Number of incorrect codes a user can enter in one
+/// [window](ATTEMPT_WINDOW), across all of their codes.
+const MAX_ATTEMPTS: i32 = 10;
+
+/// How long until a user's count of incorrect codes is reset.
+const ATTEMPT_WINDOW: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// A user's recent verification code requests and incorrect codes.
+#[derive(Debug, Clone)]
+pub(super) struct EmailLimit {
+	/// When the user was last sent a code, in seconds since the Unix epoch.
+	last_sent: i64,
+	/// When the current attempt window started, in seconds since the Unix
+	/// epoch.
+	window_start: i64,
+	/// Number of incorrect codes the user entered in the current window.
+	attempts: i32,
+}
+
+/// Gets the current time in seconds since the Unix epoch.
+pub(super) fn now() -> Result<i64> {
+	Ok(SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64)
+}
+
+impl EmailLimit {
+	/// Fetches the limits of the user with the given ID from the DB.
+	///
+	/// Returns a fresh set of limits if the user has none, or their attempt
+	/// window has ended.
+	#[tracing::instrument]
+	pub(super) async fn user_limit(user_id: UserId) -> Result<Self> {
+		let now = now()?;
+
+		let limit = Entity::find_by_id(user_id.into_db())
+			.one(connection())
+			.await?
+			.map(|model| Self {
+				last_sent: model.last_sent,
+				window_start: model.window_start,
+				attempts: model.attempts,
+			});
+
+		Ok(match limit {
+			Some(limit)
+				if limit.window_start + ATTEMPT_WINDOW.as_secs() as i64
+					> now =>
+			{
+				limit
+			}
+			Some(limit) => Self {
+				last_sent: limit.last_sent,
+				window_start: now,
+				attempts: 0,
+			},
+			None => Self {
+				last_sent: 0,
+				window_start: now,
+				attempts: 0,
+			},
+		})
+	}
+
+	/// Gets how long the user has to wait before they can be sent another
+	/// code, if at all.
+	pub(super) fn resend_wait(&self) -> Result<Option<Duration>> {
+		let ready = self.last_sent + RESEND_COOLDOWN.as_secs() as i64;
+		let now = now()?;
+
+		Ok((ready > now).then(|| Duration::from_secs((ready - now) as u64)))
+	}
+
+	/// Checks if the user entered too many incorrect codes in the current
+	/// window.
+	pub(super) fn exhausted(&self) -> bool {
+		self.attempts >= MAX_ATTEMPTS
+	}
+
+	/// Records that the user was sent a code.
+	pub(super) async fn record_sent(mut self, user_id: UserId) -> Result<()> {
+		self.last_sent = now()?;
+		self.set(user_id).await
+	}
+
+	/// Records that the user entered an incorrect code.
+	pub(super) async fn record_attempt(
+		mut self,
+		user_id: UserId,
+	) -> Result<()> {
+		self.attempts += 1;
+		self.set(user_id).await
+	}
+
+	/// Stores these limits for the user in the DB.
+	async fn set(self, user_id: UserId) -> Result<()> {
+		let model = Model {
+			user_id: user_id.into_db(),
+			last_sent: self.last_sent,
+			window_start: self.window_start,
+			attempts: self.attempts,
+		};
+
+		Entity::insert(model.into_active_model())
+			.on_conflict(
+				OnConflict::column(Column::UserId)
+					.update_columns([
+						Column::LastSent,
+						Column::WindowStart,
+						Column::Attempts,
+					])
+					.to_owned(),
+			)
+			.exec(connection())
+			.await?;
+
+		Ok(())
+	}
+}
@@ -0,0 +1,134 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! Handling for channels server administrators excluded from highlighting.
+
+use anyhow::Result;
+use futures_util::TryStreamExt;
+use sea_orm::{
+	entity::prelude::{
+		DeriveActiveModelBehavior, DeriveEntityModel, DerivePrimaryKey,
+		DeriveRelation, EntityTrait, EnumIter, PrimaryKeyTrait,
+	},
+	ColumnTrait, Condition, IntoActiveModel, QueryFilter,
+};
+use serenity::model::id::{ChannelId, GuildId};
+
+use super::{connection, DbInt, IdDbExt};
+
+#[derive(
+	Clone, Debug, PartialEq, Eq, DeriveEntityModel, DeriveActiveModelBehavior,
+)]
+#[sea_orm(table_name = "excluded_channels")]
+pub struct Model {
+	#[sea_orm(primary_key)]
+	pub(crate) guild_id: DbInt,
+	#[sea_orm(primary_key)]
+	pub(crate) channel_id: DbInt,
+}
+
+#[derive(Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+/// A channel where no one's keywords are highlighted, chosen by a guild's
+/// administrators.
+#[derive(Debug, Clone)]
+pub(crate) struct ExcludedChannel {
+	/// The guild the channel is in.
+	pub(crate) guild_id: GuildId,
+	/// The channel that's excluded.
+	pub(crate) channel_id: ChannelId,
+}
+
+impl ExcludedChannel {
+	/// Fetches the excluded channels of the guild with the given ID from the
+	/// DB.
+	#[tracing::instrument]
+	pub(crate) async fn guild_exclusions(
+		guild_id: GuildId,
+	) -> Result<Vec<Self>> {
+		Entity::find()
+			.filter(Column::GuildId.eq(guild_id.into_db()))
+			.stream(connection())
+			.await?
+			.map_err(Into::into)
+			.map_ok(Self::from)
+			.try_collect()
+			.await
+	}
+
+	/// Checks if the given channel of the given guild, or its parent if it's
+	/// a thread, is excluded.
+	#[tracing::instrument]
+	pub(crate) async fn excludes(
+		guild_id: GuildId,
+		channel_id: ChannelId,
+		parent_id: Option<ChannelId>,
+	) -> Result<bool> {
+		let channels = [Some(channel_id), parent_id]
+			.into_iter()
+			.flatten()
+			.map(|id| id.into_db())
+			.collect::<Vec<_>>();
+
+		Ok(Entity::find()
+			.filter(
+				Condition::all()
+					.add(Column::GuildId.eq(guild_id.into_db()))
+					.add(Column::ChannelId.is_in(channels)),
+			)
+			.one(connection())
+			.await?
+			.is_some())
+	}
+
+	/// Checks if this exclusion exists in the DB.
+	#[tracing::instrument]
+	pub(crate) async fn exists(self) -> Result<bool> {
+		Ok(Entity::find_by_id((
+			self.guild_id.into_db(),
+			self.channel_id.into_db(),
+		))
+		.one(connection())
+		.await?
+		.is_some())
+	}
+
+	/// Inserts this exclusion into the DB.
+	#[tracing::instrument]
+	pub(crate) async fn insert(self) -> Result<()> {
+		Entity::insert(Model::from(self).into_active_model())
+			.exec(connection())
+			.await?;
+
+		Ok(())
+	}
+
+	/// Deletes this exclusion from the DB.
+	#[tracing::instrument]
+	pub(crate) async fn delete(self) -> Result<()> {
+		Entity::delete(Model::from(self).into_active_model())
+			.exec(connection())
+			.await?;
+
+		Ok(())
+	}
+}
+
+impl From<Model> for ExcludedChannel {
+	fn from(model: Model) -> Self {
+		Self {
+			guild_id: GuildId::from_db(model.guild_id),
+			channel_id: ChannelId::from_db(model.channel_id),
+		}
+	}
+}
+
+impl From<ExcludedChannel> for Model {
+	fn from(exclusion: ExcludedChannel) -> Self {
+		Self {
+			guild_id: exclusion.guild_id.into_db(),
+			channel_id: exclusion.channel_id.into_db(),
+		}
+	}
+}
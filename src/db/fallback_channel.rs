@@ -0,0 +1,98 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! Handling for guilds' fallback notification channels.
+
+use anyhow::Result;
+use sea_orm::{
+	entity::prelude::{
+		DeriveActiveModelBehavior, DeriveEntityModel, DerivePrimaryKey,
+		DeriveRelation, EntityTrait, EnumIter, PrimaryKeyTrait,
+	},
+	sea_query::OnConflict,
+	IntoActiveModel,
+};
+use serenity::model::id::{ChannelId, GuildId};
+
+use super::{connection, DbInt, IdDbExt};
+
+#[derive(
+	Clone, Debug, PartialEq, Eq, DeriveEntityModel, DeriveActiveModelBehavior,
+)]
+#[sea_orm(table_name = "fallback_channels")]
+pub struct Model {
+	#[sea_orm(primary_key)]
+	pub(crate) guild_id: DbInt,
+	pub(crate) channel_id: DbInt,
+}
+
+#[derive(Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+/// A channel configured by a guild's administrators where notifications are
+/// sent to users that can't receive DMs.
+#[derive(Debug, Clone)]
+pub(crate) struct FallbackChannel {
+	/// The guild this fallback channel is for.
+	pub(crate) guild_id: GuildId,
+	/// The channel private notification threads are created in.
+	pub(crate) channel_id: ChannelId,
+}
+
+impl FallbackChannel {
+	/// Fetches the fallback channel of the guild with the given ID from the DB.
+	#[tracing::instrument]
+	pub(crate) async fn guild_fallback(
+		guild_id: GuildId,
+	) -> Result<Option<Self>> {
+		Ok(Entity::find_by_id(guild_id.into_db())
+			.one(connection())
+			.await?
+			.map(Self::from))
+	}
+
+	/// Sets this as the fallback channel of its guild in the DB.
+	#[tracing::instrument]
+	pub(crate) async fn set(self) -> Result<()> {
+		Entity::insert(Model::from(self).into_active_model())
+			.on_conflict(
+				OnConflict::column(Column::GuildId)
+					.update_column(Column::ChannelId)
+					.to_owned(),
+			)
+			.exec(connection())
+			.await?;
+
+		Ok(())
+	}
+
+	/// Removes the fallback channel of the guild with the given ID.
+	///
+	/// Returns whether there was a fallback channel to remove.
+	#[tracing::instrument]
+	pub(crate) async fn delete(guild_id: GuildId) -> Result<bool> {
+		let result = Entity::delete_by_id(guild_id.into_db())
+			.exec(connection())
+			.await?;
+
+		Ok(result.rows_affected > 0)
+	}
+}
+
+impl From<Model> for FallbackChannel {
+	fn from(model: Model) -> Self {
+		Self {
+			guild_id: GuildId::from_db(model.guild_id),
+			channel_id: ChannelId::from_db(model.channel_id),
+		}
+	}
+}
+
+impl From<FallbackChannel> for Model {
+	fn from(fallback: FallbackChannel) -> Self {
+		Self {
+			guild_id: fallback.guild_id.into_db(),
+			channel_id: fallback.channel_id.into_db(),
+		}
+	}
+}
@@ -0,0 +1,107 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! Handling for private threads notifications are sent in when users can't
+//! receive DMs.
+
+use anyhow::Result;
+use sea_orm::{
+	entity::prelude::{
+		DeriveActiveModelBehavior, DeriveEntityModel, DerivePrimaryKey,
+		DeriveRelation, EntityTrait, EnumIter, PrimaryKeyTrait,
+	},
+	sea_query::OnConflict,
+	ColumnTrait, IntoActiveModel, QueryFilter,
+};
+use serenity::model::id::{ChannelId, GuildId, UserId};
+
+use super::{connection, DbInt, IdDbExt};
+
+#[derive(
+	Clone, Debug, PartialEq, Eq, DeriveEntityModel, DeriveActiveModelBehavior,
+)]
+#[sea_orm(table_name = "fallback_threads")]
+pub struct Model {
+	#[sea_orm(primary_key)]
+	pub(crate) user_id: DbInt,
+	#[sea_orm(primary_key)]
+	pub(crate) guild_id: DbInt,
+	pub(crate) thread_id: DbInt,
+}
+
+#[derive(Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+/// A private thread in a guild's [fallback channel](super::FallbackChannel)
+/// where a user's notifications from that guild are sent.
+#[derive(Debug, Clone)]
+pub(crate) struct FallbackThread {
+	/// The user this thread was created for.
+	pub(crate) user_id: UserId,
+	/// The guild this thread is in.
+	pub(crate) guild_id: GuildId,
+	/// The ID of the thread.
+	pub(crate) thread_id: ChannelId,
+}
+
+impl FallbackThread {
+	/// Fetches the fallback thread of the given user in the given guild from
+	/// the DB.
+	#[tracing::instrument]
+	pub(crate) async fn user_thread(
+		user_id: UserId,
+		guild_id: GuildId,
+	) -> Result<Option<Self>> {
+		Ok(Entity::find_by_id((user_id.into_db(), guild_id.into_db()))
+			.one(connection())
+			.await?
+			.map(Self::from))
+	}
+
+	/// Sets this as the user's fallback thread in its guild in the DB.
+	#[tracing::instrument]
+	pub(crate) async fn set(self) -> Result<()> {
+		Entity::insert(Model::from(self).into_active_model())
+			.on_conflict(
+				OnConflict::columns([Column::UserId, Column::GuildId])
+					.update_column(Column::ThreadId)
+					.to_owned(),
+			)
+			.exec(connection())
+			.await?;
+
+		Ok(())
+	}
+
+	/// Forgets all fallback threads in the guild with the given ID, so that new
+	/// ones are created when needed.
+	#[tracing::instrument]
+	pub(crate) async fn delete_in_guild(guild_id: GuildId) -> Result<()> {
+		Entity::delete_many()
+			.filter(Column::GuildId.eq(guild_id.into_db()))
+			.exec(connection())
+			.await?;
+
+		Ok(())
+	}
+}
+
+impl From<Model> for FallbackThread {
+	fn from(model: Model) -> Self {
+		Self {
+			user_id: UserId::from_db(model.user_id),
+			guild_id: GuildId::from_db(model.guild_id),
+			thread_id: ChannelId::from_db(model.thread_id),
+		}
+	}
+}
+
+impl From<FallbackThread> for Model {
+	fn from(thread: FallbackThread) -> Self {
+		Self {
+			user_id: thread.user_id.into_db(),
+			guild_id: thread.guild_id.into_db(),
+			thread_id: thread.thread_id.into_db(),
+		}
+	}
+}
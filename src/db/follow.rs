@@ -0,0 +1,237 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! Handling for users following other users' messages in channels.
+
+use anyhow::Result;
+use futures_util::TryStreamExt;
+use sea_orm::{
+	entity::prelude::{
+		DeriveActiveModelBehavior, DeriveEntityModel, DerivePrimaryKey,
+		DeriveRelation, EntityTrait, EnumIter, PrimaryKeyTrait,
+	},
+	ColumnTrait, Condition, IntoActiveModel, PaginatorTrait, QueryFilter,
+};
+use serenity::model::id::{ChannelId, GuildId, UserId};
+
+use super::{
+	connection,
+	excluded_channel::ExcludedChannel,
+	guild_settings::GuildSettings,
+	keyword::{
+		revoked_users, users_hidden_from, users_muting, users_with_block,
+	},
+	opt_out::OptOut,
+	DbInt, IdDbExt,
+};
+
+#[derive(
+	Clone, Debug, PartialEq, Eq, DeriveEntityModel, DeriveActiveModelBehavior,
+)]
+#[sea_orm(table_name = "follows")]
+pub struct Model {
+	#[sea_orm(primary_key)]
+	pub(crate) user_id: DbInt,
+	#[sea_orm(primary_key)]
+	pub(crate) followed_id: DbInt,
+	#[sea_orm(primary_key)]
+	pub(crate) channel_id: DbInt,
+	pub(crate) guild_id: DbInt,
+}
+
+#[derive(Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+/// A user following another user's messages in a channel, notifying them of
+/// every message the followed user sends there.
+#[derive(Debug, Clone)]
+pub(crate) struct Follow {
+	/// The user who is notified.
+	pub(crate) user_id: UserId,
+	/// The user whose messages are followed.
+	pub(crate) followed_id: UserId,
+	/// The guild the channel is in.
+	pub(crate) guild_id: GuildId,
+	/// The channel the messages are followed in.
+	pub(crate) channel_id: ChannelId,
+}
+
+impl Follow {
+	/// Fetches the follows the given user added from the DB.
+	#[tracing::instrument]
+	pub(crate) async fn user_follows(user_id: UserId) -> Result<Vec<Self>> {
+		Entity::find()
+			.filter(Column::UserId.eq(user_id.into_db()))
+			.stream(connection())
+			.await?
+			.map_err(Into::into)
+			.map_ok(Self::from)
+			.try_collect()
+			.await
+	}
+
+	/// Counts the follows the given user added.
+	#[tracing::instrument]
+	pub(crate) async fn user_follow_count(user_id: UserId) -> Result<u64> {
+		Ok(Entity::find()
+			.filter(Column::UserId.eq(user_id.into_db()))
+			.count(connection())
+			.await?)
+	}
+
+	/// Gets the users who should be notified of a message by the given
+	/// author in the given channel because they follow the author there.
+	///
+	/// Followers who opted out, muted the channel, blocked the author, or had
+	/// their highlighting revoked in the guild are skipped, as are followers
+	/// the author hid their messages from. No one is returned if the author
+	/// opted out, or if the guild's administrators disabled highlighting in the
+	/// guild or excluded the channel.
+	#[tracing::instrument]
+	pub(crate) async fn relevant_followers(
+		guild_id: GuildId,
+		channel_id: ChannelId,
+		parent_id: Option<ChannelId>,
+		author_id: UserId,
+	) -> Result<Vec<UserId>> {
+		let followers = Entity::find()
+			.filter(
+				Condition::all()
+					.add(Column::FollowedId.eq(author_id.into_db()))
+					.add(Column::ChannelId.eq(channel_id.into_db()))
+					.add(allowed_subscribers(
+						Column::UserId,
+						guild_id,
+						channel_id,
+						author_id,
+					)),
+			)
+			.stream(connection())
+			.await?
+			.map_ok(|model| UserId::from_db(model.user_id))
+			.try_collect::<Vec<_>>()
+			.await?;
+
+		if followers.is_empty()
+			|| subscriptions_disabled(
+				guild_id, channel_id, parent_id, author_id,
+			)
+			.await?
+		{
+			return Ok(Vec::new());
+		}
+
+		Ok(followers)
+	}
+
+	/// Checks if this follow exists in the DB.
+	#[tracing::instrument]
+	pub(crate) async fn exists(self) -> Result<bool> {
+		Ok(Entity::find_by_id((
+			self.user_id.into_db(),
+			self.followed_id.into_db(),
+			self.channel_id.into_db(),
+		))
+		.one(connection())
+		.await?
+		.is_some())
+	}
+
+	/// Inserts this follow into the DB.
+	#[tracing::instrument]
+	pub(crate) async fn insert(self) -> Result<()> {
+		Entity::insert(Model::from(self).into_active_model())
+			.exec(connection())
+			.await?;
+
+		Ok(())
+	}
+
+	/// Deletes this follow from the DB.
+	#[tracing::instrument]
+	pub(crate) async fn delete(self) -> Result<()> {
+		Entity::delete(Model::from(self).into_active_model())
+			.exec(connection())
+			.await?;
+
+		Ok(())
+	}
+
+	/// Deletes all of the given user's follows of another user, returning how
+	/// many were deleted.
+	#[tracing::instrument]
+	pub(crate) async fn delete_all(
+		user_id: UserId,
+		followed_id: UserId,
+	) -> Result<u64> {
+		let result = Entity::delete_many()
+			.filter(
+				Condition::all()
+					.add(Column::UserId.eq(user_id.into_db()))
+					.add(Column::FollowedId.eq(followed_id.into_db())),
+			)
+			.exec(connection())
+			.await?;
+
+		Ok(result.rows_affected)
+	}
+}
+
+impl From<Model> for Follow {
+	fn from(model: Model) -> Self {
+		Self {
+			user_id: UserId::from_db(model.user_id),
+			followed_id: UserId::from_db(model.followed_id),
+			guild_id: GuildId::from_db(model.guild_id),
+			channel_id: ChannelId::from_db(model.channel_id),
+		}
+	}
+}
+
+impl From<Follow> for Model {
+	fn from(follow: Follow) -> Self {
+		Self {
+			user_id: follow.user_id.into_db(),
+			followed_id: follow.followed_id.into_db(),
+			channel_id: follow.channel_id.into_db(),
+			guild_id: follow.guild_id.into_db(),
+		}
+	}
+}
+
+/// Checks if no one should be notified of a message by the given author in
+/// the given channel through a subscription like a follow or a watch.
+///
+/// This is the case if the author opted out, or if the guild's administrators
+/// disabled highlighting in the guild or excluded the channel, or its parent
+/// `parent_id` if it's a thread.
+pub(super) async fn subscriptions_disabled(
+	guild_id: GuildId,
+	channel_id: ChannelId,
+	parent_id: Option<ChannelId>,
+	author_id: UserId,
+) -> Result<bool> {
+	Ok(GuildSettings::guild_settings(guild_id).await?.disabled
+		|| ExcludedChannel::excludes(guild_id, channel_id, parent_id).await?
+		|| (OptOut { user_id: author_id }).exists().await?)
+}
+
+/// Builds a condition on the given column of subscribed users that skips the
+/// users who shouldn't be notified of a message by the given author in the
+/// given channel through a subscription like a follow or a watch.
+///
+/// These are users who muted the channel, blocked the author, or had their
+/// highlighting revoked in the guild, and users the author hid their messages
+/// from.
+pub(super) fn allowed_subscribers(
+	user_column: impl ColumnTrait,
+	guild_id: GuildId,
+	channel_id: ChannelId,
+	author_id: UserId,
+) -> Condition {
+	Condition::all()
+		.add(user_column.not_in_subquery(users_muting(channel_id)))
+		.add(user_column.not_in_subquery(users_with_block(author_id)))
+		.add(user_column.not_in_subquery(users_hidden_from(author_id)))
+		.add(user_column.not_in_subquery(revoked_users(guild_id)))
+}
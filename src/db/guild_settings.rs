@@ -0,0 +1,112 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! Handling for settings server administrators choose for their servers.
+
+use anyhow::Result;
+use sea_orm::{
+	entity::prelude::{
+		DeriveActiveModelBehavior, DeriveEntityModel, DerivePrimaryKey,
+		DeriveRelation, EntityTrait, EnumIter, PrimaryKeyTrait,
+	},
+	sea_query::OnConflict,
+	IntoActiveModel,
+};
+use serenity::model::id::GuildId;
+
+use super::{connection, DbInt, IdDbExt};
+
+#[derive(
+	Clone, Debug, PartialEq, Eq, DeriveEntityModel, DeriveActiveModelBehavior,
+)]
+#[sea_orm(table_name = "guild_settings")]
+pub struct Model {
+	#[sea_orm(primary_key)]
+	pub(crate) guild_id: DbInt,
+	pub(crate) disabled: bool,
+	pub(crate) max_keywords: Option<i32>,
+	pub(crate) min_keyword_length: Option<i32>,
+}
+
+#[derive(Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+/// Settings chosen by a guild's administrators.
+#[derive(Debug, Clone)]
+pub(crate) struct GuildSettings {
+	/// The guild these settings are for.
+	pub(crate) guild_id: GuildId,
+	/// Whether highlighting is turned off in the guild entirely.
+	pub(crate) disabled: bool,
+	/// Most keywords each member can add in the guild, if limited beyond the
+	/// bot's own keyword limit.
+	pub(crate) max_keywords: Option<u32>,
+	/// Shortest keywords members can add in the guild, if longer than usual.
+	pub(crate) min_keyword_length: Option<u32>,
+}
+
+impl GuildSettings {
+	/// Fetches the settings of the guild with the given ID from the DB.
+	///
+	/// Guilds that haven't changed any settings get the defaults.
+	#[tracing::instrument]
+	pub(crate) async fn guild_settings(guild_id: GuildId) -> Result<Self> {
+		Ok(Entity::find_by_id(guild_id.into_db())
+			.one(connection())
+			.await?
+			.map_or_else(|| Self::new(guild_id), Self::from))
+	}
+
+	/// Default settings for the given guild.
+	pub(crate) fn new(guild_id: GuildId) -> Self {
+		Self {
+			guild_id,
+			disabled: false,
+			max_keywords: None,
+			min_keyword_length: None,
+		}
+	}
+
+	/// Sets these as the settings of their guild in the DB.
+	#[tracing::instrument]
+	pub(crate) async fn set(self) -> Result<()> {
+		Entity::insert(Model::from(self).into_active_model())
+			.on_conflict(
+				OnConflict::column(Column::GuildId)
+					.update_columns([
+						Column::Disabled,
+						Column::MaxKeywords,
+						Column::MinKeywordLength,
+					])
+					.to_owned(),
+			)
+			.exec(connection())
+			.await?;
+
+		Ok(())
+	}
+}
+
+impl From<Model> for GuildSettings {
+	fn from(model: Model) -> Self {
+		Self {
+			guild_id: GuildId::from_db(model.guild_id),
+			disabled: model.disabled,
+			max_keywords: model.max_keywords.map(|max| max as u32),
+			min_keyword_length: model.min_keyword_length.map(|min| min as u32),
+		}
+	}
+}
+
+impl From<GuildSettings> for Model {
+	fn from(settings: GuildSettings) -> Self {
+		Self {
+			guild_id: settings.guild_id.into_db(),
+			disabled: settings.disabled,
+			max_keywords: settings.max_keywords.map(|max| max as i32),
+			min_keyword_length: settings
+				.min_keyword_length
+				.map(|min| min as i32),
+		}
+	}
+}
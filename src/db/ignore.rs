@@ -13,7 +13,7 @@ use sea_orm::{
 	},
 	ColumnTrait, Condition, IntoActiveModel, QueryFilter, QuerySelect,
 };
-use serenity::model::id::{GuildId, UserId};
+use serenity::model::id::{ChannelId, GuildId, UserId};
 
 use super::{connection, DbInt, IdDbExt};
 
@@ -28,6 +28,12 @@ pub struct Model {
 	pub(crate) user_id: DbInt,
 	#[sea_orm(primary_key)]
 	pub(crate) guild_id: DbInt,
+	/// `0` if the phrase is ignored in every channel of the guild.
+	#[sea_orm(primary_key)]
+	pub(crate) channel_id: DbInt,
+	/// Empty if the phrase is ignored for every keyword.
+	#[sea_orm(primary_key)]
+	pub(crate) keyword: String,
 }
 
 #[derive(Debug, EnumIter, DeriveRelation)]
@@ -42,11 +48,25 @@ pub(crate) struct Ignore {
 	pub(crate) user_id: UserId,
 	/// The guild in which the user ignored the phrase.
 	pub(crate) guild_id: GuildId,
+	/// The channel the phrase is ignored in, or `None` if it's ignored
+	/// throughout the guild.
+	pub(crate) channel_id: Option<ChannelId>,
+	/// The keyword the phrase is ignored for, or `None` if it's ignored for
+	/// all of the user's keywords.
+	pub(crate) keyword: Option<String>,
 }
 
 impl Ignore {
+	/// Checks if this ignored phrase applies to the given keyword in the given
+	/// channel.
+	pub(crate) fn applies(&self, channel_id: ChannelId, keyword: &str) -> bool {
+		self.channel_id.is_none_or(|id| id == channel_id)
+			&& self.keyword.as_deref().is_none_or(|k| k == keyword)
+	}
+
 	/// Fetches the list of ignored phrases of the specified user in the
-	/// specified guild from the DB.
+	/// specified guild from the DB, including those limited to a channel or
+	/// keyword.
 	#[tracing::instrument]
 	pub(crate) async fn user_guild_ignores(
 		user_id: UserId,
@@ -88,14 +108,18 @@ impl Ignore {
 			self.guild_id = %self.guild_id,
 	))]
 	pub(crate) async fn exists(self) -> Result<bool> {
+		let model = Model::from(self);
+
 		let count = Entity::find()
 			.select_only()
 			.column_as(Column::UserId.count(), QueryAs::IgnoreCount)
 			.filter(
 				Condition::all()
-					.add(Column::UserId.eq(self.user_id.into_db()))
-					.add(Column::GuildId.eq(self.guild_id.into_db()))
-					.add(Column::Phrase.eq(&*self.phrase)),
+					.add(Column::UserId.eq(model.user_id))
+					.add(Column::GuildId.eq(model.guild_id))
+					.add(Column::Phrase.eq(model.phrase))
+					.add(Column::ChannelId.eq(model.channel_id))
+					.add(Column::Keyword.eq(model.keyword)),
 			)
 			.into_values::<i64, QueryAs>()
 			.one(connection())
@@ -166,6 +190,9 @@ impl From<Model> for Ignore {
 			phrase: model.phrase,
 			user_id: UserId::from_db(model.user_id),
 			guild_id: GuildId::from_db(model.guild_id),
+			channel_id: (model.channel_id != 0)
+				.then(|| ChannelId::from_db(model.channel_id)),
+			keyword: (!model.keyword.is_empty()).then_some(model.keyword),
 		}
 	}
 }
@@ -176,6 +203,8 @@ impl From<Ignore> for Model {
 			phrase: ignore.phrase,
 			user_id: ignore.user_id.into_db(),
 			guild_id: ignore.guild_id.into_db(),
+			channel_id: ignore.channel_id.map_or(0, IdDbExt::into_db),
+			keyword: ignore.keyword.unwrap_or_default(),
 		}
 	}
 }
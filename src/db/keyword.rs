@@ -3,20 +3,46 @@
 
 //! Handling for keywords.
 
+use std::collections::{HashMap, HashSet};
+
 use anyhow::{Context, Result};
-use futures_util::TryStreamExt;
+use futures_util::{FutureExt, TryStreamExt};
+use lazy_regex::regex;
 use sea_orm::{
-	sea_query::Expr, ColumnTrait, Condition, DeriveColumn, EntityTrait,
-	EnumIter, IntoActiveModel, QueryFilter, QuerySelect, QueryTrait,
+	sea_query::{Expr, SelectStatement},
+	ColumnTrait, Condition, ConnectionTrait, DbErr, DeriveColumn, EntityTrait,
+	EnumIter, FromQueryResult, IntoActiveModel, QueryFilter, QuerySelect,
+	QueryTrait, TransactionTrait,
 };
-use serenity::model::id::{ChannelId, GuildId, UserId};
+use serenity::model::id::{ChannelId, GuildId, RoleId, UserId};
 use tracing::info_span;
 
 use super::{
-	block, channel_keyword, connection, guild_keyword, mute, opt_out, IdDbExt,
+	banned_keyword::BannedKeyword,
+	block, channel_keyword, connection,
+	excluded_channel::ExcludedChannel,
+	guild_keyword,
+	guild_settings::GuildSettings,
+	keyword_author::{self, AuthorFilter, KeywordAuthor},
+	keyword_channel::{self, KeywordChannel},
+	keyword_pack::KeywordPack,
+	keyword_stats::{self, KeywordStats},
+	mute,
+	opt_out::{self, OptOut},
+	pack_subscription, revoked_member,
+	team_keyword::TeamKeyword,
+	team_keyword_opt_out::TeamKeywordOptOut,
+	DbInt, IdDbExt,
 };
+use crate::settings::settings;
+
+/// Most user IDs bound in one query about team keyword members.
+///
+/// Databases limit how many values one statement can bind, so members of
+/// large roles are looked up in batches.
+const MEMBER_BATCH_SIZE: usize = 500;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum KeywordKind {
 	Channel(ChannelId),
 	Guild(GuildId),
@@ -35,12 +61,143 @@ pub(crate) struct Keyword {
 	pub(crate) kind: KeywordKind,
 }
 
+/// A keyword that may be relevant to a message, as returned by
+/// [`get_relevant_keywords`](Keyword::get_relevant_keywords).
+#[derive(Debug, Clone)]
+pub(crate) struct RelevantKeyword {
+	/// The keyword.
+	pub(crate) keyword: Keyword,
+	/// Whether the creator of the keyword muted the channel of the message.
+	pub(crate) muted: bool,
+	/// Whether the creator of the keyword blocked the author of the message.
+	pub(crate) blocked: bool,
+}
+
+/// Options of a user's keyword stored apart from the keyword itself.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct KeywordOptions {
+	/// The channels a server-wide keyword is limited to.
+	pub(crate) channels: Vec<ChannelId>,
+	/// The channels a server-wide keyword is excluded from.
+	pub(crate) excluded_channels: Vec<ChannelId>,
+	/// The authors the keyword is restricted to, shared with the user's other
+	/// keywords with the same text in the guild.
+	pub(crate) authors: Vec<AuthorFilter>,
+}
+
+impl KeywordOptions {
+	/// Replaces the stored options of the given keyword in the given guild
+	/// with these, using the given connection.
+	///
+	/// Channels are only stored for server-wide keywords.
+	async fn replace_in(
+		self,
+		db: &impl ConnectionTrait,
+		keyword: &Keyword,
+		guild_id: GuildId,
+	) -> Result<(), DbErr> {
+		let user_id = keyword.user_id;
+
+		if let KeywordKind::Guild(_) = keyword.kind {
+			keyword_channel::Entity::delete_many()
+				.filter(
+					Condition::all()
+						.add(
+							keyword_channel::Column::UserId
+								.eq(user_id.into_db()),
+						)
+						.add(
+							keyword_channel::Column::GuildId
+								.eq(guild_id.into_db()),
+						)
+						.add(
+							keyword_channel::Column::Keyword
+								.eq(keyword.keyword.clone()),
+						),
+				)
+				.exec(db)
+				.await?;
+
+			let channels = self
+				.channels
+				.into_iter()
+				.map(|channel_id| (channel_id, false))
+				.chain(
+					self.excluded_channels
+						.into_iter()
+						.map(|channel_id| (channel_id, true)),
+				);
+
+			for (channel_id, excluded) in channels {
+				let channel = KeywordChannel {
+					user_id,
+					guild_id,
+					keyword: keyword.keyword.clone(),
+					channel_id,
+					excluded,
+				};
+
+				keyword_channel::Entity::insert(
+					keyword_channel::Model::from(channel).into_active_model(),
+				)
+				.exec(db)
+				.await?;
+			}
+		}
+
+		keyword_author::Entity::delete_many()
+			.filter(
+				Condition::all()
+					.add(keyword_author::Column::UserId.eq(user_id.into_db()))
+					.add(keyword_author::Column::GuildId.eq(guild_id.into_db()))
+					.add(
+						keyword_author::Column::Keyword
+							.eq(keyword.keyword.clone()),
+					),
+			)
+			.exec(db)
+			.await?;
+
+		for author in self.authors {
+			let filter = KeywordAuthor {
+				user_id,
+				guild_id,
+				keyword: keyword.keyword.clone(),
+				author,
+			};
+
+			keyword_author::Entity::insert(
+				keyword_author::Model::from(filter).into_active_model(),
+			)
+			.exec(db)
+			.await?;
+		}
+
+		Ok(())
+	}
+}
+
 enum EitherModel {
 	Channel(channel_keyword::Model),
 	Guild(guild_keyword::Model),
 }
 
 impl Keyword {
+	/// Checks if the given keyword is allowed by the bot's keyword policy.
+	pub(crate) fn is_allowed(keyword: &str) -> bool {
+		let behavior = &settings().behavior;
+
+		keyword.len() >= behavior.min_keyword_length
+			&& behavior
+				.max_keyword_length
+				.is_none_or(|max| keyword.chars().count() <= max)
+			&& !regex!(r"<([@#&]|a?:)").is_match(keyword)
+			&& !behavior
+				.forbidden_keywords
+				.iter()
+				.any(|pattern| pattern.is_match(keyword))
+	}
+
 	fn into_model(self) -> EitherModel {
 		match self.kind {
 			KeywordKind::Guild(guild_id) => {
@@ -62,17 +219,51 @@ impl Keyword {
 
 	/// Gets keywords that may be relelvant to a message.
 	///
-	/// Fetches all guild-wide keywords in the specified guild, as long as the
-	/// creator of the keyword didn't mute the channel or block the author.
+	/// Fetches all guild-wide keywords in the specified guild, and all
+	/// channel-specific keywords in the specified channel, as long as neither
+	/// the creator of the keyword nor the author have opted out.
 	///
-	/// Fetches all channel-specific keywords in the specified channel, as long
-	/// as the creator of the keyword didn't block the author.
-	#[tracing::instrument]
+	/// Keywords whose creator muted the channel or blocked the author are
+	/// still returned so they can be counted, but are flagged as such and
+	/// shouldn't be notified. Keywords whose creator was blocked by the author
+	/// with their messages hidden, or whose creator's highlighting was revoked
+	/// in the guild, aren't returned at all.
+	///
+	/// Team keywords are included as guild-wide keywords of each member of
+	/// their role, found with `role_members`, and pack keywords as guild-wide
+	/// keywords of each subscriber of their pack, unless the user opted out of
+	/// them or added the same keyword themselves.
+	///
+	/// Keywords that aren't allowed anymore, because the bot's keyword policy
+	/// forbids them or they contain a word banned in the guild, aren't
+	/// returned.
+	///
+	/// Keywords restricted to certain authors are only returned if the author,
+	/// who has `author_roles`, is one of them. Guild-wide keywords excluded
+	/// from the channel, or limited to other channels, aren't returned.
+	///
+	/// No keywords are returned if the guild's administrators disabled
+	/// highlighting in the guild or excluded the channel, or its parent
+	/// `parent_id` if it's a thread.
+	///
+	/// Only keywords for which `matches` returns true are returned.
+	#[tracing::instrument(skip(role_members, matches))]
 	pub(crate) async fn get_relevant_keywords(
 		guild_id: GuildId,
 		channel_id: ChannelId,
+		parent_id: Option<ChannelId>,
 		author_id: UserId,
-	) -> Result<Vec<Keyword>> {
+		author_roles: &[RoleId],
+		role_members: impl Fn(RoleId) -> Vec<UserId>,
+		matches: impl Fn(&str) -> bool,
+	) -> Result<Vec<RelevantKeyword>> {
+		if GuildSettings::guild_settings(guild_id).await?.disabled
+			|| ExcludedChannel::excludes(guild_id, channel_id, parent_id)
+				.await?
+		{
+			return Ok(Vec::new());
+		}
+
 		let span = info_span!(
 			"relevant_guild_keywords",
 			author_id = %author_id,
@@ -81,10 +272,7 @@ impl Keyword {
 
 		let entered = span.enter();
 
-		let opted_out = opt_out::Entity::find()
-			.select_only()
-			.column(opt_out::Column::UserId)
-			.into_query();
+		let opted_out = opted_out_users();
 
 		let muted_channels =
 			mute::Entity::find()
@@ -95,13 +283,21 @@ impl Keyword {
 				))
 				.into_query();
 
-		let users_with_block = block::Entity::find()
-			.select_only()
-			.column(block::Column::UserId)
-			.filter(block::Column::BlockedId.eq(author_id.into_db()))
-			.into_query();
+		let users_with_block = users_with_block(author_id);
+		let users_hidden_from = users_hidden_from(author_id);
+		let revoked_users = revoked_users(guild_id);
 
-		let keywords: Vec<Keyword> = guild_keyword::Entity::find()
+		let keywords: Vec<RelevantKeyword> = guild_keyword::Entity::find()
+			.column_as(
+				Expr::expr(Expr::value(channel_id.into_db()))
+					.in_subquery(muted_channels),
+				QueryAs::Muted,
+			)
+			.column_as(
+				guild_keyword::Column::UserId
+					.in_subquery(users_with_block.clone()),
+				QueryAs::Blocked,
+			)
 			.filter(
 				Condition::all()
 					.add(guild_keyword::Column::UserId.ne(author_id.into_db()))
@@ -111,22 +307,23 @@ impl Keyword {
 							.not_in_subquery(opted_out.clone()),
 					)
 					.add(
-						Expr::expr(Expr::value(author_id.into_db()))
-							.not_in_subquery(opted_out.clone()),
+						guild_keyword::Column::UserId
+							.not_in_subquery(users_hidden_from.clone()),
 					)
 					.add(
 						guild_keyword::Column::UserId
-							.not_in_subquery(users_with_block.clone()),
+							.not_in_subquery(revoked_users.clone()),
 					)
 					.add(
-						Expr::expr(Expr::value(channel_id.into_db()))
-							.not_in_subquery(muted_channels.clone()),
+						Expr::expr(Expr::value(author_id.into_db()))
+							.not_in_subquery(opted_out.clone()),
 					),
 			)
+			.into_model::<RelevantGuildKeyword>()
 			.stream(connection())
 			.await?
 			.map_err(anyhow::Error::from)
-			.map_ok(Keyword::from)
+			.map_ok(RelevantKeyword::from)
 			.try_collect()
 			.await?;
 
@@ -139,9 +336,13 @@ impl Keyword {
 			channel_id = %channel_id
 		);
 
-		let _entered = span.enter();
+		let entered = span.enter();
 
-		channel_keyword::Entity::find()
+		let keywords = channel_keyword::Entity::find()
+			.column_as(
+				channel_keyword::Column::UserId.in_subquery(users_with_block),
+				QueryAs::Blocked,
+			)
 			.filter(
 				Condition::all()
 					.add(
@@ -156,23 +357,411 @@ impl Keyword {
 							.not_in_subquery(opted_out.clone()),
 					)
 					.add(
-						Expr::expr(Expr::value(author_id.into_db()))
-							.not_in_subquery(opted_out),
+						channel_keyword::Column::UserId
+							.not_in_subquery(users_hidden_from),
 					)
 					.add(
 						channel_keyword::Column::UserId
-							.not_in_subquery(users_with_block),
+							.not_in_subquery(revoked_users),
+					)
+					.add(
+						Expr::expr(Expr::value(author_id.into_db()))
+							.not_in_subquery(opted_out),
 					),
 			)
+			.into_model::<RelevantChannelKeyword>()
 			.stream(connection())
 			.await?
 			.map_err(anyhow::Error::from)
-			.map_ok(Keyword::from)
+			.map_ok(RelevantKeyword::from)
 			.try_fold(keywords, |mut keywords, keyword| async move {
 				keywords.push(keyword);
 				Ok(keywords)
 			})
-			.await
+			.await?;
+
+		drop(entered);
+		drop(span);
+
+		let keywords = Self::add_shared_keywords(
+			guild_id,
+			channel_id,
+			author_id,
+			role_members,
+			keywords,
+		)
+		.await?;
+
+		let mut keywords = Self::filter_banned(guild_id, keywords).await?;
+
+		// the remaining filters query per user, so only users whose keywords
+		// matched are looked up
+		keywords.retain(|relevant| matches(&relevant.keyword.keyword));
+
+		let keywords =
+			Self::filter_channels(guild_id, channel_id, parent_id, keywords)
+				.await?;
+
+		Self::filter_authors(guild_id, author_id, author_roles, keywords).await
+	}
+
+	/// Removes keywords that aren't allowed anymore from the given relevant
+	/// keywords: those the bot's keyword policy forbids, and those containing
+	/// a word banned in the guild.
+	#[tracing::instrument(skip(keywords))]
+	async fn filter_banned(
+		guild_id: GuildId,
+		mut keywords: Vec<RelevantKeyword>,
+	) -> Result<Vec<RelevantKeyword>> {
+		let banned = BannedKeyword::guild_bans(guild_id).await?;
+
+		keywords.retain(|relevant| {
+			let keyword = &relevant.keyword.keyword;
+
+			Self::is_allowed(keyword)
+				&& !banned.iter().any(|ban| keyword.contains(ban.word.as_str()))
+		});
+
+		Ok(keywords)
+	}
+
+	/// Removes guild-wide keywords excluded from the given channel or limited
+	/// to other channels from the given relevant keywords.
+	///
+	/// Messages in threads are treated like messages in their parent,
+	/// `parent_id`.
+	#[tracing::instrument(skip(keywords))]
+	async fn filter_channels(
+		guild_id: GuildId,
+		channel_id: ChannelId,
+		parent_id: Option<ChannelId>,
+		mut keywords: Vec<RelevantKeyword>,
+	) -> Result<Vec<RelevantKeyword>> {
+		let users = keyword_users(keywords.iter().filter(|relevant| {
+			matches!(relevant.keyword.kind, KeywordKind::Guild(_))
+		}));
+
+		let mut channels = HashMap::new();
+
+		for batch in users.chunks(MEMBER_BATCH_SIZE) {
+			for channel in
+				KeywordChannel::guild_user_channels(guild_id, batch).await?
+			{
+				channels
+					.entry((channel.user_id, channel.keyword.clone()))
+					.or_insert_with(Vec::new)
+					.push(channel);
+			}
+		}
+
+		if channels.is_empty() {
+			return Ok(keywords);
+		}
+
+		keywords.retain(|relevant| {
+			if !matches!(relevant.keyword.kind, KeywordKind::Guild(_)) {
+				return true;
+			}
+
+			let key =
+				(relevant.keyword.user_id, relevant.keyword.keyword.clone());
+
+			channels.get(&key).is_none_or(|channels| {
+				KeywordChannel::allows(channels, channel_id, parent_id)
+			})
+		});
+
+		Ok(keywords)
+	}
+
+	/// Removes keywords restricted to other authors from the given relevant
+	/// keywords.
+	#[tracing::instrument(skip(keywords))]
+	async fn filter_authors(
+		guild_id: GuildId,
+		author_id: UserId,
+		author_roles: &[RoleId],
+		mut keywords: Vec<RelevantKeyword>,
+	) -> Result<Vec<RelevantKeyword>> {
+		let mut filters = HashMap::new();
+
+		for batch in keyword_users(&keywords).chunks(MEMBER_BATCH_SIZE) {
+			for filter in
+				KeywordAuthor::guild_user_filters(guild_id, batch).await?
+			{
+				filters
+					.entry((filter.user_id, filter.keyword))
+					.or_insert_with(Vec::new)
+					.push(filter.author);
+			}
+		}
+
+		if filters.is_empty() {
+			return Ok(keywords);
+		}
+
+		keywords.retain(|relevant| {
+			let key =
+				(relevant.keyword.user_id, relevant.keyword.keyword.clone());
+
+			filters.get(&key).is_none_or(|authors| {
+				authors
+					.iter()
+					.any(|author| author.matches(author_id, author_roles))
+			})
+		});
+
+		Ok(keywords)
+	}
+
+	/// Adds the guild's team keywords and the keywords of packs users
+	/// subscribed to in the guild to the given relevant keywords.
+	///
+	/// Team keywords are added for each member of their roles who didn't opt
+	/// out of them, and pack keywords for each subscriber of their pack.
+	/// Keywords users already have are skipped, as are users who opted out,
+	/// users the author hid their messages from, and users whose highlighting
+	/// was revoked. Nothing is added if the author opted out.
+	#[tracing::instrument(skip(role_members, keywords))]
+	async fn add_shared_keywords(
+		guild_id: GuildId,
+		channel_id: ChannelId,
+		author_id: UserId,
+		role_members: impl Fn(RoleId) -> Vec<UserId>,
+		mut keywords: Vec<RelevantKeyword>,
+	) -> Result<Vec<RelevantKeyword>> {
+		let team_keywords = TeamKeyword::guild_team_keywords(guild_id).await?;
+
+		let subscribers = pack_subscription::Entity::find()
+			.select_only()
+			.column(pack_subscription::Column::UserId)
+			.column(pack_subscription::Column::Pack)
+			.column_as(
+				pack_subscription::Column::UserId
+					.in_subquery(users_muting(channel_id)),
+				QueryAs::Muted,
+			)
+			.column_as(
+				pack_subscription::Column::UserId
+					.in_subquery(users_with_block(author_id)),
+				QueryAs::Blocked,
+			)
+			.filter(
+				Condition::all()
+					.add(
+						pack_subscription::Column::GuildId
+							.eq(guild_id.into_db()),
+					)
+					.add(
+						pack_subscription::Column::UserId
+							.ne(author_id.into_db()),
+					)
+					.add(
+						pack_subscription::Column::UserId
+							.not_in_subquery(opted_out_users()),
+					)
+					.add(
+						pack_subscription::Column::UserId
+							.not_in_subquery(users_hidden_from(author_id)),
+					)
+					.add(
+						pack_subscription::Column::UserId
+							.not_in_subquery(revoked_users(guild_id)),
+					),
+			)
+			.into_model::<RelevantSubscriber>()
+			.all(connection())
+			.await?;
+
+		if (team_keywords.is_empty() && subscribers.is_empty())
+			|| (OptOut { user_id: author_id }).exists().await?
+		{
+			return Ok(keywords);
+		}
+
+		// (user, keyword, muted, blocked)
+		let mut shared = Vec::new();
+
+		if !team_keywords.is_empty() {
+			let roles = team_keywords
+				.iter()
+				.map(|keyword| keyword.role_id)
+				.collect::<HashSet<_>>();
+
+			let team_opt_outs = TeamKeywordOptOut::role_opt_outs(roles.clone())
+				.await?
+				.into_iter()
+				.map(|opt_out| {
+					(opt_out.user_id, opt_out.role_id, opt_out.keyword)
+				})
+				.collect::<HashSet<_>>();
+
+			let members = roles
+				.into_iter()
+				.map(|role_id| (role_id, role_members(role_id)))
+				.collect::<HashMap<_, _>>();
+
+			// members come from the cache rather than the DB, so only the rows
+			// about them are fetched
+			let member_ids = members
+				.values()
+				.flatten()
+				.map(|user_id| user_id.into_db())
+				.collect::<HashSet<_>>()
+				.into_iter()
+				.collect::<Vec<_>>();
+
+			let mut skipped = HashSet::new();
+			let mut muted = HashSet::new();
+			let mut blocked = HashSet::new();
+
+			for batch in member_ids.chunks(MEMBER_BATCH_SIZE) {
+				let opted_out = opt_out::Entity::find()
+					.filter(opt_out::Column::UserId.is_in(batch.to_vec()))
+					.stream(connection())
+					.await?
+					.map_ok(|model| UserId::from_db(model.user_id))
+					.try_collect::<Vec<_>>()
+					.await?;
+
+				let hidden_from = block::Entity::find()
+					.filter(
+						Condition::all()
+							.add(block::Column::UserId.eq(author_id.into_db()))
+							.add(block::Column::HideMessages.eq(true))
+							.add(
+								block::Column::BlockedId.is_in(batch.to_vec()),
+							),
+					)
+					.stream(connection())
+					.await?
+					.map_ok(|model| UserId::from_db(model.blocked_id))
+					.try_collect::<Vec<_>>()
+					.await?;
+
+				let revoked = revoked_member::Entity::find()
+					.filter(
+						Condition::all()
+							.add(
+								revoked_member::Column::GuildId
+									.eq(guild_id.into_db()),
+							)
+							.add(
+								revoked_member::Column::UserId
+									.is_in(batch.to_vec()),
+							),
+					)
+					.stream(connection())
+					.await?
+					.map_ok(|model| UserId::from_db(model.user_id))
+					.try_collect::<Vec<_>>()
+					.await?;
+
+				skipped.extend(opted_out);
+				skipped.extend(hidden_from);
+				skipped.extend(revoked);
+
+				let batch_muted = mute::Entity::find()
+					.filter(
+						Condition::all()
+							.add(
+								mute::Column::ChannelId
+									.eq(channel_id.into_db()),
+							)
+							.add(mute::Column::UserId.is_in(batch.to_vec())),
+					)
+					.stream(connection())
+					.await?
+					.map_ok(|model| UserId::from_db(model.user_id))
+					.try_collect::<Vec<_>>()
+					.await?;
+
+				let batch_blocked = block::Entity::find()
+					.filter(
+						Condition::all()
+							.add(
+								block::Column::BlockedId
+									.eq(author_id.into_db()),
+							)
+							.add(block::Column::UserId.is_in(batch.to_vec())),
+					)
+					.stream(connection())
+					.await?
+					.map_ok(|model| UserId::from_db(model.user_id))
+					.try_collect::<Vec<_>>()
+					.await?;
+
+				muted.extend(batch_muted);
+				blocked.extend(batch_blocked);
+			}
+
+			for team_keyword in team_keywords {
+				for &user_id in &members[&team_keyword.role_id] {
+					let opt_out = (
+						user_id,
+						team_keyword.role_id,
+						team_keyword.keyword.clone(),
+					);
+
+					if !skipped.contains(&user_id)
+						&& !team_opt_outs.contains(&opt_out)
+					{
+						shared.push((
+							user_id,
+							team_keyword.keyword.clone(),
+							muted.contains(&user_id),
+							blocked.contains(&user_id),
+						));
+					}
+				}
+			}
+		}
+
+		if !subscribers.is_empty() {
+			let packs = KeywordPack::guild_packs(guild_id)
+				.await?
+				.into_iter()
+				.map(|pack| (pack.name, pack.keywords))
+				.collect::<HashMap<_, _>>();
+
+			for subscriber in subscribers {
+				for keyword in packs.get(&subscriber.pack).into_iter().flatten()
+				{
+					shared.push((
+						UserId::from_db(subscriber.user_id),
+						keyword.clone(),
+						subscriber.muted,
+						subscriber.blocked,
+					));
+				}
+			}
+		}
+
+		let mut seen = keywords
+			.iter()
+			.map(|relevant| {
+				(relevant.keyword.user_id, relevant.keyword.keyword.clone())
+			})
+			.collect::<HashSet<_>>();
+
+		for (user_id, keyword, muted, blocked) in shared {
+			if user_id == author_id || !seen.insert((user_id, keyword.clone()))
+			{
+				continue;
+			}
+
+			keywords.push(RelevantKeyword {
+				keyword: Keyword {
+					keyword,
+					user_id,
+					kind: KeywordKind::Guild(guild_id),
+				},
+				muted,
+				blocked,
+			});
+		}
+
+		Ok(keywords)
 	}
 
 	/// Fetches all guild-wide keywords created by the specified user in the
@@ -348,15 +937,22 @@ impl Keyword {
 			self.kind = ?self.kind,
 	))]
 	pub(crate) async fn insert(self) -> Result<()> {
+		self.insert_in(connection()).await?;
+
+		Ok(())
+	}
+
+	/// Inserts this keyword into the DB using the given connection.
+	async fn insert_in(self, db: &impl ConnectionTrait) -> Result<(), DbErr> {
 		match self.into_model() {
 			EitherModel::Guild(model) => {
 				guild_keyword::Entity::insert(model.into_active_model())
-					.exec(connection())
+					.exec(db)
 					.await?;
 			}
 			EitherModel::Channel(model) => {
 				channel_keyword::Entity::insert(model.into_active_model())
-					.exec(connection())
+					.exec(db)
 					.await?;
 			}
 		}
@@ -364,37 +960,271 @@ impl Keyword {
 		Ok(())
 	}
 
-	/// Deletes this keyword from the DB.
+	/// Inserts this keyword into the DB along with the given options, in one
+	/// transaction.
+	///
+	/// The author filters of keywords with the same text in the guild are
+	/// replaced with those in `options`.
 	#[tracing::instrument(
 		skip(self),
 		fields(
 			self.user_id = %self.user_id,
 			self.kind = ?self.kind,
 	))]
-	pub(crate) async fn delete(self) -> Result<()> {
-		match self.into_model() {
-			EitherModel::Guild(model) => {
-				guild_keyword::Entity::delete(model.into_active_model())
-					.exec(connection())
-					.await?;
-			}
-			EitherModel::Channel(model) => {
-				channel_keyword::Entity::delete(model.into_active_model())
-					.exec(connection())
-					.await?;
-			}
-		}
+	pub(crate) async fn insert_with_options(
+		self,
+		guild_id: GuildId,
+		options: KeywordOptions,
+	) -> Result<()> {
+		connection()
+			.transaction(|transaction| {
+				async move {
+					self.clone().insert_in(transaction).await?;
+					options.replace_in(transaction, &self, guild_id).await
+				}
+				.boxed()
+			})
+			.await
+			.map_err(Into::into)
+	}
 
-		Ok(())
+	/// Replaces the keyword `old` with this one in the DB, along with the
+	/// options stored for it, in one transaction.
+	///
+	/// The channels `old` was limited to or excluded from are deleted, and
+	/// the options of this keyword are replaced with `options`. Unless
+	/// `old_shared` is set, meaning the user has another keyword with the same
+	/// text as `old` in the guild, the author filters of `old` are deleted and
+	/// its statistics are added to this keyword's.
+	#[tracing::instrument(
+		skip(self),
+		fields(
+			self.user_id = %self.user_id,
+			self.kind = ?self.kind,
+	))]
+	pub(crate) async fn replace(
+		self,
+		old: Keyword,
+		guild_id: GuildId,
+		options: KeywordOptions,
+		old_shared: bool,
+	) -> Result<()> {
+		connection()
+			.transaction(|transaction| {
+				async move {
+					let user_id = old.user_id.into_db();
+					let guild_id_db = guild_id.into_db();
+					let renamed = old.keyword != self.keyword;
+
+					if let KeywordKind::Guild(_) = old.kind {
+						keyword_channel::Entity::delete_many()
+							.filter(
+								Condition::all()
+									.add(
+										keyword_channel::Column::UserId
+											.eq(user_id),
+									)
+									.add(
+										keyword_channel::Column::GuildId
+											.eq(guild_id_db),
+									)
+									.add(
+										keyword_channel::Column::Keyword
+											.eq(old.keyword.clone()),
+									),
+							)
+							.exec(transaction)
+							.await?;
+					}
+
+					if renamed && !old_shared {
+						keyword_author::Entity::delete_many()
+							.filter(
+								Condition::all()
+									.add(
+										keyword_author::Column::UserId
+											.eq(user_id),
+									)
+									.add(
+										keyword_author::Column::GuildId
+											.eq(guild_id_db),
+									)
+									.add(
+										keyword_author::Column::Keyword
+											.eq(old.keyword.clone()),
+									),
+							)
+							.exec(transaction)
+							.await?;
+
+						let stats = keyword_stats::Entity::find_by_id((
+							user_id,
+							guild_id_db,
+							old.keyword.clone(),
+						))
+						.one(transaction)
+						.await?;
+
+						if let Some(stats) = stats {
+							keyword_stats::Entity::delete(
+								stats.clone().into_active_model(),
+							)
+							.exec(transaction)
+							.await?;
+
+							KeywordStats {
+								keyword: self.keyword.clone(),
+								..stats.into()
+							}
+							.add_in(transaction)
+							.await?;
+						}
+					}
+
+					match old.into_model() {
+						EitherModel::Guild(model) => {
+							guild_keyword::Entity::delete(
+								model.into_active_model(),
+							)
+							.exec(transaction)
+							.await?;
+						}
+						EitherModel::Channel(model) => {
+							channel_keyword::Entity::delete(
+								model.into_active_model(),
+							)
+							.exec(transaction)
+							.await?;
+						}
+					}
+
+					self.clone().insert_in(transaction).await?;
+					options.replace_in(transaction, &self, guild_id).await
+				}
+				.boxed()
+			})
+			.await
+			.map_err(Into::into)
+	}
+
+	/// Deletes this keyword from the DB in one transaction, along with the
+	/// channels it's limited to or excluded from if it's guild-wide.
+	///
+	/// If `authors_in` is given, the author filters of the keyword in that
+	/// guild are deleted too. Since filters are shared by the user's keywords
+	/// with the same text in a guild, this should only be given if the user
+	/// has no other keyword with the same text there.
+	#[tracing::instrument(
+		skip(self),
+		fields(
+			self.user_id = %self.user_id,
+			self.kind = ?self.kind,
+	))]
+	pub(crate) async fn delete(
+		self,
+		authors_in: Option<GuildId>,
+	) -> Result<()> {
+		connection()
+			.transaction(|transaction| {
+				async move {
+					let user_id = self.user_id.into_db();
+					let keyword = self.keyword.clone();
+
+					if let Some(guild_id) = authors_in {
+						keyword_author::Entity::delete_many()
+							.filter(
+								Condition::all()
+									.add(
+										keyword_author::Column::UserId
+											.eq(user_id),
+									)
+									.add(
+										keyword_author::Column::GuildId
+											.eq(guild_id.into_db()),
+									)
+									.add(
+										keyword_author::Column::Keyword
+											.eq(keyword.clone()),
+									),
+							)
+							.exec(transaction)
+							.await?;
+					}
+
+					match self.into_model() {
+						EitherModel::Guild(model) => {
+							keyword_channel::Entity::delete_many()
+								.filter(
+									Condition::all()
+										.add(
+											keyword_channel::Column::UserId
+												.eq(user_id),
+										)
+										.add(
+											keyword_channel::Column::GuildId
+												.eq(model.guild_id),
+										)
+										.add(
+											keyword_channel::Column::Keyword
+												.eq(keyword),
+										),
+								)
+								.exec(transaction)
+								.await?;
+
+							guild_keyword::Entity::delete(
+								model.into_active_model(),
+							)
+							.exec(transaction)
+							.await?;
+						}
+						EitherModel::Channel(model) => {
+							channel_keyword::Entity::delete(
+								model.into_active_model(),
+							)
+							.exec(transaction)
+							.await?;
+						}
+					}
+
+					Ok::<(), DbErr>(())
+				}
+				.boxed()
+			})
+			.await
+			.map_err(Into::into)
 	}
 
 	/// Deletes all guild-wide keywords created by the specified user in the
-	/// specified guild.
+	/// specified guild, along with the channels they're limited to or excluded
+	/// from and their author filters.
 	#[tracing::instrument]
 	pub(crate) async fn delete_in_guild(
 		user_id: UserId,
 		guild_id: GuildId,
 	) -> Result<u64> {
+		keyword_channel::Entity::delete_many()
+			.filter(
+				Condition::all()
+					.add(keyword_channel::Column::UserId.eq(user_id.into_db()))
+					.add(
+						keyword_channel::Column::GuildId.eq(guild_id.into_db()),
+					),
+			)
+			.exec(connection())
+			.await?;
+
+		keyword_author::Entity::delete_many()
+			.filter(
+				Condition::all()
+					.add(keyword_author::Column::UserId.eq(user_id.into_db()))
+					.add(
+						keyword_author::Column::GuildId.eq(guild_id.into_db()),
+					),
+			)
+			.exec(connection())
+			.await?;
+
 		let result = guild_keyword::Entity::delete_many()
 			.filter(
 				Condition::all()
@@ -430,10 +1260,97 @@ impl Keyword {
 	}
 }
 
+/// Selects the users who opted out.
+pub(super) fn opted_out_users() -> SelectStatement {
+	opt_out::Entity::find()
+		.select_only()
+		.column(opt_out::Column::UserId)
+		.into_query()
+}
+
+/// Selects the users who muted the given channel.
+pub(super) fn users_muting(channel_id: ChannelId) -> SelectStatement {
+	mute::Entity::find()
+		.select_only()
+		.column(mute::Column::UserId)
+		.filter(mute::Column::ChannelId.eq(channel_id.into_db()))
+		.into_query()
+}
+
+/// Selects the users who blocked the given author.
+pub(super) fn users_with_block(author_id: UserId) -> SelectStatement {
+	block::Entity::find()
+		.select_only()
+		.column(block::Column::UserId)
+		.filter(block::Column::BlockedId.eq(author_id.into_db()))
+		.into_query()
+}
+
+/// Selects the users the given author hid their messages from.
+pub(super) fn users_hidden_from(author_id: UserId) -> SelectStatement {
+	block::Entity::find()
+		.select_only()
+		.column(block::Column::BlockedId)
+		.filter(
+			Condition::all()
+				.add(block::Column::UserId.eq(author_id.into_db()))
+				.add(block::Column::HideMessages.eq(true)),
+		)
+		.into_query()
+}
+
+/// Selects the users whose highlighting was revoked in the given guild.
+pub(super) fn revoked_users(guild_id: GuildId) -> SelectStatement {
+	revoked_member::Entity::find()
+		.select_only()
+		.column(revoked_member::Column::UserId)
+		.filter(revoked_member::Column::GuildId.eq(guild_id.into_db()))
+		.into_query()
+}
+
+/// Lists the users with any of the given keywords, without duplicates.
+fn keyword_users<'a>(
+	keywords: impl IntoIterator<Item = &'a RelevantKeyword>,
+) -> Vec<UserId> {
+	keywords
+		.into_iter()
+		.map(|relevant| relevant.keyword.user_id)
+		.collect::<HashSet<_>>()
+		.into_iter()
+		.collect()
+}
+
 #[derive(Clone, Copy, Debug, EnumIter, DeriveColumn)]
 enum QueryAs {
 	KeywordCount,
 	MutedChannel,
+	Muted,
+	Blocked,
+}
+
+#[derive(FromQueryResult)]
+struct RelevantGuildKeyword {
+	keyword: String,
+	user_id: DbInt,
+	guild_id: DbInt,
+	muted: bool,
+	blocked: bool,
+}
+
+#[derive(FromQueryResult)]
+struct RelevantSubscriber {
+	user_id: DbInt,
+	pack: String,
+	muted: bool,
+	blocked: bool,
+}
+
+#[derive(FromQueryResult)]
+struct RelevantChannelKeyword {
+	keyword: String,
+	user_id: DbInt,
+	channel_id: DbInt,
+	blocked: bool,
 }
 
 impl From<guild_keyword::Model> for Keyword {
@@ -455,3 +1372,33 @@ impl From<channel_keyword::Model> for Keyword {
 		}
 	}
 }
+
+impl From<RelevantGuildKeyword> for RelevantKeyword {
+	fn from(result: RelevantGuildKeyword) -> Self {
+		Self {
+			keyword: Keyword {
+				keyword: result.keyword,
+				user_id: UserId::from_db(result.user_id),
+				kind: KeywordKind::Guild(GuildId::from_db(result.guild_id)),
+			},
+			muted: result.muted,
+			blocked: result.blocked,
+		}
+	}
+}
+
+impl From<RelevantChannelKeyword> for RelevantKeyword {
+	fn from(result: RelevantChannelKeyword) -> Self {
+		Self {
+			keyword: Keyword {
+				keyword: result.keyword,
+				user_id: UserId::from_db(result.user_id),
+				kind: KeywordKind::Channel(ChannelId::from_db(
+					result.channel_id,
+				)),
+			},
+			muted: false,
+			blocked: result.blocked,
+		}
+	}
+}
@@ -0,0 +1,190 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! Handling for restrictions of keywords to messages from particular users or
+//! roles.
+
+use anyhow::Result;
+use futures_util::TryStreamExt;
+use sea_orm::{
+	entity::prelude::{
+		DeriveActiveModelBehavior, DeriveEntityModel, DerivePrimaryKey,
+		DeriveRelation, EntityTrait, EnumIter, PrimaryKeyTrait,
+	},
+	ColumnTrait, Condition, IntoActiveModel, QueryFilter,
+};
+use serenity::model::id::{GuildId, RoleId, UserId};
+
+use super::{connection, DbInt, IdDbExt};
+
+#[derive(
+	Clone, Debug, PartialEq, Eq, DeriveEntityModel, DeriveActiveModelBehavior,
+)]
+#[sea_orm(table_name = "keyword_authors")]
+pub struct Model {
+	#[sea_orm(primary_key)]
+	pub(crate) user_id: DbInt,
+	#[sea_orm(primary_key)]
+	pub(crate) guild_id: DbInt,
+	#[sea_orm(primary_key)]
+	pub(crate) keyword: String,
+	#[sea_orm(primary_key)]
+	pub(crate) author_id: DbInt,
+	pub(crate) is_role: bool,
+}
+
+#[derive(Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+/// Who a keyword restricted to certain authors can be highlighted for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum AuthorFilter {
+	/// Messages from this user.
+	User(UserId),
+	/// Messages from members of this role.
+	Role(RoleId),
+}
+
+impl AuthorFilter {
+	/// Checks if a message by the given author, who has the given roles,
+	/// passes this filter.
+	pub(crate) fn matches(self, author_id: UserId, roles: &[RoleId]) -> bool {
+		match self {
+			Self::User(user_id) => user_id == author_id,
+			Self::Role(role_id) => roles.contains(&role_id),
+		}
+	}
+
+	/// Formats this filter as a mention.
+	pub(crate) fn mention(self) -> String {
+		match self {
+			Self::User(user_id) => format!("<@{user_id}>"),
+			Self::Role(role_id) => format!("<@&{role_id}>"),
+		}
+	}
+}
+
+/// An author a user's keyword in a guild is restricted to.
+///
+/// A keyword with any author filters is only highlighted in messages that
+/// pass at least one of them.
+#[derive(Debug, Clone)]
+pub(crate) struct KeywordAuthor {
+	/// The user who added the keyword.
+	pub(crate) user_id: UserId,
+	/// The guild the restriction applies in, to both server-wide and
+	/// channel-specific keywords.
+	pub(crate) guild_id: GuildId,
+	/// The restricted keyword.
+	pub(crate) keyword: String,
+	/// The author the keyword is restricted to.
+	pub(crate) author: AuthorFilter,
+}
+
+impl KeywordAuthor {
+	/// Fetches the author filters the given user added from the DB.
+	#[tracing::instrument]
+	pub(crate) async fn user_filters(user_id: UserId) -> Result<Vec<Self>> {
+		Entity::find()
+			.filter(Column::UserId.eq(user_id.into_db()))
+			.stream(connection())
+			.await?
+			.map_err(Into::into)
+			.map_ok(Self::from)
+			.try_collect()
+			.await
+	}
+
+	/// Fetches the author filters the given users added in the given guild
+	/// from the DB.
+	#[tracing::instrument(skip(user_ids))]
+	pub(crate) async fn guild_user_filters(
+		guild_id: GuildId,
+		user_ids: &[UserId],
+	) -> Result<Vec<Self>> {
+		Entity::find()
+			.filter(
+				Condition::all()
+					.add(Column::GuildId.eq(guild_id.into_db()))
+					.add(Column::UserId.is_in(
+						user_ids.iter().map(|user_id| user_id.into_db()),
+					)),
+			)
+			.stream(connection())
+			.await?
+			.map_err(Into::into)
+			.map_ok(Self::from)
+			.try_collect()
+			.await
+	}
+
+	/// Checks if this author filter exists in the DB.
+	#[tracing::instrument]
+	pub(crate) async fn exists(self) -> Result<bool> {
+		let model = Model::from(self);
+
+		Ok(Entity::find_by_id((
+			model.user_id,
+			model.guild_id,
+			model.keyword,
+			model.author_id,
+		))
+		.one(connection())
+		.await?
+		.is_some())
+	}
+
+	/// Inserts this author filter into the DB.
+	#[tracing::instrument]
+	pub(crate) async fn insert(self) -> Result<()> {
+		Entity::insert(Model::from(self).into_active_model())
+			.exec(connection())
+			.await?;
+
+		Ok(())
+	}
+
+	/// Deletes this author filter from the DB.
+	#[tracing::instrument]
+	pub(crate) async fn delete(self) -> Result<()> {
+		Entity::delete(Model::from(self).into_active_model())
+			.exec(connection())
+			.await?;
+
+		Ok(())
+	}
+}
+
+impl From<Model> for KeywordAuthor {
+	fn from(model: Model) -> Self {
+		let author = if model.is_role {
+			AuthorFilter::Role(RoleId::from_db(model.author_id))
+		} else {
+			AuthorFilter::User(UserId::from_db(model.author_id))
+		};
+
+		Self {
+			user_id: UserId::from_db(model.user_id),
+			guild_id: GuildId::from_db(model.guild_id),
+			keyword: model.keyword,
+			author,
+		}
+	}
+}
+
+impl From<KeywordAuthor> for Model {
+	fn from(filter: KeywordAuthor) -> Self {
+		let (author_id, is_role) = match filter.author {
+			AuthorFilter::User(user_id) => (user_id.into_db(), false),
+			AuthorFilter::Role(role_id) => (role_id.into_db(), true),
+		};
+
+		Self {
+			user_id: filter.user_id.into_db(),
+			guild_id: filter.guild_id.into_db(),
+			keyword: filter.keyword,
+			author_id,
+			is_role,
+		}
+	}
+}
@@ -0,0 +1,224 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! Handling for the channels server-wide keywords are limited to or excluded
+//! from.
+
+use anyhow::Result;
+use futures_util::TryStreamExt;
+use sea_orm::{
+	entity::prelude::{
+		DeriveActiveModelBehavior, DeriveEntityModel, DerivePrimaryKey,
+		DeriveRelation, EntityTrait, EnumIter, PrimaryKeyTrait,
+	},
+	ColumnTrait, Condition, IntoActiveModel, QueryFilter,
+};
+use serenity::model::id::{ChannelId, GuildId, UserId};
+
+use super::{connection, DbInt, IdDbExt};
+
+#[derive(
+	Clone, Debug, PartialEq, Eq, DeriveEntityModel, DeriveActiveModelBehavior,
+)]
+#[sea_orm(table_name = "keyword_channels")]
+pub struct Model {
+	#[sea_orm(primary_key)]
+	pub(crate) user_id: DbInt,
+	#[sea_orm(primary_key)]
+	pub(crate) guild_id: DbInt,
+	#[sea_orm(primary_key)]
+	pub(crate) keyword: String,
+	#[sea_orm(primary_key)]
+	pub(crate) channel_id: DbInt,
+	pub(crate) excluded: bool,
+}
+
+#[derive(Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+/// A channel a user's server-wide keyword is limited to or excluded from.
+///
+/// A keyword limited to any channels is a multi-channel keyword, only
+/// highlighted in those channels. A keyword excluded from a channel is never
+/// highlighted there.
+#[derive(Debug, Clone)]
+pub(crate) struct KeywordChannel {
+	/// The user who added the keyword.
+	pub(crate) user_id: UserId,
+	/// The guild the keyword was added in.
+	pub(crate) guild_id: GuildId,
+	/// The keyword.
+	pub(crate) keyword: String,
+	/// The channel the keyword is limited to or excluded from.
+	pub(crate) channel_id: ChannelId,
+	/// Whether the keyword is excluded from the channel, rather than limited
+	/// to it.
+	pub(crate) excluded: bool,
+}
+
+impl KeywordChannel {
+	/// Checks if a keyword with the given channels can be highlighted in the
+	/// given channel, whose parent is `parent_id` if it's a thread.
+	///
+	/// Threads are treated like their parent, so a keyword excluded from a
+	/// channel isn't highlighted in its threads either.
+	pub(crate) fn allows(
+		channels: &[Self],
+		channel_id: ChannelId,
+		parent_id: Option<ChannelId>,
+	) -> bool {
+		let here = |id| id == channel_id || Some(id) == parent_id;
+
+		let mut limited = false;
+		let mut limited_here = false;
+
+		for channel in channels {
+			if channel.excluded {
+				if here(channel.channel_id) {
+					return false;
+				}
+			} else {
+				limited = true;
+				limited_here |= here(channel.channel_id);
+			}
+		}
+
+		!limited || limited_here
+	}
+
+	/// Fetches the keyword channels the given user added from the DB.
+	#[tracing::instrument]
+	pub(crate) async fn user_channels(user_id: UserId) -> Result<Vec<Self>> {
+		Entity::find()
+			.filter(Column::UserId.eq(user_id.into_db()))
+			.stream(connection())
+			.await?
+			.map_err(Into::into)
+			.map_ok(Self::from)
+			.try_collect()
+			.await
+	}
+
+	/// Fetches the keyword channels the given users added in the given guild
+	/// from the DB.
+	#[tracing::instrument(skip(user_ids))]
+	pub(crate) async fn guild_user_channels(
+		guild_id: GuildId,
+		user_ids: &[UserId],
+	) -> Result<Vec<Self>> {
+		Entity::find()
+			.filter(
+				Condition::all()
+					.add(Column::GuildId.eq(guild_id.into_db()))
+					.add(Column::UserId.is_in(
+						user_ids.iter().map(|user_id| user_id.into_db()),
+					)),
+			)
+			.stream(connection())
+			.await?
+			.map_err(Into::into)
+			.map_ok(Self::from)
+			.try_collect()
+			.await
+	}
+
+	/// Checks if this keyword channel exists in the DB, whether it's limited
+	/// or excluded.
+	#[tracing::instrument]
+	pub(crate) async fn exists(self) -> Result<bool> {
+		let model = Model::from(self);
+
+		Ok(Entity::find_by_id((
+			model.user_id,
+			model.guild_id,
+			model.keyword,
+			model.channel_id,
+		))
+		.one(connection())
+		.await?
+		.is_some())
+	}
+
+	/// Inserts this keyword channel into the DB.
+	#[tracing::instrument]
+	pub(crate) async fn insert(self) -> Result<()> {
+		Entity::insert(Model::from(self).into_active_model())
+			.exec(connection())
+			.await?;
+
+		Ok(())
+	}
+
+	/// Deletes this keyword channel from the DB.
+	#[tracing::instrument]
+	pub(crate) async fn delete(self) -> Result<()> {
+		Entity::delete(Model::from(self).into_active_model())
+			.exec(connection())
+			.await?;
+
+		Ok(())
+	}
+}
+
+impl From<Model> for KeywordChannel {
+	fn from(model: Model) -> Self {
+		Self {
+			user_id: UserId::from_db(model.user_id),
+			guild_id: GuildId::from_db(model.guild_id),
+			keyword: model.keyword,
+			channel_id: ChannelId::from_db(model.channel_id),
+			excluded: model.excluded,
+		}
+	}
+}
+
+impl From<KeywordChannel> for Model {
+	fn from(channel: KeywordChannel) -> Self {
+		Self {
+			user_id: channel.user_id.into_db(),
+			guild_id: channel.guild_id.into_db(),
+			keyword: channel.keyword,
+			channel_id: channel.channel_id.into_db(),
+			excluded: channel.excluded,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn channel(channel_id: u64, excluded: bool) -> KeywordChannel {
+		KeywordChannel {
+			user_id: UserId(1),
+			guild_id: GuildId(1),
+			keyword: "rust".to_owned(),
+			channel_id: ChannelId(channel_id),
+			excluded,
+		}
+	}
+
+	#[test]
+	fn threads_follow_parent() {
+		let excluded = [channel(10, true)];
+		assert!(!KeywordChannel::allows(&excluded, ChannelId(10), None));
+		assert!(!KeywordChannel::allows(
+			&excluded,
+			ChannelId(20),
+			Some(ChannelId(10))
+		));
+		assert!(KeywordChannel::allows(
+			&excluded,
+			ChannelId(20),
+			Some(ChannelId(30))
+		));
+
+		let limited = [channel(10, false)];
+		assert!(KeywordChannel::allows(
+			&limited,
+			ChannelId(20),
+			Some(ChannelId(10))
+		));
+		assert!(!KeywordChannel::allows(&limited, ChannelId(20), None));
+	}
+}
@@ -0,0 +1,181 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! Handling for keyword packs, named lists of keywords server admins publish
+//! for members to subscribe to.
+
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use futures_util::TryStreamExt;
+use sea_orm::{
+	entity::prelude::{
+		DeriveActiveModelBehavior, DeriveEntityModel, DerivePrimaryKey,
+		DeriveRelation, EntityTrait, EnumIter, PrimaryKeyTrait,
+	},
+	ColumnTrait, Condition, IntoActiveModel, QueryFilter,
+};
+use serenity::model::id::GuildId;
+
+use super::{connection, pack_subscription, DbInt, IdDbExt};
+
+#[derive(
+	Clone, Debug, PartialEq, Eq, DeriveEntityModel, DeriveActiveModelBehavior,
+)]
+#[sea_orm(table_name = "keyword_packs")]
+pub struct Model {
+	#[sea_orm(primary_key)]
+	pub(crate) guild_id: DbInt,
+	#[sea_orm(primary_key)]
+	pub(crate) name: String,
+	#[sea_orm(primary_key)]
+	pub(crate) keyword: String,
+}
+
+#[derive(Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+/// A named list of keywords published by a guild's administrators.
+///
+/// A pack exists as long as it has at least one keyword.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct KeywordPack {
+	/// The guild the pack was published in.
+	pub(crate) guild_id: GuildId,
+	/// The name of the pack.
+	pub(crate) name: String,
+	/// The keywords in the pack, in alphabetical order.
+	pub(crate) keywords: Vec<String>,
+}
+
+impl KeywordPack {
+	/// Fetches the packs of the guild with the given ID from the DB, in
+	/// alphabetical order.
+	#[tracing::instrument]
+	pub(crate) async fn guild_packs(guild_id: GuildId) -> Result<Vec<Self>> {
+		let mut packs = BTreeMap::<String, Vec<String>>::new();
+
+		let mut rows = Entity::find()
+			.filter(Column::GuildId.eq(guild_id.into_db()))
+			.stream(connection())
+			.await?;
+
+		while let Some(model) = rows.try_next().await? {
+			packs.entry(model.name).or_default().push(model.keyword);
+		}
+
+		Ok(packs
+			.into_iter()
+			.map(|(name, mut keywords)| {
+				keywords.sort();
+				Self {
+					guild_id,
+					name,
+					keywords,
+				}
+			})
+			.collect())
+	}
+
+	/// Fetches the pack with the given name in the given guild from the DB,
+	/// if it exists.
+	#[tracing::instrument]
+	pub(crate) async fn pack(
+		guild_id: GuildId,
+		name: &str,
+	) -> Result<Option<Self>> {
+		let mut keywords = Entity::find()
+			.filter(
+				Condition::all()
+					.add(Column::GuildId.eq(guild_id.into_db()))
+					.add(Column::Name.eq(name)),
+			)
+			.stream(connection())
+			.await?
+			.map_ok(|model| model.keyword)
+			.try_collect::<Vec<_>>()
+			.await?;
+
+		if keywords.is_empty() {
+			return Ok(None);
+		}
+
+		keywords.sort();
+
+		Ok(Some(Self {
+			guild_id,
+			name: name.to_owned(),
+			keywords,
+		}))
+	}
+
+	/// Adds a keyword to the pack with the given name in the DB, creating the
+	/// pack if it doesn't exist.
+	#[tracing::instrument]
+	pub(crate) async fn add_keyword(
+		guild_id: GuildId,
+		name: &str,
+		keyword: &str,
+	) -> Result<()> {
+		let model = Model {
+			guild_id: guild_id.into_db(),
+			name: name.to_owned(),
+			keyword: keyword.to_owned(),
+		};
+
+		Entity::insert(model.into_active_model())
+			.exec(connection())
+			.await?;
+
+		Ok(())
+	}
+
+	/// Removes a keyword from the pack with the given name in the DB.
+	///
+	/// Returns whether the keyword was in the pack.
+	#[tracing::instrument]
+	pub(crate) async fn remove_keyword(
+		guild_id: GuildId,
+		name: &str,
+		keyword: &str,
+	) -> Result<bool> {
+		let model = Model {
+			guild_id: guild_id.into_db(),
+			name: name.to_owned(),
+			keyword: keyword.to_owned(),
+		};
+
+		let result = Entity::delete(model.into_active_model())
+			.exec(connection())
+			.await?;
+
+		Ok(result.rows_affected > 0)
+	}
+
+	/// Deletes this pack from the DB, along with its subscriptions.
+	#[tracing::instrument]
+	pub(crate) async fn delete(self) -> Result<()> {
+		pack_subscription::Entity::delete_many()
+			.filter(
+				Condition::all()
+					.add(
+						pack_subscription::Column::GuildId
+							.eq(self.guild_id.into_db()),
+					)
+					.add(pack_subscription::Column::Pack.eq(&*self.name)),
+			)
+			.exec(connection())
+			.await?;
+
+		Entity::delete_many()
+			.filter(
+				Condition::all()
+					.add(Column::GuildId.eq(self.guild_id.into_db()))
+					.add(Column::Name.eq(&*self.name)),
+			)
+			.exec(connection())
+			.await?;
+
+		Ok(())
+	}
+}
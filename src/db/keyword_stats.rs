@@ -0,0 +1,227 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! Handling for counters of how often keywords match and notify.
+
+use std::ops::AddAssign;
+
+use anyhow::Result;
+use futures_util::{FutureExt, TryStreamExt};
+use sea_orm::{
+	entity::prelude::{
+		DeriveActiveModelBehavior, DeriveEntityModel, DerivePrimaryKey,
+		DeriveRelation, EntityTrait, EnumIter, PrimaryKeyTrait,
+	},
+	sea_query::{Expr, OnConflict},
+	ColumnTrait, Condition, ConnectionTrait, DbErr, IntoActiveModel,
+	QueryFilter, TransactionTrait,
+};
+use serenity::model::id::{GuildId, UserId};
+
+use super::{connection, DbInt, IdDbExt};
+
+#[derive(
+	Clone, Debug, PartialEq, Eq, DeriveEntityModel, DeriveActiveModelBehavior,
+)]
+#[sea_orm(table_name = "keyword_stats")]
+pub struct Model {
+	#[sea_orm(primary_key)]
+	pub(crate) user_id: DbInt,
+	#[sea_orm(primary_key)]
+	pub(crate) guild_id: DbInt,
+	#[sea_orm(primary_key)]
+	pub(crate) keyword: String,
+	pub(crate) matches: i64,
+	pub(crate) notified: i64,
+	pub(crate) patience: i64,
+	pub(crate) ignored: i64,
+	pub(crate) muted: i64,
+	pub(crate) blocked: i64,
+}
+
+#[derive(Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+/// Counts of what happened to messages a keyword matched.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct KeywordCounts {
+	/// Number of messages the keyword appeared in.
+	pub(crate) matches: u64,
+	/// Number of notifications sent for the keyword.
+	pub(crate) notified: u64,
+	/// Number of matches not notified because the user interacted with the
+	/// channel within the patience duration.
+	pub(crate) patience: u64,
+	/// Number of matches not notified because of an ignored phrase.
+	pub(crate) ignored: u64,
+	/// Number of matches not notified because the channel was muted.
+	pub(crate) muted: u64,
+	/// Number of matches not notified because the author was blocked.
+	pub(crate) blocked: u64,
+}
+
+impl AddAssign for KeywordCounts {
+	fn add_assign(&mut self, other: Self) {
+		self.matches += other.matches;
+		self.notified += other.notified;
+		self.patience += other.patience;
+		self.ignored += other.ignored;
+		self.muted += other.muted;
+		self.blocked += other.blocked;
+	}
+}
+
+/// Statistics about one of a user's keywords in a guild.
+///
+/// Guild-wide and channel-specific keywords with the same text in the same
+/// guild share statistics.
+#[derive(Debug, Clone)]
+pub(crate) struct KeywordStats {
+	/// The user the keyword belongs to.
+	pub(crate) user_id: UserId,
+	/// The guild the keyword matched in.
+	pub(crate) guild_id: GuildId,
+	/// The keyword.
+	pub(crate) keyword: String,
+	/// How often the keyword matched and notified.
+	pub(crate) counts: KeywordCounts,
+}
+
+impl KeywordStats {
+	/// Fetches the statistics of the given user's keywords, optionally only in
+	/// the given guild.
+	#[tracing::instrument]
+	pub(crate) async fn user_stats(
+		user_id: UserId,
+		guild_id: Option<GuildId>,
+	) -> Result<Vec<Self>> {
+		let mut condition =
+			Condition::all().add(Column::UserId.eq(user_id.into_db()));
+
+		if let Some(guild_id) = guild_id {
+			condition = condition.add(Column::GuildId.eq(guild_id.into_db()));
+		}
+
+		Entity::find()
+			.filter(condition)
+			.stream(connection())
+			.await?
+			.map_err(Into::into)
+			.map_ok(Self::from)
+			.try_collect()
+			.await
+	}
+
+	/// Adds the counts of each of the given statistics to those stored in the
+	/// DB.
+	#[tracing::instrument(skip_all, fields(stats = stats.len()))]
+	pub(crate) async fn add_all(stats: Vec<Self>) -> Result<()> {
+		connection()
+			.transaction(|transaction| {
+				async move {
+					for stats in stats {
+						stats.add_in(transaction).await?;
+					}
+
+					Ok::<(), DbErr>(())
+				}
+				.boxed()
+			})
+			.await
+			.map_err(Into::into)
+	}
+
+	/// Adds these counts to those stored in the DB, using the given
+	/// connection.
+	pub(super) async fn add_in(
+		self,
+		db: &impl ConnectionTrait,
+	) -> Result<(), DbErr> {
+		let increment = |column: Column, count: u64| {
+			let count = i64::try_from(count).unwrap_or(i64::MAX);
+			(column, Expr::col((Entity, column)).add(count))
+		};
+
+		let counts = self.counts;
+
+		Entity::insert(Model::from(self).into_active_model())
+			.on_conflict(
+				OnConflict::columns([
+					Column::UserId,
+					Column::GuildId,
+					Column::Keyword,
+				])
+				.values([
+					increment(Column::Matches, counts.matches),
+					increment(Column::Notified, counts.notified),
+					increment(Column::Patience, counts.patience),
+					increment(Column::Ignored, counts.ignored),
+					increment(Column::Muted, counts.muted),
+					increment(Column::Blocked, counts.blocked),
+				])
+				.to_owned(),
+			)
+			.exec(db)
+			.await?;
+
+		Ok(())
+	}
+
+	/// Deletes the statistics of the given user's keyword in the given guild.
+	#[tracing::instrument]
+	pub(crate) async fn delete(
+		user_id: UserId,
+		guild_id: GuildId,
+		keyword: &str,
+	) -> Result<()> {
+		Entity::delete_many()
+			.filter(
+				Condition::all()
+					.add(Column::UserId.eq(user_id.into_db()))
+					.add(Column::GuildId.eq(guild_id.into_db()))
+					.add(Column::Keyword.eq(keyword)),
+			)
+			.exec(connection())
+			.await?;
+
+		Ok(())
+	}
+}
+
+impl From<Model> for KeywordStats {
+	fn from(model: Model) -> Self {
+		let count = |n: i64| u64::try_from(n).unwrap_or(0);
+
+		Self {
+			user_id: UserId::from_db(model.user_id),
+			guild_id: GuildId::from_db(model.guild_id),
+			keyword: model.keyword,
+			counts: KeywordCounts {
+				matches: count(model.matches),
+				notified: count(model.notified),
+				patience: count(model.patience),
+				ignored: count(model.ignored),
+				muted: count(model.muted),
+				blocked: count(model.blocked),
+			},
+		}
+	}
+}
+
+impl From<KeywordStats> for Model {
+	fn from(stats: KeywordStats) -> Self {
+		let count = |n: u64| i64::try_from(n).unwrap_or(i64::MAX);
+
+		Self {
+			user_id: stats.user_id.into_db(),
+			guild_id: stats.guild_id.into_db(),
+			keyword: stats.keyword,
+			matches: count(stats.counts.matches),
+			notified: count(stats.counts.notified),
+			patience: count(stats.counts.patience),
+			ignored: count(stats.counts.ignored),
+			muted: count(stats.counts.muted),
+			blocked: count(stats.counts.blocked),
+		}
+	}
+}
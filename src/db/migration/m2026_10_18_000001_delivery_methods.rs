@@ -0,0 +1,41 @@
+use sea_orm_migration::prelude::{
+	async_trait, ColumnDef, DbErr, DeriveMigrationName, MigrationTrait,
+	SchemaManager, Table,
+};
+
+use crate::db::delivery::{self, Column};
+
+#[derive(DeriveMigrationName)]
+pub(crate) struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.create_table(
+				Table::create()
+					.table(delivery::Entity)
+					.if_not_exists()
+					.col(
+						ColumnDef::new(Column::UserId)
+							.big_integer()
+							.not_null()
+							.primary_key(),
+					)
+					.col(
+						ColumnDef::new(Column::Method)
+							.small_integer()
+							.not_null(),
+					)
+					.col(ColumnDef::new(Column::Target).string())
+					.to_owned(),
+			)
+			.await
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.drop_table(Table::drop().table(delivery::Entity).to_owned())
+			.await
+	}
+}
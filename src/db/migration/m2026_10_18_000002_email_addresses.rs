@@ -0,0 +1,45 @@
+use sea_orm_migration::prelude::{
+	async_trait, ColumnDef, DbErr, DeriveMigrationName, MigrationTrait,
+	SchemaManager, Table,
+};
+
+use crate::db::email::{self, Column};
+
+#[derive(DeriveMigrationName)]
+pub(crate) struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.create_table(
+				Table::create()
+					.table(email::Entity)
+					.if_not_exists()
+					.col(
+						ColumnDef::new(Column::UserId)
+							.big_integer()
+							.not_null()
+							.primary_key(),
+					)
+					.col(ColumnDef::new(Column::Address).string().not_null())
+					.col(ColumnDef::new(Column::Verified).boolean().not_null())
+					.col(ColumnDef::new(Column::Code).string())
+					.col(ColumnDef::new(Column::CodeExpires).big_integer())
+					.col(
+						ColumnDef::new(Column::Attempts)
+							.integer()
+							.not_null()
+							.default(0),
+					)
+					.to_owned(),
+			)
+			.await
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.drop_table(Table::drop().table(email::Entity).to_owned())
+			.await
+	}
+}
@@ -0,0 +1,97 @@
+use sea_orm::sea_query::Index;
+use sea_orm_migration::prelude::{
+	async_trait, ColumnDef, DbErr, DeriveMigrationName, MigrationTrait,
+	SchemaManager, Table,
+};
+
+use crate::db::{fallback_channel, fallback_thread, notification};
+
+#[derive(DeriveMigrationName)]
+pub(crate) struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.create_table(
+				Table::create()
+					.table(fallback_channel::Entity)
+					.if_not_exists()
+					.col(
+						ColumnDef::new(fallback_channel::Column::GuildId)
+							.big_integer()
+							.not_null()
+							.primary_key(),
+					)
+					.col(
+						ColumnDef::new(fallback_channel::Column::ChannelId)
+							.big_integer()
+							.not_null(),
+					)
+					.to_owned(),
+			)
+			.await?;
+
+		manager
+			.create_table(
+				Table::create()
+					.table(fallback_thread::Entity)
+					.if_not_exists()
+					.col(
+						ColumnDef::new(fallback_thread::Column::UserId)
+							.big_integer()
+							.not_null(),
+					)
+					.col(
+						ColumnDef::new(fallback_thread::Column::GuildId)
+							.big_integer()
+							.not_null(),
+					)
+					.col(
+						ColumnDef::new(fallback_thread::Column::ThreadId)
+							.big_integer()
+							.not_null(),
+					)
+					.primary_key(
+						Index::create()
+							.col(fallback_thread::Column::UserId)
+							.col(fallback_thread::Column::GuildId),
+					)
+					.to_owned(),
+			)
+			.await?;
+
+		manager
+			.alter_table(
+				Table::alter()
+					.table(notification::Entity)
+					.add_column(
+						ColumnDef::new(notification::Column::FallbackThread)
+							.big_integer(),
+					)
+					.to_owned(),
+			)
+			.await
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.alter_table(
+				Table::alter()
+					.table(notification::Entity)
+					.drop_column(notification::Column::FallbackThread)
+					.to_owned(),
+			)
+			.await?;
+
+		manager
+			.drop_table(Table::drop().table(fallback_thread::Entity).to_owned())
+			.await?;
+
+		manager
+			.drop_table(
+				Table::drop().table(fallback_channel::Entity).to_owned(),
+			)
+			.await
+	}
+}
@@ -0,0 +1,59 @@
+use sea_orm::sea_query::Index;
+use sea_orm_migration::prelude::{
+	async_trait, ColumnDef, DbErr, DeriveMigrationName, MigrationTrait,
+	SchemaManager, Table,
+};
+
+use crate::db::keyword_stats::{self, Column};
+
+#[derive(DeriveMigrationName)]
+pub(crate) struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		let counter = |column: Column| {
+			ColumnDef::new(column)
+				.big_integer()
+				.not_null()
+				.default(0)
+				.to_owned()
+		};
+
+		manager
+			.create_table(
+				Table::create()
+					.table(keyword_stats::Entity)
+					.if_not_exists()
+					.col(
+						ColumnDef::new(Column::UserId).big_integer().not_null(),
+					)
+					.col(
+						ColumnDef::new(Column::GuildId)
+							.big_integer()
+							.not_null(),
+					)
+					.col(ColumnDef::new(Column::Keyword).string().not_null())
+					.col(&mut counter(Column::Matches))
+					.col(&mut counter(Column::Notified))
+					.col(&mut counter(Column::Patience))
+					.col(&mut counter(Column::Ignored))
+					.col(&mut counter(Column::Muted))
+					.col(&mut counter(Column::Blocked))
+					.primary_key(
+						Index::create()
+							.col(Column::UserId)
+							.col(Column::GuildId)
+							.col(Column::Keyword),
+					)
+					.to_owned(),
+			)
+			.await
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.drop_table(Table::drop().table(keyword_stats::Entity).to_owned())
+			.await
+	}
+}
@@ -0,0 +1,116 @@
+use sea_orm::sea_query::Index;
+use sea_orm_migration::prelude::{
+	async_trait, ColumnDef, DbErr, DeriveMigrationName, MigrationTrait,
+	SchemaManager, Table,
+};
+
+use crate::db::{excluded_channel, guild_settings, settings_change};
+
+#[derive(DeriveMigrationName)]
+pub(crate) struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		use guild_settings::Column;
+
+		manager
+			.create_table(
+				Table::create()
+					.table(guild_settings::Entity)
+					.if_not_exists()
+					.col(
+						ColumnDef::new(Column::GuildId)
+							.big_integer()
+							.not_null()
+							.primary_key(),
+					)
+					.col(
+						ColumnDef::new(Column::Disabled)
+							.boolean()
+							.not_null()
+							.default(false),
+					)
+					.col(ColumnDef::new(Column::MaxKeywords).integer())
+					.col(ColumnDef::new(Column::MinKeywordLength).integer())
+					.to_owned(),
+			)
+			.await?;
+
+		manager
+			.create_table(
+				Table::create()
+					.table(excluded_channel::Entity)
+					.if_not_exists()
+					.col(
+						ColumnDef::new(excluded_channel::Column::GuildId)
+							.big_integer()
+							.not_null(),
+					)
+					.col(
+						ColumnDef::new(excluded_channel::Column::ChannelId)
+							.big_integer()
+							.not_null(),
+					)
+					.primary_key(
+						Index::create()
+							.col(excluded_channel::Column::GuildId)
+							.col(excluded_channel::Column::ChannelId),
+					)
+					.to_owned(),
+			)
+			.await?;
+
+		manager
+			.create_table(
+				Table::create()
+					.table(settings_change::Entity)
+					.if_not_exists()
+					.col(
+						ColumnDef::new(settings_change::Column::Id)
+							.integer()
+							.not_null()
+							.auto_increment()
+							.primary_key(),
+					)
+					.col(
+						ColumnDef::new(settings_change::Column::GuildId)
+							.big_integer()
+							.not_null(),
+					)
+					.col(
+						ColumnDef::new(settings_change::Column::UserId)
+							.big_integer()
+							.not_null(),
+					)
+					.col(
+						ColumnDef::new(settings_change::Column::Description)
+							.text()
+							.not_null(),
+					)
+					.col(
+						ColumnDef::new(settings_change::Column::Time)
+							.big_integer()
+							.not_null(),
+					)
+					.to_owned(),
+			)
+			.await
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.drop_table(Table::drop().table(settings_change::Entity).to_owned())
+			.await?;
+
+		manager
+			.drop_table(
+				Table::drop().table(excluded_channel::Entity).to_owned(),
+			)
+			.await?;
+
+		manager
+			.drop_table(Table::drop().table(guild_settings::Entity).to_owned())
+			.await
+	}
+}
@@ -0,0 +1,89 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+use sea_orm::sea_query::Index;
+use sea_orm_migration::prelude::{
+	async_trait, ColumnDef, DbErr, DeriveMigrationName, MigrationTrait,
+	SchemaManager, Table,
+};
+
+use crate::db::{team_keyword, team_keyword_opt_out};
+
+#[derive(DeriveMigrationName)]
+pub(crate) struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.create_table(
+				Table::create()
+					.table(team_keyword::Entity)
+					.if_not_exists()
+					.col(
+						ColumnDef::new(team_keyword::Column::RoleId)
+							.big_integer()
+							.not_null(),
+					)
+					.col(
+						ColumnDef::new(team_keyword::Column::Keyword)
+							.string()
+							.not_null(),
+					)
+					.col(
+						ColumnDef::new(team_keyword::Column::GuildId)
+							.big_integer()
+							.not_null(),
+					)
+					.primary_key(
+						Index::create()
+							.col(team_keyword::Column::RoleId)
+							.col(team_keyword::Column::Keyword),
+					)
+					.to_owned(),
+			)
+			.await?;
+
+		manager
+			.create_table(
+				Table::create()
+					.table(team_keyword_opt_out::Entity)
+					.if_not_exists()
+					.col(
+						ColumnDef::new(team_keyword_opt_out::Column::UserId)
+							.big_integer()
+							.not_null(),
+					)
+					.col(
+						ColumnDef::new(team_keyword_opt_out::Column::RoleId)
+							.big_integer()
+							.not_null(),
+					)
+					.col(
+						ColumnDef::new(team_keyword_opt_out::Column::Keyword)
+							.string()
+							.not_null(),
+					)
+					.primary_key(
+						Index::create()
+							.col(team_keyword_opt_out::Column::UserId)
+							.col(team_keyword_opt_out::Column::RoleId)
+							.col(team_keyword_opt_out::Column::Keyword),
+					)
+					.to_owned(),
+			)
+			.await
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.drop_table(
+				Table::drop().table(team_keyword_opt_out::Entity).to_owned(),
+			)
+			.await?;
+
+		manager
+			.drop_table(Table::drop().table(team_keyword::Entity).to_owned())
+			.await
+	}
+}
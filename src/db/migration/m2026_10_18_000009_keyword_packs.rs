@@ -0,0 +1,90 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+use sea_orm::sea_query::Index;
+use sea_orm_migration::prelude::{
+	async_trait, ColumnDef, DbErr, DeriveMigrationName, MigrationTrait,
+	SchemaManager, Table,
+};
+
+use crate::db::{keyword_pack, pack_subscription};
+
+#[derive(DeriveMigrationName)]
+pub(crate) struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.create_table(
+				Table::create()
+					.table(keyword_pack::Entity)
+					.if_not_exists()
+					.col(
+						ColumnDef::new(keyword_pack::Column::GuildId)
+							.big_integer()
+							.not_null(),
+					)
+					.col(
+						ColumnDef::new(keyword_pack::Column::Name)
+							.string()
+							.not_null(),
+					)
+					.col(
+						ColumnDef::new(keyword_pack::Column::Keyword)
+							.string()
+							.not_null(),
+					)
+					.primary_key(
+						Index::create()
+							.col(keyword_pack::Column::GuildId)
+							.col(keyword_pack::Column::Name)
+							.col(keyword_pack::Column::Keyword),
+					)
+					.to_owned(),
+			)
+			.await?;
+
+		manager
+			.create_table(
+				Table::create()
+					.table(pack_subscription::Entity)
+					.if_not_exists()
+					.col(
+						ColumnDef::new(pack_subscription::Column::UserId)
+							.big_integer()
+							.not_null(),
+					)
+					.col(
+						ColumnDef::new(pack_subscription::Column::GuildId)
+							.big_integer()
+							.not_null(),
+					)
+					.col(
+						ColumnDef::new(pack_subscription::Column::Pack)
+							.string()
+							.not_null(),
+					)
+					.primary_key(
+						Index::create()
+							.col(pack_subscription::Column::UserId)
+							.col(pack_subscription::Column::GuildId)
+							.col(pack_subscription::Column::Pack),
+					)
+					.to_owned(),
+			)
+			.await
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.drop_table(
+				Table::drop().table(pack_subscription::Entity).to_owned(),
+			)
+			.await?;
+
+		manager
+			.drop_table(Table::drop().table(keyword_pack::Entity).to_owned())
+			.await
+	}
+}
@@ -0,0 +1,42 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+use sea_orm::sea_query::Index;
+use sea_orm_migration::prelude::{
+	async_trait, ColumnDef, DbErr, DeriveMigrationName, MigrationTrait,
+	SchemaManager, Table,
+};
+
+use crate::db::banned_keyword::{Column, Entity};
+
+#[derive(DeriveMigrationName)]
+pub(crate) struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.create_table(
+				Table::create()
+					.table(Entity)
+					.if_not_exists()
+					.col(
+						ColumnDef::new(Column::GuildId)
+							.big_integer()
+							.not_null(),
+					)
+					.col(ColumnDef::new(Column::Word).string().not_null())
+					.primary_key(
+						Index::create().col(Column::GuildId).col(Column::Word),
+					)
+					.to_owned(),
+			)
+			.await
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.drop_table(Table::drop().table(Entity).to_owned())
+			.await
+	}
+}
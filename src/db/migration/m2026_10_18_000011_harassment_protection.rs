@@ -0,0 +1,68 @@
+use sea_orm::sea_query::Index;
+use sea_orm_migration::prelude::{
+	async_trait, ColumnDef, DbErr, DeriveMigrationName, MigrationTrait,
+	SchemaManager, Table,
+};
+
+use crate::db::{block, revoked_member};
+
+#[derive(DeriveMigrationName)]
+pub(crate) struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.alter_table(
+				Table::alter()
+					.table(block::Entity)
+					.add_column(
+						ColumnDef::new(block::Column::HideMessages)
+							.boolean()
+							.not_null()
+							.default(false),
+					)
+					.to_owned(),
+			)
+			.await?;
+
+		manager
+			.create_table(
+				Table::create()
+					.table(revoked_member::Entity)
+					.if_not_exists()
+					.col(
+						ColumnDef::new(revoked_member::Column::GuildId)
+							.big_integer()
+							.not_null(),
+					)
+					.col(
+						ColumnDef::new(revoked_member::Column::UserId)
+							.big_integer()
+							.not_null(),
+					)
+					.primary_key(
+						Index::create()
+							.col(revoked_member::Column::GuildId)
+							.col(revoked_member::Column::UserId),
+					)
+					.to_owned(),
+			)
+			.await
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.drop_table(Table::drop().table(revoked_member::Entity).to_owned())
+			.await?;
+
+		manager
+			.alter_table(
+				Table::alter()
+					.table(block::Entity)
+					.drop_column(block::Column::HideMessages)
+					.to_owned(),
+			)
+			.await
+	}
+}
@@ -0,0 +1,101 @@
+use sea_orm::sea_query::Index;
+use sea_orm_migration::prelude::{
+	async_trait, ColumnDef, DbErr, DeriveMigrationName, MigrationTrait,
+	SchemaManager, Table,
+};
+
+use crate::db::{follow, keyword_author};
+
+#[derive(DeriveMigrationName)]
+pub(crate) struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.create_table(
+				Table::create()
+					.table(keyword_author::Entity)
+					.if_not_exists()
+					.col(
+						ColumnDef::new(keyword_author::Column::UserId)
+							.big_integer()
+							.not_null(),
+					)
+					.col(
+						ColumnDef::new(keyword_author::Column::GuildId)
+							.big_integer()
+							.not_null(),
+					)
+					.col(
+						ColumnDef::new(keyword_author::Column::Keyword)
+							.string()
+							.not_null(),
+					)
+					.col(
+						ColumnDef::new(keyword_author::Column::AuthorId)
+							.big_integer()
+							.not_null(),
+					)
+					.col(
+						ColumnDef::new(keyword_author::Column::IsRole)
+							.boolean()
+							.not_null(),
+					)
+					.primary_key(
+						Index::create()
+							.col(keyword_author::Column::UserId)
+							.col(keyword_author::Column::GuildId)
+							.col(keyword_author::Column::Keyword)
+							.col(keyword_author::Column::AuthorId),
+					)
+					.to_owned(),
+			)
+			.await?;
+
+		manager
+			.create_table(
+				Table::create()
+					.table(follow::Entity)
+					.if_not_exists()
+					.col(
+						ColumnDef::new(follow::Column::UserId)
+							.big_integer()
+							.not_null(),
+					)
+					.col(
+						ColumnDef::new(follow::Column::FollowedId)
+							.big_integer()
+							.not_null(),
+					)
+					.col(
+						ColumnDef::new(follow::Column::ChannelId)
+							.big_integer()
+							.not_null(),
+					)
+					.col(
+						ColumnDef::new(follow::Column::GuildId)
+							.big_integer()
+							.not_null(),
+					)
+					.primary_key(
+						Index::create()
+							.col(follow::Column::UserId)
+							.col(follow::Column::FollowedId)
+							.col(follow::Column::ChannelId),
+					)
+					.to_owned(),
+			)
+			.await
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.drop_table(Table::drop().table(follow::Entity).to_owned())
+			.await?;
+
+		manager
+			.drop_table(Table::drop().table(keyword_author::Entity).to_owned())
+			.await
+	}
+}
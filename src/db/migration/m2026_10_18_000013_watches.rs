@@ -0,0 +1,55 @@
+use sea_orm::sea_query::Index;
+use sea_orm_migration::prelude::{
+	async_trait, ColumnDef, DbErr, DeriveMigrationName, MigrationTrait,
+	SchemaManager, Table,
+};
+
+use crate::db::watch;
+
+#[derive(DeriveMigrationName)]
+pub(crate) struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.create_table(
+				Table::create()
+					.table(watch::Entity)
+					.if_not_exists()
+					.col(
+						ColumnDef::new(watch::Column::UserId)
+							.big_integer()
+							.not_null(),
+					)
+					.col(
+						ColumnDef::new(watch::Column::ChannelId)
+							.big_integer()
+							.not_null(),
+					)
+					.col(
+						ColumnDef::new(watch::Column::GuildId)
+							.big_integer()
+							.not_null(),
+					)
+					.col(
+						ColumnDef::new(watch::Column::Thread)
+							.boolean()
+							.not_null(),
+					)
+					.primary_key(
+						Index::create()
+							.col(watch::Column::UserId)
+							.col(watch::Column::ChannelId),
+					)
+					.to_owned(),
+			)
+			.await
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.drop_table(Table::drop().table(watch::Entity).to_owned())
+			.await
+	}
+}
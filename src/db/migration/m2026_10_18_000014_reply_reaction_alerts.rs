@@ -0,0 +1,64 @@
+use sea_orm::sea_query::Index;
+use sea_orm_migration::prelude::{
+	async_trait, ColumnDef, DbErr, DeriveMigrationName, MigrationTrait,
+	SchemaManager, Table,
+};
+
+use crate::db::{reaction_alert, reply_alert};
+
+#[derive(DeriveMigrationName)]
+pub(crate) struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.create_table(
+				Table::create()
+					.table(reply_alert::Entity)
+					.if_not_exists()
+					.col(
+						ColumnDef::new(reply_alert::Column::UserId)
+							.big_integer()
+							.not_null()
+							.primary_key(),
+					)
+					.to_owned(),
+			)
+			.await?;
+
+		manager
+			.create_table(
+				Table::create()
+					.table(reaction_alert::Entity)
+					.if_not_exists()
+					.col(
+						ColumnDef::new(reaction_alert::Column::UserId)
+							.big_integer()
+							.not_null(),
+					)
+					.col(
+						ColumnDef::new(reaction_alert::Column::Emoji)
+							.string()
+							.not_null(),
+					)
+					.primary_key(
+						Index::create()
+							.col(reaction_alert::Column::UserId)
+							.col(reaction_alert::Column::Emoji),
+					)
+					.to_owned(),
+			)
+			.await
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.drop_table(Table::drop().table(reaction_alert::Entity).to_owned())
+			.await?;
+
+		manager
+			.drop_table(Table::drop().table(reply_alert::Entity).to_owned())
+			.await
+	}
+}
@@ -0,0 +1,62 @@
+use sea_orm::sea_query::Index;
+use sea_orm_migration::prelude::{
+	async_trait, ColumnDef, DbErr, DeriveMigrationName, MigrationTrait,
+	SchemaManager, Table,
+};
+
+use crate::db::keyword_channel;
+
+#[derive(DeriveMigrationName)]
+pub(crate) struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.create_table(
+				Table::create()
+					.table(keyword_channel::Entity)
+					.if_not_exists()
+					.col(
+						ColumnDef::new(keyword_channel::Column::UserId)
+							.big_integer()
+							.not_null(),
+					)
+					.col(
+						ColumnDef::new(keyword_channel::Column::GuildId)
+							.big_integer()
+							.not_null(),
+					)
+					.col(
+						ColumnDef::new(keyword_channel::Column::Keyword)
+							.string()
+							.not_null(),
+					)
+					.col(
+						ColumnDef::new(keyword_channel::Column::ChannelId)
+							.big_integer()
+							.not_null(),
+					)
+					.col(
+						ColumnDef::new(keyword_channel::Column::Excluded)
+							.boolean()
+							.not_null(),
+					)
+					.primary_key(
+						Index::create()
+							.col(keyword_channel::Column::UserId)
+							.col(keyword_channel::Column::GuildId)
+							.col(keyword_channel::Column::Keyword)
+							.col(keyword_channel::Column::ChannelId),
+					)
+					.to_owned(),
+			)
+			.await
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.drop_table(Table::drop().table(keyword_channel::Entity).to_owned())
+			.await
+	}
+}
@@ -0,0 +1,144 @@
+use sea_orm::sea_query::{
+	Alias, Expr, Index, IntoIden, Query, TableCreateStatement,
+};
+use sea_orm_migration::prelude::{
+	async_trait, ColumnDef, DbErr, DeriveMigrationName, MigrationTrait,
+	SchemaManager, Table,
+};
+
+use crate::db::ignore::{self, Column};
+
+#[derive(DeriveMigrationName)]
+pub(crate) struct Migration;
+
+/// Creates the ignores table with the given name, with or without the columns
+/// for limiting ignored phrases to a channel or keyword.
+fn create_ignores(
+	table: impl IntoIden + 'static,
+	scoped: bool,
+) -> TableCreateStatement {
+	let mut statement = Table::create();
+
+	statement
+		.table(table)
+		.col(ColumnDef::new(Column::Phrase).string().not_null())
+		.col(ColumnDef::new(Column::UserId).big_integer().not_null())
+		.col(ColumnDef::new(Column::GuildId).big_integer().not_null());
+
+	let mut primary_key = Index::create();
+	primary_key
+		.col(Column::Phrase)
+		.col(Column::UserId)
+		.col(Column::GuildId);
+
+	if scoped {
+		statement
+			.col(
+				ColumnDef::new(Column::ChannelId)
+					.big_integer()
+					.not_null()
+					.default(0),
+			)
+			.col(
+				ColumnDef::new(Column::Keyword)
+					.string()
+					.not_null()
+					.default(""),
+			);
+
+		primary_key.col(Column::ChannelId).col(Column::Keyword);
+	}
+
+	statement.primary_key(&mut primary_key).to_owned()
+}
+
+/// Copies the ignored phrases from one table to another.
+///
+/// Ignored phrases limited to a channel or keyword are left out when copying
+/// to a table without the columns for them.
+async fn copy_ignores(
+	manager: &SchemaManager<'_>,
+	(from, from_scoped): (impl IntoIden + 'static, bool),
+	(to, to_scoped): (impl IntoIden + 'static, bool),
+) -> Result<(), DbErr> {
+	let mut columns = vec![Column::Phrase, Column::UserId, Column::GuildId];
+
+	let mut select = Query::select();
+	select.from(from);
+
+	match (from_scoped, to_scoped) {
+		(true, true) => columns.extend([Column::ChannelId, Column::Keyword]),
+		(true, false) => {
+			select
+				.and_where(Expr::col(Column::ChannelId).eq(0))
+				.and_where(Expr::col(Column::Keyword).eq(""));
+		}
+		(false, _) => {}
+	}
+
+	select.columns(columns.clone());
+
+	manager
+		.exec_stmt(
+			Query::insert()
+				.into_table(to)
+				.columns(columns)
+				.select_from(select)
+				.map_err(|e| DbErr::Migration(e.to_string()))?
+				.to_owned(),
+		)
+		.await
+}
+
+/// Rebuilds the ignores table with or without the columns for limiting
+/// ignored phrases to a channel or keyword.
+///
+/// The old table is dropped before the new one is created, so the new primary
+/// key gets the usual name.
+async fn rebuild_ignores(
+	manager: &SchemaManager<'_>,
+	scoped: bool,
+) -> Result<(), DbErr> {
+	let tmp_table = Alias::new("__migrated_guild_ignores");
+
+	manager
+		.create_table(create_ignores(tmp_table.clone(), scoped))
+		.await?;
+
+	copy_ignores(
+		manager,
+		(ignore::Entity, !scoped),
+		(tmp_table.clone(), scoped),
+	)
+	.await?;
+
+	manager
+		.drop_table(Table::drop().table(ignore::Entity).to_owned())
+		.await?;
+
+	manager
+		.create_table(create_ignores(ignore::Entity, scoped))
+		.await?;
+
+	copy_ignores(
+		manager,
+		(tmp_table.clone(), scoped),
+		(ignore::Entity, scoped),
+	)
+	.await?;
+
+	manager
+		.drop_table(Table::drop().table(tmp_table).to_owned())
+		.await
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		rebuild_ignores(manager, true).await
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		rebuild_ignores(manager, false).await
+	}
+}
@@ -0,0 +1,50 @@
+use sea_orm_migration::prelude::{
+	async_trait, ColumnDef, DbErr, DeriveMigrationName, MigrationTrait,
+	SchemaManager, Table,
+};
+
+use crate::db::notification::{self, Column};
+
+#[derive(DeriveMigrationName)]
+pub(crate) struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		// SQLite can only add one column per statement
+		let columns = [
+			ColumnDef::new(Column::DeliveryMethod)
+				.small_integer()
+				.to_owned(),
+			ColumnDef::new(Column::DeliveryTarget).string().to_owned(),
+		];
+
+		for mut column in columns {
+			manager
+				.alter_table(
+					Table::alter()
+						.table(notification::Entity)
+						.add_column(&mut column)
+						.to_owned(),
+				)
+				.await?;
+		}
+
+		Ok(())
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		for column in [Column::DeliveryMethod, Column::DeliveryTarget] {
+			manager
+				.alter_table(
+					Table::alter()
+						.table(notification::Entity)
+						.drop_column(column)
+						.to_owned(),
+				)
+				.await?;
+		}
+
+		Ok(())
+	}
+}
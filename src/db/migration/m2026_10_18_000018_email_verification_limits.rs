@@ -0,0 +1,77 @@
+use sea_orm::sea_query::{Expr, Query};
+use sea_orm_migration::prelude::{
+	async_trait, ColumnDef, DbErr, DeriveMigrationName, MigrationTrait,
+	SchemaManager, Table,
+};
+
+use crate::db::{
+	email,
+	email_limit::{self, Column},
+};
+
+#[derive(DeriveMigrationName)]
+pub(crate) struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+	async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.create_table(
+				Table::create()
+					.table(email_limit::Entity)
+					.if_not_exists()
+					.col(
+						ColumnDef::new(Column::UserId)
+							.big_integer()
+							.not_null()
+							.primary_key(),
+					)
+					.col(
+						ColumnDef::new(Column::LastSent)
+							.big_integer()
+							.not_null(),
+					)
+					.col(
+						ColumnDef::new(Column::WindowStart)
+							.big_integer()
+							.not_null(),
+					)
+					.col(
+						ColumnDef::new(Column::Attempts)
+							.integer()
+							.not_null()
+							.default(0),
+					)
+					.to_owned(),
+			)
+			.await?;
+
+		// pending codes were stored in plain text; they're hashed now, so the
+		// old ones can't be checked anymore
+		manager
+			.exec_stmt(
+				Query::update()
+					.table(email::Entity)
+					.value(email::Column::Code, Expr::value(None::<String>))
+					.value(email::Column::CodeExpires, Expr::value(None::<i64>))
+					.to_owned(),
+			)
+			.await
+	}
+
+	async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+		manager
+			.exec_stmt(
+				Query::update()
+					.table(email::Entity)
+					.value(email::Column::Code, Expr::value(None::<String>))
+					.value(email::Column::CodeExpires, Expr::value(None::<i64>))
+					.to_owned(),
+			)
+			.await?;
+
+		manager
+			.drop_table(Table::drop().table(email_limit::Entity).to_owned())
+			.await
+	}
+}
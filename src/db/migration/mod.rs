@@ -3,6 +3,25 @@ use sea_orm_migration::{MigrationTrait, MigratorTrait};
 mod m2022_08_04_000001_init;
 mod m2023_01_08_000001_composite_notification_key;
 mod m2023_05_18_000001_rename_pkey_index;
+mod m2026_10_18_000001_delivery_methods;
+mod m2026_10_18_000002_email_addresses;
+mod m2026_10_18_000003_fallback_threads;
+mod m2026_10_18_000004_notification_history;
+mod m2026_10_18_000005_keyword_stats;
+mod m2026_10_18_000006_user_locales;
+mod m2026_10_18_000007_guild_settings;
+mod m2026_10_18_000008_team_keywords;
+mod m2026_10_18_000009_keyword_packs;
+mod m2026_10_18_000010_banned_keywords;
+mod m2026_10_18_000011_harassment_protection;
+mod m2026_10_18_000012_follows;
+mod m2026_10_18_000013_watches;
+mod m2026_10_18_000014_reply_reaction_alerts;
+mod m2026_10_18_000015_keyword_channels;
+mod m2026_10_18_000016_scoped_ignores;
+mod m2026_10_18_000017_notification_delivery;
+mod m2026_10_18_000018_email_verification_limits;
+mod m2026_10_18_000019_notification_triggers;
 
 pub(crate) struct Migrator;
 
@@ -12,6 +31,25 @@ impl MigratorTrait for Migrator {
 			Box::new(m2022_08_04_000001_init::Migration),
 			Box::new(m2023_01_08_000001_composite_notification_key::Migration),
 			Box::new(m2023_05_18_000001_rename_pkey_index::Migration),
+			Box::new(m2026_10_18_000001_delivery_methods::Migration),
+			Box::new(m2026_10_18_000002_email_addresses::Migration),
+			Box::new(m2026_10_18_000003_fallback_threads::Migration),
+			Box::new(m2026_10_18_000004_notification_history::Migration),
+			Box::new(m2026_10_18_000005_keyword_stats::Migration),
+			Box::new(m2026_10_18_000006_user_locales::Migration),
+			Box::new(m2026_10_18_000007_guild_settings::Migration),
+			Box::new(m2026_10_18_000008_team_keywords::Migration),
+			Box::new(m2026_10_18_000009_keyword_packs::Migration),
+			Box::new(m2026_10_18_000010_banned_keywords::Migration),
+			Box::new(m2026_10_18_000011_harassment_protection::Migration),
+			Box::new(m2026_10_18_000012_follows::Migration),
+			Box::new(m2026_10_18_000013_watches::Migration),
+			Box::new(m2026_10_18_000014_reply_reaction_alerts::Migration),
+			Box::new(m2026_10_18_000015_keyword_channels::Migration),
+			Box::new(m2026_10_18_000016_scoped_ignores::Migration),
+			Box::new(m2026_10_18_000017_notification_delivery::Migration),
+			Box::new(m2026_10_18_000018_email_verification_limits::Migration),
+			Box::new(m2026_10_18_000019_notification_triggers::Migration),
 		]
 	}
 }
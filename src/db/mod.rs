@@ -11,34 +11,77 @@ compile_error!("The sqlite feature or the postgresql feature must be enabled");
 
 #[cfg(feature = "backup")]
 mod backup;
+mod banned_keyword;
 mod block;
 mod channel_keyword;
+mod delivery;
+mod email;
+mod email_limit;
+mod excluded_channel;
+mod fallback_channel;
+mod fallback_thread;
+mod follow;
 mod guild_keyword;
+mod guild_settings;
 mod ignore;
 mod keyword;
+mod keyword_author;
+mod keyword_channel;
+mod keyword_pack;
+mod keyword_stats;
 mod migration;
 mod mute;
 mod notification;
 mod opt_out;
+mod pack_subscription;
+mod reaction_alert;
+mod reply_alert;
+mod revoked_member;
+mod settings_change;
+mod team_keyword;
+mod team_keyword_opt_out;
+mod user_locale;
 mod user_state;
+mod watch;
 
 use anyhow::{anyhow, Result};
 use once_cell::sync::OnceCell;
 use sea_orm::{Database, DatabaseConnection};
 use sea_orm_migration::MigratorTrait;
-use serenity::model::id::{ChannelId, GuildId, MessageId, UserId};
+use serenity::model::id::{ChannelId, GuildId, MessageId, RoleId, UserId};
 use tracing::info;
 
 use self::migration::Migrator;
 #[cfg(feature = "bot")]
 pub(crate) use self::{
+	banned_keyword::BannedKeyword,
 	block::Block,
+	delivery::{Delivery, DeliveryMethod},
+	email::{CodeRequest, EmailAddress, Verification},
+	excluded_channel::ExcludedChannel,
+	fallback_channel::FallbackChannel,
+	fallback_thread::FallbackThread,
+	follow::Follow,
+	guild_settings::GuildSettings,
 	ignore::Ignore,
-	keyword::{Keyword, KeywordKind},
+	keyword::{Keyword, KeywordKind, KeywordOptions},
+	keyword_author::{AuthorFilter, KeywordAuthor},
+	keyword_channel::KeywordChannel,
+	keyword_pack::KeywordPack,
+	keyword_stats::{KeywordCounts, KeywordStats},
 	mute::Mute,
-	notification::Notification,
+	notification::{HistoryFilter, Notification, NotificationTrigger},
 	opt_out::OptOut,
+	pack_subscription::PackSubscription,
+	reaction_alert::ReactionAlert,
+	reply_alert::ReplyAlert,
+	revoked_member::RevokedMember,
+	settings_change::SettingsChange,
+	team_keyword::TeamKeyword,
+	team_keyword_opt_out::TeamKeywordOptOut,
+	user_locale::UserLocale,
 	user_state::{UserState, UserStateKind},
+	watch::Watch,
 };
 use crate::settings::settings;
 
@@ -186,4 +229,4 @@ macro_rules! impl_id_ext {
 	() => {};
 }
 
-impl_id_ext!(UserId, ChannelId, GuildId, MessageId);
+impl_id_ext!(UserId, ChannelId, GuildId, MessageId, RoleId);
@@ -5,19 +5,21 @@
 
 use std::time::{SystemTime, UNIX_EPOCH};
 
-use anyhow::Result;
-use futures_util::TryStreamExt;
+use anyhow::{bail, Result};
+use futures_util::{future::ready, TryStreamExt};
 use sea_orm::{
 	entity::prelude::{
 		DeriveActiveModelBehavior, DeriveEntityModel, DerivePrimaryKey,
 		DeriveRelation, EntityTrait, EnumIter, PrimaryKeyTrait,
 	},
-	ColumnTrait, Condition, IntoActiveModel, QueryFilter, QueryOrder,
-	QuerySelect,
+	sea_query::{Expr, Func, LikeExpr},
+	ColumnTrait, Condition, IntoActiveModel, PaginatorTrait, QueryFilter,
+	QueryOrder, QuerySelect,
 };
-use serenity::model::id::{MessageId, UserId};
+use serenity::model::id::{ChannelId, GuildId, MessageId, UserId};
+use url::Url;
 
-use super::{connection, DbInt, IdDbExt};
+use super::{connection, DbInt, DeliveryMethod, IdDbExt};
 use crate::global::DISCORD_EPOCH;
 
 #[derive(
@@ -31,6 +33,15 @@ pub struct Model {
 	pub(crate) notification_message: DbInt,
 	#[sea_orm(primary_key)]
 	pub(crate) keyword: String,
+	pub(crate) fallback_thread: Option<DbInt>,
+	pub(crate) guild_id: Option<DbInt>,
+	pub(crate) channel_id: Option<DbInt>,
+	pub(crate) content: Option<String>,
+	pub(crate) cleared: bool,
+	pub(crate) delivery_method: Option<u8>,
+	pub(crate) delivery_target: Option<String>,
+	pub(crate) trigger_kind: u8,
+	pub(crate) title: Option<String>,
 }
 
 #[derive(Debug, EnumIter, DeriveRelation)]
@@ -45,28 +56,211 @@ pub(crate) struct Notification {
 	pub(crate) notification_message: MessageId,
 	/// The keyword in the original message that caused the notification to be
 	/// sent.
+	///
+	/// Empty for notifications that weren't caused by a keyword.
 	pub(crate) keyword: String,
 	/// The ID of the user that the notification was sent to.
 	pub(crate) user_id: UserId,
+	/// The ID of the guild fallback thread the notification was sent in, if it
+	/// couldn't be sent with the user's delivery method.
+	pub(crate) fallback_thread: Option<ChannelId>,
+	/// The ID of the guild the original message was sent in.
+	///
+	/// Only `None` for notifications sent before this was recorded.
+	pub(crate) guild_id: Option<GuildId>,
+	/// The ID of the channel the original message was sent in.
+	///
+	/// Only `None` for notifications sent before this was recorded.
+	pub(crate) channel_id: Option<ChannelId>,
+	/// The content of the original message, as of the last time the
+	/// notification was sent or updated.
+	///
+	/// Only `None` for notifications sent before this was recorded.
+	pub(crate) content: Option<String>,
+	/// The delivery method the notification was sent with.
+	///
+	/// Notifications sent before this was recorded are direct messages.
+	pub(crate) delivery: DeliveryMethod,
+	/// What caused the notification to be sent.
+	pub(crate) trigger: NotificationTrigger,
+	/// The title of the notification, kept for notifications not caused by
+	/// keywords so it can be reused when the notification is edited.
+	pub(crate) title: Option<String>,
+}
+
+/// What caused a notification to be sent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum NotificationTrigger {
+	/// The message contained one of the user's keywords.
+	Keyword,
+	/// The message was sent by a user the recipient follows.
+	Follow,
+	/// The message was sent in a channel or thread the recipient watches.
+	Watch,
+	/// The message replied to the recipient without mentioning them.
+	Reply,
+	/// Someone reacted to the recipient's message.
+	Reaction,
+}
+
+impl NotificationTrigger {
+	const KEYWORD: u8 = 0;
+	const FOLLOW: u8 = 1;
+	const WATCH: u8 = 2;
+	const REPLY: u8 = 3;
+	const REACTION: u8 = 4;
+
+	/// Gets the number identifying this trigger in the DB.
+	fn kind(self) -> u8 {
+		match self {
+			Self::Keyword => Self::KEYWORD,
+			Self::Follow => Self::FOLLOW,
+			Self::Watch => Self::WATCH,
+			Self::Reply => Self::REPLY,
+			Self::Reaction => Self::REACTION,
+		}
+	}
+
+	/// Gets the trigger identified by the given number in the DB.
+	fn from_db(kind: u8) -> Result<Self> {
+		Ok(match kind {
+			Self::KEYWORD => Self::Keyword,
+			Self::FOLLOW => Self::Follow,
+			Self::WATCH => Self::Watch,
+			Self::REPLY => Self::Reply,
+			Self::REACTION => Self::Reaction,
+			other => bail!("Unknown notification trigger: {other}"),
+		})
+	}
+}
+
+/// Criteria to filter a user's notification history by.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct HistoryFilter {
+	/// Only include notifications of this keyword.
+	pub(crate) keyword: Option<String>,
+	/// Only include notifications of messages in this guild.
+	pub(crate) guild_id: Option<GuildId>,
+	/// Only include notifications of messages sent at or after this time.
+	pub(crate) after: Option<SystemTime>,
+	/// Only include notifications of messages sent before this time.
+	pub(crate) before: Option<SystemTime>,
+	/// Only include notifications of messages containing this text.
+	pub(crate) text: Option<String>,
+}
+
+impl HistoryFilter {
+	/// Builds a condition selecting the given user's notifications matching
+	/// this filter.
+	fn condition(&self, user_id: UserId) -> Result<Condition> {
+		let mut condition =
+			Condition::all().add(Column::UserId.eq(user_id.into_db()));
+
+		if let Some(keyword) = &self.keyword {
+			condition = condition.add(Column::Keyword.eq(keyword.clone()));
+		}
+
+		if let Some(guild_id) = self.guild_id {
+			condition = condition.add(Column::GuildId.eq(guild_id.into_db()));
+		}
+
+		if let Some(after) = self.after {
+			condition = condition.add(
+				Column::OriginalMessage.gte(time_to_min_snowflake(after)?),
+			);
+		}
+
+		if let Some(before) = self.before {
+			condition = condition.add(
+				Column::OriginalMessage.lt(time_to_min_snowflake(before)?),
+			);
+		}
+
+		if let Some(text) = &self.text {
+			let pattern = format!(
+				"%{}%",
+				text.to_lowercase()
+					.replace('\\', "\\\\")
+					.replace('%', "\\%")
+					.replace('_', "\\_")
+			);
+
+			condition = condition.add(
+				Expr::expr(Func::lower(Expr::col(Column::Content)))
+					.like(LikeExpr::new(pattern).escape('\\')),
+			);
+		}
+
+		Ok(condition)
+	}
 }
 
 impl Notification {
 	/// Fetches the notifications that were sent because of the given message
-	/// from the DB.
+	/// and haven't been cleared from the DB.
 	#[tracing::instrument]
 	pub(crate) async fn notifications_of_message(
 		message_id: MessageId,
 	) -> Result<Vec<Self>> {
 		Entity::find()
 			.filter(Column::OriginalMessage.eq(message_id.into_db()))
+			.filter(Column::Cleared.eq(false))
 			.stream(connection())
 			.await?
 			.map_err(Into::into)
-			.map_ok(Notification::from)
+			.and_then(|model| ready(Notification::try_from(model)))
 			.try_collect()
 			.await
 	}
 
+	/// Fetches the notifications that were sent to the given user because of
+	/// the given message from the DB, including cleared ones.
+	#[tracing::instrument]
+	pub(crate) async fn user_notifications_of_message(
+		user_id: UserId,
+		message_id: MessageId,
+	) -> Result<Vec<Self>> {
+		Entity::find()
+			.filter(Column::UserId.eq(user_id.into_db()))
+			.filter(Column::OriginalMessage.eq(message_id.into_db()))
+			.order_by_asc(Column::Keyword)
+			.stream(connection())
+			.await?
+			.map_err(Into::into)
+			.and_then(|model| ready(Notification::try_from(model)))
+			.try_collect()
+			.await
+	}
+
+	/// Fetches a page of the given user's notifications matching the given
+	/// filter from the DB, most recent first.
+	///
+	/// Returns the notifications on the page and the total number of pages.
+	#[tracing::instrument]
+	pub(crate) async fn user_history(
+		user_id: UserId,
+		filter: &HistoryFilter,
+		page: u64,
+		page_size: u64,
+	) -> Result<(Vec<Self>, u64)> {
+		let paginator = Entity::find()
+			.filter(filter.condition(user_id)?)
+			.order_by_desc(Column::OriginalMessage)
+			.order_by_asc(Column::Keyword)
+			.paginate(connection(), page_size);
+
+		let pages = paginator.num_pages().await?;
+
+		let notifications = paginator
+			.fetch_page(page)
+			.await?
+			.into_iter()
+			.map(Notification::try_from)
+			.collect::<Result<_>>()?;
+
+		Ok((notifications, pages))
+	}
+
 	/// Inserts this notification into the DB.
 	#[tracing::instrument(
 		skip(self),
@@ -83,6 +277,22 @@ impl Notification {
 		Ok(())
 	}
 
+	/// Updates the recorded content of the original message of the given
+	/// notification message.
+	#[tracing::instrument(skip(content))]
+	pub(crate) async fn update_content(
+		message_id: MessageId,
+		content: String,
+	) -> Result<()> {
+		Entity::update_many()
+			.col_expr(Column::Content, Expr::value(content))
+			.filter(Column::NotificationMessage.eq(message_id.into_db()))
+			.exec(connection())
+			.await?;
+
+		Ok(())
+	}
+
 	/// Removes notifications in the given message from the DB.
 	#[tracing::instrument]
 	pub(crate) async fn delete_notification_message(
@@ -109,7 +319,8 @@ impl Notification {
 		Ok(())
 	}
 
-	/// Gets notifications older than a certain duration from the DB.
+	/// Gets notifications older than a certain duration that haven't been
+	/// cleared from the DB.
 	#[tracing::instrument]
 	pub(crate) async fn notifications_before(
 		count: u64,
@@ -117,12 +328,13 @@ impl Notification {
 	) -> Result<Vec<Notification>> {
 		Entity::find()
 			.filter(Column::OriginalMessage.lte(time_to_max_snowflake(time)?))
+			.filter(Column::Cleared.eq(false))
 			.order_by_asc(Column::OriginalMessage)
 			.limit(count)
 			.stream(connection())
 			.await?
 			.map_err(Into::into)
-			.map_ok(Notification::from)
+			.and_then(|model| ready(Notification::try_from(model)))
 			.try_collect()
 			.await
 	}
@@ -144,6 +356,50 @@ impl Notification {
 
 		Ok(())
 	}
+
+	/// Marks a list of notifications as cleared, keeping them in the DB only
+	/// for notification history.
+	#[tracing::instrument(skip_all)]
+	pub(crate) async fn mark_cleared(
+		message_ids: impl IntoIterator<Item = MessageId>,
+	) -> Result<()> {
+		Entity::update_many()
+			.col_expr(Column::Cleared, Expr::value(true))
+			.filter(message_ids.into_iter().fold(
+				Condition::any(),
+				|cond, id| {
+					cond.add(Column::NotificationMessage.eq(id.into_db()))
+				},
+			))
+			.exec(connection())
+			.await?;
+
+		Ok(())
+	}
+
+	/// Deletes notifications older than a certain duration from the DB.
+	///
+	/// If `only_cleared` is true, notifications that haven't been cleared yet
+	/// are kept.
+	#[tracing::instrument]
+	pub(crate) async fn delete_history_before(
+		time: SystemTime,
+		only_cleared: bool,
+	) -> Result<()> {
+		let mut condition = Condition::all()
+			.add(Column::OriginalMessage.lte(time_to_max_snowflake(time)?));
+
+		if only_cleared {
+			condition = condition.add(Column::Cleared.eq(true));
+		}
+
+		Entity::delete_many()
+			.filter(condition)
+			.exec(connection())
+			.await?;
+
+		Ok(())
+	}
 }
 
 fn time_to_min_snowflake(time: SystemTime) -> Result<u64> {
@@ -157,16 +413,32 @@ fn time_to_max_snowflake(time: SystemTime) -> Result<u64> {
 	Ok(min | (!0 >> 22))
 }
 
-impl From<Model> for Notification {
-	fn from(model: Model) -> Self {
-		Self {
+impl TryFrom<Model> for Notification {
+	type Error = anyhow::Error;
+
+	fn try_from(model: Model) -> Result<Self> {
+		let delivery = match model.delivery_method {
+			Some(kind) => {
+				DeliveryMethod::from_db(kind, model.delivery_target.as_deref())?
+			}
+			None => DeliveryMethod::DirectMessage,
+		};
+
+		Ok(Self {
 			user_id: UserId::from_db(model.user_id),
 			original_message: MessageId::from_db(model.original_message),
 			notification_message: MessageId::from_db(
 				model.notification_message,
 			),
 			keyword: model.keyword,
-		}
+			fallback_thread: model.fallback_thread.map(ChannelId::from_db),
+			guild_id: model.guild_id.map(GuildId::from_db),
+			channel_id: model.channel_id.map(ChannelId::from_db),
+			content: model.content,
+			delivery,
+			trigger: NotificationTrigger::from_db(model.trigger_kind)?,
+			title: model.title,
+		})
 	}
 }
 
@@ -177,6 +449,15 @@ impl From<Notification> for Model {
 			original_message: notification.original_message.into_db(),
 			notification_message: notification.notification_message.into_db(),
 			keyword: notification.keyword,
+			fallback_thread: notification.fallback_thread.map(IdDbExt::into_db),
+			guild_id: notification.guild_id.map(IdDbExt::into_db),
+			channel_id: notification.channel_id.map(IdDbExt::into_db),
+			content: notification.content,
+			cleared: false,
+			delivery_method: Some(notification.delivery.kind()),
+			delivery_target: notification.delivery.target().map(Url::to_string),
+			trigger_kind: notification.trigger.kind(),
+			title: notification.title,
 		}
 	}
 }
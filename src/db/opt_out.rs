@@ -15,8 +15,11 @@ use sea_orm::{
 use serenity::model::id::UserId;
 
 use super::{
-	block, channel_keyword, connection, guild_keyword, ignore, mute, DbInt,
-	IdDbExt,
+	block, channel_keyword, connection, delivery, email, email_limit,
+	fallback_thread, follow, guild_keyword, ignore, keyword_author,
+	keyword_channel, keyword_stats, mute, notification, pack_subscription,
+	reaction_alert, reply_alert, team_keyword_opt_out, user_locale, watch,
+	DbInt, IdDbExt,
 };
 
 #[derive(
@@ -108,11 +111,91 @@ impl OptOut {
 						.exec(transaction)
 						.await?;
 
+					delivery::Entity::delete_many()
+						.filter(delivery::Column::UserId.eq(user_id))
+						.exec(transaction)
+						.await?;
+
+					email::Entity::delete_many()
+						.filter(email::Column::UserId.eq(user_id))
+						.exec(transaction)
+						.await?;
+
+					email_limit::Entity::delete_many()
+						.filter(email_limit::Column::UserId.eq(user_id))
+						.exec(transaction)
+						.await?;
+
+					fallback_thread::Entity::delete_many()
+						.filter(fallback_thread::Column::UserId.eq(user_id))
+						.exec(transaction)
+						.await?;
+
+					keyword_stats::Entity::delete_many()
+						.filter(keyword_stats::Column::UserId.eq(user_id))
+						.exec(transaction)
+						.await?;
+
+					user_locale::Entity::delete_many()
+						.filter(user_locale::Column::UserId.eq(user_id))
+						.exec(transaction)
+						.await?;
+
+					pack_subscription::Entity::delete_many()
+						.filter(pack_subscription::Column::UserId.eq(user_id))
+						.exec(transaction)
+						.await?;
+
+					team_keyword_opt_out::Entity::delete_many()
+						.filter(
+							team_keyword_opt_out::Column::UserId.eq(user_id),
+						)
+						.exec(transaction)
+						.await?;
+
+					keyword_author::Entity::delete_many()
+						.filter(keyword_author::Column::UserId.eq(user_id))
+						.exec(transaction)
+						.await?;
+
+					keyword_channel::Entity::delete_many()
+						.filter(keyword_channel::Column::UserId.eq(user_id))
+						.exec(transaction)
+						.await?;
+
+					follow::Entity::delete_many()
+						.filter(follow::Column::UserId.eq(user_id))
+						.exec(transaction)
+						.await?;
+
+					watch::Entity::delete_many()
+						.filter(watch::Column::UserId.eq(user_id))
+						.exec(transaction)
+						.await?;
+
+					reply_alert::Entity::delete_many()
+						.filter(reply_alert::Column::UserId.eq(user_id))
+						.exec(transaction)
+						.await?;
+
+					reaction_alert::Entity::delete_many()
+						.filter(reaction_alert::Column::UserId.eq(user_id))
+						.exec(transaction)
+						.await?;
+
+					notification::Entity::delete_many()
+						.filter(notification::Column::UserId.eq(user_id))
+						.exec(transaction)
+						.await?;
+
 					Ok::<(), DbErr>(())
 				}
 				.boxed()
 			})
-			.await
-			.map_err(Into::into)
+			.await?;
+
+		reaction_alert::clear_alerted_emoji();
+
+		Ok(())
 	}
 }
@@ -0,0 +1,136 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! Handling for users' subscriptions to keyword packs.
+
+use anyhow::Result;
+use futures_util::TryStreamExt;
+use sea_orm::{
+	entity::prelude::{
+		DeriveActiveModelBehavior, DeriveEntityModel, DerivePrimaryKey,
+		DeriveRelation, EntityTrait, EnumIter, PrimaryKeyTrait,
+	},
+	ColumnTrait, IntoActiveModel, PaginatorTrait, QueryFilter,
+};
+use serenity::model::id::{GuildId, UserId};
+
+use super::{connection, keyword_pack, DbInt, IdDbExt};
+
+#[derive(
+	Clone, Debug, PartialEq, Eq, DeriveEntityModel, DeriveActiveModelBehavior,
+)]
+#[sea_orm(table_name = "pack_subscriptions")]
+pub struct Model {
+	#[sea_orm(primary_key)]
+	pub(crate) user_id: DbInt,
+	#[sea_orm(primary_key)]
+	pub(crate) guild_id: DbInt,
+	#[sea_orm(primary_key)]
+	pub(crate) pack: String,
+}
+
+#[derive(Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+/// A user's subscription to a keyword pack, notifying them of all of its
+/// keywords.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PackSubscription {
+	/// The subscribed user.
+	pub(crate) user_id: UserId,
+	/// The guild the pack is in.
+	pub(crate) guild_id: GuildId,
+	/// The name of the pack.
+	pub(crate) pack: String,
+}
+
+impl PackSubscription {
+	/// Fetches the given user's subscriptions from the DB.
+	#[tracing::instrument]
+	pub(crate) async fn user_subscriptions(
+		user_id: UserId,
+	) -> Result<Vec<Self>> {
+		Entity::find()
+			.filter(Column::UserId.eq(user_id.into_db()))
+			.stream(connection())
+			.await?
+			.map_err(Into::into)
+			.map_ok(Self::from)
+			.try_collect()
+			.await
+	}
+
+	/// Counts the keywords in all of the packs the given user subscribed to.
+	#[tracing::instrument]
+	pub(crate) async fn user_pack_keyword_count(
+		user_id: UserId,
+	) -> Result<u64> {
+		let mut count = 0;
+
+		for subscription in Self::user_subscriptions(user_id).await? {
+			count += keyword_pack::Entity::find()
+				.filter(
+					keyword_pack::Column::GuildId
+						.eq(subscription.guild_id.into_db()),
+				)
+				.filter(keyword_pack::Column::Name.eq(subscription.pack))
+				.count(connection())
+				.await?;
+		}
+
+		Ok(count)
+	}
+
+	/// Checks if this subscription exists in the DB.
+	#[tracing::instrument]
+	pub(crate) async fn exists(self) -> Result<bool> {
+		Ok(Entity::find_by_id((
+			self.user_id.into_db(),
+			self.guild_id.into_db(),
+			self.pack,
+		))
+		.one(connection())
+		.await?
+		.is_some())
+	}
+
+	/// Inserts this subscription into the DB.
+	#[tracing::instrument]
+	pub(crate) async fn insert(self) -> Result<()> {
+		Entity::insert(Model::from(self).into_active_model())
+			.exec(connection())
+			.await?;
+
+		Ok(())
+	}
+
+	/// Deletes this subscription from the DB.
+	#[tracing::instrument]
+	pub(crate) async fn delete(self) -> Result<()> {
+		Entity::delete(Model::from(self).into_active_model())
+			.exec(connection())
+			.await?;
+
+		Ok(())
+	}
+}
+
+impl From<Model> for PackSubscription {
+	fn from(model: Model) -> Self {
+		Self {
+			user_id: UserId::from_db(model.user_id),
+			guild_id: GuildId::from_db(model.guild_id),
+			pack: model.pack,
+		}
+	}
+}
+
+impl From<PackSubscription> for Model {
+	fn from(subscription: PackSubscription) -> Self {
+		Self {
+			user_id: subscription.user_id.into_db(),
+			guild_id: subscription.guild_id.into_db(),
+			pack: subscription.pack,
+		}
+	}
+}
@@ -0,0 +1,203 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! Handling for users notified of particular reactions to their messages.
+
+use std::{collections::HashSet, sync::Mutex};
+
+use anyhow::Result;
+use futures_util::TryStreamExt;
+use once_cell::sync::Lazy;
+use sea_orm::{
+	entity::prelude::{
+		DeriveActiveModelBehavior, DeriveEntityModel, DerivePrimaryKey,
+		DeriveRelation, EntityTrait, EnumIter, PrimaryKeyTrait,
+	},
+	ColumnTrait, IntoActiveModel, QueryFilter,
+};
+use serenity::model::id::{ChannelId, GuildId, UserId};
+
+use super::{
+	connection,
+	follow::{allowed_subscribers, subscriptions_disabled},
+	DbInt, IdDbExt,
+};
+
+/// Emoji any user added a reaction alert for, so reactions can be ignored
+/// without querying the DB.
+///
+/// Loaded on first use and cleared whenever reaction alerts change. The
+/// generation is bumped on every change, so a load that raced with one isn't
+/// cached.
+static ALERTED_EMOJI: Lazy<Mutex<AlertedEmoji>> = Lazy::new(Default::default);
+
+/// Cached emoji with reaction alerts; see [`ALERTED_EMOJI`].
+#[derive(Debug, Default)]
+struct AlertedEmoji {
+	generation: u64,
+	emoji: Option<HashSet<String>>,
+}
+
+/// Clears the cached emoji with reaction alerts, to be called after reaction
+/// alerts are added or deleted.
+pub(super) fn clear_alerted_emoji() {
+	let mut cache = ALERTED_EMOJI.lock().expect("Alerted emoji poisoned");
+	cache.generation += 1;
+	cache.emoji = None;
+}
+
+#[derive(
+	Clone, Debug, PartialEq, Eq, DeriveEntityModel, DeriveActiveModelBehavior,
+)]
+#[sea_orm(table_name = "reaction_alerts")]
+pub struct Model {
+	#[sea_orm(primary_key)]
+	pub(crate) user_id: DbInt,
+	#[sea_orm(primary_key)]
+	pub(crate) emoji: String,
+}
+
+#[derive(Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+/// A user who wants to be notified when an emoji is added as a reaction to
+/// their messages.
+#[derive(Debug, Clone)]
+pub(crate) struct ReactionAlert {
+	/// The user who is notified.
+	pub(crate) user_id: UserId,
+	/// The emoji to notify the user of, either a unicode emoji or the ID of a
+	/// custom emoji.
+	pub(crate) emoji: String,
+}
+
+impl ReactionAlert {
+	/// Fetches the reaction alerts the given user added from the DB.
+	#[tracing::instrument]
+	pub(crate) async fn user_alerts(user_id: UserId) -> Result<Vec<Self>> {
+		Entity::find()
+			.filter(Column::UserId.eq(user_id.into_db()))
+			.stream(connection())
+			.await?
+			.map_err(Into::into)
+			.map_ok(Self::from)
+			.try_collect()
+			.await
+	}
+
+	/// Checks if any user added a reaction alert for the given emoji.
+	///
+	/// The emoji with reaction alerts are cached, so this only queries the DB
+	/// after reaction alerts changed.
+	#[tracing::instrument]
+	pub(crate) async fn emoji_alerted(emoji: &str) -> Result<bool> {
+		let generation = {
+			let cache = ALERTED_EMOJI.lock().expect("Alerted emoji poisoned");
+
+			if let Some(alerted) = &cache.emoji {
+				return Ok(alerted.contains(emoji));
+			}
+
+			cache.generation
+		};
+
+		let alerted = Entity::find()
+			.stream(connection())
+			.await?
+			.map_ok(|model| model.emoji)
+			.try_collect::<HashSet<_>>()
+			.await?;
+
+		let found = alerted.contains(emoji);
+
+		let mut cache = ALERTED_EMOJI.lock().expect("Alerted emoji poisoned");
+		if cache.generation == generation {
+			cache.emoji = Some(alerted);
+		}
+
+		Ok(found)
+	}
+
+	/// Checks if this user should be notified of this reaction by the given
+	/// reactor in the given channel.
+	///
+	/// This is the case if the user added this reaction alert, and they didn't
+	/// block the reactor or mute the channel, and highlighting wasn't disabled
+	/// for them, the reactor, or the channel.
+	#[tracing::instrument]
+	pub(crate) async fn should_notify(
+		self,
+		guild_id: GuildId,
+		channel_id: ChannelId,
+		parent_id: Option<ChannelId>,
+		reactor_id: UserId,
+	) -> Result<bool> {
+		let allowed = Entity::find_by_id((self.user_id.into_db(), self.emoji))
+			.filter(allowed_subscribers(
+				Column::UserId,
+				guild_id,
+				channel_id,
+				reactor_id,
+			))
+			.one(connection())
+			.await?
+			.is_some();
+
+		Ok(allowed
+			&& !subscriptions_disabled(
+				guild_id, channel_id, parent_id, reactor_id,
+			)
+			.await?)
+	}
+
+	/// Checks if this reaction alert exists in the DB.
+	#[tracing::instrument]
+	pub(crate) async fn exists(self) -> Result<bool> {
+		Ok(Entity::find_by_id((self.user_id.into_db(), self.emoji))
+			.one(connection())
+			.await?
+			.is_some())
+	}
+
+	/// Inserts this reaction alert into the DB.
+	#[tracing::instrument]
+	pub(crate) async fn insert(self) -> Result<()> {
+		Entity::insert(Model::from(self).into_active_model())
+			.exec(connection())
+			.await?;
+
+		clear_alerted_emoji();
+
+		Ok(())
+	}
+
+	/// Deletes this reaction alert from the DB.
+	#[tracing::instrument]
+	pub(crate) async fn delete(self) -> Result<()> {
+		Entity::delete(Model::from(self).into_active_model())
+			.exec(connection())
+			.await?;
+
+		clear_alerted_emoji();
+
+		Ok(())
+	}
+}
+
+impl From<Model> for ReactionAlert {
+	fn from(model: Model) -> Self {
+		Self {
+			user_id: UserId::from_db(model.user_id),
+			emoji: model.emoji,
+		}
+	}
+}
+
+impl From<ReactionAlert> for Model {
+	fn from(alert: ReactionAlert) -> Self {
+		Self {
+			user_id: alert.user_id.into_db(),
+			emoji: alert.emoji,
+		}
+	}
+}
@@ -0,0 +1,108 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! Handling for users notified of replies that don't mention them.
+
+use anyhow::Result;
+use sea_orm::{
+	entity::prelude::{
+		DeriveActiveModelBehavior, DeriveEntityModel, DerivePrimaryKey,
+		DeriveRelation, EntityTrait, EnumIter, PrimaryKeyTrait,
+	},
+	ActiveValue, QueryFilter,
+};
+use serenity::model::id::{ChannelId, GuildId, UserId};
+
+use super::{
+	connection,
+	follow::{allowed_subscribers, subscriptions_disabled},
+	DbInt, IdDbExt,
+};
+
+#[derive(
+	Clone, Debug, PartialEq, Eq, DeriveEntityModel, DeriveActiveModelBehavior,
+)]
+#[sea_orm(table_name = "reply_alerts")]
+pub struct Model {
+	#[sea_orm(primary_key)]
+	pub(crate) user_id: DbInt,
+}
+
+#[derive(Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+/// A user who wants to be notified when someone replies to their messages
+/// without mentioning them.
+#[derive(Debug, Clone)]
+pub(crate) struct ReplyAlert {
+	/// The user who is notified.
+	pub(crate) user_id: UserId,
+}
+
+impl ReplyAlert {
+	/// Checks if this user enabled reply alerts.
+	#[tracing::instrument]
+	pub(crate) async fn exists(self) -> Result<bool> {
+		let result = Entity::find_by_id(self.user_id.into_db())
+			.one(connection())
+			.await?;
+
+		Ok(result.is_some())
+	}
+
+	/// Checks if this user should be notified of a reply by the given author
+	/// in the given channel.
+	///
+	/// This is the case if the user enabled reply alerts, and they didn't
+	/// block the author or mute the channel, and highlighting wasn't disabled
+	/// for them, the author, or the channel.
+	#[tracing::instrument]
+	pub(crate) async fn should_notify(
+		self,
+		guild_id: GuildId,
+		channel_id: ChannelId,
+		parent_id: Option<ChannelId>,
+		author_id: UserId,
+	) -> Result<bool> {
+		let allowed = Entity::find_by_id(self.user_id.into_db())
+			.filter(allowed_subscribers(
+				Column::UserId,
+				guild_id,
+				channel_id,
+				author_id,
+			))
+			.one(connection())
+			.await?
+			.is_some();
+
+		Ok(allowed
+			&& !subscriptions_disabled(
+				guild_id, channel_id, parent_id, author_id,
+			)
+			.await?)
+	}
+
+	/// Adds this reply alert to the DB.
+	#[tracing::instrument]
+	pub(crate) async fn insert(self) -> Result<()> {
+		Entity::insert(ActiveModel {
+			user_id: ActiveValue::Set(self.user_id.into_db()),
+		})
+		.exec(connection())
+		.await?;
+
+		Ok(())
+	}
+
+	/// Deletes this reply alert from the DB.
+	#[tracing::instrument]
+	pub(crate) async fn delete(self) -> Result<()> {
+		Entity::delete(ActiveModel {
+			user_id: ActiveValue::Set(self.user_id.into_db()),
+		})
+		.exec(connection())
+		.await?;
+
+		Ok(())
+	}
+}
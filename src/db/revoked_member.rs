@@ -0,0 +1,109 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! Handling for members whose highlighting server administrators revoked.
+
+use anyhow::Result;
+use futures_util::TryStreamExt;
+use sea_orm::{
+	entity::prelude::{
+		DeriveActiveModelBehavior, DeriveEntityModel, DerivePrimaryKey,
+		DeriveRelation, EntityTrait, EnumIter, PrimaryKeyTrait,
+	},
+	ColumnTrait, IntoActiveModel, QueryFilter,
+};
+use serenity::model::id::{GuildId, UserId};
+
+use super::{connection, DbInt, IdDbExt};
+
+#[derive(
+	Clone, Debug, PartialEq, Eq, DeriveEntityModel, DeriveActiveModelBehavior,
+)]
+#[sea_orm(table_name = "revoked_members")]
+pub struct Model {
+	#[sea_orm(primary_key)]
+	pub(crate) guild_id: DbInt,
+	#[sea_orm(primary_key)]
+	pub(crate) user_id: DbInt,
+}
+
+#[derive(Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+/// A member who can't use highlighting in a guild, chosen by the guild's
+/// administrators.
+#[derive(Debug, Clone)]
+pub(crate) struct RevokedMember {
+	/// The guild the member can't use highlighting in.
+	pub(crate) guild_id: GuildId,
+	/// The member whose highlighting was revoked.
+	pub(crate) user_id: UserId,
+}
+
+impl RevokedMember {
+	/// Fetches the revoked members of the guild with the given ID from the
+	/// DB.
+	#[tracing::instrument]
+	pub(crate) async fn guild_revocations(
+		guild_id: GuildId,
+	) -> Result<Vec<Self>> {
+		Entity::find()
+			.filter(Column::GuildId.eq(guild_id.into_db()))
+			.stream(connection())
+			.await?
+			.map_err(Into::into)
+			.map_ok(Self::from)
+			.try_collect()
+			.await
+	}
+
+	/// Checks if this revocation exists in the DB.
+	#[tracing::instrument]
+	pub(crate) async fn exists(self) -> Result<bool> {
+		Ok(Entity::find_by_id((
+			self.guild_id.into_db(),
+			self.user_id.into_db(),
+		))
+		.one(connection())
+		.await?
+		.is_some())
+	}
+
+	/// Inserts this revocation into the DB.
+	#[tracing::instrument]
+	pub(crate) async fn insert(self) -> Result<()> {
+		Entity::insert(Model::from(self).into_active_model())
+			.exec(connection())
+			.await?;
+
+		Ok(())
+	}
+
+	/// Deletes this revocation from the DB.
+	#[tracing::instrument]
+	pub(crate) async fn delete(self) -> Result<()> {
+		Entity::delete(Model::from(self).into_active_model())
+			.exec(connection())
+			.await?;
+
+		Ok(())
+	}
+}
+
+impl From<Model> for RevokedMember {
+	fn from(model: Model) -> Self {
+		Self {
+			guild_id: GuildId::from_db(model.guild_id),
+			user_id: UserId::from_db(model.user_id),
+		}
+	}
+}
+
+impl From<RevokedMember> for Model {
+	fn from(revoked: RevokedMember) -> Self {
+		Self {
+			guild_id: revoked.guild_id.into_db(),
+			user_id: revoked.user_id.into_db(),
+		}
+	}
+}
@@ -0,0 +1,97 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! Handling for the record of changes administrators make to their servers'
+//! settings.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::Result;
+use sea_orm::{
+	entity::prelude::{
+		DeriveActiveModelBehavior, DeriveEntityModel, DerivePrimaryKey,
+		DeriveRelation, EntityTrait, EnumIter, PrimaryKeyTrait,
+	},
+	ActiveValue, ColumnTrait, QueryFilter, QueryOrder, QuerySelect,
+};
+use serenity::model::id::{GuildId, UserId};
+
+use super::{connection, DbInt, IdDbExt};
+
+#[derive(
+	Clone, Debug, PartialEq, Eq, DeriveEntityModel, DeriveActiveModelBehavior,
+)]
+#[sea_orm(table_name = "guild_settings_changes")]
+pub struct Model {
+	#[sea_orm(primary_key)]
+	pub(crate) id: i32,
+	pub(crate) guild_id: DbInt,
+	pub(crate) user_id: DbInt,
+	pub(crate) description: String,
+	pub(crate) time: i64,
+}
+
+#[derive(Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+/// A change an administrator made to a guild's settings.
+#[derive(Debug, Clone)]
+pub(crate) struct SettingsChange {
+	/// The guild whose settings were changed.
+	pub(crate) guild_id: GuildId,
+	/// The administrator that made the change.
+	pub(crate) user_id: UserId,
+	/// What was changed.
+	pub(crate) description: String,
+	/// When the change was made.
+	pub(crate) time: SystemTime,
+}
+
+impl SettingsChange {
+	/// Fetches the most recent changes to the settings of the guild with the
+	/// given ID, newest first.
+	#[tracing::instrument]
+	pub(crate) async fn guild_changes(
+		guild_id: GuildId,
+		limit: u64,
+	) -> Result<Vec<Self>> {
+		Ok(Entity::find()
+			.filter(Column::GuildId.eq(guild_id.into_db()))
+			.order_by_desc(Column::Id)
+			.limit(limit)
+			.all(connection())
+			.await?
+			.into_iter()
+			.map(Self::from)
+			.collect())
+	}
+
+	/// Inserts this change into the DB.
+	#[tracing::instrument]
+	pub(crate) async fn insert(self) -> Result<()> {
+		let time = self.time.duration_since(UNIX_EPOCH)?.as_secs() as i64;
+
+		Entity::insert(ActiveModel {
+			id: ActiveValue::NotSet,
+			guild_id: ActiveValue::Set(self.guild_id.into_db()),
+			user_id: ActiveValue::Set(self.user_id.into_db()),
+			description: ActiveValue::Set(self.description),
+			time: ActiveValue::Set(time),
+		})
+		.exec(connection())
+		.await?;
+
+		Ok(())
+	}
+}
+
+impl From<Model> for SettingsChange {
+	fn from(model: Model) -> Self {
+		Self {
+			guild_id: GuildId::from_db(model.guild_id),
+			user_id: UserId::from_db(model.user_id),
+			description: model.description,
+			time: UNIX_EPOCH + Duration::from_secs(model.time as u64),
+		}
+	}
+}
@@ -0,0 +1,143 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! Handling for team keywords, which notify every member of a role.
+
+use anyhow::Result;
+use futures_util::TryStreamExt;
+use sea_orm::{
+	entity::prelude::{
+		DeriveActiveModelBehavior, DeriveEntityModel, DerivePrimaryKey,
+		DeriveRelation, EntityTrait, EnumIter, PrimaryKeyTrait,
+	},
+	ColumnTrait, Condition, IntoActiveModel, QueryFilter,
+};
+use serenity::model::id::{GuildId, RoleId};
+
+use super::{connection, team_keyword_opt_out, DbInt, IdDbExt};
+
+#[derive(
+	Clone, Debug, PartialEq, Eq, DeriveEntityModel, DeriveActiveModelBehavior,
+)]
+#[sea_orm(table_name = "team_keywords")]
+pub struct Model {
+	#[sea_orm(primary_key)]
+	pub(crate) role_id: DbInt,
+	#[sea_orm(primary_key)]
+	pub(crate) keyword: String,
+	pub(crate) guild_id: DbInt,
+}
+
+#[derive(Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+/// A keyword a guild's administrators added for every member of a role.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct TeamKeyword {
+	/// The guild the role is in.
+	pub(crate) guild_id: GuildId,
+	/// The role whose members are notified.
+	pub(crate) role_id: RoleId,
+	/// The keyword.
+	pub(crate) keyword: String,
+}
+
+impl TeamKeyword {
+	/// Fetches the team keywords of the guild with the given ID from the DB.
+	#[tracing::instrument]
+	pub(crate) async fn guild_team_keywords(
+		guild_id: GuildId,
+	) -> Result<Vec<Self>> {
+		Entity::find()
+			.filter(Column::GuildId.eq(guild_id.into_db()))
+			.stream(connection())
+			.await?
+			.map_err(Into::into)
+			.map_ok(Self::from)
+			.try_collect()
+			.await
+	}
+
+	/// Fetches the team keywords of any of the given roles from the DB.
+	#[tracing::instrument(skip(role_ids))]
+	pub(crate) async fn role_team_keywords(
+		role_ids: impl IntoIterator<Item = RoleId>,
+	) -> Result<Vec<Self>> {
+		Entity::find()
+			.filter(
+				Column::RoleId
+					.is_in(role_ids.into_iter().map(IdDbExt::into_db)),
+			)
+			.stream(connection())
+			.await?
+			.map_err(Into::into)
+			.map_ok(Self::from)
+			.try_collect()
+			.await
+	}
+
+	/// Checks if this team keyword exists in the DB.
+	#[tracing::instrument]
+	pub(crate) async fn exists(self) -> Result<bool> {
+		Ok(Entity::find_by_id((self.role_id.into_db(), self.keyword))
+			.one(connection())
+			.await?
+			.is_some())
+	}
+
+	/// Inserts this team keyword into the DB.
+	#[tracing::instrument]
+	pub(crate) async fn insert(self) -> Result<()> {
+		Entity::insert(Model::from(self).into_active_model())
+			.exec(connection())
+			.await?;
+
+		Ok(())
+	}
+
+	/// Deletes this team keyword from the DB, along with members' opt-outs of
+	/// it.
+	#[tracing::instrument]
+	pub(crate) async fn delete(self) -> Result<()> {
+		team_keyword_opt_out::Entity::delete_many()
+			.filter(
+				Condition::all()
+					.add(
+						team_keyword_opt_out::Column::RoleId
+							.eq(self.role_id.into_db()),
+					)
+					.add(
+						team_keyword_opt_out::Column::Keyword
+							.eq(&*self.keyword),
+					),
+			)
+			.exec(connection())
+			.await?;
+
+		Entity::delete(Model::from(self).into_active_model())
+			.exec(connection())
+			.await?;
+
+		Ok(())
+	}
+}
+
+impl From<Model> for TeamKeyword {
+	fn from(model: Model) -> Self {
+		Self {
+			guild_id: GuildId::from_db(model.guild_id),
+			role_id: RoleId::from_db(model.role_id),
+			keyword: model.keyword,
+		}
+	}
+}
+
+impl From<TeamKeyword> for Model {
+	fn from(keyword: TeamKeyword) -> Self {
+		Self {
+			role_id: keyword.role_id.into_db(),
+			keyword: keyword.keyword,
+			guild_id: keyword.guild_id.into_db(),
+		}
+	}
+}
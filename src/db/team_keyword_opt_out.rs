@@ -0,0 +1,132 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! Handling for members opting out of individual team keywords.
+
+use anyhow::Result;
+use futures_util::TryStreamExt;
+use sea_orm::{
+	entity::prelude::{
+		DeriveActiveModelBehavior, DeriveEntityModel, DerivePrimaryKey,
+		DeriveRelation, EntityTrait, EnumIter, PrimaryKeyTrait,
+	},
+	ColumnTrait, IntoActiveModel, QueryFilter,
+};
+use serenity::model::id::{RoleId, UserId};
+
+use super::{connection, DbInt, IdDbExt};
+
+#[derive(
+	Clone, Debug, PartialEq, Eq, DeriveEntityModel, DeriveActiveModelBehavior,
+)]
+#[sea_orm(table_name = "team_keyword_opt_outs")]
+pub struct Model {
+	#[sea_orm(primary_key)]
+	pub(crate) user_id: DbInt,
+	#[sea_orm(primary_key)]
+	pub(crate) role_id: DbInt,
+	#[sea_orm(primary_key)]
+	pub(crate) keyword: String,
+}
+
+#[derive(Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+/// A member who doesn't want to be notified about one of their role's team
+/// keywords.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct TeamKeywordOptOut {
+	/// The member who opted out.
+	pub(crate) user_id: UserId,
+	/// The role of the team keyword.
+	pub(crate) role_id: RoleId,
+	/// The team keyword.
+	pub(crate) keyword: String,
+}
+
+impl TeamKeywordOptOut {
+	/// Fetches the team keywords the given user opted out of from the DB.
+	#[tracing::instrument]
+	pub(crate) async fn user_opt_outs(user_id: UserId) -> Result<Vec<Self>> {
+		Entity::find()
+			.filter(Column::UserId.eq(user_id.into_db()))
+			.stream(connection())
+			.await?
+			.map_err(Into::into)
+			.map_ok(Self::from)
+			.try_collect()
+			.await
+	}
+
+	/// Fetches all opt-outs of any of the given roles' team keywords from the
+	/// DB.
+	#[tracing::instrument(skip(role_ids))]
+	pub(crate) async fn role_opt_outs(
+		role_ids: impl IntoIterator<Item = RoleId>,
+	) -> Result<Vec<Self>> {
+		Entity::find()
+			.filter(
+				Column::RoleId
+					.is_in(role_ids.into_iter().map(IdDbExt::into_db)),
+			)
+			.stream(connection())
+			.await?
+			.map_err(Into::into)
+			.map_ok(Self::from)
+			.try_collect()
+			.await
+	}
+
+	/// Checks if this opt-out exists in the DB.
+	#[tracing::instrument]
+	pub(crate) async fn exists(self) -> Result<bool> {
+		Ok(Entity::find_by_id((
+			self.user_id.into_db(),
+			self.role_id.into_db(),
+			self.keyword,
+		))
+		.one(connection())
+		.await?
+		.is_some())
+	}
+
+	/// Inserts this opt-out into the DB.
+	#[tracing::instrument]
+	pub(crate) async fn insert(self) -> Result<()> {
+		Entity::insert(Model::from(self).into_active_model())
+			.exec(connection())
+			.await?;
+
+		Ok(())
+	}
+
+	/// Deletes this opt-out from the DB.
+	#[tracing::instrument]
+	pub(crate) async fn delete(self) -> Result<()> {
+		Entity::delete(Model::from(self).into_active_model())
+			.exec(connection())
+			.await?;
+
+		Ok(())
+	}
+}
+
+impl From<Model> for TeamKeywordOptOut {
+	fn from(model: Model) -> Self {
+		Self {
+			user_id: UserId::from_db(model.user_id),
+			role_id: RoleId::from_db(model.role_id),
+			keyword: model.keyword,
+		}
+	}
+}
+
+impl From<TeamKeywordOptOut> for Model {
+	fn from(opt_out: TeamKeywordOptOut) -> Self {
+		Self {
+			user_id: opt_out.user_id.into_db(),
+			role_id: opt_out.role_id.into_db(),
+			keyword: opt_out.keyword,
+		}
+	}
+}
@@ -0,0 +1,77 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! Handling for the locale each user last used the bot in, so notifications
+//! can be shown in their language.
+
+use anyhow::Result;
+use sea_orm::{
+	entity::prelude::{
+		DeriveActiveModelBehavior, DeriveEntityModel, DerivePrimaryKey,
+		DeriveRelation, EntityTrait, EnumIter, PrimaryKeyTrait,
+	},
+	sea_query::OnConflict,
+	IntoActiveModel,
+};
+use serenity::model::id::UserId;
+
+use super::{connection, DbInt, IdDbExt};
+
+#[derive(
+	Clone, Debug, PartialEq, Eq, DeriveEntityModel, DeriveActiveModelBehavior,
+)]
+#[sea_orm(table_name = "user_locales")]
+pub struct Model {
+	#[sea_orm(primary_key)]
+	pub(crate) user_id: DbInt,
+	pub(crate) locale: String,
+}
+
+#[derive(Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+/// The locale a user last used the bot in.
+#[derive(Debug, Clone)]
+pub(crate) struct UserLocale {
+	pub(crate) user_id: UserId,
+	/// Discord's name for the locale, like `en-US`.
+	pub(crate) locale: String,
+}
+
+impl UserLocale {
+	/// Fetches the locale of the user with the given ID from the DB.
+	///
+	/// Returns `None` if the user hasn't used the bot since locales were
+	/// recorded.
+	#[tracing::instrument]
+	pub(crate) async fn user_locale(user_id: UserId) -> Result<Option<String>> {
+		Ok(Entity::find_by_id(user_id.into_db())
+			.one(connection())
+			.await?
+			.map(|model| model.locale))
+	}
+
+	/// Sets the locale of the user in the DB.
+	#[tracing::instrument]
+	pub(crate) async fn set(self) -> Result<()> {
+		Entity::insert(Model::from(self).into_active_model())
+			.on_conflict(
+				OnConflict::column(Column::UserId)
+					.update_column(Column::Locale)
+					.to_owned(),
+			)
+			.exec(connection())
+			.await?;
+
+		Ok(())
+	}
+}
+
+impl From<UserLocale> for Model {
+	fn from(locale: UserLocale) -> Self {
+		Self {
+			user_id: locale.user_id.into_db(),
+			locale: locale.locale,
+		}
+	}
+}
@@ -1,8 +1,8 @@
 // Copyright 2022 ThatsNoMoon
 // Licensed under the Open Software License version 3.0
 
-//! Handling for user states; whether or not the last notification DM was
-//! successful.
+//! Handling for user states; whether or not the last notification was
+//! delivered successfully.
 
 use anyhow::{bail, Result};
 use sea_orm::{
@@ -42,10 +42,21 @@ pub(crate) struct UserState {
 pub(crate) enum UserStateKind {
 	/// Indicates that the last DM sent to notify this user failed.
 	CannotDm = 0,
+	/// Indicates that the last notification sent through this user's webhook
+	/// failed.
+	WebhookFailed = 1,
+	/// Indicates that the last notification sent to this user's HTTP endpoint
+	/// failed.
+	HttpFailed = 2,
+	/// Indicates that the last email sent to notify this user failed.
+	EmailFailed = 3,
 }
 
 impl UserState {
 	const CANNOT_DM_STATE: u8 = UserStateKind::CannotDm as u8;
+	const WEBHOOK_FAILED_STATE: u8 = UserStateKind::WebhookFailed as u8;
+	const HTTP_FAILED_STATE: u8 = UserStateKind::HttpFailed as u8;
+	const EMAIL_FAILED_STATE: u8 = UserStateKind::EmailFailed as u8;
 
 	/// Fetches the state of the user with the given ID from the DB.
 	///
@@ -64,7 +75,7 @@ impl UserState {
 	pub(crate) async fn set(self) -> Result<()> {
 		Entity::insert(Model::from(self).into_active_model())
 			.on_conflict(
-				OnConflict::column(Column::State)
+				OnConflict::column(Column::UserId)
 					.update_column(Column::State)
 					.to_owned(),
 			)
@@ -99,6 +110,9 @@ impl TryFrom<Model> for UserState {
 			user_id: UserId::from_db(model.user_id),
 			state: match model.state {
 				Self::CANNOT_DM_STATE => UserStateKind::CannotDm,
+				Self::WEBHOOK_FAILED_STATE => UserStateKind::WebhookFailed,
+				Self::HTTP_FAILED_STATE => UserStateKind::HttpFailed,
+				Self::EMAIL_FAILED_STATE => UserStateKind::EmailFailed,
 				other => bail!("Unknown user state: {other}"),
 			},
 		})
@@ -0,0 +1,172 @@
+// Copyright 2026 ThatsNoMoon
+// Licensed under the Open Software License version 3.0
+
+//! Handling for users watching every message in channels or threads.
+
+use anyhow::Result;
+use futures_util::TryStreamExt;
+use sea_orm::{
+	entity::prelude::{
+		DeriveActiveModelBehavior, DeriveEntityModel, DerivePrimaryKey,
+		DeriveRelation, EntityTrait, EnumIter, PrimaryKeyTrait,
+	},
+	ColumnTrait, Condition, IntoActiveModel, PaginatorTrait, QueryFilter,
+};
+use serenity::model::id::{ChannelId, GuildId, UserId};
+
+use super::{
+	connection,
+	follow::{allowed_subscribers, subscriptions_disabled},
+	DbInt, IdDbExt,
+};
+
+#[derive(
+	Clone, Debug, PartialEq, Eq, DeriveEntityModel, DeriveActiveModelBehavior,
+)]
+#[sea_orm(table_name = "watches")]
+pub struct Model {
+	#[sea_orm(primary_key)]
+	pub(crate) user_id: DbInt,
+	#[sea_orm(primary_key)]
+	pub(crate) channel_id: DbInt,
+	pub(crate) guild_id: DbInt,
+	pub(crate) thread: bool,
+}
+
+#[derive(Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+/// A user watching a channel or thread, notifying them of every message sent
+/// there.
+#[derive(Debug, Clone)]
+pub(crate) struct Watch {
+	/// The user who is notified.
+	pub(crate) user_id: UserId,
+	/// The watched channel or thread.
+	pub(crate) channel_id: ChannelId,
+	/// The guild the channel is in.
+	pub(crate) guild_id: GuildId,
+	/// Whether the watched channel is a thread.
+	pub(crate) thread: bool,
+}
+
+impl Watch {
+	/// Fetches the watches the given user added from the DB.
+	#[tracing::instrument]
+	pub(crate) async fn user_watches(user_id: UserId) -> Result<Vec<Self>> {
+		Entity::find()
+			.filter(Column::UserId.eq(user_id.into_db()))
+			.stream(connection())
+			.await?
+			.map_err(Into::into)
+			.map_ok(Self::from)
+			.try_collect()
+			.await
+	}
+
+	/// Counts the watches the given user added.
+	#[tracing::instrument]
+	pub(crate) async fn user_watch_count(user_id: UserId) -> Result<u64> {
+		Ok(Entity::find()
+			.filter(Column::UserId.eq(user_id.into_db()))
+			.count(connection())
+			.await?)
+	}
+
+	/// Gets the users who should be notified of a message by the given
+	/// author in the given channel because they watch the channel.
+	///
+	/// The author is never returned. Watchers are skipped and no one is
+	/// returned in the same cases as
+	/// [`relevant_followers`](super::Follow::relevant_followers).
+	#[tracing::instrument]
+	pub(crate) async fn relevant_watchers(
+		guild_id: GuildId,
+		channel_id: ChannelId,
+		parent_id: Option<ChannelId>,
+		author_id: UserId,
+	) -> Result<Vec<UserId>> {
+		let watchers = Entity::find()
+			.filter(
+				Condition::all()
+					.add(Column::ChannelId.eq(channel_id.into_db()))
+					.add(Column::UserId.ne(author_id.into_db()))
+					.add(allowed_subscribers(
+						Column::UserId,
+						guild_id,
+						channel_id,
+						author_id,
+					)),
+			)
+			.stream(connection())
+			.await?
+			.map_ok(|model| UserId::from_db(model.user_id))
+			.try_collect::<Vec<_>>()
+			.await?;
+
+		if watchers.is_empty()
+			|| subscriptions_disabled(
+				guild_id, channel_id, parent_id, author_id,
+			)
+			.await?
+		{
+			return Ok(Vec::new());
+		}
+
+		Ok(watchers)
+	}
+
+	/// Checks if this watch exists in the DB.
+	#[tracing::instrument]
+	pub(crate) async fn exists(self) -> Result<bool> {
+		Ok(Entity::find_by_id((
+			self.user_id.into_db(),
+			self.channel_id.into_db(),
+		))
+		.one(connection())
+		.await?
+		.is_some())
+	}
+
+	/// Inserts this watch into the DB.
+	#[tracing::instrument]
+	pub(crate) async fn insert(self) -> Result<()> {
+		Entity::insert(Model::from(self).into_active_model())
+			.exec(connection())
+			.await?;
+
+		Ok(())
+	}
+
+	/// Deletes this watch from the DB.
+	#[tracing::instrument]
+	pub(crate) async fn delete(self) -> Result<()> {
+		Entity::delete(Model::from(self).into_active_model())
+			.exec(connection())
+			.await?;
+
+		Ok(())
+	}
+}
+
+impl From<Model> for Watch {
+	fn from(model: Model) -> Self {
+		Self {
+			user_id: UserId::from_db(model.user_id),
+			channel_id: ChannelId::from_db(model.channel_id),
+			guild_id: GuildId::from_db(model.guild_id),
+			thread: model.thread,
+		}
+	}
+}
+
+impl From<Watch> for Model {
+	fn from(watch: Watch) -> Self {
+		Self {
+			user_id: watch.user_id.into_db(),
+			channel_id: watch.channel_id.into_db(),
+			guild_id: watch.guild_id.into_db(),
+			thread: watch.thread,
+		}
+	}
+}
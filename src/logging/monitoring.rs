@@ -36,7 +36,7 @@ pub(crate) fn init<S: Subscriber + for<'span> LookupSpan<'span>>(
 			metadata.is_event()
 				|| metadata
 					.module_path()
-					.map_or(true, |path| !path.starts_with("h2::"))
+					.is_none_or(|path| !path.starts_with("h2::"))
 		}))))
 	} else {
 		Ok(None)
@@ -198,7 +198,7 @@ impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for WebhookLayer {
 
 /// Reports a panic to the configured webhook URL.
 pub(crate) fn report_panic(
-	info: &panic::PanicInfo,
+	info: &panic::PanicHookInfo,
 	url: Url,
 ) -> Result<blocking::Response> {
 	let client = BlockingClient::builder().build()?;
@@ -226,7 +226,7 @@ pub(crate) fn init() -> Option<WebhookLayer> {
 		let default_panic_hook = panic::take_hook();
 
 		let reporting_panic_hook: Box<
-			dyn Fn(&panic::PanicInfo<'_>) + Send + Sync + 'static,
+			dyn Fn(&panic::PanicHookInfo<'_>) + Send + Sync + 'static,
 		> = {
 			let url = url.clone();
 			Box::new(move |info| {
@@ -19,6 +19,8 @@ use config::{
 	builder::DefaultState, ConfigBuilder, ConfigError, Environment, File,
 	FileFormat,
 };
+#[cfg(feature = "bot")]
+use lazy_regex::Regex;
 use once_cell::sync::OnceCell;
 use serde::Deserialize;
 #[cfg(feature = "bot")]
@@ -59,6 +61,28 @@ mod duration_de {
 #[cfg(feature = "bot")]
 use duration_de::deserialize_duration;
 
+#[cfg(feature = "bot")]
+mod regex_de {
+	use lazy_regex::Regex;
+	use serde::{de, Deserialize, Deserializer};
+
+	/// Deserializes a list of regular expressions from strings.
+	pub(super) fn deserialize_regexes<'de, D>(
+		d: D,
+	) -> Result<Vec<Regex>, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		Vec::<String>::deserialize(d)?
+			.into_iter()
+			.map(|pattern| Regex::new(&pattern).map_err(de::Error::custom))
+			.collect()
+	}
+}
+
+#[cfg(feature = "bot")]
+use regex_de::deserialize_regexes;
+
 #[cfg(feature = "monitoring")]
 mod user_address {
 	use std::{
@@ -249,6 +273,23 @@ pub(crate) struct BehaviorSettings {
 	#[serde(alias = "maxkeywords")]
 	pub(crate) max_keywords: u32,
 
+	/// Shortest keywords users can add.
+	#[serde(alias = "minkeywordlength")]
+	pub(crate) min_keyword_length: usize,
+
+	/// Longest keywords users can add, if limited.
+	#[serde(alias = "maxkeywordlength", default)]
+	pub(crate) max_keyword_length: Option<usize>,
+
+	/// Patterns matching keywords users can't add.
+	#[serde(
+		alias = "forbiddenkeywords",
+		deserialize_with = "deserialize_regexes",
+		default
+	)]
+	#[cfg(feature = "bot")]
+	pub(crate) forbidden_keywords: Vec<Regex>,
+
 	/// Duration to wait for activity before sending a notification.
 	#[serde(with = "humantime_serde")]
 	#[cfg(feature = "bot")]
@@ -263,6 +304,44 @@ pub(crate) struct BehaviorSettings {
 	#[cfg(feature = "bot")]
 	pub(crate) notification_lifetime: Option<Duration>,
 
+	/// Duration to keep notifications in users' history.
+	#[serde(
+		alias = "historyretention",
+		with = "humantime_serde::option",
+		default
+	)]
+	#[cfg(feature = "bot")]
+	pub(crate) history_retention: Option<Duration>,
+
+	/// Whether users may have notifications sent to arbitrary HTTP endpoints.
+	#[serde(alias = "httpdelivery", default)]
+	pub(crate) http_delivery: bool,
+
+	/// Whether keywords of packs users subscribe to count against
+	/// `max_keywords`.
+	#[serde(alias = "packkeywordslimited", default)]
+	pub(crate) pack_keywords_limited: bool,
+
+	/// Maximum number of users one user can follow in channels.
+	#[serde(alias = "maxfollows")]
+	pub(crate) max_follows: u32,
+
+	/// Minimum duration between notifications about messages of one followed
+	/// user.
+	#[serde(alias = "followcooldown", with = "humantime_serde")]
+	#[cfg(feature = "bot")]
+	pub(crate) follow_cooldown: Duration,
+
+	/// Maximum number of channels and threads one user can watch.
+	#[serde(alias = "maxwatches")]
+	pub(crate) max_watches: u32,
+
+	/// Minimum duration between notifications about messages in one watched
+	/// channel or thread.
+	#[serde(alias = "watchinterval", with = "humantime_serde")]
+	#[cfg(feature = "bot")]
+	pub(crate) watch_interval: Duration,
+
 	/// Deprecated method to specify patience.
 	#[serde(
 		deserialize_with = "deserialize_duration",
@@ -290,6 +369,51 @@ pub(crate) struct BotSettings {
 	pub(crate) test_guild: Option<GuildId>,
 }
 
+/// Security used to connect to the SMTP server.
+#[cfg(feature = "bot")]
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum SmtpSecurity {
+	/// Upgrade a plaintext connection with STARTTLS (usually port 587).
+	#[default]
+	StartTls,
+	/// Connect with TLS from the start (usually port 465).
+	Tls,
+	/// Don't encrypt the connection at all; only for local relays.
+	None,
+}
+
+/// Settings for sending notifications by email.
+#[cfg(feature = "bot")]
+#[derive(Debug, Deserialize)]
+pub(crate) struct SmtpSettings {
+	/// Hostname of the SMTP server.
+	pub(crate) host: String,
+	/// Port of the SMTP server, if not the default for the security used.
+	pub(crate) port: Option<u16>,
+	/// Username to authenticate with.
+	pub(crate) username: Option<String>,
+	/// Password to authenticate with.
+	pub(crate) password: Option<String>,
+	/// Mailbox emails are sent from, e.g. `Highlights <bot@example.com>`.
+	pub(crate) from: String,
+	/// Security used to connect to the SMTP server.
+	#[serde(default)]
+	pub(crate) security: SmtpSecurity,
+	/// How often to send digest emails.
+	#[serde(
+		alias = "digestinterval",
+		with = "humantime_serde",
+		default = "default_digest_interval"
+	)]
+	pub(crate) digest_interval: Duration,
+}
+
+#[cfg(feature = "bot")]
+fn default_digest_interval() -> Duration {
+	Duration::from_secs(60 * 60)
+}
+
 /// Settings for various logging facilities.
 #[derive(Debug, Deserialize)]
 pub(crate) struct LoggingSettings {
@@ -344,6 +468,8 @@ pub(crate) struct Settings {
 	pub(crate) behavior: BehaviorSettings,
 	#[cfg(feature = "bot")]
 	pub(crate) bot: BotSettings,
+	#[cfg(feature = "bot")]
+	pub(crate) smtp: Option<SmtpSettings>,
 	pub(crate) logging: LoggingSettings,
 	pub(crate) database: DatabaseSettings,
 }
@@ -355,6 +481,11 @@ impl Settings {
 
 		#[cfg(feature = "bot")]
 		let b = b.set_default("behavior.max_keywords", 100i64)?
+			.set_default("behavior.min_keyword_length", 3i64)?
+			.set_default("behavior.max_follows", 10i64)?
+			.set_default("behavior.follow_cooldown", "10m")?
+			.set_default("behavior.max_watches", 5i64)?
+			.set_default("behavior.watch_interval", "15m")?
 			.set_default("behavior.patience", "2m")?
 			.set_default("bot.private", false)?;
 